@@ -60,7 +60,7 @@ mod tests {
     use super::*;
     use datamodel::{
         dml, Datamodel, DefaultValue as DMLDefault, Field, FieldArity, FieldType, IndexDefinition, Model,
-        OnDeleteStrategy, RelationField, RelationInfo, ScalarField, ScalarType, ValueGenerator,
+        ReferentialAction, RelationField, RelationInfo, ScalarField, ScalarType, ValueGenerator,
     };
     use native_types::{NativeType, PostgresType};
     use pretty_assertions::assert_eq;
@@ -97,6 +97,9 @@ mod tests {
                         .to_string(),
                 ),
                 is_embedded: false,
+                is_view: false,
+                checks: vec![],
+                datasource: None,
                 is_generated: false,
                 is_commented_out: true,
                 indices: vec![],
@@ -153,9 +156,11 @@ mod tests {
                 indices: vec![],
                 primary_key: None,
                 foreign_keys: vec![],
+                checks: vec![],
             }],
             enums: vec![],
             sequences: vec![],
+            views: vec![],
         };
         let introspection_result =
             calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
@@ -171,6 +176,9 @@ mod tests {
                 name: "Table1".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                checks: vec![],
+                datasource: None,
                 is_commented_out: false,
                 fields: vec![
                     Field::ScalarField(ScalarField::new(
@@ -234,9 +242,11 @@ mod tests {
                     constraint_name: None,
                 }),
                 foreign_keys: vec![],
+                checks: vec![],
             }],
             enums: vec![],
             sequences: vec![],
+            views: vec![],
         };
         let introspection_result =
             calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
@@ -252,6 +262,9 @@ mod tests {
                 name: "Table1".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                checks: vec![],
+                datasource: None,
                 is_commented_out: false,
                 fields: vec![
                     Field::ScalarField(ScalarField::new(
@@ -365,9 +378,11 @@ mod tests {
                 }],
                 primary_key: None,
                 foreign_keys: vec![],
+                checks: vec![],
             }],
             enums: vec![],
             sequences: vec![],
+            views: vec![],
         };
         let introspection_result =
             calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
@@ -385,6 +400,9 @@ mod tests {
                     name: "Table1".to_string(),
                     documentation: None,
                     is_embedded: false,
+                    is_view: false,
+                    checks: vec![],
+                    datasource: None,
                     is_commented_out: false,
                     fields: vec![Field::ScalarField(ScalarField {
                         name: "primary".to_string(),
@@ -409,6 +427,9 @@ mod tests {
                     name: "Table2".to_string(),
                     documentation: None,
                     is_embedded: false,
+                    is_view: false,
+                    checks: vec![],
+                    datasource: None,
                     is_commented_out: false,
                     fields: vec![Field::ScalarField(ScalarField {
                         name: "primary".to_string(),
@@ -433,6 +454,9 @@ mod tests {
                     name: "Table3".to_string(),
                     documentation: None,
                     is_embedded: false,
+                    is_view: false,
+                    checks: vec![],
+                    datasource: None,
                     is_commented_out: false,
                     fields: vec![Field::ScalarField(ScalarField {
                         name: "primary".to_string(),
@@ -479,6 +503,7 @@ mod tests {
                         constraint_name: None,
                     }),
                     foreign_keys: vec![],
+                    checks: vec![],
                 },
                 Table {
                     name: "Table2".to_string(),
@@ -502,6 +527,7 @@ mod tests {
                         constraint_name: None,
                     }),
                     foreign_keys: vec![],
+                    checks: vec![],
                 },
                 Table {
                     name: "Table3".to_string(),
@@ -530,10 +556,12 @@ mod tests {
                         constraint_name: None,
                     }),
                     foreign_keys: vec![],
+                    checks: vec![],
                 },
             ],
             enums: vec![],
             sequences: vec![],
+            views: vec![],
         };
         let introspection_result =
             calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
@@ -549,6 +577,9 @@ mod tests {
                 name: "Table1".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                checks: vec![],
+                datasource: None,
                 is_commented_out: false,
                 fields: vec![
                     Field::ScalarField(ScalarField::new(
@@ -601,9 +632,11 @@ mod tests {
                 }],
                 primary_key: None,
                 foreign_keys: vec![],
+                checks: vec![],
             }],
             enums: vec![],
             sequences: vec![],
+            views: vec![],
         };
         let introspection_result =
             calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
@@ -620,6 +653,9 @@ mod tests {
                     name: "City".to_string(),
                     documentation: None,
                     is_embedded: false,
+                    is_view: false,
+                    checks: vec![],
+                    datasource: None,
                     is_commented_out: false,
                     fields: vec![
                         Field::ScalarField(ScalarField {
@@ -648,7 +684,8 @@ mod tests {
                                 fields: vec![],
                                 to_fields: vec![],
                                 name: "CityToUser".to_string(),
-                                on_delete: OnDeleteStrategy::None,
+                                on_delete: None,
+                                on_update: None,
                             },
                         )),
                     ],
@@ -661,6 +698,9 @@ mod tests {
                     name: "User".to_string(),
                     documentation: None,
                     is_embedded: false,
+                    is_view: false,
+                    checks: vec![],
+                    datasource: None,
                     is_commented_out: false,
                     fields: vec![
                         Field::ScalarField(ScalarField {
@@ -710,7 +750,8 @@ mod tests {
                                 to: "City".to_string(),
                                 fields: vec!["city_id".to_string(), "city_name".to_string()],
                                 to_fields: vec!["id".to_string(), "name".to_string()],
-                                on_delete: OnDeleteStrategy::None,
+                                on_delete: Some(ReferentialAction::NoAction),
+                                on_update: Some(ReferentialAction::NoAction),
                             },
                         )),
                     ],
@@ -763,6 +804,7 @@ mod tests {
                         constraint_name: None,
                     }),
                     foreign_keys: vec![],
+                    checks: vec![],
                 },
                 Table {
                     name: "User".to_string(),
@@ -825,10 +867,12 @@ mod tests {
                         on_update_action: ForeignKeyAction::NoAction,
                         referenced_columns: vec!["id".to_string(), "name".to_string()],
                     }],
+                    checks: vec![],
                 },
             ],
             enums: vec![],
             sequences: vec![],
+            views: vec![],
         };
         let introspection_result =
             calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
@@ -844,6 +888,9 @@ mod tests {
                 name: "User".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                checks: vec![],
+                datasource: None,
                 is_commented_out: false,
                 fields: vec![
                     Field::ScalarField(ScalarField {
@@ -939,9 +986,11 @@ mod tests {
                     constraint_name: None,
                 }),
                 foreign_keys: vec![],
+                checks: vec![],
             }],
             enums: vec![],
             sequences: vec![],
+            views: vec![],
         };
         let introspection_result =
             calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
@@ -958,6 +1007,9 @@ mod tests {
                     name: "City".to_string(),
                     documentation: None,
                     is_embedded: false,
+                    is_view: false,
+                    checks: vec![],
+                    datasource: None,
                     is_commented_out: false,
                     fields: vec![
                         Field::ScalarField(ScalarField {
@@ -986,7 +1038,8 @@ mod tests {
                                 fields: vec![],
                                 to_fields: vec![],
                                 name: "CityToUser".to_string(),
-                                on_delete: OnDeleteStrategy::None,
+                                on_delete: None,
+                                on_update: None,
                             },
                         )),
                     ],
@@ -999,6 +1052,9 @@ mod tests {
                     name: "User".to_string(),
                     documentation: None,
                     is_embedded: false,
+                    is_view: false,
+                    checks: vec![],
+                    datasource: None,
                     is_commented_out: false,
                     fields: vec![
                         Field::ScalarField(ScalarField {
@@ -1027,7 +1083,8 @@ mod tests {
                                 to: "City".to_string(),
                                 fields: vec!["city_id".to_string()],
                                 to_fields: vec!["id".to_string()],
-                                on_delete: OnDeleteStrategy::None,
+                                on_delete: Some(ReferentialAction::NoAction),
+                                on_update: Some(ReferentialAction::NoAction),
                             },
                         )),
                     ],
@@ -1080,6 +1137,7 @@ mod tests {
                         constraint_name: None,
                     }),
                     foreign_keys: vec![],
+                    checks: vec![],
                 },
                 Table {
                     name: "User".to_string(),
@@ -1127,10 +1185,12 @@ mod tests {
                         on_update_action: ForeignKeyAction::NoAction,
                         referenced_columns: vec!["id".to_string()],
                     }],
+                    checks: vec![],
                 },
             ],
             enums: vec![],
             sequences: vec![],
+            views: vec![],
         };
         let introspection_result =
             calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
@@ -1172,6 +1232,7 @@ mod tests {
                 values: enum_values,
             }],
             sequences: vec![],
+            views: vec![],
         };
         let introspection_result =
             calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
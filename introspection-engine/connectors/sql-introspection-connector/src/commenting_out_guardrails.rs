@@ -73,9 +73,9 @@ pub fn commenting_out_guardrails(datamodel: &mut Datamodel) -> Vec<Warning> {
         };
     }
 
-    // models without uniques / ids
+    // models without uniques / ids, views don't need one
     for model in datamodel.models_mut() {
-        if model.strict_unique_criterias().is_empty() {
+        if model.strict_unique_criterias().is_empty() && !model.is_view {
             model.is_commented_out = true;
             model.documentation = Some(
                 "The underlying table does not contain a valid unique identifier and can therefore currently not be handled."
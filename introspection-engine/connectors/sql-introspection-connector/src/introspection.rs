@@ -1,7 +1,7 @@
 use crate::misc_helpers::{
-    calculate_backrelation_field, calculate_index, calculate_many_to_many_field, calculate_relation_field,
-    calculate_scalar_field, is_migration_table, is_prisma_1_point_0_join_table, is_prisma_1_point_1_or_2_join_table,
-    is_relay_table,
+    calculate_backrelation_field, calculate_check, calculate_index, calculate_many_to_many_field,
+    calculate_relation_field, calculate_scalar_field, is_migration_table, is_prisma_1_point_0_join_table,
+    is_prisma_1_point_1_or_2_join_table, is_relay_table,
 };
 use crate::version_checker::VersionChecker;
 use crate::SqlError;
@@ -48,7 +48,7 @@ pub fn introspect(
         }) {
             version_check.has_inline_relations(table);
             version_check.uses_on_delete(foreign_key, table);
-            let relation_field = calculate_relation_field(schema, table, foreign_key)?;
+            let relation_field = calculate_relation_field(schema, table, foreign_key, &sql_family)?;
             model.add_field(Field::RelationField(relation_field));
         }
 
@@ -64,12 +64,37 @@ pub fn introspect(
             model.id_fields = table.primary_key_columns();
         }
 
+        model.checks = table.checks.iter().map(calculate_check).collect();
+
         version_check.always_has_created_at_updated_at(table, &model);
         version_check.has_p1_compatible_primary_key_column(table);
 
         data_model.add_model(model);
     }
 
+    for view in schema.views.iter().filter(|view| !view.columns.is_empty()) {
+        debug!("Calculating model for view: {}", view.name);
+        let mut model = Model::new(view.name.clone(), None);
+        model.is_view = true;
+
+        // Views have no keys or constraints, so their columns are handled like those of a table without any.
+        let table = Table {
+            name: view.name.clone(),
+            columns: view.columns.clone(),
+            indices: vec![],
+            primary_key: None,
+            foreign_keys: vec![],
+            checks: vec![],
+        };
+
+        for column in &view.columns {
+            let field = calculate_scalar_field(&table, &column, &sql_family, native_types);
+            model.add_field(Field::ScalarField(field));
+        }
+
+        data_model.add_model(model);
+    }
+
     for e in schema.enums.iter() {
         let values = e.values.iter().map(|v| dml::EnumValue::new(v)).collect();
         data_model.add_enum(dml::Enum::new(&e.name, values));
@@ -1,13 +1,14 @@
 use crate::SqlError;
 use datamodel::{
     common::RelationNames, Datamodel, DefaultValue as DMLDef, FieldArity, FieldType, IndexDefinition, Model,
-    OnDeleteStrategy, RelationField, RelationInfo, ScalarField, ScalarType, ValueGenerator as VG,
+    ReferentialAction, RelationField, RelationInfo, ScalarField, ScalarType, ValueGenerator as VG,
 };
 use datamodel_connector::Connector;
 use quaint::connector::SqlFamily;
 use sql_datamodel_connector::SqlDatamodelConnectors;
 use sql_schema_describer::{
-    Column, ColumnArity, ColumnTypeFamily, DefaultValue as SQLDef, ForeignKey, Index, IndexType, SqlSchema, Table,
+    CheckConstraint, Column, ColumnArity, ColumnTypeFamily, DefaultValue as SQLDef, ForeignKey, ForeignKeyAction,
+    Index, IndexType, SqlSchema, Table,
 };
 use tracing::debug;
 
@@ -95,7 +96,8 @@ pub fn calculate_many_to_many_field(
         fields: vec![],
         to: opposite_foreign_key.referenced_table.clone(),
         to_fields: opposite_foreign_key.referenced_columns.clone(),
-        on_delete: OnDeleteStrategy::None,
+        on_delete: None,
+        on_update: None,
     };
 
     let basename = opposite_foreign_key.referenced_table.clone();
@@ -113,6 +115,7 @@ pub(crate) fn calculate_index(index: &Index) -> IndexDefinition {
     let tpe = match index.tpe {
         IndexType::Unique => datamodel::dml::IndexType::Unique,
         IndexType::Normal => datamodel::dml::IndexType::Normal,
+        IndexType::Fulltext => datamodel::dml::IndexType::Fulltext,
     };
 
     IndexDefinition {
@@ -122,6 +125,15 @@ pub(crate) fn calculate_index(index: &Index) -> IndexDefinition {
     }
 }
 
+pub(crate) fn calculate_check(check: &CheckConstraint) -> datamodel::dml::CheckConstraint {
+    debug!("Handling check constraint {:?}", check);
+
+    datamodel::dml::CheckConstraint {
+        name: Some(check.name.clone()).filter(|name| !name.is_empty()),
+        expression: check.definition.clone(),
+    }
+}
+
 pub(crate) fn calculate_scalar_field(
     table: &Table,
     column: &Column,
@@ -171,17 +183,10 @@ pub(crate) fn calculate_relation_field(
     schema: &SqlSchema,
     table: &Table,
     foreign_key: &ForeignKey,
+    family: &SqlFamily,
 ) -> Result<RelationField, SqlError> {
     debug!("Handling foreign key  {:?}", foreign_key);
 
-    let relation_info = RelationInfo {
-        name: calculate_relation_name(schema, foreign_key, table)?,
-        fields: foreign_key.columns.clone(),
-        to: foreign_key.referenced_table.clone(),
-        to_fields: foreign_key.referenced_columns.clone(),
-        on_delete: OnDeleteStrategy::None,
-    };
-
     let columns: Vec<&Column> = foreign_key
         .columns
         .iter()
@@ -193,9 +198,55 @@ pub(crate) fn calculate_relation_field(
         false => FieldArity::Required,
     };
 
+    let (on_delete, on_update) = calculate_referential_actions(foreign_key, arity, family);
+
+    let relation_info = RelationInfo {
+        name: calculate_relation_name(schema, foreign_key, table)?,
+        fields: foreign_key.columns.clone(),
+        to: foreign_key.referenced_table.clone(),
+        to_fields: foreign_key.referenced_columns.clone(),
+        on_delete,
+        on_update,
+    };
+
     Ok(RelationField::new(&foreign_key.referenced_table, arity, relation_info))
 }
 
+/// Referential actions are only rendered when they differ from the ones the migration engine
+/// creates by default, so that migrating the introspected data model leaves the foreign keys as
+/// they are. Foreign keys created without `ON DELETE` and `ON UPDATE` clauses are rendered with
+/// `NoAction`.
+fn calculate_referential_actions(
+    foreign_key: &ForeignKey,
+    arity: FieldArity,
+    family: &SqlFamily,
+) -> (Option<ReferentialAction>, Option<ReferentialAction>) {
+    let default_on_delete = match arity {
+        FieldArity::Required => ReferentialAction::Cascade,
+        _ => ReferentialAction::SetNull,
+    };
+
+    let on_delete =
+        Some(referential_action(&foreign_key.on_delete_action, family)).filter(|action| *action != default_on_delete);
+    let on_update = Some(referential_action(&foreign_key.on_update_action, family))
+        .filter(|action| *action != ReferentialAction::Cascade);
+
+    (on_delete, on_update)
+}
+
+fn referential_action(action: &ForeignKeyAction, family: &SqlFamily) -> ReferentialAction {
+    match action {
+        ForeignKeyAction::NoAction => ReferentialAction::NoAction,
+        // MySQL treats `NO ACTION` as `RESTRICT`, and depending on the version reports foreign
+        // keys without clauses with either.
+        ForeignKeyAction::Restrict if family.is_mysql() => ReferentialAction::NoAction,
+        ForeignKeyAction::Restrict => ReferentialAction::Restrict,
+        ForeignKeyAction::Cascade => ReferentialAction::Cascade,
+        ForeignKeyAction::SetNull => ReferentialAction::SetNull,
+        ForeignKeyAction::SetDefault => ReferentialAction::SetDefault,
+    }
+}
+
 pub(crate) fn calculate_backrelation_field(
     schema: &SqlSchema,
     model: &Model,
@@ -213,7 +264,8 @@ pub(crate) fn calculate_backrelation_field(
                 to: model.name.clone(),
                 fields: vec![],
                 to_fields: vec![],
-                on_delete: OnDeleteStrategy::None,
+                on_delete: None,
+                on_update: None,
             };
 
             // unique or id
@@ -345,7 +397,7 @@ pub(crate) fn calculate_scalar_field_type(column: &Column, family: &SqlFamily) -
         _ if is_postgres_interval => FieldType::Base(ScalarType::String, None),
         ColumnTypeFamily::Int => FieldType::Base(ScalarType::Int, None),
         ColumnTypeFamily::Float => FieldType::Base(ScalarType::Float, None),
-        ColumnTypeFamily::Decimal => FieldType::Base(ScalarType::Float, None),
+        ColumnTypeFamily::Decimal => FieldType::Base(ScalarType::Decimal, None),
         ColumnTypeFamily::Boolean => FieldType::Base(ScalarType::Boolean, None),
         ColumnTypeFamily::String => FieldType::Base(ScalarType::String, None),
         ColumnTypeFamily::DateTime => FieldType::Base(ScalarType::DateTime, None),
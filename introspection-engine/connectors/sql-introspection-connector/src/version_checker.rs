@@ -45,6 +45,7 @@ const POSTGRES_TYPES: &[(&str, &str)] = &[
     ("boolean", "bool"),
     ("timestamp without time zone", "timestamp"),
     ("numeric", "numeric"),
+    ("double precision", "float8"),
     ("integer", "int4"),
     ("text", "text"),
     ("character varying", "varchar"),
@@ -57,6 +58,7 @@ const MYSQL_TYPES: &[(&str, &str)] = &[
     ("tinyint", "tinyint(1)"),
     ("datetime", "datetime(3)"),
     ("decimal", "decimal(65,30)"),
+    ("double", "double"),
     ("int", "int(11)"),
     ("int", "int(4)"),
     ("int", "int"),
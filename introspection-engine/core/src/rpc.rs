@@ -71,6 +71,15 @@ impl RpcImpl {
     ) -> Result<(Configuration, String, Box<dyn IntrospectionConnector>), Error> {
         let config = datamodel::parse_configuration(&schema)?;
 
+        // Only the database of the first datasource would be introspected, and the models of the
+        // other datasources would be dropped from the schema.
+        if config.subject.datasources.len() > 1 {
+            return Err(CommandError::Generic(anyhow::anyhow!(
+                "You defined more than one datasource. Introspection does not support multiple datasources yet."
+            ))
+            .into());
+        }
+
         let url = config
             .subject
             .datasources
@@ -26,7 +26,7 @@ async fn a_table_without_uniques(api: &TestApi) -> crate::TestResult {
             // model Post {
               // id      Int
               // user_id Int
-              // User    User @relation(fields: [user_id], references: [id])
+              // User    User @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
 
               // @@index([user_id], name: "user_id")
             // }
@@ -42,7 +42,7 @@ async fn a_table_without_uniques(api: &TestApi) -> crate::TestResult {
             // model Post {
               // id      Int
               // user_id Int
-              // User    User @relation(fields: [user_id], references: [id])
+              // User    User @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
             // }
 
             model User {
@@ -11,5 +11,6 @@ mod relations_with_compound_fk;
 mod remapping_database_names;
 mod rpc_calls;
 mod tables;
+mod views;
 
 pub type TestResult = eyre::Result<()>;
@@ -168,8 +168,8 @@ async fn native_type_columns_feature_off(api: &TestApi) -> crate::TestResult {
             tinyint_bool                   Boolean
             mediumint                      Int
             bigint                         Int
-            decimal                        Float
-            numeric                        Float
+            decimal                        Decimal
+            numeric                        Decimal
             float                          Float
             double                         Float
             bits                           Int
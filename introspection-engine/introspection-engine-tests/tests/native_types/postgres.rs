@@ -141,8 +141,8 @@ async fn native_type_columns_feature_off(api: &TestApi) -> crate::TestResult {
             smallint        Int
             int             Int
             bigint          Int
-            decimal         Float
-            numeric         Float
+            decimal         Decimal
+            numeric         Decimal
             real            Float
             doublePrecision Float
             smallSerial     Int      @default(autoincrement())
@@ -134,7 +134,7 @@ async fn mapped_model_and_field_name(api: &TestApi) -> crate::TestResult {
         model Post {{
             id               Int         @id @default(autoincrement())
             c_user_id        Int         @map("user_id")
-            Custom_User      Custom_User @relation(fields: [c_user_id], references: [c_id])
+            Custom_User      Custom_User @relation(fields: [c_user_id], references: [c_id], onDelete: NoAction, onUpdate: NoAction)
             {}
         }}
 
@@ -153,7 +153,7 @@ async fn mapped_model_and_field_name(api: &TestApi) -> crate::TestResult {
         model Post {{
             id               Int         @id @default(autoincrement())
             c_user_id        Int         @map("user_id")
-            Custom_User      Custom_User @relation(fields: [c_user_id], references: [c_id])
+            Custom_User      Custom_User @relation(fields: [c_user_id], references: [c_id], onDelete: NoAction, onUpdate: NoAction)
             {}
         }}
 
@@ -235,7 +235,7 @@ async fn manually_mapped_model_and_field_name(api: &TestApi) -> crate::TestResul
         model Post {{
             id               Int         @id @default(autoincrement())
             c_user_id        Int         @map("user_id")
-            Custom_User      Custom_User @relation(fields: [c_user_id], references: [c_id])
+            Custom_User      Custom_User @relation(fields: [c_user_id], references: [c_id], onDelete: NoAction, onUpdate: NoAction)
             {}
         }}
 
@@ -254,7 +254,7 @@ async fn manually_mapped_model_and_field_name(api: &TestApi) -> crate::TestResul
         model Post {{
             id               Int         @id @default(autoincrement())
             c_user_id        Int         @map("user_id")
-            Custom_User      Custom_User @relation(fields: [c_user_id], references: [c_id])
+            Custom_User      Custom_User @relation(fields: [c_user_id], references: [c_id], onDelete: NoAction, onUpdate: NoAction)
             {}
         }}
 
@@ -817,8 +817,8 @@ async fn multiple_changed_relation_names(api: &TestApi) -> crate::TestResult {
             id                                            Int         @id @default(autoincrement())
             morningEmployeeId                             Int
             eveningEmployeeId                             Int
-            Employee_EmployeeToSchedule_eveningEmployeeId Employee    @relation("EmployeeToSchedule_eveningEmployeeId", fields: [eveningEmployeeId], references: [id])
-            Employee_EmployeeToSchedule_morningEmployeeId Employee    @relation("EmployeeToSchedule_morningEmployeeId", fields: [morningEmployeeId], references: [id])
+            Employee_EmployeeToSchedule_eveningEmployeeId Employee    @relation("EmployeeToSchedule_eveningEmployeeId", fields: [eveningEmployeeId], references: [id], onDelete: NoAction, onUpdate: NoAction)
+            Employee_EmployeeToSchedule_morningEmployeeId Employee    @relation("EmployeeToSchedule_morningEmployeeId", fields: [morningEmployeeId], references: [id], onDelete: NoAction, onUpdate: NoAction)
             {}
             {}
         }}
@@ -838,8 +838,8 @@ async fn multiple_changed_relation_names(api: &TestApi) -> crate::TestResult {
             id                                            Int         @id @default(autoincrement())
             morningEmployeeId                             Int
             eveningEmployeeId                             Int
-            Employee_EmployeeToSchedule_eveningEmployeeId Employee    @relation("EmployeeToSchedule_eveningEmployeeId", fields: [eveningEmployeeId], references: [id])
-            Employee_EmployeeToSchedule_morningEmployeeId Employee    @relation("EmployeeToSchedule_morningEmployeeId", fields: [morningEmployeeId], references: [id])
+            Employee_EmployeeToSchedule_eveningEmployeeId Employee    @relation("EmployeeToSchedule_eveningEmployeeId", fields: [eveningEmployeeId], references: [id], onDelete: NoAction, onUpdate: NoAction)
+            Employee_EmployeeToSchedule_morningEmployeeId Employee    @relation("EmployeeToSchedule_morningEmployeeId", fields: [morningEmployeeId], references: [id], onDelete: NoAction, onUpdate: NoAction)
             {}
             {}
         }}
@@ -880,7 +880,7 @@ async fn custom_virtual_relation_field_names(api: &TestApi) -> crate::TestResult
         model Post {
             id               Int @id @default(autoincrement())
             user_id          Int  @unique
-            custom_User      User @relation(fields: [user_id], references: [id])
+            custom_User      User @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
         }
 
         model User {
@@ -893,7 +893,7 @@ async fn custom_virtual_relation_field_names(api: &TestApi) -> crate::TestResult
         model Post {
             id               Int @id @default(autoincrement())
             user_id          Int  @unique
-            custom_User      User @relation(fields: [user_id], references: [id])
+            custom_User      User @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
         }
 
         model User {
@@ -1121,8 +1121,8 @@ async fn multiple_changed_relation_names_due_to_mapped_models(api: &TestApi) ->
             id               Int @id @default(autoincrement())
             user_id          Int  @unique
             user_id2         Int  @unique
-            custom_User      Custom_User @relation("CustomRelationName", fields: [user_id], references: [id])
-            custom_User2     Custom_User @relation("AnotherCustomRelationName", fields: [user_id2], references: [id])
+            custom_User      Custom_User @relation("CustomRelationName", fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
+            custom_User2     Custom_User @relation("AnotherCustomRelationName", fields: [user_id2], references: [id], onDelete: NoAction, onUpdate: NoAction)
         }
 
         model Custom_User {
@@ -1139,8 +1139,8 @@ async fn multiple_changed_relation_names_due_to_mapped_models(api: &TestApi) ->
             id               Int @id @default(autoincrement())
             user_id          Int  @unique
             user_id2         Int  @unique
-            custom_User      Custom_User @relation("CustomRelationName", fields: [user_id], references: [id])
-            custom_User2     Custom_User @relation("AnotherCustomRelationName", fields: [user_id2], references: [id])
+            custom_User      Custom_User @relation("CustomRelationName", fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
+            custom_User2     Custom_User @relation("AnotherCustomRelationName", fields: [user_id2], references: [id], onDelete: NoAction, onUpdate: NoAction)
         }
 
         model Custom_User {
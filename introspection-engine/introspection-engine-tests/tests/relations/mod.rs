@@ -27,7 +27,7 @@ async fn one_to_one_req_relation(api: &TestApi) -> crate::TestResult {
         model Post {
             id       Int @id @default(autoincrement())
             user_id  Int  @unique
-            User     User @relation(fields: [user_id], references: [id])
+            User     User @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
         }
 
         model User {
@@ -62,7 +62,7 @@ async fn one_to_one_relation_on_a_singular_primary_key(api: &TestApi) -> crate::
     let dm = indoc! {r##"
         model Post {
             id   Int  @unique
-            User User @relation(fields: [id], references: [id])
+            User User @relation(fields: [id], references: [id], onDelete: NoAction, onUpdate: NoAction)
         }
 
         model User {
@@ -118,14 +118,14 @@ async fn two_one_to_one_relations_between_the_same_models(api: &TestApi) -> crat
         model Post {
             id                      Int   @id @default(autoincrement())
             user_id                 Int   @unique
-            User_Post_user_idToUser User  @relation("Post_user_idToUser", fields: [user_id], references: [id])
+            User_Post_user_idToUser User  @relation("Post_user_idToUser", fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
             User_PostToUser_post_id User? @relation("PostToUser_post_id")
         }
 
         model User {
             id                      Int   @id @default(autoincrement())
             post_id                 Int   @unique
-            Post_PostToUser_post_id Post  @relation("PostToUser_post_id", fields: [post_id], references: [id])
+            Post_PostToUser_post_id Post  @relation("PostToUser_post_id", fields: [post_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
             Post_Post_user_idToUser Post? @relation("Post_user_idToUser")
         }
     "##};
@@ -158,7 +158,7 @@ async fn a_one_to_one_relation(api: &TestApi) -> crate::TestResult {
         model Post {
             id      Int  @id @default(autoincrement())
             user_id Int?  @unique
-            User    User? @relation(fields: [user_id], references: [id])
+            User    User? @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
         }
 
         model User {
@@ -196,7 +196,7 @@ async fn a_one_to_one_relation_referencing_non_id(api: &TestApi) -> crate::TestR
         model Post {
             id         Int     @id @default(autoincrement())
             user_email String? @unique
-            User       User?   @relation(fields: [user_email], references: [email])
+            User       User?   @relation(fields: [user_email], references: [email], onDelete: NoAction, onUpdate: NoAction)
         }
 
         model User {
@@ -236,7 +236,7 @@ async fn a_one_to_many_relation(api: &TestApi) -> crate::TestResult {
                 model Post {
                     id      Int   @id @default(autoincrement())
                     user_id Int?
-                    User    User? @relation(fields: [user_id], references: [id])
+                    User    User? @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
                     @@index([user_id], name: "user_id")
                 }
 
@@ -251,7 +251,7 @@ async fn a_one_to_many_relation(api: &TestApi) -> crate::TestResult {
                 model Post {
                     id      Int   @id @default(autoincrement())
                     user_id Int?
-                    User    User? @relation(fields: [user_id], references: [id])
+                    User    User? @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
                 }
 
                 model User {
@@ -292,7 +292,7 @@ async fn a_one_req_to_many_relation(api: &TestApi) -> crate::TestResult {
                 model Post {
                     id      Int   @id @default(autoincrement())
                     user_id Int
-                    User    User @relation(fields: [user_id], references: [id])
+                    User    User @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
                     @@index([user_id], name: "user_id")
                 }
 
@@ -307,7 +307,7 @@ async fn a_one_req_to_many_relation(api: &TestApi) -> crate::TestResult {
                 model Post {
                     id      Int   @id @default(autoincrement())
                     user_id Int
-                    User    User @relation(fields: [user_id], references: [id])
+                    User    User @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
                 }
 
                 model User {
@@ -406,8 +406,8 @@ async fn a_many_to_many_relation_with_an_id(api: &TestApi) -> crate::TestResult
                     id      Int  @id @default(autoincrement())
                     user_id Int
                     post_id Int
-                    Post    Post @relation(fields: [post_id], references: [id])
-                    User    User @relation(fields: [user_id], references: [id])
+                    Post    Post @relation(fields: [post_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
+                    User    User @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
                     @@index([post_id], name: "post_id")
                     @@index([user_id], name: "user_id")
                 }
@@ -429,8 +429,8 @@ async fn a_many_to_many_relation_with_an_id(api: &TestApi) -> crate::TestResult
                     id      Int  @id @default(autoincrement())
                     user_id Int
                     post_id Int
-                    Post    Post @relation(fields: [post_id], references: [id])
-                    User    User @relation(fields: [user_id], references: [id])
+                    Post    Post @relation(fields: [post_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
+                    User    User @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
                 }
 
                 model User {
@@ -471,8 +471,8 @@ async fn a_self_relation(api: &TestApi) -> crate::TestResult {
                     id                                  Int    @id @default(autoincrement())
                     recruited_by                        Int?
                     direct_report                       Int?
-                    User_UserToUser_direct_report       User?  @relation("UserToUser_direct_report", fields: [direct_report], references: [id])
-                    User_UserToUser_recruited_by        User?  @relation("UserToUser_recruited_by", fields: [recruited_by], references: [id])
+                    User_UserToUser_direct_report       User?  @relation("UserToUser_direct_report", fields: [direct_report], references: [id], onDelete: NoAction, onUpdate: NoAction)
+                    User_UserToUser_recruited_by        User?  @relation("UserToUser_recruited_by", fields: [recruited_by], references: [id], onDelete: NoAction, onUpdate: NoAction)
                     other_User_UserToUser_direct_report User[] @relation("UserToUser_direct_report")
                     other_User_UserToUser_recruited_by  User[] @relation("UserToUser_recruited_by")
                     @@index([direct_report], name: "direct_report")
@@ -486,8 +486,8 @@ async fn a_self_relation(api: &TestApi) -> crate::TestResult {
                     id                                  Int    @id @default(autoincrement())
                     recruited_by                        Int?
                     direct_report                       Int?
-                    User_UserToUser_direct_report       User?  @relation("UserToUser_direct_report", fields: [direct_report], references: [id])
-                    User_UserToUser_recruited_by        User?  @relation("UserToUser_recruited_by", fields: [recruited_by], references: [id])
+                    User_UserToUser_direct_report       User?  @relation("UserToUser_direct_report", fields: [direct_report], references: [id], onDelete: NoAction, onUpdate: NoAction)
+                    User_UserToUser_recruited_by        User?  @relation("UserToUser_recruited_by", fields: [recruited_by], references: [id], onDelete: NoAction, onUpdate: NoAction)
                     other_User_UserToUser_direct_report User[] @relation("UserToUser_direct_report")
                     other_User_UserToUser_recruited_by  User[] @relation("UserToUser_recruited_by")
                 }
@@ -522,7 +522,7 @@ async fn id_fields_with_foreign_key(api: &TestApi) -> crate::TestResult {
     let dm = indoc! {r##"
         model Post {
             user_id Int    @id
-            User    User   @relation(fields: [user_id], references: [id])
+            User    User   @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
         }
 
         model User {
@@ -566,7 +566,7 @@ async fn duplicate_fks_should_ignore_one_of_them(api: &TestApi) -> crate::TestRe
                 model Post {
                     id      Int   @id @default(autoincrement())
                     user_id Int?
-                    User    User? @relation("Post_user_idToUser", fields: [user_id], references: [id])
+                    User    User? @relation("Post_user_idToUser", fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
                     @@index([user_id], name: "user_id")
                 }
 
@@ -581,7 +581,7 @@ async fn duplicate_fks_should_ignore_one_of_them(api: &TestApi) -> crate::TestRe
                 model Post {
                     id      Int   @id @default(autoincrement())
                     user_id Int?
-                    User    User? @relation("Post_user_idToUser", fields: [user_id], references: [id])
+                    User    User? @relation("Post_user_idToUser", fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
                 }
 
                 model User {
@@ -616,7 +616,7 @@ async fn default_values_on_relations(api: &TestApi) -> crate::TestResult {
         model Post {
             id      Int   @id @default(autoincrement())
             user_id Int?  @default(0)
-            User    User? @relation(fields: [user_id], references: [id])
+            User    User? @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
         }
 
         model User {
@@ -696,7 +696,7 @@ async fn relations_should_avoid_name_clashes(api: &TestApi) -> crate::TestResult
                 model x {
                     id Int @id @default(autoincrement())
                     y  Int
-                    y_xToy  y      @relation(fields: [y], references: [id])
+                    y_xToy  y      @relation(fields: [y], references: [id], onDelete: NoAction, onUpdate: NoAction)
                 }
 
                 model y {
@@ -711,7 +711,7 @@ async fn relations_should_avoid_name_clashes(api: &TestApi) -> crate::TestResult
                 model x {
                     id Int @id
                     y  Int
-                    y_xToy  y      @relation(fields: [y], references: [id])
+                    y_xToy  y      @relation(fields: [y], references: [id], onDelete: NoAction, onUpdate: NoAction)
                     @@index([y], name: "y")
                 }
 
@@ -727,7 +727,7 @@ async fn relations_should_avoid_name_clashes(api: &TestApi) -> crate::TestResult
                 model x {
                     id Int @id
                     y  Int
-                    y_xToy  y      @relation(fields: [y], references: [id])
+                    y_xToy  y      @relation(fields: [y], references: [id], onDelete: NoAction, onUpdate: NoAction)
                 }
 
                 model y {
@@ -791,7 +791,7 @@ async fn relations_should_avoid_name_clashes_2(api: &TestApi) -> crate::TestResu
                 model x {
                     id                   Int @id @default(autoincrement())
                     y                    Int
-                    y_x_yToy             y   @relation("x_yToy", fields: [y], references: [id])
+                    y_x_yToy             y   @relation("x_yToy", fields: [y], references: [id], onDelete: NoAction, onUpdate: NoAction)
                     y_xToy_fk_x_1_fk_x_2 y[] @relation("xToy_fk_x_1_fk_x_2")
                     @@unique([id, y], name: "unique_y_id")
                     @@index([y], name: "y")
@@ -802,7 +802,7 @@ async fn relations_should_avoid_name_clashes_2(api: &TestApi) -> crate::TestResu
                     x                    Int
                     fk_x_1               Int
                     fk_x_2               Int
-                    x_xToy_fk_x_1_fk_x_2 x   @relation("xToy_fk_x_1_fk_x_2", fields: [fk_x_1, fk_x_2], references: [id, y])
+                    x_xToy_fk_x_1_fk_x_2 x   @relation("xToy_fk_x_1_fk_x_2", fields: [fk_x_1, fk_x_2], references: [id, y], onDelete: NoAction, onUpdate: NoAction)
                     x_x_yToy             x[] @relation("x_yToy")
                     @@index([fk_x_1, fk_x_2], name: "fk_x_1")
                 }
@@ -813,7 +813,7 @@ async fn relations_should_avoid_name_clashes_2(api: &TestApi) -> crate::TestResu
                 model x {
                     id                   Int @id @default(autoincrement())
                     y                    Int
-                    y_x_yToy             y   @relation("x_yToy", fields: [y], references: [id])
+                    y_x_yToy             y   @relation("x_yToy", fields: [y], references: [id], onDelete: NoAction, onUpdate: NoAction)
                     y_xToy_fk_x_1_fk_x_2 y[] @relation("xToy_fk_x_1_fk_x_2")
                     @@unique([id, y], name: "unique_y_id")
                 }
@@ -823,7 +823,7 @@ async fn relations_should_avoid_name_clashes_2(api: &TestApi) -> crate::TestResu
                     x                    Int
                     fk_x_1               Int
                     fk_x_2               Int
-                    x_xToy_fk_x_1_fk_x_2 x   @relation("xToy_fk_x_1_fk_x_2", fields: [fk_x_1, fk_x_2], references: [id, y])
+                    x_xToy_fk_x_1_fk_x_2 x   @relation("xToy_fk_x_1_fk_x_2", fields: [fk_x_1, fk_x_2], references: [id, y], onDelete: NoAction, onUpdate: NoAction)
                     x_x_yToy             x[] @relation("x_yToy")
                 }
             "##}
@@ -882,7 +882,7 @@ async fn one_to_many_relation_field_names_do_not_conflict_with_many_to_many_rela
             model Event {{
                 id                           Int    @id @default(autoincrement())
                 host_id                      Int
-                User_EventToUser             User   @relation(fields: [host_id], references: [id])
+                User_EventToUser             User   @relation(fields: [host_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
                 User_EventToUserManyToMany   User[] @relation("EventToUserManyToMany")
                 {}
             }}
@@ -103,7 +103,7 @@ async fn remapping_models_in_relations(api: &TestApi) -> crate::TestResult {
         model Post {
             id              Int             @id @default(autoincrement())
             user_id         Int             @unique
-            User_with_Space User_with_Space @relation(fields: [user_id], references: [id])
+            User_with_Space User_with_Space @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
         }
 
         model User_with_Space {
@@ -144,7 +144,7 @@ async fn remapping_models_in_relations_should_not_map_virtual_fields(api: &TestA
         model Post_With_Space {
             id      Int  @id @default(autoincrement())
             user_id Int  @unique
-            User    User @relation(fields: [user_id], references: [id])
+            User    User @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
 
             @@map("Post With Space")
         }
@@ -205,7 +205,7 @@ async fn remapping_models_in_compound_relations(api: &TestApi) -> crate::TestRes
             id              Int             @id @default(autoincrement())
             user_id         Int
             user_age        Int
-            User_with_Space User_with_Space @relation(fields: [user_id, user_age], references: [id, age])
+            User_with_Space User_with_Space @relation(fields: [user_id, user_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
 
             @@unique([user_id, user_age], name: "{}")
         }}
@@ -274,7 +274,7 @@ async fn remapping_fields_in_compound_relations(api: &TestApi) -> crate::TestRes
             id       Int  @id @default(autoincrement())
             user_id  Int
             user_age Int
-            User     User @relation(fields: [user_id, user_age], references: [id, age_that_is_invalid])
+            User     User @relation(fields: [user_id, user_age], references: [id, age_that_is_invalid], onDelete: NoAction, onUpdate: NoAction)
 
             @@unique([user_id, user_age], name: "{}")
         }}
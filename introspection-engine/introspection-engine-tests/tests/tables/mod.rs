@@ -420,6 +420,33 @@ async fn a_table_with_an_index_that_contains_expressions_should_be_ignored(api:
     Ok(())
 }
 
+#[test_each_connector(tags("postgres"))]
+async fn decimal_columns_are_introspected_as_decimal(api: &TestApi) -> crate::TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("Product", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("price numeric(12,2) NOT NULL");
+                t.inject_custom("weight Decimal(65,30) NOT NULL");
+                t.inject_custom("ratio double precision NOT NULL");
+            });
+        })
+        .await?;
+
+    let dm = indoc! {r#"
+        model Product {
+            id      Int     @id @default(autoincrement())
+            price   Decimal
+            weight  Decimal
+            ratio   Float
+        }
+    "#};
+
+    assert_eq_datamodels!(dm, &api.introspect().await?);
+
+    Ok(())
+}
+
 #[test_each_connector(tags("postgres"))]
 async fn default_values_on_lists_should_be_ignored(api: &TestApi) -> crate::TestResult {
     api.barrel()
@@ -0,0 +1,41 @@
+use barrel::types;
+use indoc::indoc;
+use introspection_engine_tests::{assert_eq_datamodels, test_api::*};
+use quaint::prelude::Queryable;
+use test_macros::test_each_connector_mssql as test_each_connector;
+
+#[test_each_connector(tags("postgres"))]
+async fn views_are_introspected_as_read_only_models(api: &TestApi) -> crate::TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("name", types::text().nullable(false));
+            });
+        })
+        .await?;
+
+    let create_view = format!(
+        r#"CREATE VIEW "{0}"."UserName" AS SELECT name FROM "{0}"."User""#,
+        api.schema_name()
+    );
+
+    api.database().raw_cmd(&create_view).await?;
+
+    let dm = indoc! {r#"
+        model User {
+            id   Int    @id @default(autoincrement())
+            name String
+        }
+
+        model UserName {
+            name String?
+
+            @@view
+        }
+    "#};
+
+    assert_eq_datamodels!(dm, &api.introspect().await?);
+
+    Ok(())
+}
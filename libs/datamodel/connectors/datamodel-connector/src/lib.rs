@@ -69,6 +69,10 @@ pub trait Connector: Send + Sync {
     fn supports_non_indexed_auto_increment(&self) -> bool {
         self.has_capability(ConnectorCapability::AutoIncrementNonIndexedAllowed)
     }
+
+    fn supports_fulltext_index(&self) -> bool {
+        self.has_capability(ConnectorCapability::FullTextIndex)
+    }
 }
 
 /// Not all Databases are created equal. Hence connectors for our datasources support different capabilities.
@@ -85,8 +89,12 @@ pub enum ConnectorCapability {
     AutoIncrementMultipleAllowed,
     AutoIncrementNonIndexedAllowed,
     RelationsOverNullableField,
+    FullTextIndex,
     // start of Query Engine Capabilities
     InsensitiveFilters,
+    JsonFiltering,
+    FullTextSearch,
+    FullTextRelevance,
 }
 
 /// Contains all capabilities that the connector is able to serve.
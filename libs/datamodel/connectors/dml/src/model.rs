@@ -23,6 +23,13 @@ pub struct Model {
     pub is_generated: bool,
     /// Indicates if this model has to be commented out.
     pub is_commented_out: bool,
+    /// Indicates if this model is backed by a database view. Views are read-only.
+    pub is_view: bool,
+    /// Describes check constraints defined via `@@check`.
+    pub checks: Vec<CheckConstraint>,
+    /// The name of the datasource the model is stored in, defined via `@@datasource`.
+    /// Models without one are stored in the first datasource.
+    pub datasource: Option<String>,
 }
 
 /// Represents an index defined via `@@index` or `@@unique`.
@@ -43,6 +50,15 @@ impl IndexDefinition {
 pub enum IndexType {
     Unique,
     Normal,
+    /// A full-text index, defined via `@@fulltext`.
+    Fulltext,
+}
+
+/// Represents a check constraint defined via `@@check`. The expression is passed to the database as-is.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CheckConstraint {
+    pub name: Option<String>,
+    pub expression: String,
 }
 
 /// A unique criteria is a set of fields through which a record can be uniquely identified.
@@ -70,6 +86,9 @@ impl Model {
             is_embedded: false,
             is_generated: false,
             is_commented_out: false,
+            is_view: false,
+            checks: vec![],
+            datasource: None,
         }
     }
 
@@ -177,6 +196,8 @@ impl Model {
     pub fn first_unique_criterion(&self) -> Vec<&ScalarField> {
         match self.strict_unique_criterias().first() {
             Some(criteria) => criteria.fields.clone(),
+            // Views without a unique criteria are identified by all of their scalar fields.
+            None if self.is_view => self.scalar_fields().filter(|f| !f.is_list()).collect(),
             None => panic!("Could not find the first unique criteria on model {}", self.name()),
         }
     }
@@ -9,9 +9,12 @@ pub struct RelationInfo {
     pub to_fields: Vec<String>,
     /// The name of the relation. Internally, an empty string signals no name.
     pub name: String,
-    /// A strategy indicating what happens when
-    /// a related node is deleted.
-    pub on_delete: OnDeleteStrategy,
+    /// What happens to the records holding the relation when the referenced record is deleted.
+    /// `None` keeps the default behaviour.
+    pub on_delete: Option<ReferentialAction>,
+    /// What happens to the records holding the relation when the referenced fields are updated.
+    /// `None` keeps the default behaviour.
+    pub on_update: Option<ReferentialAction>,
 }
 
 impl PartialEq for RelationInfo {
@@ -21,6 +24,7 @@ impl PartialEq for RelationInfo {
             && self.fields == other.fields
             && self.to_fields == other.to_fields
             && self.on_delete == other.on_delete
+            && self.on_update == other.on_update
     }
 }
 
@@ -33,23 +37,61 @@ impl RelationInfo {
             fields: Vec::new(),
             to_fields: Vec::new(),
             name: String::new(),
-            on_delete: OnDeleteStrategy::None,
+            on_delete: None,
+            on_update: None,
         }
     }
 }
 
-/// Describes what happens when related nodes are deleted.
+/// Describes what happens to the records holding a relation when the referenced record is
+/// deleted or its referenced fields are updated.
 #[derive(Debug, Copy, PartialEq, Clone)]
-pub enum OnDeleteStrategy {
+pub enum ReferentialAction {
+    /// Delete or update the records holding the relation along with the referenced record.
     Cascade,
-    None,
+    /// Prevent the deletion or update as long as records hold the relation.
+    Restrict,
+    /// Like `Restrict`, but the check may be deferred to the end of the transaction.
+    NoAction,
+    /// Set the relation fields of the records holding the relation to `NULL`.
+    SetNull,
+    /// Set the relation fields of the records holding the relation to their default values.
+    SetDefault,
 }
 
-impl ToString for OnDeleteStrategy {
-    fn to_string(&self) -> String {
-        match self {
-            OnDeleteStrategy::Cascade => String::from("CASCADE"),
-            OnDeleteStrategy::None => String::from("NONE"),
-        }
+impl ReferentialAction {
+    /// All the actions, in the order they are documented.
+    pub const ALL: &'static [ReferentialAction] = &[
+        ReferentialAction::Cascade,
+        ReferentialAction::Restrict,
+        ReferentialAction::NoAction,
+        ReferentialAction::SetNull,
+        ReferentialAction::SetDefault,
+    ];
+}
+
+impl std::str::FromStr for ReferentialAction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|action| action.to_string() == s)
+            .ok_or_else(|| format!("Unknown referential action `{}`.", s))
+    }
+}
+
+impl std::fmt::Display for ReferentialAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ReferentialAction::Cascade => "Cascade",
+            ReferentialAction::Restrict => "Restrict",
+            ReferentialAction::NoAction => "NoAction",
+            ReferentialAction::SetNull => "SetNull",
+            ReferentialAction::SetDefault => "SetDefault",
+        };
+
+        f.write_str(name)
     }
 }
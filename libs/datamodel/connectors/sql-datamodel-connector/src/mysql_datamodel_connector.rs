@@ -51,9 +51,13 @@ impl MySqlDatamodelConnector {
             ConnectorCapability::RelationsOverNonUniqueCriteria,
             ConnectorCapability::Enums,
             ConnectorCapability::Json,
+            ConnectorCapability::JsonFiltering,
             ConnectorCapability::MultipleIndexesWithSameName,
             ConnectorCapability::AutoIncrementAllowedOnNonId,
             ConnectorCapability::RelationsOverNullableField,
+            ConnectorCapability::FullTextIndex,
+            ConnectorCapability::FullTextSearch,
+            ConnectorCapability::FullTextRelevance,
         ];
 
         let int = NativeTypeConstructor::without_args(INT_TYPE_NAME, ScalarType::Int);
@@ -49,7 +49,10 @@ impl PostgresDatamodelConnector {
             ConnectorCapability::AutoIncrementAllowedOnNonId,
             ConnectorCapability::AutoIncrementNonIndexedAllowed,
             ConnectorCapability::InsensitiveFilters,
+            ConnectorCapability::JsonFiltering,
             ConnectorCapability::RelationsOverNullableField,
+            ConnectorCapability::FullTextSearch,
+            ConnectorCapability::FullTextRelevance,
         ];
 
         let small_int = NativeTypeConstructor::without_args(SMALL_INT_TYPE_NAME, ScalarType::Int);
@@ -10,6 +10,8 @@ pub struct Datasource {
     /// the provider that was selected as active from all specified providers
     pub active_provider: String,
     pub url: StringFromEnvVar,
+    /// the URLs of the read replicas of the database, if any
+    pub replica_urls: Vec<StringFromEnvVar>,
     pub documentation: Option<String>,
     /// a connector representing the intersection of all providers specified
     pub combined_connector: Box<dyn Connector>,
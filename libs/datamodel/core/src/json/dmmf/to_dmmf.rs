@@ -144,6 +144,9 @@ fn prisma_value_to_serde(value: &PrismaValue) -> serde_json::Value {
         PrismaValue::Null => serde_json::Value::Null,
         PrismaValue::Uuid(val) => serde_json::Value::String(val.to_string()),
         PrismaValue::Json(val) => serde_json::Value::String(val.to_string()),
+        PrismaValue::BigInt(val) => serde_json::Value::String(val.to_string()),
+        PrismaValue::Bytes(val) => serde_json::Value::String(prisma_value::encode_bytes(val)),
+        PrismaValue::Decimal(val) => serde_json::Value::String(val.to_string()),
         PrismaValue::List(value_vec) => {
             serde_json::Value::Array(value_vec.iter().map(|pv| prisma_value_to_serde(pv)).collect())
         }
@@ -194,9 +197,19 @@ fn get_relation_to_fields(field: &dml::Field) -> Option<Vec<String>> {
     }
 }
 
+/// The DMMF keeps its upper case names for the referential actions, `NONE` being the default.
 fn get_relation_delete_strategy(field: &dml::Field) -> Option<String> {
-    match &field {
-        dml::Field::RelationField(rf) => Some(rf.relation_info.on_delete.to_string()),
-        _ => None,
-    }
+    let strategy = match &field {
+        dml::Field::RelationField(rf) => match rf.relation_info.on_delete {
+            Some(dml::ReferentialAction::Cascade) => "CASCADE",
+            Some(dml::ReferentialAction::Restrict) => "RESTRICT",
+            Some(dml::ReferentialAction::NoAction) => "NO_ACTION",
+            Some(dml::ReferentialAction::SetNull) => "SET_NULL",
+            Some(dml::ReferentialAction::SetDefault) => "SET_DEFAULT",
+            None => "NONE",
+        },
+        _ => return None,
+    };
+
+    Some(String::from(strategy))
 }
@@ -13,6 +13,7 @@ use crate::{ast, Datasource};
 use datamodel_connector::{CombinedConnector, Connector};
 
 const PREVIEW_FEATURES_KEY: &str = "previewFeatures";
+const REPLICA_URLS_KEY: &str = "replicaUrls";
 
 /// Is responsible for loading and validating Datasources defined in an AST.
 pub struct DatasourceLoader {
@@ -65,16 +66,6 @@ impl DatasourceLoader {
             }
         }
 
-        if sources.len() > 1 {
-            for src in &ast_schema.sources() {
-                diagnostics.push_error(DatamodelError::new_source_validation_error(
-                    &"You defined more than one datasource. This is not allowed yet because support for multiple databases has not been implemented yet.".to_string(),
-                    &src.name.name,
-                    src.span,
-                ));
-            }
-        }
-
         if diagnostics.has_errors() {
             Err(diagnostics)
         } else {
@@ -157,6 +148,25 @@ impl DatasourceLoader {
             )));
         }
 
+        // Replica URLs are checked against the providers below, together with the primary URL.
+        let replica_urls_arg = args.optional_arg(REPLICA_URLS_KEY);
+        let replica_urls: Vec<StringFromEnvVar> = match &replica_urls_arg {
+            Some(_) if ignore_datasource_urls => Vec::new(),
+            Some(arg) => arg
+                .as_array()
+                .iter()
+                .map(|replica_url| {
+                    replica_url
+                        .as_str_from_env()
+                        .map(|(from_env_var, value)| StringFromEnvVar {
+                            from_env_var,
+                            value: value.trim().to_owned(),
+                        })
+                })
+                .collect::<Result<_, _>>()?,
+            None => Vec::new(),
+        };
+
         let preview_features_arg = args.arg(PREVIEW_FEATURES_KEY);
         let (preview_features, span) = match preview_features_arg.ok() {
             Some(x) => (x.as_array().to_str_vec()?, x.span()),
@@ -208,7 +218,18 @@ impl DatasourceLoader {
                 let url_check_result = provider.can_handle_url(source_name, &url).map_err(|err_msg| {
                     DatamodelError::new_source_validation_error(&err_msg, source_name, url_args.span())
                 });
-                url_check_result.map(|_| provider)
+
+                let replica_urls_check_result = replica_urls.iter().try_for_each(|replica_url| {
+                    provider.can_handle_url(source_name, replica_url).map_err(|err_msg| {
+                        let span = replica_urls_arg
+                            .as_ref()
+                            .map(|arg| arg.span())
+                            .unwrap_or_else(|| url_args.span());
+                        DatamodelError::new_source_validation_error(&err_msg, source_name, span)
+                    })
+                });
+
+                url_check_result.and(replica_urls_check_result).map(|_| provider)
             })
             .collect();
 
@@ -228,6 +249,7 @@ impl DatasourceLoader {
                     provider: providers,
                     active_provider: first_successful_provider.canonical_name().to_string(),
                     url,
+                    replica_urls,
                     documentation,
                     combined_connector,
                     active_connector: first_successful_provider.connector(),
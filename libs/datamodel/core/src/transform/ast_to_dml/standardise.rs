@@ -4,7 +4,7 @@ use crate::{
     ast,
     common::{NameNormalizer, RelationNames},
     diagnostics::Diagnostics,
-    dml, Field, OnDeleteStrategy, ScalarField, UniqueCriteria,
+    dml, Field, ScalarField, UniqueCriteria,
 };
 
 /// Helper for standardsing a datamodel.
@@ -178,7 +178,8 @@ impl Standardiser {
                         fields: vec![],
                         to_fields: vec![],
                         name: rel_info.name.clone(),
-                        on_delete: OnDeleteStrategy::None,
+                        on_delete: None,
+                        on_update: None,
                     };
                     let mut back_relation_field = dml::RelationField::new_generated(&model.name, relation_info);
                     back_relation_field.arity = dml::FieldArity::List;
@@ -249,7 +250,8 @@ impl Standardiser {
                         fields: underlying_field_names,
                         to_fields: unique_criteria_field_names,
                         name: rel_info.name.clone(),
-                        on_delete: OnDeleteStrategy::None,
+                        on_delete: None,
+                        on_update: None,
                     };
 
                     let back_relation_field = dml::RelationField::new_generated(&model.name, relation_info);
@@ -12,6 +12,7 @@ use std::collections::HashSet;
 /// When validating, we check if the datamodel is valid, and generate errors otherwise.
 pub struct Validator<'a> {
     source: Option<&'a configuration::Datasource>,
+    sources: &'a [configuration::Datasource],
 }
 
 /// State error message. Seeing this error means something went really wrong internally. It's the datamodel equivalent of a bluescreen.
@@ -22,8 +23,11 @@ const PRISMA_FORMAT_HINT: &str = "You can run `prisma format` to fix this automa
 
 impl<'a> Validator<'a> {
     /// Creates a new instance, with all builtin attributes registered.
-    pub fn new(source: Option<&'a configuration::Datasource>) -> Validator<'a> {
-        Self { source }
+    pub fn new(sources: &'a [configuration::Datasource]) -> Validator<'a> {
+        Self {
+            source: sources.first(),
+            sources,
+        }
     }
 
     pub fn validate(&self, ast_schema: &ast::SchemaAst, schema: &mut dml::Datamodel) -> Result<(), Diagnostics> {
@@ -78,6 +82,16 @@ impl<'a> Validator<'a> {
                 errors_for_model.append(the_errors)
             }
 
+            if let Err(err) =
+                self.validate_fulltext_indexes(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.push_error(err);
+            }
+
+            if let Err(err) = self.validate_datasource(ast_schema.find_model(&model.name).expect(STATE_ERROR), model) {
+                errors_for_model.push_error(err);
+            }
+
             if let Err(ref mut the_errors) =
                 self.validate_enum_default_values(schema, ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
             {
@@ -286,6 +300,52 @@ impl<'a> Validator<'a> {
         }
     }
 
+    fn validate_fulltext_indexes(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), DatamodelError> {
+        let supports_fulltext_index = self
+            .source
+            .map(|source| source.combined_connector.supports_fulltext_index())
+            .unwrap_or(false);
+
+        if supports_fulltext_index || !model.indices.iter().any(|index| index.tpe == dml::IndexType::Fulltext) {
+            return Ok(());
+        }
+
+        let ast_index = ast_model
+            .attributes
+            .iter()
+            .find(|attribute| attribute.name.name == "fulltext")
+            .unwrap();
+
+        Err(DatamodelError::new_attribute_validation_error(
+            "Fulltext indexes are not supported by the current connector.",
+            "fulltext",
+            ast_index.span,
+        ))
+    }
+
+    fn validate_datasource(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), DatamodelError> {
+        let datasource = match &model.datasource {
+            Some(datasource) => datasource,
+            None => return Ok(()),
+        };
+
+        if self.sources.iter().any(|source| &source.name == datasource) {
+            return Ok(());
+        }
+
+        let ast_attribute = ast_model
+            .attributes
+            .iter()
+            .find(|attribute| attribute.name.name == "datasource")
+            .unwrap();
+
+        Err(DatamodelError::new_attribute_validation_error(
+            &format!("The datasource `{}` is not defined in the schema.", datasource),
+            "datasource",
+            ast_attribute.span,
+        ))
+    }
+
     fn validate_enum_default_values(
         &self,
         data_model: &dml::Datamodel,
@@ -420,7 +480,8 @@ impl<'a> Validator<'a> {
             ast_model.span,
         ));
 
-        if model.strict_unique_criterias().is_empty() {
+        // Views are read-only and don't need to identify single records.
+        if model.strict_unique_criterias().is_empty() && !model.is_view {
             return missing_id_criteria_error;
         }
 
@@ -590,6 +651,27 @@ impl<'a> Validator<'a> {
                         ast_field.span)
                     );
             }
+
+            let set_null_actions = rel_info
+                .on_delete
+                .iter()
+                .map(|action| ("onDelete", action))
+                .chain(rel_info.on_update.iter().map(|action| ("onUpdate", action)))
+                .filter(|(_, action)| **action == dml::ReferentialAction::SetNull);
+
+            for (argument, _) in set_null_actions {
+                if at_least_one_underlying_field_is_required {
+                    errors.push_error(DatamodelError::new_validation_error(
+                        &format!(
+                            "The `{}` referential action of the relation field `{}` can not be `SetNull`, because some of the scalar fields {} are required.",
+                            argument,
+                            &field.name,
+                            rel_info.fields.join(", ")
+                        ),
+                        ast_field.span,
+                    ));
+                }
+            }
         }
 
         if errors.has_errors() {
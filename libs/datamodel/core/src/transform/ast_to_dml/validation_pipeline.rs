@@ -17,7 +17,7 @@ impl<'a, 'b> ValidationPipeline<'a, 'b> {
         let source = sources.first();
         ValidationPipeline {
             lifter: LiftAstToDml::new(source, generators),
-            validator: Validator::new(source),
+            validator: Validator::new(sources),
             standardiser: Standardiser::new(),
         }
     }
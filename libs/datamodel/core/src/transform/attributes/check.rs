@@ -0,0 +1,64 @@
+use super::{super::helpers::*, AttributeValidator};
+use crate::diagnostics::DatamodelError;
+use crate::{ast, dml};
+
+/// Prismas builtin `@@check` attribute.
+pub struct CheckAttributeValidator {}
+
+impl AttributeValidator<dml::Model> for CheckAttributeValidator {
+    fn attribute_name(&self) -> &'static str {
+        &"check"
+    }
+
+    fn is_duplicate_definition_allowed(&self) -> bool {
+        true
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        let expression = args.default_arg("expression")?.as_str()?;
+
+        if expression.trim().is_empty() {
+            return self.new_attribute_validation_error("The check expression can not be empty.", args.span());
+        }
+
+        let name = match args.optional_arg("name") {
+            Some(name_arg) => Some(name_arg.as_str()?),
+            None => None,
+        };
+
+        if let Some(name) = &name {
+            if obj.checks.iter().any(|check| check.name.as_ref() == Some(name)) {
+                return self.new_attribute_validation_error(
+                    &format!("The check constraint name `{}` is already used in this model.", name),
+                    args.span(),
+                );
+            }
+        }
+
+        obj.checks.push(dml::CheckConstraint { name, expression });
+
+        Ok(())
+    }
+
+    fn serialize(
+        &self,
+        model: &dml::Model,
+        _datamodel: &dml::Datamodel,
+    ) -> Result<Vec<ast::Attribute>, DatamodelError> {
+        let attributes = model
+            .checks
+            .iter()
+            .map(|check| {
+                let mut args = vec![ast::Argument::new_string("", &check.expression)];
+
+                if let Some(name) = &check.name {
+                    args.push(ast::Argument::new_string("name", name));
+                }
+
+                ast::Attribute::new(self.attribute_name(), args)
+            })
+            .collect();
+
+        Ok(attributes)
+    }
+}
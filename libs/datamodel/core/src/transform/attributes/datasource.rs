@@ -0,0 +1,32 @@
+use super::{super::helpers::*, AttributeValidator};
+use crate::diagnostics::DatamodelError;
+use crate::{ast, dml};
+
+/// Prismas builtin `@@datasource` attribute.
+pub struct DatasourceAttributeValidator {}
+
+impl AttributeValidator<dml::Model> for DatasourceAttributeValidator {
+    fn attribute_name(&self) -> &'static str {
+        &"datasource"
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        obj.datasource = Some(args.default_arg("name")?.as_constant_literal()?);
+
+        Ok(())
+    }
+
+    fn serialize(
+        &self,
+        model: &dml::Model,
+        _datamodel: &dml::Datamodel,
+    ) -> Result<Vec<ast::Attribute>, DatamodelError> {
+        match &model.datasource {
+            Some(datasource) => Ok(vec![ast::Attribute::new(
+                self.attribute_name(),
+                vec![ast::Argument::new_constant("", datasource)],
+            )]),
+            None => Ok(vec![]),
+        }
+    }
+}
@@ -1,11 +1,14 @@
 mod attribute_list_validator;
 mod attribute_validator;
+mod check;
+mod datasource;
 mod default;
 mod id;
 mod map;
 mod relation;
 mod unique_and_index;
 mod updated_at;
+mod view;
 
 use crate::dml;
 use attribute_list_validator::AttributeListValidator;
@@ -51,7 +54,11 @@ fn new_builtin_model_attributes() -> AttributeListValidator<dml::Model> {
     validator.add(Box::new(id::ModelLevelIdAttributeValidator {}));
     validator.add(Box::new(unique_and_index::ModelLevelUniqueAttributeValidator {}));
     validator.add(Box::new(unique_and_index::ModelLevelIndexAttributeValidator {}));
+    validator.add(Box::new(unique_and_index::ModelLevelFulltextAttributeValidator {}));
     validator.add(Box::new(map::MapAttributeValidator {}));
+    validator.add(Box::new(view::ViewAttributeValidator {}));
+    validator.add(Box::new(check::CheckAttributeValidator {}));
+    validator.add(Box::new(datasource::DatasourceAttributeValidator {}));
 
     validator
 }
@@ -31,10 +31,22 @@ impl AttributeValidator<dml::Field> for RelationAttributeValidator {
                 rf.relation_info.fields = base_fields.as_array().to_literal_vec()?;
             }
 
-            // TODO: bring `onDelete` back once `prisma migrate` is a thing
-            //            if let Ok(on_delete) = args.arg("onDelete") {
-            //                relation_info.on_delete = on_delete.parse_literal::<dml::OnDeleteStrategy>()?;
-            //            }
+            if let Ok(on_delete) = args.arg("onDelete") {
+                rf.relation_info.on_delete = Some(self.parse_referential_action(&on_delete)?);
+            }
+
+            if let Ok(on_update) = args.arg("onUpdate") {
+                rf.relation_info.on_update = Some(self.parse_referential_action(&on_update)?);
+            }
+
+            let has_referential_actions = rf.relation_info.on_delete.is_some() || rf.relation_info.on_update.is_some();
+
+            if has_referential_actions && rf.relation_info.fields.is_empty() {
+                return self.new_attribute_validation_error(
+                    "Referential actions can only be defined on the relation field that defines the `fields` argument.",
+                    args.span(),
+                );
+            }
 
             Ok(())
         } else {
@@ -99,11 +111,12 @@ impl AttributeValidator<dml::Field> for RelationAttributeValidator {
                 }
             }
 
-            if relation_info.on_delete != dml::OnDeleteStrategy::None {
-                args.push(ast::Argument::new_constant(
-                    "onDelete",
-                    &relation_info.on_delete.to_string(),
-                ));
+            if let Some(on_delete) = relation_info.on_delete {
+                args.push(ast::Argument::new_constant("onDelete", &on_delete.to_string()));
+            }
+
+            if let Some(on_update) = relation_info.on_update {
+                args.push(ast::Argument::new_constant("onUpdate", &on_update.to_string()));
             }
 
             if !args.is_empty() {
@@ -113,3 +126,25 @@ impl AttributeValidator<dml::Field> for RelationAttributeValidator {
         Ok(vec![])
     }
 }
+
+impl RelationAttributeValidator {
+    fn parse_referential_action(&self, value: &ValueValidator) -> Result<dml::ReferentialAction, DatamodelError> {
+        let action = value.as_constant_literal()?;
+
+        action.parse().map_err(|_| {
+            DatamodelError::new_attribute_validation_error(
+                &format!(
+                    "Invalid referential action: `{}`. Possible values: {}.",
+                    action,
+                    dml::ReferentialAction::ALL
+                        .iter()
+                        .map(|action| format!("`{}`", action))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                self.attribute_name(),
+                value.span(),
+            )
+        })
+    }
+}
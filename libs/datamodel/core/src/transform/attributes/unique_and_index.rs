@@ -1,6 +1,6 @@
 use super::{super::helpers::*, AttributeValidator};
 use crate::diagnostics::DatamodelError;
-use crate::{ast, dml, IndexDefinition, IndexType};
+use crate::{ast, dml, IndexDefinition, IndexType, ScalarType};
 use std::cmp::Ordering;
 use std::collections::HashMap;
 
@@ -121,7 +121,59 @@ impl AttributeValidator<dml::Model> for ModelLevelIndexAttributeValidator {
     }
 }
 
-/// common logic for `@@unique` and `@@index`
+/// Prismas builtin `@@fulltext` attribute.
+pub struct ModelLevelFulltextAttributeValidator {}
+
+impl IndexAttributeBase<dml::Model> for ModelLevelFulltextAttributeValidator {}
+impl AttributeValidator<dml::Model> for ModelLevelFulltextAttributeValidator {
+    fn attribute_name(&self) -> &str {
+        "fulltext"
+    }
+
+    fn is_duplicate_definition_allowed(&self) -> bool {
+        true
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        let index_def = self.validate_index(args, obj, IndexType::Fulltext)?;
+
+        let non_string_fields: Vec<&str> = index_def
+            .fields
+            .iter()
+            .filter(|field| {
+                obj.find_scalar_field(&field)
+                    .map(|sf| sf.field_type.scalar_type() != Some(ScalarType::String))
+                    .unwrap_or(false)
+            })
+            .map(|field| field.as_str())
+            .collect();
+
+        if !non_string_fields.is_empty() {
+            return Err(DatamodelError::new_model_validation_error(
+                &format!(
+                    "The fulltext index definition refers to the fields {}. Fulltext indexes can only be defined on String fields.",
+                    non_string_fields.join(", ")
+                ),
+                &obj.name,
+                args.span(),
+            ));
+        }
+
+        obj.indices.push(index_def);
+
+        Ok(())
+    }
+
+    fn serialize(
+        &self,
+        model: &dml::Model,
+        _datamodel: &dml::Datamodel,
+    ) -> Result<Vec<ast::Attribute>, DatamodelError> {
+        self.serialize_index_definitions(&model, IndexType::Fulltext)
+    }
+}
+
+/// common logic for `@@unique`, `@@index` and `@@fulltext`
 trait IndexAttributeBase<T>: AttributeValidator<T> {
     fn validate_index(
         &self,
@@ -153,7 +205,7 @@ trait IndexAttributeBase<T>: AttributeValidator<T> {
             return Err(DatamodelError::new_model_validation_error(
                 &format!(
                     "The {}index definition refers to the fields {} multiple times.",
-                    if index_type == IndexType::Unique { "unique " } else { "" },
+                    index_prefix(index_type),
                     duplicated_fields.join(", ")
                 ),
                 &obj.name,
@@ -184,7 +236,7 @@ trait IndexAttributeBase<T>: AttributeValidator<T> {
             return Err(DatamodelError::new_model_validation_error(
                 &format!(
                     "The {}index definition refers to the unknown fields {}.",
-                    if index_type == IndexType::Unique { "unique " } else { "" },
+                    index_prefix(index_type),
                     undefined_fields.join(", ")
                 ),
                 &obj.name,
@@ -222,7 +274,7 @@ trait IndexAttributeBase<T>: AttributeValidator<T> {
             return Err(DatamodelError::new_model_validation_error(
                 &format!(
                     "The {prefix}index definition refers to the relation fields {the_fields}. Index definitions must reference only scalar fields.{suggestion}",
-                    prefix = if index_type == IndexType::Unique { "unique " } else { "" },
+                    prefix = index_prefix(index_type),
                     the_fields = referenced_relation_fields.join(", "),
                     suggestion = suggestion
                 ),
@@ -267,10 +319,19 @@ trait IndexAttributeBase<T>: AttributeValidator<T> {
 }
 
 fn attribute_name(index_type: dml::IndexType) -> &'static str {
-    if index_type == dml::IndexType::Unique {
-        "unique"
-    } else {
-        "index"
+    match index_type {
+        dml::IndexType::Unique => "unique",
+        dml::IndexType::Normal => "index",
+        dml::IndexType::Fulltext => "fulltext",
+    }
+}
+
+/// The kind of index in validation messages, e.g. "unique index".
+fn index_prefix(index_type: dml::IndexType) -> &'static str {
+    match index_type {
+        dml::IndexType::Unique => "unique ",
+        dml::IndexType::Normal => "",
+        dml::IndexType::Fulltext => "fulltext ",
     }
 }
 
@@ -0,0 +1,37 @@
+use super::{super::helpers::*, AttributeValidator};
+use crate::diagnostics::DatamodelError;
+use crate::{ast, dml};
+
+/// Prismas builtin `@@view` attribute.
+pub struct ViewAttributeValidator {}
+
+impl AttributeValidator<dml::Model> for ViewAttributeValidator {
+    fn attribute_name(&self) -> &'static str {
+        &"view"
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        if obj.relation_fields().next().is_some() {
+            return self.new_attribute_validation_error(
+                "Models that are backed by a view can not have relation fields.",
+                args.span(),
+            );
+        }
+
+        obj.is_view = true;
+
+        Ok(())
+    }
+
+    fn serialize(
+        &self,
+        model: &dml::Model,
+        _datamodel: &dml::Datamodel,
+    ) -> Result<Vec<ast::Attribute>, DatamodelError> {
+        if model.is_view {
+            Ok(vec![ast::Attribute::new(self.attribute_name(), Vec::new())])
+        } else {
+            Ok(vec![])
+        }
+    }
+}
@@ -31,6 +31,23 @@ impl DatasourceSerializer {
             }
         }
 
+        if !source.replica_urls.is_empty() {
+            let replica_urls: Vec<ast::Expression> = source
+                .replica_urls
+                .iter()
+                .map(|replica_url| match replica_url.from_env_var {
+                    Some(ref env_var) => ast::Expression::Function(
+                        "env".to_owned(),
+                        vec![ast::Expression::StringValue(env_var.to_string(), ast::Span::empty())],
+                        ast::Span::empty(),
+                    ),
+                    None => ast::Expression::StringValue(replica_url.value.clone(), ast::Span::empty()),
+                })
+                .collect();
+
+            arguments.push(ast::Argument::new_array("replicaUrls", replica_urls));
+        }
+
         if !&source.preview_features.is_empty() {
             let features: Vec<ast::Expression> = source
                 .preview_features
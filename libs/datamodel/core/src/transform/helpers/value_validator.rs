@@ -54,12 +54,14 @@ impl ValueValidator {
     pub fn as_type(&self, scalar_type: ScalarType) -> Result<PrismaValue, DatamodelError> {
         match scalar_type {
             ScalarType::Int => self.as_int().map(PrismaValue::Int),
+            ScalarType::BigInt => self.as_int().map(PrismaValue::BigInt),
             ScalarType::Float => self.as_float().map(PrismaValue::Float),
             ScalarType::Boolean => self.as_bool().map(PrismaValue::Boolean),
             ScalarType::DateTime => self.as_date_time().map(PrismaValue::DateTime),
             ScalarType::String => self.as_str().map(PrismaValue::String),
             ScalarType::Json => self.as_str().map(PrismaValue::String),
-            ScalarType::Decimal => self.as_float().map(PrismaValue::Float),
+            ScalarType::Decimal => self.as_float().map(PrismaValue::Decimal),
+            ScalarType::Bytes => self.as_bytes().map(PrismaValue::Bytes),
             _ => todo!(),
         }
     }
@@ -121,6 +123,16 @@ impl ValueValidator {
         }
     }
 
+    /// Tries to convert the wrapped value to Prisma Bytes, from a base64
+    /// encoded string.
+    pub fn as_bytes(&self) -> Result<Vec<u8>, DatamodelError> {
+        let value = self.as_str()?;
+
+        prisma_value::decode_bytes(&value).map_err(|_| {
+            DatamodelError::new_value_parser_error("base64 encoded bytes", "Invalid base64 string", &value, self.span())
+        })
+    }
+
     /// Tries to convert the wrapped value to a Prisma Boolean.
     pub fn as_bool(&self) -> Result<bool, DatamodelError> {
         match &self.value {
@@ -2,7 +2,8 @@
 //! The most prominent functionality is the pain free navigation of relations.
 use crate::{
     dml::{
-        Datamodel, DefaultValue, Enum, FieldArity, FieldType, IndexDefinition, Model, ScalarField, WithDatabaseName,
+        CheckConstraint, Datamodel, DefaultValue, Enum, FieldArity, FieldType, IndexDefinition, Model,
+        ReferentialAction, ScalarField, WithDatabaseName,
     },
     NativeTypeInstance, RelationField,
 };
@@ -68,6 +69,11 @@ impl<'a> ModelWalker<'a> {
         self.model.final_database_name()
     }
 
+    /// Whether the model is backed by a database view rather than a table.
+    pub fn is_view(&self) -> bool {
+        self.model.is_view
+    }
+
     pub fn into_relation_fields(self) -> impl Iterator<Item = RelationFieldWalker<'a>> + 'a {
         self.model.relation_fields().map(move |field| RelationFieldWalker {
             datamodel: self.datamodel,
@@ -104,6 +110,10 @@ impl<'a> ModelWalker<'a> {
         self.model.indices.iter()
     }
 
+    pub fn checks<'b>(&'b self) -> impl Iterator<Item = &'a CheckConstraint> + 'b {
+        self.model.checks.iter()
+    }
+
     pub fn name(&self) -> &'a str {
         &self.model.name
     }
@@ -248,6 +258,16 @@ impl<'a> RelationFieldWalker<'a> {
         }
     }
 
+    /// The `onDelete` referential action, if one was defined.
+    pub fn on_delete(&self) -> Option<ReferentialAction> {
+        self.field.relation_info.on_delete
+    }
+
+    /// The `onUpdate` referential action, if one was defined.
+    pub fn on_update(&self) -> Option<ReferentialAction> {
+        self.field.relation_info.on_update
+    }
+
     pub fn opposite_side(&self) -> RelationFieldWalker<'a> {
         RelationFieldWalker {
             datamodel: self.datamodel,
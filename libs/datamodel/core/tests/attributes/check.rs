@@ -0,0 +1,63 @@
+use crate::common::*;
+use datamodel::dml;
+
+#[test]
+fn should_apply_check_attributes() {
+    let dml = r#"
+    model Product {
+        id    Int @id
+        price Int
+        stock Int
+
+        @@check("price > 0", name: "positive_price")
+        @@check("stock >= 0")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let model = schema.assert_has_model("Product");
+
+    assert_eq!(
+        model.checks,
+        vec![
+            dml::CheckConstraint {
+                name: Some("positive_price".to_owned()),
+                expression: "price > 0".to_owned(),
+            },
+            dml::CheckConstraint {
+                name: None,
+                expression: "stock >= 0".to_owned(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn should_fail_on_duplicate_check_names() {
+    let dml = r#"
+    model Product {
+        id    Int @id
+        price Int
+
+        @@check("price > 0", name: "price_check")
+        @@check("price < 1000", name: "price_check")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error parsing attribute \"@check\": The check constraint name `price_check` is already used in this model.",
+    );
+}
+
+#[test]
+fn should_render_check_attributes() {
+    let dml =
+        "model Product {\n  id    Int @id\n  price Int\n\n  @@check(\"price > 0\", name: \"positive_price\")\n}\n";
+
+    let schema = parse(dml);
+    let rendered = datamodel::render_datamodel_to_string(&schema).unwrap();
+
+    assert_eq!(rendered, dml);
+}
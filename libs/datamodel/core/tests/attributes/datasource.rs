@@ -0,0 +1,104 @@
+use crate::common::*;
+
+const DATASOURCES: &str = r#"
+    datasource db {
+        provider = "postgresql"
+        url = "postgresql://localhost/db"
+    }
+
+    datasource analytics {
+        provider = "postgresql"
+        url = "postgresql://localhost/analytics"
+    }
+"#;
+
+#[test]
+fn should_apply_datasource_attribute() {
+    let dml = format!(
+        r#"
+    {}
+
+    model User {{
+        id Int @id
+    }}
+
+    model PageView {{
+        id     Int @id
+        userId Int
+
+        @@datasource(analytics)
+    }}
+    "#,
+        DATASOURCES
+    );
+
+    let schema = parse(&dml);
+
+    assert_eq!(schema.assert_has_model("User").datasource, None);
+    assert_eq!(
+        schema.assert_has_model("PageView").datasource.as_deref(),
+        Some("analytics")
+    );
+}
+
+#[test]
+fn should_allow_relations_across_datasources() {
+    let dml = format!(
+        r#"
+    {}
+
+    model User {{
+        id        Int        @id
+        pageViews PageView[]
+    }}
+
+    model PageView {{
+        id     Int  @id
+        userId Int
+        user   User @relation(fields: [userId], references: [id])
+
+        @@datasource(analytics)
+    }}
+    "#,
+        DATASOURCES
+    );
+
+    let schema = parse(&dml);
+
+    schema
+        .assert_has_model("PageView")
+        .assert_has_relation_field("user")
+        .assert_relation_to("User");
+}
+
+#[test]
+fn should_fail_on_undefined_datasources() {
+    let dml = format!(
+        r#"
+    {}
+
+    model PageView {{
+        id Int @id
+
+        @@datasource(metrics)
+    }}
+    "#,
+        DATASOURCES
+    );
+
+    let errors = parse_error(&dml);
+
+    errors.assert_is_message(
+        "Error parsing attribute \"@datasource\": The datasource `metrics` is not defined in the schema.",
+    );
+}
+
+#[test]
+fn should_render_the_datasource_attribute() {
+    let dml = "model PageView {\n  id Int @id\n\n  @@datasource(analytics)\n}\n";
+
+    let schema = parse(&format!("{}\n{}", DATASOURCES, dml));
+    let rendered = datamodel::render_datamodel_to_string(&schema).unwrap();
+
+    assert_eq!(rendered, dml);
+}
@@ -0,0 +1,99 @@
+use datamodel::{render_datamodel_to_string, IndexDefinition, IndexType};
+
+use crate::common::*;
+
+#[test]
+fn fulltext_index_must_work_on_mysql() {
+    let dml = r#"
+    datasource mysql {
+        provider = "mysql"
+        url = "mysql://asdlj"
+    }
+
+    model Post {
+        id      Int    @id
+        title   String
+        content String
+
+        @@fulltext([title, content], name: "post_search")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let post_model = schema.assert_has_model("Post");
+    post_model.assert_has_index(IndexDefinition {
+        name: Some("post_search".to_string()),
+        fields: vec!["title".to_string(), "content".to_string()],
+        tpe: IndexType::Fulltext,
+    });
+}
+
+#[test]
+fn fulltext_index_must_error_on_non_string_fields() {
+    let dml = r#"
+    datasource mysql {
+        provider = "mysql"
+        url = "mysql://asdlj"
+    }
+
+    model Post {
+        id    Int    @id
+        title String
+        views Int
+
+        @@fulltext([title, views])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error validating model \"Post\": The fulltext index definition refers to the fields views. Fulltext indexes can only be defined on String fields.",
+    );
+}
+
+#[test]
+fn fulltext_index_must_error_on_connectors_without_support() {
+    let dml = r#"
+    datasource postgres {
+        provider = "postgres"
+        url = "postgres://asdlj"
+    }
+
+    model Post {
+        id    Int    @id
+        title String
+
+        @@fulltext([title])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error parsing attribute \"@fulltext\": Fulltext indexes are not supported by the current connector.",
+    );
+}
+
+#[test]
+fn fulltext_index_must_serialize_to_valid_dml() {
+    let dml = r#"
+    datasource mysql {
+        provider = "mysql"
+        url = "mysql://asdlj"
+    }
+
+    model Post {
+        id      Int    @id
+        title   String
+        content String
+
+        @@fulltext([title, content])
+    }
+    "#;
+
+    let schema = parse(dml);
+    let rendered = render_datamodel_to_string(&schema).unwrap();
+
+    assert!(rendered.contains("@@fulltext([title, content])"));
+}
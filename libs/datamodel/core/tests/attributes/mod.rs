@@ -1,10 +1,14 @@
 pub mod builtin_attributes;
+pub mod check;
+pub mod datasource;
 pub mod default_negative;
 pub mod default_positive;
+pub mod fulltext;
 pub mod id_negative;
 pub mod id_positive;
 pub mod index;
 pub mod map;
+pub mod referential_actions;
 pub mod relations_basic;
 pub mod relations_consistency;
 pub mod relations_legacy;
@@ -15,5 +19,6 @@ pub mod unique;
 pub mod unique_criteria;
 pub mod updated_at_negative;
 pub mod updated_at_positive;
+pub mod view;
 
 pub mod arg_parsing;
@@ -0,0 +1,125 @@
+use crate::common::*;
+use datamodel::dml;
+
+#[test]
+fn should_parse_referential_actions() {
+    let dml = r#"
+    model User {
+        id    Int @id
+        posts Post[]
+    }
+
+    model Post {
+        id     Int  @id
+        userId Int
+        user   User @relation(fields: [userId], references: [id], onDelete: Restrict, onUpdate: NoAction)
+    }
+    "#;
+
+    let schema = parse(dml);
+
+    schema
+        .assert_has_model("Post")
+        .assert_has_relation_field("user")
+        .assert_relation_delete_strategy(dml::ReferentialAction::Restrict)
+        .assert_relation_update_strategy(dml::ReferentialAction::NoAction);
+}
+
+#[test]
+fn should_allow_set_null_on_optional_relations() {
+    let dml = r#"
+    model User {
+        id    Int @id
+        posts Post[]
+    }
+
+    model Post {
+        id     Int   @id
+        userId Int?
+        user   User? @relation(fields: [userId], references: [id], onDelete: SetNull)
+    }
+    "#;
+
+    let schema = parse(dml);
+
+    schema
+        .assert_has_model("Post")
+        .assert_has_relation_field("user")
+        .assert_relation_delete_strategy(dml::ReferentialAction::SetNull);
+}
+
+#[test]
+fn should_fail_on_invalid_referential_actions() {
+    let dml = r#"
+    model User {
+        id    Int @id
+        posts Post[]
+    }
+
+    model Post {
+        id     Int  @id
+        userId Int
+        user   User @relation(fields: [userId], references: [id], onDelete: Delete)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error parsing attribute \"@relation\": Invalid referential action: `Delete`. Possible values: `Cascade`, `Restrict`, `NoAction`, `SetNull`, `SetDefault`.",
+    );
+}
+
+#[test]
+fn should_fail_on_set_null_for_required_fields() {
+    let dml = r#"
+    model User {
+        id    Int @id
+        posts Post[]
+    }
+
+    model Post {
+        id     Int  @id
+        userId Int
+        user   User @relation(fields: [userId], references: [id], onDelete: SetNull)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error validating: The `onDelete` referential action of the relation field `user` can not be `SetNull`, because some of the scalar fields userId are required.",
+    );
+}
+
+#[test]
+fn should_fail_on_referential_actions_on_the_back_relation_field() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[] @relation(onDelete: Cascade)
+    }
+
+    model Post {
+        id     Int  @id
+        userId Int
+        user   User @relation(fields: [userId], references: [id])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error parsing attribute \"@relation\": Referential actions can only be defined on the relation field that defines the `fields` argument.",
+    );
+}
+
+#[test]
+fn should_render_referential_actions() {
+    let dml = "model User {\n  id    Int    @id\n  posts Post[]\n}\n\nmodel Post {\n  id     Int  @id\n  userId Int\n  user   User @relation(fields: [userId], references: [id], onDelete: Restrict, onUpdate: SetDefault)\n}\n";
+
+    let schema = parse(dml);
+    let rendered = datamodel::render_datamodel_to_string(&schema).unwrap();
+
+    assert_eq!(rendered, dml);
+}
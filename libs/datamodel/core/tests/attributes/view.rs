@@ -0,0 +1,70 @@
+use crate::common::*;
+use datamodel::ScalarType;
+
+#[test]
+fn should_apply_view_attribute() {
+    let dml = r#"
+    model UserStats {
+        name       String
+        postCount  Int
+
+        @@view
+    }
+    "#;
+
+    let schema = parse(dml);
+    let model = schema.assert_has_model("UserStats");
+
+    assert!(model.is_view);
+    model
+        .assert_has_scalar_field("postCount")
+        .assert_base_type(&ScalarType::Int);
+}
+
+#[test]
+fn views_do_not_need_a_unique_criteria() {
+    let dml = r#"
+    model UserStats {
+        name String
+
+        @@view
+    }
+    "#;
+
+    let schema = parse(dml);
+    let model = schema.assert_has_model("UserStats");
+
+    assert_eq!(model.first_unique_criterion().len(), 1);
+}
+
+#[test]
+fn should_fail_on_views_with_relation_fields() {
+    let dml = r#"
+    model User {
+        id Int @id
+    }
+
+    model UserStats {
+        userId Int
+        user   User @relation(fields: [userId], references: [id])
+
+        @@view
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error parsing attribute \"@view\": Models that are backed by a view can not have relation fields.",
+    );
+}
+
+#[test]
+fn should_render_the_view_attribute() {
+    let dml = "model UserStats {\n  name String\n\n  @@view\n}\n";
+
+    let schema = parse(dml);
+    let rendered = datamodel::render_datamodel_to_string(&schema).unwrap();
+
+    assert_eq!(rendered, dml);
+}
@@ -5,7 +5,7 @@ use serial_test::serial;
 
 #[test]
 #[serial]
-fn must_error_if_multiple_datasources_are_defined() {
+fn must_load_multiple_datasources() {
     let schema = r#"
 datasource db1 {
     provider = "postgresql"
@@ -18,16 +18,13 @@ datasource db2 {
 }
 "#;
 
-    let diagnostics = parse_error(&schema);
-    diagnostics.assert_length(2);
-    diagnostics.assert_is_at(
-        0,
-        DatamodelError::new_source_validation_error("You defined more than one datasource. This is not allowed yet because support for multiple databases has not been implemented yet.", "db1", Span::new(1, 82)),
-    );
-    diagnostics.assert_is_at(
-        1,
-        DatamodelError::new_source_validation_error("You defined more than one datasource. This is not allowed yet because support for multiple databases has not been implemented yet.", "db2", Span::new(84, 155)),
-    );
+    let config = parse_configuration(schema);
+
+    assert_eq!(config.datasources.len(), 2);
+    assert_eq!(config.datasources[0].name, "db1");
+    assert_eq!(config.datasources[0].active_provider, "postgresql");
+    assert_eq!(config.datasources[1].name, "db2");
+    assert_eq!(config.datasources[1].active_provider, "mysql");
 }
 
 #[test]
@@ -332,6 +329,50 @@ fn fail_when_no_source_is_declared() {
     }
 }
 
+#[test]
+#[serial]
+fn replica_urls_must_be_loaded() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgres://primary"
+          replicaUrls = ["postgres://replica1", env("REPLICA_URL")]
+        }
+    "#;
+    std::env::set_var("REPLICA_URL", "postgres://replica2");
+
+    let config = parse_configuration(schema);
+    let data_source = config.datasources.first().unwrap();
+
+    assert_eq!(
+        data_source.replica_urls,
+        vec![
+            StringFromEnvVar {
+                from_env_var: None,
+                value: "postgres://replica1".to_string(),
+            },
+            StringFromEnvVar {
+                from_env_var: Some("REPLICA_URL".to_string()),
+                value: "postgres://replica2".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn replica_urls_must_match_the_provider() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgres://primary"
+          replicaUrls = ["mysql://replica"]
+        }
+    "#;
+
+    let config = datamodel::parse_configuration(schema);
+    assert!(config.is_err());
+}
+
 #[test]
 fn microsoft_sql_server_preview_feature_must_work() {
     let schema = r#"
@@ -30,6 +30,35 @@ fn test_dmmf_rendering() {
     }
 }
 
+#[test]
+fn referential_actions_are_rendered_in_upper_case() {
+    let dml = parse(
+        r#"
+        model User {
+            id    Int    @id
+            posts Post[]
+        }
+
+        model Post {
+            id       Int  @id
+            authorId Int?
+            author   User? @relation(fields: [authorId], references: [id], onDelete: SetNull)
+        }
+    "#,
+    );
+
+    let dmmf = datamodel::json::dmmf::render_to_dmmf_value(&dml);
+    let strategies: Vec<&str> = dmmf["models"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(|model| model["fields"].as_array().unwrap())
+        .filter_map(|field| field["relationOnDelete"].as_str())
+        .collect();
+
+    assert_eq!(strategies, &["NONE", "SET_NULL"]);
+}
+
 fn assert_eq_json(a: &str, b: &str, msg: &str) {
     let json_a: serde_json::Value = serde_json::from_str(a).expect("The String a was not valid JSON.");
     let json_b: serde_json::Value = serde_json::from_str(b).expect("The String b was not valid JSON.");
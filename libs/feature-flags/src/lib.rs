@@ -55,7 +55,23 @@ macro_rules! flags {
 // `transaction`: Transactional batches support in the QE.
 // `connectOrCreate`: `connectOrCreate` nested query in the QE.
 // `microsoftSqlServer`: Support for Microsoft SQL Server databases
-flags!(transaction, connectOrCreate, microsoftSqlServer);
+// `createMany`: `createMany` bulk insert mutation in the QE.
+// `groupBy`: `groupBy` aggregation query in the QE.
+// `orderByRelation`: Ordering by fields of related records and by relation counts in the QE.
+// `interactiveTransactions`: Long-running transactions that are controlled over the HTTP server in the QE.
+// `filterJson`: Filtering on the contents of Json fields and on nested Json paths in the QE.
+// `fullTextSearch`: `search` filters on String fields and ordering by search relevance in the QE.
+flags!(
+    transaction,
+    connectOrCreate,
+    microsoftSqlServer,
+    createMany,
+    groupBy,
+    orderByRelation,
+    interactiveTransactions,
+    filterJson,
+    fullTextSearch
+);
 
 /// Initializes the feature flags with given flags.
 /// Noop if already initialized.
@@ -1,6 +1,7 @@
 use crate::*;
 use datamodel::{dml, DefaultValue, WithDatabaseName};
 use itertools::Itertools;
+use std::collections::HashMap;
 
 pub struct DatamodelConverter<'a> {
     datamodel: &'a dml::Datamodel,
@@ -30,6 +31,7 @@ impl<'a> DatamodelConverter<'a> {
             relations: self.convert_relations(),
             enums: self.convert_enums(),
             version: Some("v2".to_string()),
+            datasource_db_names: HashMap::new(),
         }
     }
 
@@ -58,6 +60,8 @@ impl<'a> DatamodelConverter<'a> {
             .map(|model| ModelTemplate {
                 name: model.name.clone(),
                 is_embedded: model.is_embedded,
+                is_view: model.is_view,
+                datasource: model.datasource.clone(),
                 fields: self.convert_fields(&model),
                 manifestation: model.database_name().map(|s| s.to_owned()),
                 id_field_names: model.id_fields.clone(),
@@ -133,6 +137,7 @@ impl<'a> DatamodelConverter<'a> {
                 typ: match i.tpe {
                     dml::IndexType::Unique => IndexType::Unique,
                     dml::IndexType::Normal => IndexType::Normal,
+                    dml::IndexType::Fulltext => IndexType::Fulltext,
                 },
             })
             .collect()
@@ -349,8 +354,11 @@ impl DatamodelFieldExtensions for dml::ScalarField {
                 dml::ScalarType::DateTime => TypeIdentifier::DateTime,
                 dml::ScalarType::Float => TypeIdentifier::Float,
                 dml::ScalarType::Int => TypeIdentifier::Int,
+                dml::ScalarType::BigInt => TypeIdentifier::BigInt,
                 dml::ScalarType::String => TypeIdentifier::String,
                 dml::ScalarType::Json => TypeIdentifier::Json,
+                dml::ScalarType::Bytes => TypeIdentifier::Bytes,
+                dml::ScalarType::Decimal => TypeIdentifier::Decimal,
                 _ => todo!(),
             },
             dml::FieldType::Unsupported(_) => panic!("These should always be commented out"),
@@ -376,7 +384,7 @@ impl DatamodelFieldExtensions for dml::ScalarField {
     fn is_auto_generated_int_id(&self) -> bool {
         let is_autogenerated_id = matches!(self.default_value, Some(DefaultValue::Expression(_)) if self.is_id);
 
-        let is_an_int = self.type_identifier() == TypeIdentifier::Int;
+        let is_an_int = matches!(self.type_identifier(), TypeIdentifier::Int | TypeIdentifier::BigInt);
 
         is_autogenerated_id && is_an_int
     }
@@ -66,6 +66,9 @@ pub enum TypeIdentifier {
     DateTime,
     UUID,
     Int,
+    BigInt,
+    Bytes,
+    Decimal,
 }
 
 impl Field {
@@ -166,11 +169,13 @@ impl From<ScalarType> for TypeIdentifier {
         match st {
             ScalarType::String => Self::String,
             ScalarType::Int => Self::Int,
+            ScalarType::BigInt => Self::BigInt,
             ScalarType::Float => Self::Float,
             ScalarType::Boolean => Self::Boolean,
             ScalarType::DateTime => Self::DateTime,
             ScalarType::Json => Self::Json,
-            ScalarType::Decimal => Self::Float,
+            ScalarType::Bytes => Self::Bytes,
+            ScalarType::Decimal => Self::Decimal,
             _ => todo!(),
         }
     }
@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use datamodel::{FieldArity, RelationInfo};
+use datamodel::{FieldArity, ReferentialAction, RelationInfo};
 use once_cell::sync::OnceCell;
 use std::{
     fmt::Debug,
@@ -227,6 +227,13 @@ impl RelationField {
         self.relation().is_inline_relation() && !self.relation_is_inlined_in_parent()
     }
 
+    /// The `onDelete` referential action of the relation, which is defined on either side.
+    pub fn on_delete(&self) -> Option<ReferentialAction> {
+        self.relation_info
+            .on_delete
+            .or_else(|| self.related_field().relation_info.on_delete)
+    }
+
     pub fn related_model(&self) -> ModelRef {
         match self.relation_side {
             RelationSide::A => self.relation().model_b(),
@@ -234,6 +241,12 @@ impl RelationField {
         }
     }
 
+    /// Whether the related model is stored in another datasource. Such relations are resolved in
+    /// memory, so they can't be filtered or ordered by in the query on this model.
+    pub fn spans_datasources(&self) -> bool {
+        !self.model().shares_datasource_with(&self.related_model())
+    }
+
     pub fn related_field(&self) -> Arc<RelationField> {
         match self.relation_side {
             RelationSide::A => self.relation().field_b(),
@@ -13,6 +13,7 @@ impl IndexTemplate {
         let fields = match self.typ {
             IndexType::Unique => Self::map_fields(self.fields, fields),
             IndexType::Normal => Self::map_fields(self.fields, fields),
+            IndexType::Fulltext => Self::map_fields(self.fields, fields),
         };
 
         Index {
@@ -54,4 +55,5 @@ impl Index {
 pub enum IndexType {
     Unique,
     Normal,
+    Fulltext,
 }
@@ -1,6 +1,9 @@
 use crate::prelude::*;
 use once_cell::sync::OnceCell;
-use std::sync::{Arc, Weak};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Weak},
+};
 
 pub type InternalDataModelRef = Arc<InternalDataModel>;
 pub type InternalDataModelWeakRef = Weak<InternalDataModel>;
@@ -11,6 +14,8 @@ pub struct InternalDataModelTemplate {
     pub relations: Vec<RelationTemplate>,
     pub enums: Vec<InternalEnum>,
     pub version: Option<String>,
+    /// The db names of the datasources other than the first one, by datasource name.
+    pub datasource_db_names: HashMap<String, String>,
 }
 
 #[derive(Debug)]
@@ -25,6 +30,10 @@ pub struct InternalDataModel {
     /// influence the `database` part instead.
     pub db_name: String,
 
+    /// The db names of the datasources other than the first one, by datasource name.
+    /// Models stored in these datasources are queried with their db name instead of `db_name`.
+    pub datasource_db_names: HashMap<String, String>,
+
     models: OnceCell<Vec<ModelRef>>,
     relations: OnceCell<Vec<RelationRef>>,
     relation_fields: OnceCell<Vec<RelationFieldRef>>,
@@ -98,6 +107,7 @@ impl InternalDataModelTemplate {
             enums: self.enums,
             version: self.version,
             db_name,
+            datasource_db_names: self.datasource_db_names,
             relation_fields: OnceCell::new(),
         });
 
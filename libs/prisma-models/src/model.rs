@@ -13,6 +13,8 @@ pub type ModelWeakRef = Weak<Model>;
 pub struct ModelTemplate {
     pub name: String,
     pub is_embedded: bool,
+    pub is_view: bool,
+    pub datasource: Option<String>,
     pub fields: Vec<FieldTemplate>,
     pub manifestation: Option<String>,
     pub id_field_names: Vec<String>,
@@ -23,6 +25,8 @@ pub struct ModelTemplate {
 pub struct Model {
     pub name: String,
     pub is_embedded: bool,
+    pub is_view: bool,
+    pub datasource: Option<String>,
 
     manifestation: Option<String>,
     fields: OnceCell<Fields>,
@@ -38,6 +42,8 @@ impl Debug for Model {
         f.debug_struct("Model")
             .field("name", &self.name)
             .field("is_embedded", &self.is_embedded)
+            .field("is_view", &self.is_view)
+            .field("datasource", &self.datasource)
             .field("manifestation", &self.manifestation)
             .field("fields", &self.fields)
             .field("indexes", &self.indexes)
@@ -53,6 +59,8 @@ impl ModelTemplate {
         let model = Arc::new(Model {
             name: self.name,
             is_embedded: self.is_embedded,
+            is_view: self.is_view,
+            datasource: self.datasource,
             manifestation: self.manifestation,
             fields: OnceCell::new(),
             indexes: OnceCell::new(),
@@ -151,6 +159,31 @@ impl Model {
         self.manifestation.as_ref().map(|m| m.as_ref())
     }
 
+    /// The db name of the datasource the model is stored in, see `InternalDataModel::db_name`.
+    pub fn datasource_db_name(&self) -> String {
+        let internal_data_model = self.internal_data_model();
+
+        self.datasource
+            .as_ref()
+            .and_then(|datasource| internal_data_model.datasource_db_names.get(datasource))
+            .unwrap_or(&internal_data_model.db_name)
+            .clone()
+    }
+
+    /// Whether the model is stored in the same datasource as `other`.
+    pub fn shares_datasource_with(&self, other: &Model) -> bool {
+        self.additional_datasource() == other.additional_datasource()
+    }
+
+    /// The datasource the model is stored in, if it is not the first one.
+    fn additional_datasource(&self) -> Option<&str> {
+        let internal_data_model = self.internal_data_model();
+
+        self.datasource
+            .as_deref()
+            .filter(|datasource| internal_data_model.datasource_db_names.contains_key(*datasource))
+    }
+
     pub fn internal_data_model(&self) -> InternalDataModelRef {
         self.internal_data_model
             .upgrade()
@@ -1,15 +1,123 @@
-use crate::{ModelRef, ScalarFieldRef};
+use crate::{ModelRef, RelationFieldRef, ScalarFieldRef};
 use std::string::ToString;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct OrderBy {
+    /// The field to order by. If `path` is not empty, the field is on the model
+    /// at the end of the path instead of the queried model.
     pub field: ScalarFieldRef,
+
+    /// Relation fields to walk from the queried model to the model of `field`.
+    /// All hops are to-one relations, except for the last hop of a relation count ordering.
+    pub path: Vec<RelationFieldRef>,
+
     pub sort_order: SortOrder,
+
+    /// If set, the records are ordered by the aggregated value of the field
+    /// instead of the field itself. Only valid when records are grouped, or
+    /// for counting the records of a to-many relation at the end of `path`.
+    pub sort_aggregation: Option<SortAggregation>,
+
+    /// If set, the records are ordered by the relevance of a full-text search
+    /// instead of the field itself. `field` is the first of the searched fields.
+    pub relevance: Option<OrderByRelevance>,
+}
+
+/// Ordering by how well the records match a full-text search on the given fields.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OrderByRelevance {
+    pub fields: Vec<ScalarFieldRef>,
+    pub search: String,
 }
 
 impl OrderBy {
     pub fn new(field: ScalarFieldRef, sort_order: SortOrder) -> Self {
-        Self { field, sort_order }
+        Self {
+            field,
+            path: vec![],
+            sort_order,
+            sort_aggregation: None,
+            relevance: None,
+        }
+    }
+
+    pub fn aggregation(field: ScalarFieldRef, sort_order: SortOrder, sort_aggregation: SortAggregation) -> Self {
+        Self {
+            field,
+            path: vec![],
+            sort_order,
+            sort_aggregation: Some(sort_aggregation),
+            relevance: None,
+        }
+    }
+
+    /// Orders by a field of a related record, reached by walking the given to-one relations.
+    pub fn relation(field: ScalarFieldRef, path: Vec<RelationFieldRef>, sort_order: SortOrder) -> Self {
+        Self {
+            field,
+            path,
+            sort_order,
+            sort_aggregation: None,
+            relevance: None,
+        }
+    }
+
+    /// Orders by the number of related records of the to-many relation at the end of the path.
+    /// The count is expressed as a count over the primary identifier of the related model.
+    pub fn relation_count(path: Vec<RelationFieldRef>, sort_order: SortOrder) -> Self {
+        let related_model = path
+            .last()
+            .expect("A relation count ordering requires at least one relation field.")
+            .related_model();
+
+        let field = related_model
+            .primary_identifier()
+            .scalar_fields()
+            .next()
+            .expect("Expected the related model to have a primary identifier.");
+
+        Self {
+            field,
+            path,
+            sort_order,
+            sort_aggregation: Some(SortAggregation::Count),
+            relevance: None,
+        }
+    }
+
+    /// Orders by the relevance of the records for a full-text search of `search` on the given fields.
+    pub fn relevance(fields: Vec<ScalarFieldRef>, search: String, sort_order: SortOrder) -> Self {
+        let field = fields
+            .first()
+            .cloned()
+            .expect("A relevance ordering requires at least one field.");
+
+        Self {
+            field,
+            path: vec![],
+            sort_order,
+            sort_aggregation: None,
+            relevance: Some(OrderByRelevance { fields, search }),
+        }
+    }
+
+    /// Returns `true` if the ordering is on a plain field of the queried model.
+    pub fn is_scalar(&self) -> bool {
+        self.path.is_empty() && self.sort_aggregation.is_none() && self.relevance.is_none()
+    }
+
+    /// Returns `true` if the value ordered by can be null, i.e. if the field
+    /// or any of the to-one relations on the way to it are optional.
+    pub fn is_nullable(&self) -> bool {
+        if self.relevance.is_some() {
+            return false;
+        }
+
+        match self.sort_aggregation {
+            Some(SortAggregation::Count) => false,
+            Some(_) => true,
+            None => !self.field.is_required || self.path.iter().any(|rf| !rf.is_required),
+        }
     }
 }
 
@@ -23,6 +131,15 @@ pub enum SortOrder {
     Descending,
 }
 
+#[derive(Clone, Copy, PartialEq, Debug, Eq, Hash)]
+pub enum SortAggregation {
+    Count,
+    Avg,
+    Sum,
+    Min,
+    Max,
+}
+
 impl ToString for SortOrder {
     fn to_string(&self) -> String {
         match self {
@@ -36,7 +153,10 @@ impl From<ScalarFieldRef> for OrderBy {
     fn from(field: ScalarFieldRef) -> Self {
         Self {
             field,
+            path: vec![],
             sort_order: SortOrder::Ascending,
+            sort_aggregation: None,
+            relevance: None,
         }
     }
 }
@@ -21,6 +21,9 @@ impl PrismaValueExtensions for PrismaValue {
             (val @ PrismaValue::DateTime(_), TypeIdentifier::DateTime) => val,
             (val @ PrismaValue::Enum(_), TypeIdentifier::Enum(_)) => val,
             (val @ PrismaValue::Uuid(_), TypeIdentifier::UUID) => val,
+            (val @ PrismaValue::BigInt(_), TypeIdentifier::BigInt) => val,
+            (val @ PrismaValue::Bytes(_), TypeIdentifier::Bytes) => val,
+            (val @ PrismaValue::Decimal(_), TypeIdentifier::Decimal) => val,
 
             // Valid String coercions
             (PrismaValue::Int(i), TypeIdentifier::String) => PrismaValue::String(format!("{}", i)),
@@ -31,6 +34,8 @@ impl PrismaValueExtensions for PrismaValue {
             }
             (PrismaValue::Enum(e), TypeIdentifier::String) => PrismaValue::String(e),
             (PrismaValue::Uuid(u), TypeIdentifier::String) => PrismaValue::String(u.to_string()),
+            (PrismaValue::BigInt(i), TypeIdentifier::String) => PrismaValue::String(format!("{}", i)),
+            (PrismaValue::Decimal(d), TypeIdentifier::String) => PrismaValue::String(d.to_string()),
 
             // Valid Int coersions
             (PrismaValue::String(s), TypeIdentifier::Int) => match s.parse() {
@@ -43,6 +48,46 @@ impl PrismaValueExtensions for PrismaValue {
                 }
             },
             (PrismaValue::Float(f), TypeIdentifier::Int) => PrismaValue::Int(f.trunc().to_i64().unwrap()),
+            (PrismaValue::BigInt(i), TypeIdentifier::Int) => PrismaValue::Int(i),
+
+            // Valid BigInt coercions
+            (PrismaValue::Int(i), TypeIdentifier::BigInt) => PrismaValue::BigInt(i),
+            (PrismaValue::String(s), TypeIdentifier::BigInt) => match s.parse() {
+                Ok(i) => PrismaValue::BigInt(i),
+                Err(_) => {
+                    return Err(DomainError::ConversionFailure(
+                        format!("{:?}", s),
+                        format!("{:?}", to_type),
+                    ))
+                }
+            },
+
+            // Valid Decimal coercions
+            (PrismaValue::Float(f), TypeIdentifier::Decimal) => PrismaValue::Decimal(f),
+            (PrismaValue::Int(i), TypeIdentifier::Decimal) | (PrismaValue::BigInt(i), TypeIdentifier::Decimal) => {
+                PrismaValue::Decimal(i.into())
+            }
+            (PrismaValue::String(s), TypeIdentifier::Decimal) => match s.parse() {
+                Ok(d) => PrismaValue::Decimal(d),
+                Err(_) => {
+                    return Err(DomainError::ConversionFailure(
+                        format!("{:?}", s),
+                        format!("{:?}", to_type),
+                    ))
+                }
+            },
+            (PrismaValue::Decimal(d), TypeIdentifier::Float) => PrismaValue::Float(d),
+
+            // Valid Bytes coercions
+            (PrismaValue::String(s), TypeIdentifier::Bytes) => match prisma_value::decode_bytes(&s) {
+                Ok(bytes) => PrismaValue::Bytes(bytes),
+                Err(_) => {
+                    return Err(DomainError::ConversionFailure(
+                        format!("{:?}", s),
+                        format!("{:?}", to_type),
+                    ))
+                }
+            },
 
             // Todo other coercions here
 
@@ -93,27 +93,24 @@ impl AsColumns for Field {
 impl AsColumns for RelationField {
     fn as_columns(&self) -> ColumnIterator {
         let model = self.model();
-        let internal_data_model = model.internal_data_model();
 
         let relation = self.relation();
-        let table_name = if relation.is_many_to_many() {
+        let (db_name, table_name) = if relation.is_many_to_many() {
+            // Relation tables are stored in the datasource of the `A` model.
             if let RelationLinkManifestation::RelationTable(ref rt) = relation.manifestation {
-                rt.table.clone()
+                (relation.model_a().datasource_db_name(), rt.table.clone())
             } else {
                 unreachable!()
             }
         } else {
-            model.db_name().to_string()
+            (model.datasource_db_name(), model.db_name().to_string())
         };
 
         let inner: Vec<_> = self
             .scalar_fields()
             .iter()
             .map(|f| {
-                let parts = (
-                    (internal_data_model.db_name.clone(), table_name.clone()),
-                    f.db_name().to_owned(),
-                );
+                let parts = ((db_name.clone(), table_name.clone()), f.db_name().to_owned());
 
                 Column::from(parts)
             })
@@ -149,7 +146,7 @@ where
 {
     fn as_column(&self) -> Column<'static> {
         let sf = self.as_ref();
-        let db = sf.internal_data_model().db_name.clone();
+        let db = sf.model().datasource_db_name();
         let table = sf.model().db_name().to_string();
         let col = sf.db_name().to_string();
 
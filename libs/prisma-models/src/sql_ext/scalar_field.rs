@@ -16,6 +16,9 @@ impl ScalarFieldExt for ScalarField {
             (PrismaValue::DateTime(d), _) => d.into(),
             (PrismaValue::Enum(e), _) => e.into(),
             (PrismaValue::Int(i), _) => (i as i64).into(),
+            (PrismaValue::BigInt(i), _) => i.into(),
+            (PrismaValue::Bytes(b), _) => Value::Bytes(Some(b.into())),
+            (PrismaValue::Decimal(d), _) => d.into(),
             (PrismaValue::Uuid(u), _) => u.to_string().into(),
             (PrismaValue::List(l), _) => Value::Array(Some(l.into_iter().map(|x| self.value(x)).collect())),
             (PrismaValue::Json(s), _) => Value::Json(serde_json::from_str(&s).unwrap()),
@@ -29,6 +32,9 @@ impl ScalarFieldExt for ScalarField {
                 TypeIdentifier::DateTime => Value::DateTime(None),
                 TypeIdentifier::UUID => Value::Uuid(None),
                 TypeIdentifier::Int => Value::Integer(None),
+                TypeIdentifier::BigInt => Value::Integer(None),
+                TypeIdentifier::Bytes => Value::Bytes(None),
+                TypeIdentifier::Decimal => Value::Real(None),
             },
         }
     }
@@ -44,6 +50,9 @@ pub fn convert_lossy<'a>(pv: PrismaValue) -> Value<'a> {
         PrismaValue::DateTime(d) => d.into(),
         PrismaValue::Enum(e) => e.into(),
         PrismaValue::Int(i) => (i as i64).into(),
+        PrismaValue::BigInt(i) => i.into(),
+        PrismaValue::Bytes(b) => Value::Bytes(Some(b.into())),
+        PrismaValue::Decimal(d) => d.into(),
         PrismaValue::Uuid(u) => u.to_string().into(),
         PrismaValue::List(l) => Value::Array(Some(l.into_iter().map(convert_lossy).collect())),
         PrismaValue::Json(s) => Value::Json(serde_json::from_str(&s).unwrap()),
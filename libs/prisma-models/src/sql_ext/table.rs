@@ -8,7 +8,7 @@ pub trait AsTable {
 
 impl AsTable for Model {
     fn as_table(&self) -> Table<'static> {
-        let table: Table<'static> = (self.internal_data_model().db_name.clone(), self.db_name().to_string()).into();
+        let table: Table<'static> = (self.datasource_db_name(), self.db_name().to_string()).into();
 
         self.unique_indexes().into_iter().fold(table, |table, index| {
             let index: Vec<Column<'static>> = index.fields().iter().map(AsColumn::as_column).collect();
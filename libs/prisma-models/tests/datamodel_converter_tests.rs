@@ -124,6 +124,49 @@ fn db_names_work() {
     assert_eq!(field.db_name(), "my_column")
 }
 
+#[test]
+fn models_are_queried_with_the_db_name_of_their_datasource() {
+    let datamodel = datamodel::parse_datamodel(
+        r#"
+            datasource db {
+                provider = "postgresql"
+                url = "postgresql://localhost/db"
+            }
+
+            datasource analytics {
+                provider = "postgresql"
+                url = "postgresql://localhost/analytics"
+            }
+
+            model User {
+                id Int @id
+            }
+
+            model PageView {
+                id Int @id
+
+                @@datasource(analytics)
+            }
+        "#,
+    )
+    .unwrap()
+    .subject;
+
+    let mut template = DatamodelConverter::convert(&datamodel);
+    template
+        .datasource_db_names
+        .insert("analytics".to_owned(), "tracking".to_owned());
+
+    let internal_data_model = template.build("public".to_owned());
+    let user = internal_data_model.assert_model("User");
+    let page_view = internal_data_model.assert_model("PageView");
+
+    assert_eq!(user.datasource, None);
+    assert_eq!(user.datasource_db_name(), "public");
+    assert_eq!(page_view.datasource.as_deref(), Some("analytics"));
+    assert_eq!(page_view.datasource_db_name(), "tracking");
+}
+
 #[test]
 fn scalar_lists_work() {
     let datamodel = convert(
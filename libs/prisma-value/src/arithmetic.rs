@@ -23,6 +23,22 @@ macro_rules! number_operation {
 
           (PrismaValue::Float(l), PrismaValue::Float(r)) => PrismaValue::Float(l $op r),
 
+          (PrismaValue::BigInt(l), PrismaValue::BigInt(r))
+          | (PrismaValue::BigInt(l), PrismaValue::Int(r))
+          | (PrismaValue::Int(l), PrismaValue::BigInt(r)) => PrismaValue::BigInt(l $op r),
+
+          (PrismaValue::Decimal(l), PrismaValue::Decimal(r))
+          | (PrismaValue::Decimal(l), PrismaValue::Float(r))
+          | (PrismaValue::Float(l), PrismaValue::Decimal(r)) => PrismaValue::Decimal(l $op r),
+
+          (PrismaValue::Decimal(l), PrismaValue::Int(r)) | (PrismaValue::Decimal(l), PrismaValue::BigInt(r)) => {
+              PrismaValue::Decimal(l $op Decimal::from(r))
+          }
+
+          (PrismaValue::Int(l), PrismaValue::Decimal(r)) | (PrismaValue::BigInt(l), PrismaValue::Decimal(r)) => {
+              PrismaValue::Decimal(Decimal::from(l) $op r)
+          }
+
           _ => unimplemented!(),
         }
       }
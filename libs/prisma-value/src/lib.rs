@@ -37,12 +37,35 @@ pub enum PrismaValue {
 
     #[serde(serialize_with = "serialize_decimal")]
     Float(Decimal),
+
+    /// Serialized as a string, since JSON numbers lose precision beyond 2^53
+    /// in JavaScript.
+    #[serde(serialize_with = "serialize_bigint")]
+    BigInt(i64),
+
+    /// Serialized as a base64 encoded string.
+    #[serde(serialize_with = "serialize_bytes")]
+    Bytes(Vec<u8>),
+
+    /// An exact decimal. Serialized as a string, keeping its scale.
+    #[serde(serialize_with = "serialize_exact_decimal")]
+    Decimal(Decimal),
 }
 
 pub fn stringify_date(date: &DateTime<Utc>) -> String {
     format!("{}", date.format("%Y-%m-%dT%H:%M:%S%.3fZ"))
 }
 
+/// Encodes bytes in base64, the wire format of `PrismaValue::Bytes`.
+pub fn encode_bytes(bytes: &[u8]) -> String {
+    base64::encode(bytes)
+}
+
+/// Decodes base64 encoded bytes, the wire format of `PrismaValue::Bytes`.
+pub fn decode_bytes(s: &str) -> PrismaValueResult<Vec<u8>> {
+    base64::decode(s).map_err(|_| ConversionFailure::new("base64 encoded bytes", "PrismaValue::Bytes"))
+}
+
 impl TryFrom<serde_json::Value> for PrismaValue {
     type Error = crate::error::ConversionFailure;
 
@@ -91,6 +114,20 @@ where
     stringify_date(date).serialize(serializer)
 }
 
+fn serialize_bigint<S>(int: &i64, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    int.to_string().serialize(serializer)
+}
+
+fn serialize_bytes<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    encode_bytes(bytes).serialize(serializer)
+}
+
 fn serialize_null<S>(serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
@@ -105,6 +142,13 @@ where
     decimal.to_string().parse::<f64>().unwrap().serialize(serializer)
 }
 
+fn serialize_exact_decimal<S>(decimal: &Decimal, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    decimal.to_string().serialize(serializer)
+}
+
 impl PrismaValue {
     pub fn is_null(&self) -> bool {
         matches!(self, PrismaValue::Null)
@@ -145,6 +189,9 @@ impl fmt::Display for PrismaValue {
             PrismaValue::Null => "null".fmt(f),
             PrismaValue::Uuid(x) => x.fmt(f),
             PrismaValue::Json(x) => x.fmt(f),
+            PrismaValue::BigInt(x) => x.fmt(f),
+            PrismaValue::Bytes(x) => encode_bytes(x).fmt(f),
+            PrismaValue::Decimal(x) => x.fmt(f),
             PrismaValue::List(x) => {
                 let as_string = format!("{:?}", x);
                 as_string.fmt(f)
@@ -218,7 +265,7 @@ impl TryFrom<PrismaValue> for i64 {
 
     fn try_from(value: PrismaValue) -> PrismaValueResult<i64> {
         match value {
-            PrismaValue::Int(i) => Ok(i),
+            PrismaValue::Int(i) | PrismaValue::BigInt(i) => Ok(i),
             _ => Err(ConversionFailure::new("PrismaValue", "i64")),
         }
     }
@@ -41,10 +41,12 @@ impl<'a> From<Value<'a>> for PrismaValue {
             Value::Char(c) => c
                 .map(|c| PrismaValue::String(c.to_string()))
                 .unwrap_or(PrismaValue::Null),
+            // Text columns are returned as bytes by some drivers, so only the
+            // values that are not valid UTF-8 are binary data.
             Value::Bytes(bytes) => bytes
-                .map(|bytes| {
-                    let s = String::from_utf8(bytes.into_owned()).expect("PrismaValue::String from Value::Bytes");
-                    PrismaValue::String(s)
+                .map(|bytes| match String::from_utf8(bytes.into_owned()) {
+                    Ok(s) => PrismaValue::String(s),
+                    Err(err) => PrismaValue::Bytes(err.into_bytes()),
                 })
                 .unwrap_or(PrismaValue::Null),
             Value::Xml(_) => todo!(),
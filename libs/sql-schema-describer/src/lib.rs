@@ -28,15 +28,33 @@ pub mod walkers;
 pub enum SqlSchemaDescriberError {
     /// An unknown error occurred.
     UnknownError,
+    /// A query of the database failed.
+    QueryError(quaint::error::Error),
 }
 
 impl Display for SqlSchemaDescriberError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "unknown")
+        match self {
+            SqlSchemaDescriberError::UnknownError => write!(f, "unknown"),
+            SqlSchemaDescriberError::QueryError(err) => write!(f, "querying the database failed: {}", err),
+        }
+    }
+}
+
+impl Error for SqlSchemaDescriberError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SqlSchemaDescriberError::UnknownError => None,
+            SqlSchemaDescriberError::QueryError(err) => Some(err),
+        }
     }
 }
 
-impl Error for SqlSchemaDescriberError {}
+impl From<quaint::error::Error> for SqlSchemaDescriberError {
+    fn from(err: quaint::error::Error) -> Self {
+        SqlSchemaDescriberError::QueryError(err)
+    }
+}
 
 /// The result type.
 pub type SqlSchemaDescriberResult<T> = core::result::Result<T, SqlSchemaDescriberError>;
@@ -73,6 +91,9 @@ pub struct SqlSchema {
     pub enums: Vec<Enum>,
     /// The schema's sequences, unique to Postgres.
     pub sequences: Vec<Sequence>,
+    /// The schema's views.
+    #[serde(default)]
+    pub views: Vec<View>,
 }
 
 impl SqlSchema {
@@ -85,6 +106,11 @@ impl SqlSchema {
         self.tables.iter().find(|x| x.name == name)
     }
 
+    /// Get a view.
+    pub fn get_view(&self, name: &str) -> Option<&View> {
+        self.views.iter().find(|x| x.name == name)
+    }
+
     /// Get an enum.
     pub fn get_enum(&self, name: &str) -> Option<&Enum> {
         self.enums.iter().find(|x| x.name == name)
@@ -98,7 +124,8 @@ impl SqlSchema {
                 tables,
                 enums,
                 sequences,
-            } if tables.is_empty() && enums.is_empty() && sequences.is_empty()
+                views,
+            } if tables.is_empty() && enums.is_empty() && sequences.is_empty() && views.is_empty()
         )
     }
 
@@ -123,6 +150,7 @@ impl SqlSchema {
             tables: Vec::new(),
             enums: Vec::new(),
             sequences: Vec::new(),
+            views: Vec::new(),
         }
     }
 
@@ -145,6 +173,9 @@ pub struct Table {
     pub primary_key: Option<PrimaryKey>,
     /// The table's foreign keys.
     pub foreign_keys: Vec<ForeignKey>,
+    /// The table's check constraints.
+    #[serde(default)]
+    pub checks: Vec<CheckConstraint>,
 }
 
 impl Table {
@@ -208,6 +239,8 @@ pub enum IndexType {
     Unique,
     /// Normal type.
     Normal,
+    /// Full-text index type (MySQL `FULLTEXT`).
+    Fulltext,
 }
 
 impl IndexType {
@@ -436,6 +469,112 @@ pub enum ForeignKeyAction {
     SetDefault,
 }
 
+/// A check constraint.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckConstraint {
+    /// The constraint's name. Empty for unnamed constraints on SQLite, and for `@@check` attributes
+    /// without a name, which the database names.
+    pub name: String,
+    /// The checked expression, as returned by the database.
+    pub definition: String,
+}
+
+impl CheckConstraint {
+    /// Whether two constraints should be considered the same: they check the same expression and
+    /// have the same name, unless one of them is unnamed.
+    pub fn matches(&self, other: &CheckConstraint) -> bool {
+        let same_name = self.name.is_empty() || other.name.is_empty() || self.name == other.name;
+
+        same_name && normalize_check_definition(&self.definition) == normalize_check_definition(&other.definition)
+    }
+}
+
+/// Turns a check clause as returned by the database, e.g. `CHECK ((price > 0))`, into the bare
+/// checked expression.
+pub(crate) fn check_expression(clause: &str) -> String {
+    let clause = clause.trim();
+    let mut expression = match clause.get(..5) {
+        Some(keyword)
+            if keyword.eq_ignore_ascii_case("check")
+                && clause[5..].starts_with(|c: char| c == '(' || c.is_whitespace()) =>
+        {
+            clause[5..].trim_start()
+        }
+        _ => clause,
+    };
+
+    while expression.starts_with('(') && expression.ends_with(')') && is_balanced(&expression[1..expression.len() - 1])
+    {
+        expression = expression[1..expression.len() - 1].trim();
+    }
+
+    expression.to_owned()
+}
+
+/// Databases rewrite check expressions: Postgres returns `price > 0 AND price < 100` as
+/// `((price > 0) AND (price < 100))`, MySQL quotes the identifiers. For comparisons, whitespace,
+/// parentheses, identifier quotes, Postgres type casts (`'A'::text`) and the casing outside of
+/// string literals are ignored.
+fn normalize_check_definition(definition: &str) -> String {
+    let expression = check_expression(definition);
+    let mut chars = expression.chars().peekable();
+    let mut normalized = String::with_capacity(expression.len());
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => {
+                normalized.push(c);
+
+                while let Some(next) = chars.next() {
+                    normalized.push(next);
+
+                    if next == '\'' {
+                        // Quotes are escaped by doubling them.
+                        match chars.peek() {
+                            Some('\'') => normalized.push(chars.next().unwrap()),
+                            _ => break,
+                        }
+                    }
+                }
+            }
+            ':' if chars.peek() == Some(&':') => {
+                chars.next();
+
+                while let Some(next) = chars.peek() {
+                    if next.is_alphanumeric() || ['_', '"', '[', ']'].contains(next) {
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+            }
+            c if c.is_whitespace() || ['(', ')', '`', '"', '[', ']'].contains(&c) => (),
+            c => normalized.extend(c.to_lowercase()),
+        }
+    }
+
+    normalized
+}
+
+fn is_balanced(expression: &str) -> bool {
+    let mut depth = 0i32;
+
+    for c in expression.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            _ => (),
+        }
+
+        if depth < 0 {
+            return false;
+        }
+    }
+
+    depth == 0
+}
+
 /// A foreign key.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -472,6 +611,25 @@ pub struct Enum {
     pub values: Vec<String>,
 }
 
+/// A view found in a schema. Views are read-only.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct View {
+    /// The view's name.
+    pub name: String,
+    /// The query defining the view, if the database exposes it.
+    pub definition: Option<String>,
+    /// The columns of the view's result set.
+    pub columns: Vec<Column>,
+}
+
+impl View {
+    /// Get a column.
+    pub fn column(&self, name: &str) -> Option<&Column> {
+        self.columns.iter().find(|c| c.name == name)
+    }
+}
+
 /// A SQL sequence.
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -596,4 +754,31 @@ mod tests {
 
         assert_eq!(unquote_string("heh "), "heh ");
     }
+
+    #[test]
+    fn check_expressions_are_extracted_from_check_clauses() {
+        assert_eq!(check_expression("CHECK ((price > 0))"), "price > 0");
+        assert_eq!(check_expression("([price]>(0))"), "[price]>(0)");
+        assert_eq!(check_expression("(a > 0) AND (b > 0)"), "(a > 0) AND (b > 0)");
+        assert_eq!(check_expression("checked = true"), "checked = true");
+    }
+
+    #[test]
+    fn check_constraints_match_the_expressions_rewritten_by_databases() {
+        let check = |name: &str, definition: &str| CheckConstraint {
+            name: name.to_owned(),
+            definition: definition.to_owned(),
+        };
+
+        assert!(
+            check("", "price > 0 AND price < 100").matches(&check("Product_check", "((price > 0) AND (price < 100))"))
+        );
+        assert!(check("", "`price` > 0").matches(&check("Product_chk_1", "(`price` > 0)")));
+        assert!(check("", "label <> 'A'").matches(&check("Product_check", "((label)::text <> 'A'::text)")));
+        assert!(check("positive", "price > 0").matches(&check("positive", "[price]>(0)")));
+
+        assert!(!check("positive", "price > 0").matches(&check("positive", "price >= 0")));
+        assert!(!check("positive", "price > 0").matches(&check("nonnegative", "price > 0")));
+        assert!(!check("", "label <> 'A'").matches(&check("", "label <> 'a'")));
+    }
 }
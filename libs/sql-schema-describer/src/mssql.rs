@@ -68,19 +68,23 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
         let mut columns = self.get_all_columns(schema).await;
         let mut indexes = self.get_all_indices(schema).await;
         let mut foreign_keys = self.get_foreign_keys(schema).await;
+        let mut checks = self.get_check_constraints(schema).await;
 
         let table_names = self.get_table_names(schema).await;
         let mut tables = Vec::with_capacity(table_names.len());
 
         for table_name in table_names {
-            let table = self.get_table(&table_name, &mut columns, &mut indexes, &mut foreign_keys);
+            let table = self.get_table(&table_name, &mut columns, &mut indexes, &mut foreign_keys, &mut checks);
             tables.push(table);
         }
 
+        let views = self.get_views(schema, &mut columns).await;
+
         Ok(SqlSchema {
             tables,
             enums: vec![],
             sequences: vec![],
+            views,
         })
     }
 
@@ -186,11 +190,13 @@ impl SqlSchemaDescriber {
         columns: &mut HashMap<String, Vec<Column>>,
         indexes: &mut HashMap<String, (BTreeMap<String, Index>, Option<PrimaryKey>)>,
         foreign_keys: &mut HashMap<String, Vec<ForeignKey>>,
+        checks: &mut HashMap<String, Vec<CheckConstraint>>,
     ) -> Table {
         let columns = columns.remove(name).expect("table columns not found");
         let (indices, primary_key) = indexes.remove(name).unwrap_or_else(|| (BTreeMap::new(), None));
 
         let foreign_keys = foreign_keys.remove(name).unwrap_or_default();
+        let checks = checks.remove(name).unwrap_or_default();
 
         Table {
             name: name.to_string(),
@@ -198,9 +204,88 @@ impl SqlSchemaDescriber {
             foreign_keys,
             indices: indices.into_iter().map(|(_k, v)| v).collect(),
             primary_key,
+            checks,
         }
     }
 
+    /// Returns a map from table name to check constraints.
+    async fn get_check_constraints(&self, schema: &str) -> HashMap<String, Vec<CheckConstraint>> {
+        let sql = r#"
+            SELECT
+                OBJECT_NAME(cc.parent_object_id) AS table_name,
+                cc.name AS constraint_name,
+                cc.definition AS definition
+            FROM sys.check_constraints cc
+            WHERE SCHEMA_NAME(cc.schema_id) = @P1
+            AND cc.is_ms_shipped = 0
+            ORDER BY table_name, constraint_name
+        "#;
+
+        let rows = self
+            .conn
+            .query_raw(sql, &[schema.into()])
+            .await
+            .expect("querying for check constraints");
+        let mut map: HashMap<String, Vec<CheckConstraint>> = HashMap::new();
+
+        for row in rows.into_iter() {
+            debug!("Got check constraint row {:?}", row);
+            let table_name = row
+                .get("table_name")
+                .and_then(|x| x.to_string())
+                .expect("get table_name");
+            let name = row
+                .get("constraint_name")
+                .and_then(|x| x.to_string())
+                .expect("get constraint_name");
+            let definition = row
+                .get("definition")
+                .and_then(|x| x.to_string())
+                .expect("get definition");
+
+            map.entry(table_name).or_default().push(CheckConstraint {
+                name,
+                definition: check_expression(&definition),
+            });
+        }
+
+        map
+    }
+
+    /// Returns the views of the schema, with their columns taken from `columns`.
+    async fn get_views(&self, schema: &str, columns: &mut HashMap<String, Vec<Column>>) -> Vec<View> {
+        debug!("Getting views");
+
+        let select = r#"
+            SELECT v.name view_name, m.definition view_definition
+            FROM sys.views v
+            LEFT JOIN sys.sql_modules m ON m.object_id = v.object_id
+            WHERE SCHEMA_NAME(v.schema_id) = @P1
+            AND v.is_ms_shipped = 0
+            ORDER BY v.name ASC
+        "#;
+
+        let rows = self.conn.query_raw(select, &[schema.into()]).await.expect("get views");
+
+        let views = rows
+            .into_iter()
+            .map(|row| {
+                let name = row.get("view_name").and_then(|x| x.to_string()).expect("get view name");
+                let definition = row.get("view_definition").and_then(|x| x.to_string());
+                let columns = columns.remove(&name).unwrap_or_default();
+
+                View {
+                    name,
+                    definition,
+                    columns,
+                }
+            })
+            .collect();
+
+        debug!("Found views: {:?}", views);
+        views
+    }
+
     async fn get_all_columns(&self, schema: &str) -> HashMap<String, Vec<Column>> {
         let sql = r#"
             SELECT
@@ -212,9 +297,10 @@ impl SqlSchemaDescriber {
                 columnproperty(object_id(@P1 + '.' + table_name), column_name, 'IsIdentity') is_identity,
                 table_name
             FROM information_schema.columns c
-            INNER JOIN sys.tables t
+            INNER JOIN sys.objects t
             ON c.TABLE_NAME = t.name AND SCHEMA_ID(c.TABLE_SCHEMA) = t.schema_id
             WHERE table_schema = @P1
+            AND t.type IN ('U', 'V')
             AND t.is_ms_shipped = 'false'
             ORDER BY ordinal_position
         "#;
@@ -616,7 +702,8 @@ impl SqlSchemaDescriber {
 
         let family = match data_type {
             "date" | "time" | "datetime" | "datetime2" | "smalldatetime" | "datetimeoffset" => DateTime,
-            "numeric" | "decimal" | "float" | "real" | "smallmoney" | "money" => Float,
+            "numeric" | "decimal" | "smallmoney" | "money" => Decimal,
+            "float" | "real" => Float,
             "char" | "nchar" | "varchar" | "nvarchar" | "text" | "ntext" => String,
             "tinyint" | "smallint" | "int" | "bigint" => Int,
             "binary" | "varbinary" | "image" => Binary,
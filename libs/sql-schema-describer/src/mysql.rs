@@ -58,18 +58,22 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
         let mut columns = get_all_columns(&self.conn, schema, &flavour).await;
         let mut indexes = get_all_indexes(&self.conn, schema).await;
         let mut fks = get_foreign_keys(&self.conn, schema).await;
+        let mut checks = get_check_constraints(&self.conn, schema).await?;
 
         let mut enums = vec![];
         for table_name in &table_names {
-            let (table, enms) = self.get_table(table_name, &mut columns, &mut indexes, &mut fks);
+            let (table, enms) = self.get_table(table_name, &mut columns, &mut indexes, &mut fks, &mut checks);
             tables.push(table);
             enums.extend(enms.iter().cloned());
         }
 
+        let views = self.get_views(schema, &mut columns).await;
+
         Ok(SqlSchema {
             tables,
             enums,
             sequences: vec![],
+            views,
         })
     }
 
@@ -127,6 +131,32 @@ impl SqlSchemaDescriber {
         names
     }
 
+    /// Returns the views of the schema, with their columns taken from `columns`.
+    async fn get_views(&self, schema: &str, columns: &mut HashMap<String, (Vec<Column>, Vec<Enum>)>) -> Vec<View> {
+        debug!("Getting views");
+        let sql = "SELECT table_name view_name, view_definition view_definition FROM information_schema.views
+            WHERE table_schema = ?
+            ORDER BY table_name";
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await.expect("get views");
+        let views = rows
+            .into_iter()
+            .map(|row| {
+                let name = row.get("view_name").and_then(|x| x.to_string()).expect("get view name");
+                let definition = row.get("view_definition").and_then(|x| x.to_string());
+                let (columns, _enums) = columns.remove(&name).unwrap_or_default();
+
+                View {
+                    name,
+                    definition,
+                    columns,
+                }
+            })
+            .collect();
+
+        debug!("Found views: {:?}", views);
+        views
+    }
+
     async fn get_size(&self, schema: &str) -> usize {
         use rust_decimal::prelude::*;
 
@@ -157,12 +187,14 @@ impl SqlSchemaDescriber {
         columns: &mut HashMap<String, (Vec<Column>, Vec<Enum>)>,
         indexes: &mut HashMap<String, (BTreeMap<String, Index>, Option<PrimaryKey>)>,
         foreign_keys: &mut HashMap<String, Vec<ForeignKey>>,
+        checks: &mut HashMap<String, Vec<CheckConstraint>>,
     ) -> (Table, Vec<Enum>) {
         debug!("Getting table '{}'", name);
         let (columns, enums) = columns.remove(name).expect("table columns not found");
         let (indices, primary_key) = indexes.remove(name).unwrap_or_else(|| (BTreeMap::new(), None));
 
         let foreign_keys = foreign_keys.remove(name).unwrap_or_default();
+        let checks = checks.remove(name).unwrap_or_default();
         (
             Table {
                 name: name.to_string(),
@@ -170,6 +202,7 @@ impl SqlSchemaDescriber {
                 foreign_keys,
                 indices: indices.into_iter().map(|(_k, v)| v).collect(),
                 primary_key,
+                checks,
             },
             enums,
         )
@@ -355,6 +388,7 @@ async fn get_all_indexes(
             SELECT DISTINCT
                 index_name AS index_name,
                 non_unique AS non_unique,
+                index_type AS index_type,
                 column_name AS column_name,
                 seq_in_index AS seq_in_index,
                 table_name AS table_name
@@ -376,6 +410,11 @@ async fn get_all_indexes(
                 let seq_in_index = row.get("seq_in_index").and_then(|x| x.as_i64()).expect("seq_in_index");
                 let pos = seq_in_index - 1;
                 let is_unique = !row.get("non_unique").and_then(|x| x.as_bool()).expect("non_unique");
+                let is_fulltext = row
+                    .get("index_type")
+                    .and_then(|x| x.to_string())
+                    .map(|index_type| index_type.eq_ignore_ascii_case("fulltext"))
+                    .unwrap_or(false);
 
                 // Multi-column indices will return more than one row (with different column_name values).
                 // We cannot assume that one row corresponds to one index.
@@ -417,9 +456,10 @@ async fn get_all_indexes(
                         Index {
                             name: index_name,
                             columns: vec![column_name],
-                            tpe: match is_unique {
-                                true => IndexType::Unique,
-                                false => IndexType::Normal,
+                            tpe: match (is_unique, is_fulltext) {
+                                (true, _) => IndexType::Unique,
+                                (false, true) => IndexType::Fulltext,
+                                (false, false) => IndexType::Normal,
                             },
                         },
                     );
@@ -442,6 +482,68 @@ async fn get_all_indexes(
     map
 }
 
+/// Returns a map from table name to check constraints. Check constraints are only enforced and
+/// exposed in the information schema from MySQL 8.0.16 and MariaDB 10.2 on, older versions return
+/// an empty map.
+async fn get_check_constraints(
+    conn: &dyn Queryable,
+    schema_name: &str,
+) -> SqlSchemaDescriberResult<HashMap<String, Vec<CheckConstraint>>> {
+    let mut map: HashMap<String, Vec<CheckConstraint>> = HashMap::new();
+
+    let check_constraints_table = "
+        SELECT table_name
+        FROM information_schema.tables
+        WHERE table_schema = 'information_schema' AND table_name = 'CHECK_CONSTRAINTS'
+    ";
+
+    if conn.query_raw(check_constraints_table, &[]).await?.is_empty() {
+        return Ok(map);
+    }
+
+    // We alias all the columns because MySQL column names are case-insensitive in queries, but the
+    // information schema column names became upper-case in MySQL 8.
+    let sql = "
+        SELECT
+            tc.table_name table_name,
+            cc.constraint_name constraint_name,
+            cc.check_clause check_clause
+        FROM information_schema.check_constraints AS cc
+        INNER JOIN information_schema.table_constraints AS tc ON
+            cc.constraint_schema = tc.constraint_schema
+            AND cc.constraint_name = tc.constraint_name
+        WHERE
+            cc.constraint_schema = ?
+            AND tc.constraint_type = 'CHECK'
+        ORDER BY tc.table_name, cc.constraint_name
+    ";
+
+    let rows = conn.query_raw(sql, &[schema_name.into()]).await?;
+
+    for row in rows.into_iter() {
+        debug!("Got check constraint row {:?}", row);
+        let table_name = row
+            .get("table_name")
+            .and_then(|x| x.to_string())
+            .expect("get table_name");
+        let name = row
+            .get("constraint_name")
+            .and_then(|x| x.to_string())
+            .expect("get constraint_name");
+        let definition = row
+            .get("check_clause")
+            .and_then(|x| x.to_string())
+            .expect("get check_clause");
+
+        map.entry(table_name).or_default().push(CheckConstraint {
+            name,
+            definition: check_expression(&definition),
+        });
+    }
+
+    Ok(map)
+}
+
 async fn get_foreign_keys(conn: &dyn Queryable, schema_name: &str) -> HashMap<String, Vec<ForeignKey>> {
     // Foreign keys covering multiple columns will return multiple rows, which we need to
     // merge.
@@ -33,18 +33,22 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
         let mut columns = self.get_columns(schema, &enums).await;
         let mut foreign_keys = self.get_foreign_keys(schema).await;
         let mut indexes = self.get_indices(schema, &sequences).await;
+        let mut checks = self.get_check_constraints(schema).await;
 
         let table_names = self.get_table_names(schema).await;
         let mut tables = Vec::with_capacity(table_names.len());
 
         for table_name in &table_names {
-            tables.push(self.get_table(&table_name, &mut columns, &mut foreign_keys, &mut indexes));
+            tables.push(self.get_table(&table_name, &mut columns, &mut foreign_keys, &mut indexes, &mut checks));
         }
 
+        let views = self.get_views(schema, &mut columns).await;
+
         Ok(SqlSchema {
             enums,
             sequences,
             tables,
+            views,
         })
     }
 
@@ -102,6 +106,32 @@ impl SqlSchemaDescriber {
         names
     }
 
+    /// Returns the views of the schema, with their columns taken from `columns`.
+    async fn get_views(&self, schema: &str, columns: &mut HashMap<String, Vec<Column>>) -> Vec<View> {
+        debug!("Getting views");
+        let sql = "SELECT table_name as view_name, view_definition FROM information_schema.views
+            WHERE table_schema = $1
+            ORDER BY table_name";
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await.expect("get views");
+        let views = rows
+            .into_iter()
+            .map(|row| {
+                let name = row.get("view_name").and_then(|x| x.to_string()).expect("get view name");
+                let definition = row.get("view_definition").and_then(|x| x.to_string());
+                let columns = columns.remove(&name).unwrap_or_else(Vec::new);
+
+                View {
+                    name,
+                    definition,
+                    columns,
+                }
+            })
+            .collect();
+
+        debug!("Found views: {:?}", views);
+        views
+    }
+
     async fn get_size(&self, schema: &str) -> usize {
         debug!("Getting db size");
         let sql =
@@ -124,18 +154,66 @@ impl SqlSchemaDescriber {
         columns: &mut HashMap<String, Vec<Column>>,
         foreign_keys: &mut HashMap<String, Vec<ForeignKey>>,
         indices: &mut HashMap<String, (Vec<Index>, Option<PrimaryKey>)>,
+        checks: &mut HashMap<String, Vec<CheckConstraint>>,
     ) -> Table {
         debug!("Getting table '{}'", name);
         let (indices, primary_key) = indices.remove(name).unwrap_or_else(|| (Vec::new(), None));
         let foreign_keys = foreign_keys.remove(name).unwrap_or_else(Vec::new);
         let columns = columns.remove(name).expect("could not get columns");
+        let checks = checks.remove(name).unwrap_or_else(Vec::new);
         Table {
             name: name.to_string(),
             columns,
             foreign_keys,
             indices,
             primary_key,
+            checks,
+        }
+    }
+
+    /// Returns a map from table name to check constraints.
+    async fn get_check_constraints(&self, schema: &str) -> HashMap<String, Vec<CheckConstraint>> {
+        let sql = r#"
+            SELECT
+                cl.relname AS table_name,
+                con.conname AS constraint_name,
+                pg_get_constraintdef(con.oid) AS definition
+            FROM pg_constraint con
+                JOIN pg_class cl ON con.conrelid = cl.oid
+                JOIN pg_namespace ns ON cl.relnamespace = ns.oid
+            WHERE ns.nspname = $1
+                AND con.contype = 'c'
+            ORDER BY cl.relname, con.conname"#;
+
+        let rows = self
+            .conn
+            .query_raw(sql, &[schema.into()])
+            .await
+            .expect("querying for check constraints");
+        let mut checks: HashMap<String, Vec<CheckConstraint>> = HashMap::new();
+
+        for row in rows.into_iter() {
+            debug!("Got check constraint row {:?}", row);
+            let table_name = row
+                .get("table_name")
+                .and_then(|x| x.to_string())
+                .expect("get table_name");
+            let name = row
+                .get("constraint_name")
+                .and_then(|x| x.to_string())
+                .expect("get constraint_name");
+            let definition = row
+                .get("definition")
+                .and_then(|x| x.to_string())
+                .expect("get definition");
+
+            checks.entry(table_name).or_default().push(CheckConstraint {
+                name,
+                definition: check_expression(&definition),
+            });
         }
+
+        checks
     }
 
     async fn get_columns(&self, schema: &str, enums: &[Enum]) -> HashMap<String, Vec<Column>> {
@@ -50,12 +50,15 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
             tables[table_index].foreign_keys[fk_index].referenced_columns = columns
         }
 
+        let views = self.get_views(schema).await;
+
         Ok(SqlSchema {
             // There's no enum type in SQLite.
             enums: vec![],
             // There are no sequences in SQLite.
             sequences: vec![],
             tables,
+            views,
         })
     }
     async fn version(&self, schema: &str) -> crate::SqlSchemaDescriberResult<Option<String>> {
@@ -101,6 +104,29 @@ impl SqlSchemaDescriber {
         names
     }
 
+    async fn get_views(&self, schema: &str) -> Vec<View> {
+        let sql = r#"SELECT name, sql FROM sqlite_master WHERE type='view' ORDER BY name ASC"#;
+        debug!("describing views with query: '{}'", sql);
+        let result_set = self.conn.query_raw(&sql, &[]).await.expect("get views");
+        let mut views = Vec::with_capacity(result_set.len());
+
+        for row in result_set.into_iter() {
+            let name = row.get("name").and_then(|x| x.to_string()).expect("get view name");
+            let definition = row.get("sql").and_then(|x| x.to_string());
+            // `PRAGMA table_info` describes the result columns of views as well.
+            let (columns, _) = self.get_columns(schema, &name).await;
+
+            views.push(View {
+                name,
+                definition,
+                columns,
+            });
+        }
+
+        debug!("Found views: {:?}", views);
+        views
+    }
+
     async fn get_size(&self, _schema: &str) -> usize {
         debug!("Getting db size");
         let sql = r#"SELECT page_count * page_size as size FROM pragma_page_count(), pragma_page_size();"#;
@@ -118,15 +144,37 @@ impl SqlSchemaDescriber {
         let (columns, primary_key) = self.get_columns(schema, name).await;
         let foreign_keys = self.get_foreign_keys(schema, name).await;
         let indices = self.get_indices(schema, name).await;
+        let checks = self.get_check_constraints(name).await;
         Table {
             name: name.to_string(),
             columns,
             indices,
             primary_key,
             foreign_keys,
+            checks,
         }
     }
 
+    /// SQLite has no catalog for check constraints, so we parse them from the table's `CREATE TABLE` statement.
+    async fn get_check_constraints(&self, table: &str) -> Vec<CheckConstraint> {
+        let sql = r#"SELECT sql FROM sqlite_master WHERE type='table' AND name = ?"#;
+        debug!("describing table check constraints, query: '{}'", sql);
+        let result_set = self
+            .conn
+            .query_raw(&sql, &[table.into()])
+            .await
+            .expect("querying for check constraints");
+
+        let checks = result_set
+            .first()
+            .and_then(|row| row.get("sql").and_then(|x| x.to_string()))
+            .map(|create_table| parse_check_constraints(&create_table))
+            .unwrap_or_default();
+
+        debug!("Found check constraints: {:?}", checks);
+        checks
+    }
+
     async fn get_columns(&self, _schema: &str, table: &str) -> (Vec<Column>, Option<PrimaryKey>) {
         let sql = format!(r#"PRAGMA table_info ("{}")"#, table);
         debug!("describing table columns, query: '{}'", sql);
@@ -428,8 +476,8 @@ fn get_column_type(tpe: &str, arity: ColumnArity) -> ColumnType {
         // It's tolerant though, and you can assign any data type you like to columns
         "int" => ColumnTypeFamily::Int,
         "integer" => ColumnTypeFamily::Int,
-        "real" => ColumnTypeFamily::Decimal,
-        "float" => ColumnTypeFamily::Decimal,
+        "real" => ColumnTypeFamily::Float,
+        "float" => ColumnTypeFamily::Float,
         "serial" => ColumnTypeFamily::Int,
         "boolean" => ColumnTypeFamily::Boolean,
         "text" => ColumnTypeFamily::String,
@@ -440,6 +488,7 @@ fn get_column_type(tpe: &str, arity: ColumnArity) -> ColumnType {
         "datetime" => ColumnTypeFamily::DateTime,
         "timestamp" => ColumnTypeFamily::DateTime,
         "binary" => ColumnTypeFamily::Binary,
+        "blob" => ColumnTypeFamily::Binary,
         "double" => ColumnTypeFamily::Float,
         "binary[]" => ColumnTypeFamily::Binary,
         "boolean[]" => ColumnTypeFamily::Boolean,
@@ -478,6 +527,133 @@ fn unquote_sqlite_string_default(s: &str) -> Cow<'_, str> {
     }
 }
 
+/// The tokens of a SQL statement that matter for finding check constraints.
+#[derive(Debug, PartialEq)]
+enum SqlToken {
+    Word(String),
+    QuotedIdentifier(String),
+    OpeningParen,
+    ClosingParen,
+    Other,
+}
+
+/// Splits a SQL statement into the tokens we need to find check constraints, with their byte offsets.
+fn tokenize_sql(sql: &str) -> Vec<(SqlToken, usize)> {
+    let mut tokens = Vec::new();
+    let mut chars = sql.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '(' => SqlToken::OpeningParen,
+            ')' => SqlToken::ClosingParen,
+            '"' | '`' | '[' | '\'' => {
+                let closing = if c == '[' { ']' } else { c };
+                let mut content = String::new();
+
+                while let Some((_, next)) = chars.next() {
+                    if next == closing {
+                        // Quotes are escaped by doubling them.
+                        if closing != ']' && chars.peek().map(|(_, c)| *c) == Some(closing) {
+                            chars.next();
+                        } else {
+                            break;
+                        }
+                    }
+
+                    content.push(next);
+                }
+
+                if c == '\'' {
+                    SqlToken::Other
+                } else {
+                    SqlToken::QuotedIdentifier(content)
+                }
+            }
+            c if c.is_alphanumeric() || c == '_' => {
+                let mut word = c.to_string();
+
+                while let Some((_, next)) = chars.peek() {
+                    if next.is_alphanumeric() || *next == '_' {
+                        word.push(*next);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+
+                SqlToken::Word(word)
+            }
+            _ => SqlToken::Other,
+        };
+
+        tokens.push((token, start));
+    }
+
+    tokens
+}
+
+/// Finds the table and column level check constraints in a `CREATE TABLE` statement.
+fn parse_check_constraints(create_table: &str) -> Vec<CheckConstraint> {
+    let tokens = tokenize_sql(create_table);
+    let mut checks = Vec::new();
+
+    for (idx, (token, _)) in tokens.iter().enumerate() {
+        match token {
+            SqlToken::Word(word) if word.eq_ignore_ascii_case("check") => (),
+            _ => continue,
+        }
+
+        let expression_start = match tokens.get(idx + 1) {
+            Some((SqlToken::OpeningParen, start)) => *start,
+            _ => continue,
+        };
+
+        let mut depth = 0;
+        let mut expression_end = None;
+
+        for (token, start) in &tokens[idx + 1..] {
+            match token {
+                SqlToken::OpeningParen => depth += 1,
+                SqlToken::ClosingParen => {
+                    depth -= 1;
+
+                    if depth == 0 {
+                        expression_end = Some(*start);
+                        break;
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        let expression_end = match expression_end {
+            Some(end) => end,
+            None => continue,
+        };
+
+        let name = match (
+            idx.checked_sub(2).map(|i| &tokens[i].0),
+            idx.checked_sub(1).map(|i| &tokens[i].0),
+        ) {
+            (Some(SqlToken::Word(keyword)), Some(SqlToken::Word(name)))
+            | (Some(SqlToken::Word(keyword)), Some(SqlToken::QuotedIdentifier(name)))
+                if keyword.eq_ignore_ascii_case("constraint") =>
+            {
+                name.clone()
+            }
+            _ => String::new(),
+        };
+
+        checks.push(CheckConstraint {
+            name,
+            definition: create_table[expression_start + 1..expression_end].trim().to_owned(),
+        });
+    }
+
+    checks
+}
+
 /// Returns whether a table is one of the SQLite system tables.
 fn is_system_table(table_name: &str) -> bool {
     SQLITE_SYSTEM_TABLES
@@ -3,8 +3,8 @@
 #![deny(missing_docs)]
 
 use crate::{
-    Column, ColumnArity, ColumnType, ColumnTypeFamily, DefaultValue, Enum, ForeignKey, ForeignKeyAction, Index,
-    IndexType, PrimaryKey, SqlSchema, Table,
+    CheckConstraint, Column, ColumnArity, ColumnType, ColumnTypeFamily, DefaultValue, Enum, ForeignKey,
+    ForeignKeyAction, Index, IndexType, PrimaryKey, SqlSchema, Table,
 };
 
 /// Traverse all the columns in the schema.
@@ -214,6 +214,11 @@ impl<'a> TableWalker<'a> {
         self.table.foreign_key_for_column(column)
     }
 
+    /// Traverse the check constraints on the table.
+    pub fn checks(&self) -> impl Iterator<Item = &'a CheckConstraint> + 'a {
+        self.table.checks.iter()
+    }
+
     /// Traverse to the primary key of the table.
     pub fn primary_key(&self) -> Option<&'a PrimaryKey> {
         self.table.primary_key.as_ref()
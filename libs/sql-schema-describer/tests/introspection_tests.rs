@@ -196,6 +196,7 @@ async fn foreign_keys_must_work(api: &TestApi) {
                 on_delete_action,
                 on_update_action: ForeignKeyAction::NoAction,
             }],
+            checks: vec![],
         }
     );
 }
@@ -311,6 +312,7 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
                 on_delete_action,
                 on_update_action: ForeignKeyAction::NoAction,
             },],
+            checks: vec![],
         }
     );
 }
@@ -427,6 +429,7 @@ async fn composite_primary_keys_must_work(api: &TestApi) {
                 },
             }),
             foreign_keys: vec![],
+            checks: vec![],
         }
     );
 }
@@ -509,6 +512,7 @@ async fn indices_must_work(api: &TestApi) {
                 },
             }),
             foreign_keys: vec![],
+            checks: vec![],
         }
     );
 }
@@ -618,6 +622,7 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
                     indices: expected_indices,
                     primary_key: None,
                     foreign_keys: vec![],
+                    checks: vec![],
                 }
             );
         }
@@ -669,6 +674,7 @@ async fn defaults_must_work(api: &TestApi) {
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            checks: vec![],
         }
     );
 }
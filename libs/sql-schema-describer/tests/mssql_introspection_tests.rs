@@ -83,7 +83,7 @@ async fn all_mssql_column_types_must_work() {
                 data_type: "decimal".to_string(),
                 full_data_type: "decimal".to_string(),
                 character_maximum_length: None,
-                family: ColumnTypeFamily::Float,
+                family: ColumnTypeFamily::Decimal,
                 arity: ColumnArity::Required,
                 native_type: None,
             },
@@ -110,7 +110,7 @@ async fn all_mssql_column_types_must_work() {
                 data_type: "money".to_string(),
                 full_data_type: "money".to_string(),
                 character_maximum_length: None,
-                family: ColumnTypeFamily::Float,
+                family: ColumnTypeFamily::Decimal,
                 arity: ColumnArity::Required,
                 native_type: None,
             },
@@ -124,7 +124,7 @@ async fn all_mssql_column_types_must_work() {
                 data_type: "numeric".to_string(),
                 full_data_type: "numeric".to_string(),
                 character_maximum_length: None,
-                family: ColumnTypeFamily::Float,
+                family: ColumnTypeFamily::Decimal,
                 arity: ColumnArity::Required,
                 native_type: None,
             },
@@ -152,7 +152,7 @@ async fn all_mssql_column_types_must_work() {
                 data_type: "smallmoney".to_string(),
                 full_data_type: "smallmoney".to_string(),
                 character_maximum_length: None,
-                family: ColumnTypeFamily::Float,
+                family: ColumnTypeFamily::Decimal,
                 arity: ColumnArity::Required,
                 native_type: None,
             },
@@ -454,6 +454,7 @@ async fn all_mssql_column_types_must_work() {
                 constraint_name: None,
             }),
             foreign_keys: vec![],
+            checks: vec![],
         }
     );
 }
@@ -547,6 +548,7 @@ async fn mssql_foreign_key_on_delete_must_be_handled() {
                     on_delete_action: ForeignKeyAction::Cascade,
                 },
             ],
+            checks: vec![],
         }
     );
 }
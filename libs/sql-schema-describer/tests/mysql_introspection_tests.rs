@@ -625,6 +625,7 @@ async fn all_mysql_column_types_must_work() {
                 constraint_name: None,
             }),
             foreign_keys: vec![],
+            checks: vec![],
         }
     );
 }
@@ -785,6 +786,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     on_update_action: ForeignKeyAction::NoAction,
                 },
             ],
+            checks: vec![],
         }
     );
 }
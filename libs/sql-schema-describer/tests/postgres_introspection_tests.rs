@@ -735,6 +735,7 @@ async fn all_postgres_column_types_must_work() {
                 constraint_name: Some("User_pkey".into()),
             }),
             foreign_keys: vec![],
+            checks: vec![],
         }
     );
 }
@@ -899,6 +900,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     on_delete_action: ForeignKeyAction::SetNull,
                 },
             ],
+            checks: vec![],
         }
     );
 }
@@ -1053,3 +1055,58 @@ async fn escaped_backslashes_in_string_literals_must_be_unescaped(api: &TestApi)
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn postgres_views_must_be_described(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"CREATE TABLE "{0}"."User" (id INTEGER PRIMARY KEY, name TEXT NOT NULL)"#,
+        api.schema_name()
+    );
+    let create_view = format!(
+        r#"CREATE VIEW "{0}"."UserName" AS SELECT name FROM "{0}"."User""#,
+        api.schema_name()
+    );
+
+    api.database().query_raw(&create_table, &[]).await?;
+    api.database().query_raw(&create_view, &[]).await?;
+
+    let schema = api.describe().await?;
+
+    assert!(!schema.has_table("UserName"));
+
+    let view = schema.get_view("UserName").expect("getting UserName view");
+    let column = view.column("name").expect("getting name column");
+
+    assert_eq!(view.columns.len(), 1);
+    assert_eq!(column.tpe.family, ColumnTypeFamily::String);
+    assert!(view.definition.as_ref().unwrap().contains("SELECT"));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn postgres_check_constraints_must_be_described(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"CREATE TABLE "{0}"."Product" (
+            id INTEGER PRIMARY KEY,
+            price INTEGER NOT NULL,
+            CONSTRAINT "positive_price" CHECK (price > 0)
+        )"#,
+        api.schema_name()
+    );
+
+    api.database().query_raw(&create_table, &[]).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("Product");
+
+    assert_eq!(
+        table.checks,
+        &[CheckConstraint {
+            name: "positive_price".to_owned(),
+            definition: "price > 0".to_owned(),
+        }]
+    );
+
+    Ok(())
+}
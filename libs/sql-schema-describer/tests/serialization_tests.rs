@@ -86,6 +86,7 @@ fn database_schema_is_serializable() {
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::NoAction,
                 }],
+                checks: vec![],
             },
             Table {
                 name: "table2".to_string(),
@@ -110,6 +111,7 @@ fn database_schema_is_serializable() {
                     constraint_name: None,
                 }),
                 foreign_keys: vec![],
+                checks: vec![],
             },
         ],
         enums: vec![Enum {
@@ -121,6 +123,7 @@ fn database_schema_is_serializable() {
             initial_value: 1,
             allocation_size: 32,
         }],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -156,9 +159,11 @@ fn database_schema_without_primary_key_is_serializable() {
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            checks: vec![],
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-without-primary-key.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -208,9 +213,11 @@ fn database_schema_is_serializable_for_every_column_type_family() {
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            checks: vec![],
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-all-column-type-families.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -252,9 +259,11 @@ fn database_schema_is_serializable_for_every_column_arity() {
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            checks: vec![],
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-all-column-arities.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -390,9 +399,11 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     on_delete_action: ForeignKeyAction::SetDefault,
                 },
             ],
+            checks: vec![],
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-all-foreign-key-actions.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
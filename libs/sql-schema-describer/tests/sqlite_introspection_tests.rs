@@ -73,7 +73,7 @@ async fn sqlite_column_types_must_work() {
                 data_type: "REAL".to_string(),
                 full_data_type: "REAL".to_string(),
                 character_maximum_length: None,
-                family: ColumnTypeFamily::Decimal,
+                family: ColumnTypeFamily::Float,
                 arity: ColumnArity::Required,
                 native_type: None,
             },
@@ -120,6 +120,7 @@ async fn sqlite_column_types_must_work() {
                 constraint_name: None,
             }),
             foreign_keys: vec![],
+            checks: vec![],
         }
     );
 }
@@ -277,6 +278,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     on_delete_action: ForeignKeyAction::SetNull,
                 },
             ],
+            checks: vec![],
         }
     );
 }
@@ -397,3 +399,39 @@ async fn escaped_backslashes_in_string_literals_must_be_unescaped(api: &TestApi)
 
     Ok(())
 }
+
+#[test_each_connector(tags("sqlite"))]
+async fn sqlite_check_constraints_must_be_parsed_from_the_table_definition(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"
+            CREATE TABLE "{0}"."Product" (
+                id INTEGER PRIMARY KEY,
+                price INTEGER NOT NULL CHECK (price > 0),
+                name TEXT NOT NULL DEFAULT 'check (this)',
+                CONSTRAINT "name_not_empty" CHECK (length(name) > 0 AND (name <> ''))
+            );
+        "#,
+        api.schema_name()
+    );
+
+    api.database().query_raw(&create_table, &[]).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("Product");
+
+    assert_eq!(
+        table.checks,
+        &[
+            CheckConstraint {
+                name: "".to_owned(),
+                definition: "price > 0".to_owned(),
+            },
+            CheckConstraint {
+                name: "name_not_empty".to_owned(),
+                definition: "length(name) > 0 AND (name <> '')".to_owned(),
+            },
+        ]
+    );
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use anyhow::Context;
 use colored::Colorize;
-use migration_core::commands::SchemaPushInput;
+use migration_core::commands::{DiffInput, DiffSource, SchemaPushInput};
 use std::{fs::File, io::Read};
 use structopt::*;
 
@@ -32,6 +32,8 @@ enum Command {
     Dmmf(DmmfCommand),
     /// Push a prisma schema directly to the database, without interacting with migrations.
     SchemaPush(SchemaPush),
+    /// Compare two schemas, each coming from a prisma schema, a migrations directory, a live database or empty.
+    Diff(Diff),
 }
 
 #[derive(StructOpt)]
@@ -55,6 +57,40 @@ struct SchemaPush {
     force: bool,
 }
 
+#[derive(StructOpt)]
+struct Diff {
+    /// Diff from an empty schema.
+    #[structopt(long)]
+    from_empty: bool,
+    /// Diff from the prisma schema at this path.
+    #[structopt(long)]
+    from_schema: Option<String>,
+    /// Diff from the migrations directory at this path. The migrations are replayed on a temporary database.
+    #[structopt(long)]
+    from_migrations: Option<String>,
+    /// Diff from the live database at this URL.
+    #[structopt(long)]
+    from_url: Option<String>,
+    /// Diff to an empty schema.
+    #[structopt(long)]
+    to_empty: bool,
+    /// Diff to the prisma schema at this path.
+    #[structopt(long)]
+    to_schema: Option<String>,
+    /// Diff to the migrations directory at this path. The migrations are replayed on a temporary database.
+    #[structopt(long)]
+    to_migrations: Option<String>,
+    /// Diff to the live database at this URL.
+    #[structopt(long)]
+    to_url: Option<String>,
+    /// Print the SQL script migrating from one schema to the other, instead of a summary.
+    #[structopt(long)]
+    script: bool,
+    /// Exit with code 2 when the schemas differ. Useful for drift checks in CI.
+    #[structopt(long)]
+    exit_code: bool,
+}
+
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     init_logger();
@@ -62,6 +98,7 @@ async fn main() -> anyhow::Result<()> {
     match Command::from_args() {
         Command::Dmmf(cmd) => generate_dmmf(&cmd).await?,
         Command::SchemaPush(cmd) => schema_push(&cmd).await?,
+        Command::Diff(cmd) => diff(&cmd).await?,
         Command::Introspect { url, file_path } => {
             if url.as_ref().xor(file_path.as_ref()).is_none() {
                 anyhow::bail!(
@@ -283,6 +320,76 @@ async fn schema_push(cmd: &SchemaPush) -> anyhow::Result<()> {
     Ok(())
 }
 
+async fn diff(cmd: &Diff) -> anyhow::Result<()> {
+    let from = diff_source(
+        "from",
+        cmd.from_empty,
+        &cmd.from_schema,
+        &cmd.from_migrations,
+        &cmd.from_url,
+    )?;
+    let to = diff_source("to", cmd.to_empty, &cmd.to_schema, &cmd.to_migrations, &cmd.to_url)?;
+
+    // The migration engine needs a database to replay migrations on, and to know which kind of
+    // database to render the diff for. Use the first datasource we are given.
+    let datamodel = match (&from, &to) {
+        (DiffSource::Schema { schema }, _) | (_, DiffSource::Schema { schema }) => schema.clone(),
+        (DiffSource::Database { url }, _) | (_, DiffSource::Database { url }) => minimal_schema_from_url(url)?,
+        _ => anyhow::bail!(
+            "{}",
+            "At least one side of the diff must be a schema or a database URL."
+                .bold()
+                .red()
+        ),
+    };
+
+    let api = migration_core::migration_api(&datamodel).await?;
+
+    let output = api
+        .diff(&DiffInput {
+            from,
+            to,
+            script: cmd.script,
+        })
+        .await?;
+
+    print!("{}", output.diff);
+
+    if cmd.exit_code && output.has_changes {
+        std::process::exit(2);
+    }
+
+    Ok(())
+}
+
+fn diff_source(
+    side: &str,
+    empty: bool,
+    schema_path: &Option<String>,
+    migrations_path: &Option<String>,
+    url: &Option<String>,
+) -> anyhow::Result<DiffSource> {
+    match (empty, schema_path, migrations_path, url) {
+        (true, None, None, None) => Ok(DiffSource::Empty),
+        (false, Some(schema_path), None, None) => Ok(DiffSource::Schema {
+            schema: read_datamodel_from_file(schema_path).context("Error reading the schema from file")?,
+        }),
+        (false, None, Some(migrations_path), None) => Ok(DiffSource::MigrationsDirectory {
+            path: migrations_path.clone(),
+        }),
+        (false, None, None, Some(url)) => Ok(DiffSource::Database { url: url.clone() }),
+        _ => anyhow::bail!(
+            "{}",
+            format!(
+                "Exactly one of --{side}-empty, --{side}-schema, --{side}-migrations or --{side}-url must be provided",
+                side = side
+            )
+            .bold()
+            .red()
+        ),
+    }
+}
+
 fn init_logger() {
     use tracing_error::ErrorLayer;
     use tracing_subscriber::prelude::*;
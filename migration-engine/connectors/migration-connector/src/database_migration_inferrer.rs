@@ -1,4 +1,4 @@
-use crate::{migrations_directory::MigrationDirectory, ConnectorResult, MigrationStep};
+use crate::{migrations_directory::MigrationDirectory, ConnectorResult, DiffTarget, MigrationStep, RenameHint};
 use datamodel::Datamodel;
 
 /// The component responsible for generating a
@@ -31,12 +31,37 @@ pub trait DatabaseMigrationInferrer<T>: Send + Sync {
 
     /// Look at the previous migrations and the target schema, and infer a
     /// database migration taking the database to the expected Prisma schema.
+    /// The tables and columns described by `rename_hints` are renamed instead
+    /// of being dropped and recreated.
     async fn infer_next_migration(
         &self,
         previous_migrations: &[MigrationDirectory],
         target_schema: &Datamodel,
+        rename_hints: &[RenameHint],
     ) -> ConnectorResult<T>;
 
+    /// Infer a database migration creating the current schema of the database
+    /// from an empty database.
+    async fn infer_baseline_migration(&self) -> ConnectorResult<T>;
+
+    /// Infer the database migration taking the schema described by `from` to
+    /// the schema described by `to`.
+    async fn infer_diff(&self, from: &DiffTarget, to: &DiffTarget) -> ConnectorResult<T>;
+
+    /// Infer the database migration of each of the migrations from
+    /// `migrations[first_index..]`, i.e. the migration from the end state of
+    /// the migrations before it to its own end state. The history is only
+    /// replayed once.
+    async fn infer_history_migrations(
+        &self,
+        migrations: &[MigrationDirectory],
+        first_index: usize,
+    ) -> ConnectorResult<Vec<T>>;
+
+    /// Infer the database migration reverting `migration`, i.e. taking the
+    /// database from the end state of `migration` back to its start state.
+    fn infer_reverse_migration(&self, migration: &T) -> ConnectorResult<T>;
+
     /// Check that the current local database's schema matches its expected
     /// state at the end of the passed in migrations history.
     async fn detect_drift(&self, applied_migrations: &[MigrationDirectory]) -> ConnectorResult<bool>;
@@ -12,9 +12,21 @@ pub trait DatabaseMigrationStepApplier<T>: Send + Sync {
     /// Render steps for the CLI. Each step will contain the raw field.
     fn render_steps_pretty(&self, database_migration: &T) -> ConnectorResult<Vec<PrettyDatabaseMigrationStep>>;
 
+    /// Render a human-readable summary of the changes performed by the
+    /// migration, for display in the CLI.
+    fn render_summary(&self, database_migration: &T) -> String;
+
     /// Render the migration to a runnable script.
     fn render_script(&self, database_migration: &T, diagnostics: &DestructiveChangeDiagnostics) -> String;
 
+    /// Render the migration to a runnable script, avoiding the statements that
+    /// take locks blocking writes on the affected tables where the database
+    /// supports it. Connectors without an online mode render the regular
+    /// script.
+    fn render_online_script(&self, database_migration: &T, diagnostics: &DestructiveChangeDiagnostics) -> String {
+        self.render_script(database_migration, diagnostics)
+    }
+
     /// Apply a migration script to the database. The migration persistence is
     /// managed by the core.
     async fn apply_script(&self, script: &str) -> ConnectorResult<()>;
@@ -1,4 +1,4 @@
-use crate::ConnectorResult;
+use crate::{ConnectorResult, DestructiveChangePolicy};
 use serde::{Deserialize, Serialize};
 use std::marker::PhantomData;
 
@@ -14,11 +14,32 @@ where
     T: Send + Sync + 'static,
 {
     /// Check destructive changes resulting of applying the provided migration.
-    async fn check(&self, database_migration: &T) -> ConnectorResult<DestructiveChangeDiagnostics>;
+    async fn check(&self, database_migration: &T) -> ConnectorResult<DestructiveChangeDiagnostics> {
+        self.check_with_policy(database_migration, &DestructiveChangePolicy::default())
+            .await
+    }
 
     /// Check the database migration for destructive or unexecutable steps
     /// without performing any IO.
-    fn pure_check(&self, database_migration: &T) -> DestructiveChangeDiagnostics;
+    fn pure_check(&self, database_migration: &T) -> DestructiveChangeDiagnostics {
+        self.pure_check_with_policy(database_migration, &DestructiveChangePolicy::default())
+    }
+
+    /// Same as [check](trait.DestructiveChangeChecker.html#method.check), with
+    /// the severity of the checks configured by the policy.
+    async fn check_with_policy(
+        &self,
+        database_migration: &T,
+        policy: &DestructiveChangePolicy,
+    ) -> ConnectorResult<DestructiveChangeDiagnostics>;
+
+    /// Same as [pure_check](trait.DestructiveChangeChecker.html#method.pure_check),
+    /// with the severity of the checks configured by the policy.
+    fn pure_check_with_policy(
+        &self,
+        database_migration: &T,
+        policy: &DestructiveChangePolicy,
+    ) -> DestructiveChangeDiagnostics;
 }
 
 /// The errors and warnings emitted by the [DestructiveChangeChecker](trait.DestructiveChangeChecker.html).
@@ -79,11 +100,19 @@ impl<T> DestructiveChangeChecker<T> for EmptyDestructiveChangeChecker<T>
 where
     T: Send + Sync + 'static,
 {
-    async fn check(&self, _database_migration: &T) -> ConnectorResult<DestructiveChangeDiagnostics> {
+    async fn check_with_policy(
+        &self,
+        _database_migration: &T,
+        _policy: &DestructiveChangePolicy,
+    ) -> ConnectorResult<DestructiveChangeDiagnostics> {
         Ok(DestructiveChangeDiagnostics::new())
     }
 
-    fn pure_check(&self, _database_migration: &T) -> DestructiveChangeDiagnostics {
+    fn pure_check_with_policy(
+        &self,
+        _database_migration: &T,
+        _policy: &DestructiveChangePolicy,
+    ) -> DestructiveChangeDiagnostics {
         DestructiveChangeDiagnostics::new()
     }
 }
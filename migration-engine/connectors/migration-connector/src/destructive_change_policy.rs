@@ -0,0 +1,273 @@
+//! Configuration of the destructive change checks.
+//!
+//! The policy lives in a JSON file at the root of the migrations directory. It
+//! changes the severity of the checks performed by the
+//! [DestructiveChangeChecker](trait.DestructiveChangeChecker.html), globally or
+//! for specific tables:
+//!
+//! ```json
+//! {
+//!     "largeTableRowCount": 1000000,
+//!     "rules": [
+//!         { "check": "riskyCast", "severity": "error" },
+//!         { "check": "nonEmptyColumnDrop", "tables": ["AuditLog"], "severity": "allow" }
+//!     ]
+//! }
+//! ```
+//!
+//! Migration scripts can override the policy for themselves with annotations:
+//!
+//! ```sql
+//! -- prisma-policy: allow nonEmptyColumnDrop AuditLog
+//! ```
+//!
+//! The annotations of a script only apply to the schema changes of its own
+//! migration. Rules and annotations naming a check or a severity that does not
+//! exist are rejected, so a typo does not silently leave the default severity
+//! in place.
+
+use crate::{ConnectorError, ConnectorResult};
+use serde::{Deserialize, Serialize};
+use std::{io, path::Path};
+
+/// The file name of the destructive change policy, in the migrations directory.
+pub const DESTRUCTIVE_CHANGE_POLICY_FILENAME: &str = "destructive_change_policy.json";
+
+/// The prefix of the policy annotations in migration scripts.
+const POLICY_ANNOTATION_PREFIX: &str = "-- prisma-policy:";
+
+/// The names of the destructive change checks, as used in policy rules and
+/// annotations.
+const CHECK_NAMES: &[&str] = &[
+    "addedRequiredFieldToTable",
+    "dropAndRecreateColumn",
+    "dropAndRecreateRequiredColumn",
+    "enumValueRemoval",
+    "madeOptionalFieldRequired",
+    "madeScalarFieldIntoArrayField",
+    "nonConcurrentIndexOnLargeTable",
+    "nonEmptyColumnDrop",
+    "nonEmptyTableDrop",
+    "primaryKeyChange",
+    "riskyCast",
+    "uniqueConstraintAddition",
+];
+
+/// How the result of a destructive change check is reported.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CheckSeverity {
+    /// The check is not reported.
+    Allow,
+    /// The check is reported as a warning. Warnings can be overridden with
+    /// the `force` flag.
+    Warn,
+    /// The check is reported as an unexecutable step.
+    Error,
+}
+
+/// A rule changing the severity of a check.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PolicyRule {
+    /// The name of the check, for example `riskyCast` or `nonEmptyColumnDrop`.
+    pub check: String,
+    /// The tables the rule applies to. The rule applies to all tables if empty.
+    #[serde(default)]
+    pub tables: Vec<String>,
+    /// The severity of the check.
+    pub severity: CheckSeverity,
+}
+
+impl PolicyRule {
+    fn matches(&self, check: &str, table: Option<&str>) -> bool {
+        self.check == check
+            && (self.tables.is_empty()
+                || table
+                    .map(|table| self.tables.iter().any(|t| t == table))
+                    .unwrap_or(false))
+    }
+}
+
+/// The configuration of the destructive change checks. The default policy
+/// reports every check with its default severity.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestructiveChangePolicy {
+    /// The rules, in order. When multiple rules match a check, the last one
+    /// wins.
+    #[serde(default)]
+    pub rules: Vec<PolicyRule>,
+    /// The row count from which a table is considered large. The checks about
+    /// large tables only run when this is set.
+    #[serde(default)]
+    pub large_table_row_count: Option<i64>,
+    /// The indexes the migration scripts create with `CREATE INDEX
+    /// CONCURRENTLY`, which do not block writes to their table.
+    #[serde(skip)]
+    pub concurrently_created_indexes: Vec<String>,
+}
+
+impl DestructiveChangePolicy {
+    /// Read the policy from the migrations directory. Returns the default
+    /// policy if there is no policy file.
+    pub fn load(migrations_directory_path: &Path) -> ConnectorResult<Self> {
+        let path = migrations_directory_path.join(DESTRUCTIVE_CHANGE_POLICY_FILENAME);
+
+        let contents = match std::fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if matches!(err.kind(), io::ErrorKind::NotFound) => return Ok(Self::default()),
+            Err(err) => {
+                return Err(ConnectorError::generic(anyhow::anyhow!(
+                    "Failed to read the destructive change policy at `{}`. {}",
+                    path.to_string_lossy(),
+                    err
+                )))
+            }
+        };
+
+        let policy: Self = serde_json::from_str(&contents).map_err(|err| {
+            ConnectorError::generic(anyhow::anyhow!(
+                "Failed to parse the destructive change policy at `{}`. {}",
+                path.to_string_lossy(),
+                err
+            ))
+        })?;
+
+        for rule in &policy.rules {
+            validate_check_name(&rule.check).map_err(|err| {
+                ConnectorError::generic(anyhow::anyhow!(
+                    "Invalid destructive change policy at `{}`. {}",
+                    path.to_string_lossy(),
+                    err
+                ))
+            })?;
+        }
+
+        Ok(policy)
+    }
+
+    /// Returns true if the policy does not change any check.
+    pub fn is_default(&self) -> bool {
+        self.rules.is_empty() && self.large_table_row_count.is_none()
+    }
+
+    /// The severity of a check on the given table, given its default severity.
+    pub fn severity(&self, check: &str, table: Option<&str>, default: CheckSeverity) -> CheckSeverity {
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| rule.matches(check, table))
+            .map(|rule| rule.severity)
+            .unwrap_or(default)
+    }
+
+    /// The policy extended with the annotations in the migration script. The
+    /// annotations take precedence over the rules of the policy. The indexes
+    /// created concurrently by the script are recorded as well.
+    ///
+    /// Annotations have the form `-- prisma-policy: <severity> <check> [tables...]`.
+    /// Annotations with an unknown severity or check are an error.
+    pub fn with_script_annotations(&self, script: &str) -> ConnectorResult<Self> {
+        let mut policy = self.clone();
+
+        for rule in script.lines().filter_map(parse_annotation) {
+            let rule = rule.map_err(|err| {
+                ConnectorError::generic(anyhow::anyhow!(
+                    "Invalid policy annotation in the migration script. {}",
+                    err
+                ))
+            })?;
+
+            policy.rules.push(rule);
+        }
+
+        policy
+            .concurrently_created_indexes
+            .extend(script.split(';').filter_map(concurrently_created_index));
+
+        Ok(policy)
+    }
+}
+
+fn validate_check_name(check: &str) -> Result<(), String> {
+    if CHECK_NAMES.contains(&check) {
+        return Ok(());
+    }
+
+    Err(format!(
+        "Unknown check `{}`. The known checks are: {}.",
+        check,
+        CHECK_NAMES.join(", ")
+    ))
+}
+
+/// The name of the index created by a `CREATE [UNIQUE] INDEX CONCURRENTLY
+/// [IF NOT EXISTS] <name>` statement.
+fn concurrently_created_index(statement: &str) -> Option<String> {
+    let statement: String = statement
+        .lines()
+        .filter(|line| !line.trim_start().starts_with("--"))
+        .collect::<Vec<&str>>()
+        .join(" ");
+    let mut words = statement.split_whitespace().peekable();
+
+    if !words.next()?.eq_ignore_ascii_case("CREATE") {
+        return None;
+    }
+
+    if words.peek()?.eq_ignore_ascii_case("UNIQUE") {
+        words.next();
+    }
+
+    if !words.next()?.eq_ignore_ascii_case("INDEX") || !words.next()?.eq_ignore_ascii_case("CONCURRENTLY") {
+        return None;
+    }
+
+    let mut name = words.next()?;
+
+    if name.eq_ignore_ascii_case("IF") {
+        words.next();
+        words.next();
+        name = words.next()?;
+    }
+
+    Some(name.trim_matches('"').to_owned())
+}
+
+/// The rule of a policy annotation, or `None` if the line is not an
+/// annotation.
+fn parse_annotation(line: &str) -> Option<Result<PolicyRule, String>> {
+    let annotation = line.trim().strip_prefix(POLICY_ANNOTATION_PREFIX)?;
+    let mut words = annotation
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|word| !word.is_empty());
+
+    let severity = match words.next() {
+        Some("allow") => CheckSeverity::Allow,
+        Some("warn") => CheckSeverity::Warn,
+        Some("error") => CheckSeverity::Error,
+        Some(severity) => {
+            return Some(Err(format!(
+                "Unknown severity `{}`. The known severities are: allow, warn, error.",
+                severity
+            )))
+        }
+        None => return Some(Err(format!("Missing severity in `{}`.", line.trim()))),
+    };
+
+    let check = match words.next() {
+        Some(check) => check.to_owned(),
+        None => return Some(Err(format!("Missing check in `{}`.", line.trim()))),
+    };
+
+    if let Err(err) = validate_check_name(&check) {
+        return Some(Err(err));
+    }
+
+    Some(Ok(PolicyRule {
+        check,
+        tables: words.map(String::from).collect(),
+        severity,
+    }))
+}
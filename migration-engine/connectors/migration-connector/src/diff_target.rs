@@ -0,0 +1,18 @@
+use crate::migrations_directory::MigrationDirectory;
+use datamodel::Datamodel;
+
+/// One side of a schema diff. See
+/// [DatabaseMigrationInferrer::infer_diff](trait.DatabaseMigrationInferrer.html#tymethod.infer_diff).
+#[derive(Debug)]
+pub enum DiffTarget {
+    /// The schema described by a Prisma schema.
+    Datamodel(Datamodel),
+    /// The schema at the end of a migrations history. The migrations are
+    /// replayed on a temporary database.
+    Migrations(Vec<MigrationDirectory>),
+    /// The current schema of a live database, identified by its connection
+    /// string.
+    Database(String),
+    /// An empty schema.
+    Empty,
+}
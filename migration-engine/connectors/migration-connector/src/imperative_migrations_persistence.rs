@@ -1,4 +1,4 @@
-use crate::{checksum, ConnectorError, ConnectorResult};
+use crate::{checksum, ConnectorError, ConnectorResult, MigrationDirectory};
 use serde::Deserialize;
 
 /// A timestamp.
@@ -15,6 +15,11 @@ pub trait ImperativeMigrationsPersistence: Send + Sync {
     /// migration persistence. If not, return a DatabaseSchemaNotEmpty error.
     async fn initialize(&self) -> ConnectorResult<()>;
 
+    /// Create the migrations persistence if it is not present in the target
+    /// database, even if the database schema is not empty. This is used to
+    /// baseline existing databases.
+    async fn initialize_on_existing_schema(&self) -> ConnectorResult<()>;
+
     /// Record that a migration is about to be applied. Returns the unique
     /// identifier for the migration.
     ///
@@ -25,6 +30,20 @@ pub trait ImperativeMigrationsPersistence: Send + Sync {
             .await
     }
 
+    /// Record that a migration from the migrations directory is about to be
+    /// applied. The checksum covers all the scripts of the migration. Returns
+    /// the unique identifier for the migration.
+    async fn record_migration_directory_started(
+        &self,
+        migration_directory: &MigrationDirectory,
+    ) -> ConnectorResult<String> {
+        let script = migration_directory.read_migration_script()?;
+        let checksum = migration_directory.scripts_checksum()?;
+
+        self.record_migration_started_impl(migration_directory.migration_name(), &script, &checksum)
+            .await
+    }
+
     /// Record that a migration is about to be applied. Returns the unique
     /// identifier for the migration.
     ///
@@ -40,14 +59,24 @@ pub trait ImperativeMigrationsPersistence: Send + Sync {
     /// Increase the applied_steps_count counter, and append the given logs.
     async fn record_successful_step(&self, id: &str, logs: &str) -> ConnectorResult<()>;
 
-    /// Report logs for a failed migration step. We assume the next steps in the
-    /// migration will not be applied, and the error reported.
-    async fn record_failed_step(&self, id: &str, logs: &str) -> ConnectorResult<()>;
+    /// Report logs for a failed migration step, and the name of the script
+    /// that failed. We assume the next steps in the migration will not be
+    /// applied, and the error reported.
+    async fn record_failed_step(&self, id: &str, step_name: &str, logs: &str) -> ConnectorResult<()>;
+
+    /// Record that a failed migration is applied again from its failed step,
+    /// with the checksum of its scripts, that may have been fixed since.
+    async fn record_migration_resumed(&self, id: &str, checksum: &str) -> ConnectorResult<()>;
 
     /// Record that the migration completed *successfully*. This means
     /// populating the `finished_at` field in the migration record.
     async fn record_migration_finished(&self, id: &str) -> ConnectorResult<()>;
 
+    /// Record that the migration was rolled back by applying its down
+    /// migration script. This means populating the `rolled_back_at` field in
+    /// the migration record.
+    async fn record_migration_rolled_back(&self, id: &str) -> ConnectorResult<()>;
+
     /// List all applied migrations, ordered by `started_at`. This should fail
     /// hard if the migration persistence is not initialized.
     async fn list_migrations(&self) -> ConnectorResult<Result<Vec<MigrationRecord>, PersistenceNotInitializedError>>;
@@ -71,9 +100,10 @@ impl PersistenceNotInitializedError {
 pub struct MigrationRecord {
     /// A unique, randomly generated identifier.
     pub id: String,
-    /// The SHA-256 checksum of the migration script, to detect if it was
-    /// edited. It covers only the content of the script, it does not include
-    /// timestamp or migration name information.
+    /// The SHA-256 checksum of the migration scripts, to detect if they were
+    /// edited. It covers only the content of the migration script and the data
+    /// migration scripts, it does not include timestamp or migration name
+    /// information.
     pub checksum: String,
     /// The timestamp at which the migration completed *successfully*.
     pub finished_at: Option<Timestamp>,
@@ -92,6 +122,10 @@ pub struct MigrationRecord {
     pub started_at: Timestamp,
     /// The number of migration steps that were successfully applied.
     pub applied_steps_count: u32,
+    /// The name of the script that failed last, for example `post` for the
+    /// post-migration data script. Failed migrations are resumed from this
+    /// script.
+    pub failed_step_name: Option<String>,
     /// The whole migration script.
     pub script: String,
 }
@@ -101,4 +135,9 @@ impl MigrationRecord {
     pub fn is_failed(&self) -> bool {
         self.finished_at.is_none()
     }
+
+    /// Was the migration rolled back?
+    pub fn is_rolled_back(&self) -> bool {
+        self.rolled_back_at.is_some()
+    }
 }
@@ -5,10 +5,13 @@
 mod database_migration_inferrer;
 mod database_migration_step_applier;
 mod destructive_change_checker;
+mod destructive_change_policy;
+mod diff_target;
 mod error;
 mod imperative_migrations_persistence;
 #[allow(missing_docs)]
 mod migration_applier;
+mod migration_linter;
 #[allow(missing_docs)]
 mod migration_persistence;
 
@@ -16,17 +19,28 @@ mod migration_persistence;
 pub mod steps;
 
 mod migrations_directory;
+mod rename_hints;
 
 pub use database_migration_inferrer::*;
 pub use database_migration_step_applier::*;
 pub use destructive_change_checker::*;
+pub use destructive_change_policy::{
+    CheckSeverity, DestructiveChangePolicy, PolicyRule, DESTRUCTIVE_CHANGE_POLICY_FILENAME,
+};
+pub use diff_target::DiffTarget;
 pub use error::*;
 pub use imperative_migrations_persistence::{
     ImperativeMigrationsPersistence, MigrationRecord, PersistenceNotInitializedError, Timestamp,
 };
 pub use migration_applier::*;
+pub use migration_linter::{LintContext, LintDiagnostic, MigrationLinter};
 pub use migration_persistence::*;
-pub use migrations_directory::{create_migration_directory, list_migrations, ListMigrationsError, MigrationDirectory};
+pub use migrations_directory::{
+    create_migration_directory, create_migration_directory_with_timestamp, list_migrations, ListMigrationsError,
+    MigrationDirectory, MigrationStepScript, MIGRATION_SCRIPT_FILENAME, POST_MIGRATION_SCRIPT_FILENAME,
+    PRE_MIGRATION_SCRIPT_FILENAME,
+};
+pub use rename_hints::RenameHint;
 pub use steps::MigrationStep;
 
 use sha2::{Digest, Sha256};
@@ -82,6 +96,9 @@ pub trait MigrationConnector: Send + Sync + 'static {
     /// See [DestructiveChangeChecker](trait.DestructiveChangeChecker.html).
     fn destructive_change_checker(&self) -> &dyn DestructiveChangeChecker<Self::DatabaseMigration>;
 
+    /// See [MigrationLinter](trait.MigrationLinter.html).
+    fn migration_linter(&self) -> &dyn MigrationLinter<Self::DatabaseMigration>;
+
     // TODO: figure out if this is the best way to do this or move to a better place/interface
     // this is placed here so i can use the associated type
     /// Deprecated
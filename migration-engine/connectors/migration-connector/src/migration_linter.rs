@@ -0,0 +1,59 @@
+use crate::ConnectorResult;
+use datamodel::dml::Datamodel;
+use serde::Serialize;
+
+/// Implementors of this trait are responsible for the static analysis of
+/// migrations before they are applied, reporting the patterns that are
+/// dangerous on a production database.
+///
+/// The type parameter is the connector's
+/// [DatabaseMigration](trait.MigrationConnector.html#associatedtype.DatabaseMigration)
+/// type.
+#[async_trait::async_trait]
+pub trait MigrationLinter<T>: Send + Sync
+where
+    T: Send + Sync + 'static,
+{
+    /// Lint a migration. The script is the migration script as written in the
+    /// migrations directory, and it is what gets linted. The database
+    /// migration is inferred from the schema the migration applies to and the
+    /// schema it produces, and tells what the statements of the script apply
+    /// to.
+    async fn lint(
+        &self,
+        database_migration: &T,
+        script: &str,
+        context: &LintContext<'_>,
+    ) -> ConnectorResult<Vec<LintDiagnostic>>;
+}
+
+/// The information the linter needs beside the migration itself.
+#[derive(Debug, Default)]
+pub struct LintContext<'a> {
+    /// The Prisma schema the migrations should lead to, if known.
+    pub target_schema: Option<&'a Datamodel>,
+    /// The row count from which a table is considered large, from the
+    /// [DestructiveChangePolicy](struct.DestructiveChangePolicy.html). The
+    /// lints about large tables only run when this is set.
+    pub large_table_row_count: Option<i64>,
+}
+
+/// A dangerous pattern found in a migration.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LintDiagnostic {
+    /// The name of the lint, for example `tableRewrite`.
+    pub lint: String,
+    /// The human-readable description of the problem.
+    pub message: String,
+}
+
+impl LintDiagnostic {
+    /// Construct a diagnostic.
+    pub fn new(lint: &str, message: String) -> Self {
+        LintDiagnostic {
+            lint: lint.to_owned(),
+            message,
+        }
+    }
+}
@@ -7,6 +7,10 @@
 //! directorys, named after the migration id, and each containing:
 //!
 //! - A migration script
+//! - Optionally, data migration scripts running before and after the migration
+//!   script, for example to backfill new columns
+//! - Optionally, a down migration script reverting the migration
+//! - For squashed migrations, the list of the migrations they replace
 
 use sha2::{Digest, Sha256, Sha512};
 use std::{
@@ -23,12 +27,38 @@ use crate::FormatChecksum;
 /// The file name for migration scripts, not including the file extension.
 pub const MIGRATION_SCRIPT_FILENAME: &str = "migration";
 
+/// The file name for data migration scripts running before the migration
+/// script, not including the file extension.
+pub const PRE_MIGRATION_SCRIPT_FILENAME: &str = "pre";
+
+/// The file name for data migration scripts running after the migration
+/// script, not including the file extension.
+pub const POST_MIGRATION_SCRIPT_FILENAME: &str = "post";
+
+/// The file name for down migration scripts, not including the file extension.
+pub const DOWN_MIGRATION_SCRIPT_FILENAME: &str = "down";
+
+/// The name of the file listing the migrations replaced by a squashed migration.
+pub const SQUASHED_MIGRATIONS_FILENAME: &str = "squashed_migrations.txt";
+
 /// Create a directory for a new migration.
 pub fn create_migration_directory(
     migrations_directory_path: &Path,
     migration_name: &str,
 ) -> io::Result<MigrationDirectory> {
-    let timestamp = chrono::Utc::now().format("%Y%m%d%H%M%S");
+    let timestamp = chrono::Utc::now().format("%Y%m%d%H%M%S").to_string();
+
+    create_migration_directory_with_timestamp(migrations_directory_path, &timestamp, migration_name)
+}
+
+/// Create a directory for a new migration, using the provided timestamp
+/// instead of the current time. This places the migration at a given position
+/// in the migrations history.
+pub fn create_migration_directory_with_timestamp(
+    migrations_directory_path: &Path,
+    timestamp: &str,
+    migration_name: &str,
+) -> io::Result<MigrationDirectory> {
     let directory_name = format!(
         "{timestamp}_{migration_name}",
         timestamp = timestamp,
@@ -98,6 +128,15 @@ pub fn list_migrations(migrations_directory_path: &Path) -> Result<Vec<Migration
     Ok(entries)
 }
 
+/// One of the scripts of a migration, applied as a separate step.
+#[derive(Debug, Clone)]
+pub struct MigrationStepScript {
+    /// The file name of the script, not including the file extension.
+    pub name: &'static str,
+    /// The contents of the script.
+    pub script: String,
+}
+
 /// Proxy to a directory containing one migration, as returned by
 /// `create_migration_directory` and `list_migrations`.
 #[derive(Debug, Clone)]
@@ -136,6 +175,11 @@ impl MigrationDirectory {
             .expect("Migration directory name is not valid UTF-8.")
     }
 
+    /// The timestamp part of the migration name.
+    pub fn timestamp(&self) -> &str {
+        self.migration_name().splitn(2, '_').next().unwrap_or_default()
+    }
+
     /// Write the checksum of the migration script file to `buf`.
     pub fn checksum(&mut self, buf: &mut Vec<u8>) -> Result<(), ReadMigrationScriptError> {
         let script = self.read_migration_script()?;
@@ -149,15 +193,33 @@ impl MigrationDirectory {
         Ok(())
     }
 
-    /// Check whether the checksum of the migration script matches the provided one.
-    #[tracing::instrument]
-    pub fn matches_checksum(&self, checksum_str: &str) -> Result<bool, ReadMigrationScriptError> {
-        let filesystem_script = self.read_migration_script()?;
+    /// The SHA-256 checksum of the scripts of the migration, formatted to be
+    /// human-readable. The data migration scripts are hashed after the
+    /// migration script, each preceded by its name, so the checksum of a
+    /// migration without data migration scripts is the checksum of its
+    /// migration script.
+    pub fn scripts_checksum(&self) -> Result<String, ReadMigrationScriptError> {
         let mut hasher = Sha256::new();
-        hasher.update(&filesystem_script);
-        let filesystem_script_checksum: [u8; 32] = hasher.finalize().into();
+        hasher.update(self.read_migration_script()?);
+
+        for file_name in &[PRE_MIGRATION_SCRIPT_FILENAME, POST_MIGRATION_SCRIPT_FILENAME] {
+            if let Some(script) = self.read_optional_script(file_name)? {
+                hasher.update(b"\0");
+                hasher.update(file_name);
+                hasher.update(b"\0");
+                hasher.update(script);
+            }
+        }
+
+        let checksum: [u8; 32] = hasher.finalize().into();
 
-        Ok(checksum_str == filesystem_script_checksum.format_checksum())
+        Ok(checksum.format_checksum())
+    }
+
+    /// Check whether the checksum of the migration scripts matches the provided one.
+    #[tracing::instrument]
+    pub fn matches_checksum(&self, checksum_str: &str) -> Result<bool, ReadMigrationScriptError> {
+        Ok(checksum_str == self.scripts_checksum()?)
     }
 
     /// Write the migration script to the directory.
@@ -175,12 +237,124 @@ impl MigrationDirectory {
         Ok(())
     }
 
+    /// Write the down migration script to the directory.
+    #[tracing::instrument]
+    pub fn write_down_migration_script(&self, script: &str, extension: &str) -> std::io::Result<()> {
+        let mut path = self.path.join(DOWN_MIGRATION_SCRIPT_FILENAME);
+
+        path.set_extension(extension);
+
+        tracing::debug!("Writing down migration script at {:?}", &path);
+
+        let mut file = std::fs::File::create(&path)?;
+        file.write_all(script.as_bytes())?;
+
+        Ok(())
+    }
+
+    /// Write the names of the migrations replaced by this squashed migration
+    /// to the directory.
+    #[tracing::instrument]
+    pub fn write_squashed_migration_names(&self, migration_names: &[String]) -> std::io::Result<()> {
+        let path = self.path.join(SQUASHED_MIGRATIONS_FILENAME);
+
+        tracing::debug!("Writing squashed migration names at {:?}", &path);
+
+        let mut file = std::fs::File::create(&path)?;
+
+        for migration_name in migration_names {
+            writeln!(file, "{}", migration_name)?;
+        }
+
+        Ok(())
+    }
+
     /// Read the migration script to a string.
     #[tracing::instrument]
     pub fn read_migration_script(&self) -> Result<String, ReadMigrationScriptError> {
         Ok(std::fs::read_to_string(&self.path.join("migration.sql"))?)
     }
 
+    /// Read the scripts of the migration, in the order they are applied: the
+    /// pre-migration data script, the migration script and the post-migration
+    /// data script. Only the migration script is required.
+    ///
+    /// Only the migration script changes the schema, the data scripts are not
+    /// replayed when the migrations are replayed on a temporary database.
+    #[tracing::instrument]
+    pub fn read_migration_steps(&self) -> Result<Vec<MigrationStepScript>, ReadMigrationScriptError> {
+        let mut steps = Vec::with_capacity(3);
+
+        if let Some(script) = self.read_optional_script(PRE_MIGRATION_SCRIPT_FILENAME)? {
+            steps.push(MigrationStepScript {
+                name: PRE_MIGRATION_SCRIPT_FILENAME,
+                script,
+            });
+        }
+
+        steps.push(MigrationStepScript {
+            name: MIGRATION_SCRIPT_FILENAME,
+            script: self.read_migration_script()?,
+        });
+
+        if let Some(script) = self.read_optional_script(POST_MIGRATION_SCRIPT_FILENAME)? {
+            steps.push(MigrationStepScript {
+                name: POST_MIGRATION_SCRIPT_FILENAME,
+                script,
+            });
+        }
+
+        Ok(steps)
+    }
+
+    /// Whether the migration has data migration scripts.
+    pub fn has_data_scripts(&self) -> bool {
+        self.path
+            .join(format!("{}.sql", PRE_MIGRATION_SCRIPT_FILENAME))
+            .exists()
+            || self
+                .path
+                .join(format!("{}.sql", POST_MIGRATION_SCRIPT_FILENAME))
+                .exists()
+    }
+
+    fn read_optional_script(&self, file_name: &str) -> Result<Option<String>, ReadMigrationScriptError> {
+        match std::fs::read_to_string(&self.path.join(format!("{}.sql", file_name))) {
+            Ok(script) => Ok(Some(script)),
+            Err(err) if matches!(err.kind(), io::ErrorKind::NotFound) => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Read the down migration script to a string. Returns `None` if the
+    /// migration has no down migration script.
+    #[tracing::instrument]
+    pub fn read_down_migration_script(&self) -> Result<Option<String>, ReadMigrationScriptError> {
+        self.read_optional_script(DOWN_MIGRATION_SCRIPT_FILENAME)
+    }
+
+    /// Read the names of the migrations replaced by this migration, if it is a
+    /// squashed migration. Returns an empty Vec otherwise.
+    #[tracing::instrument]
+    pub fn read_squashed_migration_names(&self) -> Result<Vec<String>, ReadMigrationScriptError> {
+        match std::fs::read_to_string(&self.path.join(SQUASHED_MIGRATIONS_FILENAME)) {
+            Ok(contents) => Ok(contents
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(String::from)
+                .collect()),
+            Err(err) if matches!(err.kind(), io::ErrorKind::NotFound) => Ok(Vec::new()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Delete the directory and its contents.
+    #[tracing::instrument]
+    pub fn delete(self) -> std::io::Result<()> {
+        std::fs::remove_dir_all(&self.path)
+    }
+
     /// The filesystem path to the directory.
     pub fn path(&self) -> &Path {
         &self.path
@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+/// A hint that a table or a column was renamed, as opposed to dropped and
+/// recreated under a new name. Names are database names, i.e. they take `@map`
+/// and `@@map` into account.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum RenameHint {
+    /// A table was renamed.
+    #[serde(rename_all = "camelCase")]
+    Table {
+        /// The name of the table in the previous schema.
+        previous_name: String,
+        /// The name of the table in the next schema.
+        next_name: String,
+    },
+    /// A column was renamed.
+    #[serde(rename_all = "camelCase")]
+    Column {
+        /// The name of the table containing the column, in the next schema.
+        table: String,
+        /// The name of the column in the previous schema.
+        previous_name: String,
+        /// The name of the column in the next schema.
+        next_name: String,
+    },
+}
+
+impl std::fmt::Display for RenameHint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RenameHint::Table {
+                previous_name,
+                next_name,
+            } => write!(f, "table `{}` to `{}`", previous_name, next_name),
+            RenameHint::Column {
+                table,
+                previous_name,
+                next_name,
+            } => write!(f, "column `{}` to `{}` on table `{}`", previous_name, next_name, table),
+        }
+    }
+}
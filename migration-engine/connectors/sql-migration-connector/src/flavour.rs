@@ -29,6 +29,11 @@ use std::fmt::Debug;
 /// reference: https://dev.mysql.com/doc/refman/5.7/en/identifier-length.html
 pub(crate) const MYSQL_IDENTIFIER_SIZE_LIMIT: usize = 64;
 
+/// The maximum length of postgres identifiers, in bytes.
+///
+/// Reference: https://www.postgresql.org/docs/12/limits.html
+pub(crate) const POSTGRES_IDENTIFIER_SIZE_LIMIT: usize = 63;
+
 pub(crate) fn from_connection_info(connection_info: &ConnectionInfo) -> Box<dyn SqlFlavour + Send + Sync + 'static> {
     match connection_info {
         ConnectionInfo::Mysql(url) => Box::new(MysqlFlavour(url.clone())),
@@ -56,6 +61,10 @@ pub(crate) trait SqlFlavour:
     /// Initialize the `_prisma_migrations` table.
     async fn create_imperative_migrations_table(&self, connection: &Connection) -> ConnectorResult<()>;
 
+    /// Add the `failed_step_name` column to a `_prisma_migrations` table
+    /// created before the column existed.
+    async fn add_failed_step_name_column(&self, connection: &Connection) -> ConnectorResult<()>;
+
     /// Describe the SQL schema.
     async fn describe_schema<'a>(&'a self, conn: &Connection) -> ConnectorResult<SqlSchema>;
 
@@ -74,10 +83,28 @@ pub(crate) trait SqlFlavour:
     async fn reset(&self, connection: &Connection) -> ConnectorResult<()>;
 
     /// Apply the given migration history to a temporary database, and return
-    /// the final introspected SQL schema.
+    /// the final introspected SQL schema.
     async fn sql_schema_from_migration_history(
         &self,
         migrations: &[MigrationDirectory],
         connection: &Connection,
-    ) -> ConnectorResult<SqlSchema>;
+    ) -> ConnectorResult<SqlSchema> {
+        let mut sql_schemas = self
+            .sql_schemas_from_migration_history(migrations, migrations.len(), connection)
+            .await?;
+
+        Ok(sql_schemas
+            .pop()
+            .expect("The schema at the end of the migration history"))
+    }
+
+    /// Apply the given migration history to a temporary database, and return
+    /// the introspected SQL schema after the first `described_from` migrations
+    /// and after each of the following migrations.
+    async fn sql_schemas_from_migration_history(
+        &self,
+        migrations: &[MigrationDirectory],
+        described_from: usize,
+        connection: &Connection,
+    ) -> ConnectorResult<Vec<SqlSchema>>;
 }
@@ -1,4 +1,4 @@
-use crate::{connect, connection_wrapper::Connection, SqlFlavour};
+use crate::{connect, connection_wrapper::Connection, error::quaint_error_to_connector_error, SqlFlavour};
 use migration_connector::{ConnectorError, ConnectorResult, MigrationDirectory};
 use quaint::connector::MssqlUrl;
 use sql_schema_describer::{SqlSchema, SqlSchemaDescriberBackend, SqlSchemaDescriberError};
@@ -63,6 +63,7 @@ impl SqlFlavour for MssqlFlavour {
                 rolled_back_at          DATETIMEOFFSET,
                 started_at              DATETIMEOFFSET NOT NULL DEFAULT CURRENT_TIMESTAMP,
                 applied_steps_count     INT NOT NULL DEFAULT 0,
+                failed_step_name        VARCHAR(32),
                 script                  NVARCHAR(MAX) NOT NULL
             );
         "#;
@@ -70,6 +71,12 @@ impl SqlFlavour for MssqlFlavour {
         Ok(connection.raw_cmd(sql).await?)
     }
 
+    async fn add_failed_step_name_column(&self, connection: &Connection) -> ConnectorResult<()> {
+        let sql = "ALTER TABLE [_prisma_migrations] ADD failed_step_name VARCHAR(32)";
+
+        Ok(connection.raw_cmd(sql).await?)
+    }
+
     async fn describe_schema<'a>(&'a self, connection: &Connection) -> ConnectorResult<SqlSchema> {
         sql_schema_describer::mssql::SqlSchemaDescriber::new(connection.quaint().clone())
             .describe(connection.connection_info().schema_name())
@@ -78,6 +85,9 @@ impl SqlFlavour for MssqlFlavour {
                 SqlSchemaDescriberError::UnknownError => {
                     ConnectorError::generic(anyhow::anyhow!("An unknown error occurred in sql-schema-describer"))
                 }
+                SqlSchemaDescriberError::QueryError(err) => {
+                    quaint_error_to_connector_error(err, connection.connection_info())
+                }
             })
     }
 
@@ -161,11 +171,12 @@ impl SqlFlavour for MssqlFlavour {
         Ok(())
     }
 
-    async fn sql_schema_from_migration_history(
+    async fn sql_schemas_from_migration_history(
         &self,
         _: &[MigrationDirectory],
+        _: usize,
         _: &Connection,
-    ) -> ConnectorResult<SqlSchema> {
+    ) -> ConnectorResult<Vec<SqlSchema>> {
         todo!("Needs the connection string crate, so leaving it unimplemented for now")
     }
 }
@@ -1,7 +1,9 @@
 use super::SqlFlavour;
 use crate::{
-    connect, connection_wrapper::Connection, database_info::DatabaseInfo, error::CheckDatabaseInfoResult,
-    error::SystemDatabase,
+    connect,
+    connection_wrapper::Connection,
+    database_info::DatabaseInfo,
+    error::{quaint_error_to_connector_error, CheckDatabaseInfoResult, SystemDatabase},
 };
 use migration_connector::{ConnectorError, ConnectorResult, MigrationDirectory};
 use once_cell::sync::Lazy;
@@ -63,6 +65,7 @@ impl SqlFlavour for MysqlFlavour {
                 rolled_back_at          DATETIME(3),
                 started_at              DATETIME(3) NOT NULL DEFAULT CURRENT_TIMESTAMP(3),
                 applied_steps_count     INTEGER UNSIGNED NOT NULL DEFAULT 0,
+                failed_step_name        VARCHAR(32),
                 script                  TEXT NOT NULL
             ) DEFAULT CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci;
         "#;
@@ -70,6 +73,12 @@ impl SqlFlavour for MysqlFlavour {
         Ok(connection.raw_cmd(sql).await?)
     }
 
+    async fn add_failed_step_name_column(&self, connection: &Connection) -> ConnectorResult<()> {
+        let sql = "ALTER TABLE _prisma_migrations ADD COLUMN failed_step_name VARCHAR(32)";
+
+        Ok(connection.raw_cmd(sql).await?)
+    }
+
     async fn describe_schema<'a>(&'a self, connection: &Connection) -> ConnectorResult<SqlSchema> {
         sql_schema_describer::mysql::SqlSchemaDescriber::new(connection.quaint().clone())
             .describe(connection.connection_info().schema_name())
@@ -78,6 +87,9 @@ impl SqlFlavour for MysqlFlavour {
                 SqlSchemaDescriberError::UnknownError => {
                     ConnectorError::generic(anyhow::anyhow!("An unknown error occurred in sql-schema-describer"))
                 }
+                SqlSchemaDescriberError::QueryError(err) => {
+                    quaint_error_to_connector_error(err, connection.connection_info())
+                }
             })
     }
 
@@ -126,11 +138,12 @@ impl SqlFlavour for MysqlFlavour {
     }
 
     #[tracing::instrument(skip(self, migrations, connection))]
-    async fn sql_schema_from_migration_history(
+    async fn sql_schemas_from_migration_history(
         &self,
         migrations: &[MigrationDirectory],
+        described_from: usize,
         connection: &Connection,
-    ) -> ConnectorResult<SqlSchema> {
+    ) -> ConnectorResult<Vec<SqlSchema>> {
         let database_name = format!("prisma_shadow_db{}", uuid::Uuid::new_v4());
         let drop_database = format!("DROP DATABASE IF EXISTS `{}`", database_name);
         let create_database = format!("CREATE DATABASE `{}`", database_name);
@@ -145,8 +158,13 @@ impl SqlFlavour for MysqlFlavour {
         tracing::debug!("Connecting to temporary database at {:?}", temporary_database_url);
 
         let temp_database = crate::connect(&temporary_database_url).await?;
+        let mut sql_schemas = Vec::new();
+
+        for (index, migration) in migrations.iter().enumerate() {
+            if index >= described_from {
+                sql_schemas.push(self.describe_schema(&temp_database).await?);
+            }
 
-        for migration in migrations {
             let script = migration.read_migration_script()?;
 
             tracing::debug!(
@@ -163,10 +181,10 @@ impl SqlFlavour for MysqlFlavour {
                 })?;
         }
 
-        let sql_schema = self.describe_schema(&temp_database).await?;
+        sql_schemas.push(self.describe_schema(&temp_database).await?);
 
         connection.raw_cmd(&drop_database).await?;
 
-        Ok(sql_schema)
+        Ok(sql_schemas)
     }
 }
@@ -1,4 +1,7 @@
-use crate::{connect, connection_wrapper::Connection, SqlFlavour};
+use crate::{
+    connect, connection_wrapper::Connection, error::quaint_error_to_connector_error,
+    sql_database_step_applier::split_script, SqlFlavour,
+};
 use migration_connector::{ConnectorError, ConnectorResult, MigrationDirectory};
 use quaint::{connector::PostgresUrl, error::ErrorKind as QuaintKind};
 use sql_schema_describer::{SqlSchema, SqlSchemaDescriberBackend, SqlSchemaDescriberError};
@@ -67,6 +70,7 @@ impl SqlFlavour for PostgresFlavour {
                 rolled_back_at          TIMESTAMPTZ,
                 started_at              TIMESTAMPTZ NOT NULL DEFAULT now(),
                 applied_steps_count     INTEGER NOT NULL DEFAULT 0,
+                failed_step_name        VARCHAR(32),
                 script                  TEXT NOT NULL
             );
         "#;
@@ -74,6 +78,12 @@ impl SqlFlavour for PostgresFlavour {
         Ok(connection.raw_cmd(sql).await?)
     }
 
+    async fn add_failed_step_name_column(&self, connection: &Connection) -> ConnectorResult<()> {
+        let sql = "ALTER TABLE _prisma_migrations ADD COLUMN failed_step_name VARCHAR(32)";
+
+        Ok(connection.raw_cmd(sql).await?)
+    }
+
     async fn describe_schema<'a>(&'a self, connection: &Connection) -> ConnectorResult<SqlSchema> {
         sql_schema_describer::postgres::SqlSchemaDescriber::new(connection.quaint().clone())
             .describe(connection.connection_info().schema_name())
@@ -82,6 +92,9 @@ impl SqlFlavour for PostgresFlavour {
                 SqlSchemaDescriberError::UnknownError => {
                     ConnectorError::generic(anyhow::anyhow!("An unknown error occurred in sql-schema-describer"))
                 }
+                SqlSchemaDescriberError::QueryError(err) => {
+                    quaint_error_to_connector_error(err, connection.connection_info())
+                }
             })
     }
 
@@ -166,11 +179,12 @@ impl SqlFlavour for PostgresFlavour {
     }
 
     #[tracing::instrument(skip(self, migrations, connection))]
-    async fn sql_schema_from_migration_history(
+    async fn sql_schemas_from_migration_history(
         &self,
         migrations: &[MigrationDirectory],
+        described_from: usize,
         connection: &Connection,
-    ) -> ConnectorResult<SqlSchema> {
+    ) -> ConnectorResult<Vec<SqlSchema>> {
         let database_name = format!("prisma_migrations_shadow_database_{}", uuid::Uuid::new_v4());
         let drop_database = format!("DROP DATABASE IF EXISTS \"{}\"", database_name);
         let create_database = format!("CREATE DATABASE \"{}\"", database_name);
@@ -185,12 +199,17 @@ impl SqlFlavour for PostgresFlavour {
 
         tracing::debug!("Connecting to temporary database at {}", temporary_database_url);
 
-        let sql_schema = {
+        let sql_schemas = {
             let temporary_database = crate::connect(&temporary_database_url).await?;
+            let mut sql_schemas = Vec::new();
 
             temporary_database.raw_cmd(&create_schema).await?;
 
-            for migration in migrations {
+            for (index, migration) in migrations.iter().enumerate() {
+                if index >= described_from {
+                    sql_schemas.push(self.describe_schema(&temporary_database).await?);
+                }
+
                 let script = migration.read_migration_script()?;
 
                 tracing::debug!(
@@ -198,23 +217,30 @@ impl SqlFlavour for PostgresFlavour {
                     migration.migration_name()
                 );
 
-                temporary_database
-                    .raw_cmd(&script)
-                    .await
-                    .map_err(ConnectorError::from)
-                    .map_err(|connector_error| {
-                        connector_error.into_migration_does_not_apply_cleanly(migration.migration_name().to_owned())
-                    })?;
+                // The non-transactional statements, like `CREATE INDEX
+                // CONCURRENTLY`, cannot run in the same command as the rest
+                // of the script.
+                for (batch, _transactional) in split_script(&script) {
+                    temporary_database
+                        .raw_cmd(&batch)
+                        .await
+                        .map_err(ConnectorError::from)
+                        .map_err(|connector_error| {
+                            connector_error.into_migration_does_not_apply_cleanly(migration.migration_name().to_owned())
+                        })?;
+                }
             }
 
+            sql_schemas.push(self.describe_schema(&temporary_database).await?);
+
             // the connection to the temporary database is dropped at the end of
             // the block.
-            self.describe_schema(&temporary_database).await?
+            sql_schemas
         };
 
         connection.raw_cmd(&drop_database).await?;
 
-        Ok(sql_schema)
+        Ok(sql_schemas)
     }
 }
 
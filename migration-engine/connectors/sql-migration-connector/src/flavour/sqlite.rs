@@ -1,4 +1,4 @@
-use crate::{connect, connection_wrapper::Connection, flavour::SqlFlavour};
+use crate::{connect, connection_wrapper::Connection, error::quaint_error_to_connector_error, flavour::SqlFlavour};
 use migration_connector::{ConnectorError, ConnectorResult, MigrationDirectory};
 use quaint::prelude::ConnectionInfo;
 use sql_schema_describer::{SqlSchema, SqlSchemaDescriberBackend, SqlSchemaDescriberError};
@@ -44,6 +44,7 @@ impl SqlFlavour for SqliteFlavour {
                 "rolled_back_at"        DATETIME,
                 "started_at"            DATETIME NOT NULL DEFAULT current_timestamp,
                 "applied_steps_count"   INTEGER UNSIGNED NOT NULL DEFAULT 0,
+                "failed_step_name"      TEXT,
                 "script"                TEXT NOT NULL
             );
             "#;
@@ -51,6 +52,12 @@ impl SqlFlavour for SqliteFlavour {
         Ok(connection.raw_cmd(sql).await?)
     }
 
+    async fn add_failed_step_name_column(&self, connection: &Connection) -> ConnectorResult<()> {
+        let sql = r#"ALTER TABLE "_prisma_migrations" ADD COLUMN "failed_step_name" TEXT"#;
+
+        Ok(connection.raw_cmd(sql).await?)
+    }
+
     async fn describe_schema<'a>(&'a self, connection: &Connection) -> ConnectorResult<SqlSchema> {
         sql_schema_describer::sqlite::SqlSchemaDescriber::new(connection.quaint().clone())
             .describe(connection.connection_info().schema_name())
@@ -59,6 +66,9 @@ impl SqlFlavour for SqliteFlavour {
                 SqlSchemaDescriberError::UnknownError => {
                     ConnectorError::generic(anyhow::anyhow!("An unknown error occurred in sql-schema-describer"))
                 }
+                SqlSchemaDescriberError::QueryError(err) => {
+                    quaint_error_to_connector_error(err, connection.connection_info())
+                }
             })
     }
 
@@ -99,11 +109,12 @@ impl SqlFlavour for SqliteFlavour {
     }
 
     #[tracing::instrument(skip(self, migrations, _connection))]
-    async fn sql_schema_from_migration_history(
+    async fn sql_schemas_from_migration_history(
         &self,
         migrations: &[MigrationDirectory],
+        described_from: usize,
         _connection: &Connection,
-    ) -> ConnectorResult<SqlSchema> {
+    ) -> ConnectorResult<Vec<SqlSchema>> {
         let temp_dir = tempfile::tempdir().expect("Failed to create temporary directory.");
         let database_url = format!(
             "file:{}/scratch.db?db_name={}",
@@ -114,8 +125,13 @@ impl SqlFlavour for SqliteFlavour {
         tracing::debug!("Applying migrations to temporary SQLite database at `{}`", database_url);
 
         let conn = crate::connect(&database_url).await?;
+        let mut sql_schemas = Vec::new();
+
+        for (index, migration) in migrations.iter().enumerate() {
+            if index >= described_from {
+                sql_schemas.push(self.describe_schema(&conn).await?);
+            }
 
-        for migration in migrations {
             let script = migration.read_migration_script()?;
 
             tracing::debug!(
@@ -131,8 +147,8 @@ impl SqlFlavour for SqliteFlavour {
                 })?;
         }
 
-        let sql_schema = self.describe_schema(&conn).await?;
+        sql_schemas.push(self.describe_schema(&conn).await?);
 
-        Ok(sql_schema)
+        Ok(sql_schemas)
     }
 }
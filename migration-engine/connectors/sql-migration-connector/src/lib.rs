@@ -15,7 +15,9 @@ mod sql_database_migration_inferrer;
 mod sql_database_step_applier;
 mod sql_destructive_change_checker;
 mod sql_imperative_migration_persistence;
+mod sql_migration_linter;
 mod sql_migration_persistence;
+mod sql_migration_summary;
 mod sql_renderer;
 mod sql_schema_calculator;
 mod sql_schema_differ;
@@ -136,6 +138,10 @@ impl MigrationConnector for SqlMigrationConnector {
     async fn initialize(&self) -> ConnectorResult<()> {
         self.migration_persistence().init().await?;
 
+        // The commands only reading the migrations table do not initialize it.
+        let schema = self.describe_schema().await?;
+        self.upgrade_imperative_migrations_table(&schema).await?;
+
         Ok(())
     }
 
@@ -168,6 +174,10 @@ impl MigrationConnector for SqlMigrationConnector {
         self
     }
 
+    fn migration_linter(&self) -> &dyn MigrationLinter<SqlMigration> {
+        self
+    }
+
     fn deserialize_database_migration(&self, json: serde_json::Value) -> Option<SqlMigration> {
         serde_json::from_value(json).ok()
     }
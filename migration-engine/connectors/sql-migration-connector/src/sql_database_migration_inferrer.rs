@@ -1,5 +1,8 @@
 use crate::*;
-use crate::{sql_schema_calculator::SqlSchemaCalculator, sql_schema_differ::SqlSchemaDiffer};
+use crate::{
+    sql_schema_calculator::SqlSchemaCalculator,
+    sql_schema_differ::{apply_rename_hints, reverse_renames, SqlSchemaDiffer},
+};
 use datamodel::*;
 use migration_connector::steps::MigrationStep;
 use migration_connector::*;
@@ -16,12 +19,14 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlMigrationConnector {
     ) -> ConnectorResult<SqlMigration> {
         let current_database_schema: SqlSchema = self.describe_schema().await?;
         let expected_database_schema = SqlSchemaCalculator::calculate(next, self.database_info(), self.flavour());
-        Ok(infer(
+
+        infer(
             current_database_schema,
             expected_database_schema,
+            &[],
             self.database_info(),
             self.flavour(),
-        ))
+        )
     }
 
     /// Infer the database migration steps, skipping the schema describer and assuming an empty database.
@@ -29,12 +34,13 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlMigrationConnector {
         let current_database_schema = SqlSchema::empty();
         let expected_database_schema = SqlSchemaCalculator::calculate(next, self.database_info(), self.flavour());
 
-        Ok(infer(
+        infer(
             current_database_schema,
             expected_database_schema,
+            &[],
             self.database_info(),
             self.flavour(),
-        ))
+        )
     }
 
     fn infer_from_datamodels(
@@ -47,19 +53,21 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlMigrationConnector {
             SqlSchemaCalculator::calculate(previous, self.database_info(), self.flavour());
         let expected_database_schema = SqlSchemaCalculator::calculate(next, self.database_info(), self.flavour());
 
-        Ok(infer(
+        infer(
             current_database_schema,
             expected_database_schema,
+            &[],
             self.database_info(),
             self.flavour(),
-        ))
+        )
     }
 
-    #[tracing::instrument(skip(self, previous_migrations, target_schema))]
+    #[tracing::instrument(skip(self, previous_migrations, target_schema, rename_hints))]
     async fn infer_next_migration(
         &self,
         previous_migrations: &[MigrationDirectory],
         target_schema: &Datamodel,
+        rename_hints: &[RenameHint],
     ) -> ConnectorResult<SqlMigration> {
         let current_database_schema = self
             .flavour()
@@ -68,12 +76,69 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlMigrationConnector {
         let expected_database_schema =
             SqlSchemaCalculator::calculate(target_schema, self.database_info(), self.flavour());
 
-        Ok(infer(
+        infer(
             current_database_schema,
             expected_database_schema,
+            rename_hints,
+            self.database_info(),
+            self.flavour(),
+        )
+    }
+
+    async fn infer_baseline_migration(&self) -> ConnectorResult<SqlMigration> {
+        let current_database_schema = self.describe_schema().await?;
+
+        infer(
+            SqlSchema::empty(),
+            current_database_schema,
+            &[],
             self.database_info(),
             self.flavour(),
-        ))
+        )
+    }
+
+    async fn infer_diff(&self, from: &DiffTarget, to: &DiffTarget) -> ConnectorResult<SqlMigration> {
+        let from_schema = self.schema_from_diff_target(from).await?;
+        let to_schema = self.schema_from_diff_target(to).await?;
+
+        infer(from_schema, to_schema, &[], self.database_info(), self.flavour())
+    }
+
+    async fn infer_history_migrations(
+        &self,
+        migrations: &[MigrationDirectory],
+        first_index: usize,
+    ) -> ConnectorResult<Vec<SqlMigration>> {
+        let sql_schemas = self
+            .flavour()
+            .sql_schemas_from_migration_history(migrations, first_index, self.conn())
+            .await?;
+
+        sql_schemas
+            .windows(2)
+            .map(|schemas| {
+                infer(
+                    schemas[0].clone(),
+                    schemas[1].clone(),
+                    &[],
+                    self.database_info(),
+                    self.flavour(),
+                )
+            })
+            .collect()
+    }
+
+    fn infer_reverse_migration(&self, migration: &SqlMigration) -> ConnectorResult<SqlMigration> {
+        // The renamed tables and columns are renamed back instead of being dropped.
+        let (original_schema, rename_hints) = reverse_renames(migration);
+
+        infer(
+            migration.after.clone(),
+            original_schema,
+            &rename_hints,
+            self.database_info(),
+            self.flavour(),
+        )
     }
 
     async fn detect_drift(&self, applied_migrations: &[MigrationDirectory]) -> ConnectorResult<bool> {
@@ -91,25 +156,63 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlMigrationConnector {
     }
 }
 
+impl SqlMigrationConnector {
+    async fn schema_from_diff_target(&self, target: &DiffTarget) -> ConnectorResult<SqlSchema> {
+        match target {
+            DiffTarget::Datamodel(datamodel) => Ok(SqlSchemaCalculator::calculate(
+                datamodel,
+                self.database_info(),
+                self.flavour(),
+            )),
+            DiffTarget::Migrations(migrations) => {
+                self.flavour()
+                    .sql_schema_from_migration_history(migrations, self.conn())
+                    .await
+            }
+            DiffTarget::Database(url) => {
+                let connector = SqlMigrationConnector::new(url).await?;
+
+                // The migration is rendered for our database, so the databases
+                // on both sides must be of the same kind.
+                if connector.sql_family() != self.sql_family() {
+                    return Err(ConnectorError::generic(anyhow::anyhow!(
+                        "Cannot diff a {} database with a {} database.",
+                        connector.sql_family().as_str(),
+                        self.sql_family().as_str()
+                    )));
+                }
+
+                connector.describe_schema().await
+            }
+            DiffTarget::Empty => Ok(SqlSchema::empty()),
+        }
+    }
+}
+
 fn infer(
-    current_database_schema: SqlSchema,
+    mut current_database_schema: SqlSchema,
     expected_database_schema: SqlSchema,
+    rename_hints: &[RenameHint],
     database_info: &DatabaseInfo,
     flavour: &dyn SqlFlavour,
-) -> SqlMigration {
-    let steps = SqlSchemaDiffer::diff(
-        &current_database_schema,
-        &expected_database_schema,
-        flavour,
-        &database_info,
-    )
-    .into_steps();
-
-    SqlMigration {
+) -> ConnectorResult<SqlMigration> {
+    let mut steps = apply_rename_hints(&mut current_database_schema, &expected_database_schema, rename_hints)?;
+
+    steps.extend(
+        SqlSchemaDiffer::diff(
+            &current_database_schema,
+            &expected_database_schema,
+            flavour,
+            &database_info,
+        )
+        .into_steps(),
+    );
+
+    Ok(SqlMigration {
         before: current_database_schema,
         after: expected_database_schema,
         steps,
-    }
+    })
 }
 
 pub fn wrap_as_step<T, F>(steps: Vec<T>, wrap_fn: F) -> impl Iterator<Item = SqlMigrationStep>
@@ -1,6 +1,8 @@
 use crate::{
     database_info::DatabaseInfo,
     sql_migration::{CreateTable, DropTable, SqlMigration, SqlMigrationStep},
+    sql_migration_summary::render_summary,
+    sql_renderer::OnlineStatement,
     sql_schema_differ::SqlSchemaDiffer,
     SqlFlavour, SqlMigrationConnector,
 };
@@ -10,6 +12,10 @@ use migration_connector::{
 };
 use sql_schema_describer::{walkers::SqlSchemaExt, SqlSchema};
 
+/// The comment preceding the statements that cannot run inside a transaction
+/// in migration scripts. These statements are run on their own.
+const NON_TRANSACTIONAL_STATEMENT_MARKER: &str = "-- prisma:non-transactional";
+
 #[async_trait::async_trait]
 impl DatabaseMigrationStepApplier<SqlMigration> for SqlMigrationConnector {
     #[tracing::instrument(skip(self, database_migration))]
@@ -37,7 +43,42 @@ impl DatabaseMigrationStepApplier<SqlMigration> for SqlMigrationConnector {
         )
     }
 
+    fn render_summary(&self, database_migration: &SqlMigration) -> String {
+        render_summary(database_migration)
+    }
+
     fn render_script(&self, database_migration: &SqlMigration, diagnostics: &DestructiveChangeDiagnostics) -> String {
+        self.render_script_impl(database_migration, diagnostics, false)
+    }
+
+    fn render_online_script(
+        &self,
+        database_migration: &SqlMigration,
+        diagnostics: &DestructiveChangeDiagnostics,
+    ) -> String {
+        self.render_script_impl(database_migration, diagnostics, true)
+    }
+
+    /// The statements following a non-transactional statement marker are run
+    /// on their own. The rest of the script is run in batches between them.
+    async fn apply_script(&self, script: &str) -> ConnectorResult<()> {
+        for (batch, transactional) in split_script(script) {
+            tracing::debug!(transactional, batch = batch.as_str(), "Applying script batch");
+
+            self.conn().raw_cmd(&batch).await?;
+        }
+
+        Ok(())
+    }
+}
+
+impl SqlMigrationConnector {
+    fn render_script_impl(
+        &self,
+        database_migration: &SqlMigration,
+        diagnostics: &DestructiveChangeDiagnostics,
+        online: bool,
+    ) -> String {
         if database_migration.is_empty() {
             return "-- This is an empty migration.".to_string();
         }
@@ -65,20 +106,38 @@ impl DatabaseMigrationStepApplier<SqlMigration> for SqlMigrationConnector {
         }
 
         for step in &database_migration.steps {
-            let statements: Vec<String> = render_raw_sql(
-                step,
-                self.flavour(),
-                self.database_info(),
-                &database_migration.before,
-                &database_migration.after,
-            );
+            let statements: Vec<OnlineStatement> = if online {
+                render_raw_sql_online(
+                    step,
+                    self.flavour(),
+                    self.database_info(),
+                    &database_migration.before,
+                    &database_migration.after,
+                )
+            } else {
+                render_raw_sql(
+                    step,
+                    self.flavour(),
+                    self.database_info(),
+                    &database_migration.before,
+                    &database_migration.after,
+                )
+                .into_iter()
+                .map(OnlineStatement::transactional)
+                .collect()
+            };
 
             script.push_str("-- ");
             script.push_str(step.description());
             script.push('\n');
 
             for statement in statements {
-                script.push_str(&statement);
+                if !statement.transactional {
+                    script.push_str(NON_TRANSACTIONAL_STATEMENT_MARKER);
+                    script.push('\n');
+                }
+
+                script.push_str(&statement.sql);
                 script.push_str(";\n");
             }
         }
@@ -86,12 +145,6 @@ impl DatabaseMigrationStepApplier<SqlMigration> for SqlMigrationConnector {
         script
     }
 
-    async fn apply_script(&self, script: &str) -> ConnectorResult<()> {
-        Ok(self.conn().raw_cmd(script).await?)
-    }
-}
-
-impl SqlMigrationConnector {
     async fn apply_next_step(
         &self,
         steps: &[SqlMigrationStep],
@@ -142,6 +195,75 @@ fn render_steps_pretty(
     Ok(steps)
 }
 
+/// Split a migration script into the batches to run separately, each with
+/// whether it can run inside a transaction. A non-transactional statement
+/// starts after a marker line and ends at the first line ending with a
+/// semicolon.
+pub(crate) fn split_script(script: &str) -> Vec<(String, bool)> {
+    let mut batches = Vec::new();
+    let mut current_batch = String::new();
+    let mut in_non_transactional_statement = false;
+
+    for line in script.lines() {
+        if line.trim() == NON_TRANSACTIONAL_STATEMENT_MARKER {
+            if !current_batch.trim().is_empty() {
+                batches.push((std::mem::take(&mut current_batch), true));
+            }
+
+            in_non_transactional_statement = true;
+            continue;
+        }
+
+        current_batch.push_str(line);
+        current_batch.push('\n');
+
+        if in_non_transactional_statement && line.trim_end().ends_with(';') {
+            batches.push((std::mem::take(&mut current_batch), false));
+            in_non_transactional_statement = false;
+        }
+    }
+
+    if !current_batch.trim().is_empty() {
+        batches.push((current_batch, !in_non_transactional_statement));
+    }
+
+    batches
+}
+
+/// Render a step in online mode. Only the steps creating or dropping indexes
+/// and foreign keys on existing tables are rendered differently.
+fn render_raw_sql_online(
+    step: &SqlMigrationStep,
+    renderer: &(dyn SqlFlavour + Send + Sync),
+    database_info: &DatabaseInfo,
+    current_schema: &SqlSchema,
+    next_schema: &SqlSchema,
+) -> Vec<OnlineStatement> {
+    match step {
+        SqlMigrationStep::CreateIndex(create_index) if !create_index.caused_by_create_table => {
+            vec![renderer.render_create_index_online(create_index)]
+        }
+        SqlMigrationStep::DropIndex(drop_index) => vec![renderer.render_drop_index_online(drop_index)],
+        SqlMigrationStep::AddForeignKey(add_foreign_key) => {
+            let table = next_schema.table_walker_at(add_foreign_key.table_index);
+            let foreign_key = table.foreign_key_at(add_foreign_key.foreign_key_index);
+
+            // Foreign keys on new tables have no rows to validate.
+            if current_schema.table_walker(table.name()).is_none() {
+                return vec![OnlineStatement::transactional(
+                    renderer.render_add_foreign_key(&foreign_key),
+                )];
+            }
+
+            renderer.render_add_foreign_key_online(&foreign_key)
+        }
+        _ => render_raw_sql(step, renderer, database_info, current_schema, next_schema)
+            .into_iter()
+            .map(OnlineStatement::transactional)
+            .collect(),
+    }
+}
+
 fn render_raw_sql(
     step: &SqlMigrationStep,
     renderer: &(dyn SqlFlavour + Send + Sync),
@@ -171,6 +293,15 @@ fn render_raw_sql(
         }
         SqlMigrationStep::DropTable(DropTable { name }) => renderer.render_drop_table(name),
         SqlMigrationStep::RenameTable { name, new_name } => vec![renderer.render_rename_table(name, new_name)],
+        SqlMigrationStep::RenameColumn { table, name, new_name } => {
+            let column = current_schema
+                .table_walker(table)
+                .and_then(|table| table.column(new_name))
+                .ok_or_else(|| anyhow::anyhow!("RenameColumn referring to an unknown column: `{}`.", new_name))
+                .unwrap();
+
+            vec![renderer.render_rename_column(name, column)]
+        }
         SqlMigrationStep::AddForeignKey(add_foreign_key) => {
             let foreign_key = next_schema
                 .table_walker_at(add_foreign_key.table_index)
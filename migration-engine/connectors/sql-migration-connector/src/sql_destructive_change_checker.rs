@@ -22,6 +22,7 @@ mod unexecutable_step_check;
 mod warning_check;
 
 pub(crate) use destructive_change_checker_flavour::DestructiveChangeCheckerFlavour;
+pub(crate) use destructive_check_plan::count_rows_in_table;
 
 use crate::{
     sql_migration::{AlterEnum, AlterTable, CreateIndex, DropTable, SqlMigrationStep, TableChange},
@@ -29,7 +30,10 @@ use crate::{
     SqlMigration, SqlMigrationConnector,
 };
 use destructive_check_plan::DestructiveCheckPlan;
-use migration_connector::{ConnectorResult, DestructiveChangeChecker, DestructiveChangeDiagnostics};
+use migration_connector::{
+    ConnectorResult, DestructiveChangeChecker, DestructiveChangeDiagnostics, DestructiveChangePolicy,
+};
+use quaint::prelude::SqlFamily;
 use sql_schema_describer::{
     walkers::{find_column, ColumnWalker, SqlSchemaExt},
     SqlSchema,
@@ -79,7 +83,47 @@ impl SqlMigrationConnector {
         plan.push_unexecutable(typed_unexecutable, step_index);
     }
 
-    fn plan(&self, steps: &[SqlMigrationStep], before: &SqlSchema, after: &SqlSchema) -> DestructiveCheckPlan {
+    /// Creating an index without `CONCURRENTLY` on Postgres blocks writes to
+    /// the table while the index is built. This is only checked when the
+    /// policy defines what a large table is, and not for the indexes the
+    /// migration script creates concurrently.
+    fn check_create_index(
+        &self,
+        create_index: &CreateIndex,
+        policy: &DestructiveChangePolicy,
+        plan: &mut DestructiveCheckPlan,
+        step_index: usize,
+    ) {
+        let row_count_threshold = match policy.large_table_row_count {
+            Some(row_count_threshold) if self.sql_family() == SqlFamily::Postgres => row_count_threshold,
+            _ => return,
+        };
+
+        if policy
+            .concurrently_created_indexes
+            .iter()
+            .any(|index_name| index_name == &create_index.index.name)
+        {
+            return;
+        }
+
+        plan.push_warning(
+            SqlMigrationWarningCheck::NonConcurrentIndexOnLargeTable {
+                table: create_index.table.clone(),
+                index: create_index.index.name.clone(),
+                row_count_threshold,
+            },
+            step_index,
+        );
+    }
+
+    fn plan(
+        &self,
+        steps: &[SqlMigrationStep],
+        before: &SqlSchema,
+        after: &SqlSchema,
+        policy: &DestructiveChangePolicy,
+    ) -> DestructiveCheckPlan {
         let mut plan = DestructiveCheckPlan::new();
 
         for (step_index, step) in steps.iter().enumerate() {
@@ -150,7 +194,9 @@ impl SqlMigrationConnector {
                                     self.flavour
                                         .check_drop_and_recreate_column(&differ, changes, &mut plan, step_index)
                                 }
-                                TableChange::AddPrimaryKey { .. } => (),
+                                TableChange::AddPrimaryKey { .. }
+                                | TableChange::AddCheckConstraint { .. }
+                                | TableChange::DropCheckConstraint { .. } => (),
                             }
                         }
                     }
@@ -193,17 +239,19 @@ impl SqlMigrationConnector {
                 SqlMigrationStep::DropTable(DropTable { name }) => {
                     self.check_table_drop(name, &mut plan, step_index);
                 }
-                SqlMigrationStep::CreateIndex(CreateIndex {
-                    table,
-                    index,
-                    caused_by_create_table: false,
-                }) if index.is_unique() => plan.push_warning(
-                    SqlMigrationWarningCheck::UniqueConstraintAddition {
-                        table: table.clone(),
-                        columns: index.columns.clone(),
-                    },
-                    step_index,
-                ),
+                SqlMigrationStep::CreateIndex(create_index) if !create_index.caused_by_create_table => {
+                    if create_index.index.is_unique() {
+                        plan.push_warning(
+                            SqlMigrationWarningCheck::UniqueConstraintAddition {
+                                table: create_index.table.clone(),
+                                columns: create_index.index.columns.clone(),
+                            },
+                            step_index,
+                        );
+                    }
+
+                    self.check_create_index(create_index, policy, &mut plan, step_index);
+                }
                 SqlMigrationStep::AlterEnum(AlterEnum {
                     name,
                     created_variants: _,
@@ -222,38 +270,48 @@ impl SqlMigrationConnector {
         plan
     }
 
-    #[tracing::instrument(skip(self, steps, before), target = "SqlDestructiveChangeChecker::check")]
+    #[tracing::instrument(skip(self, steps, before, policy), target = "SqlDestructiveChangeChecker::check")]
     async fn check_impl(
         &self,
         steps: &[SqlMigrationStep],
         before: &SqlSchema,
         after: &SqlSchema,
+        policy: &DestructiveChangePolicy,
     ) -> ConnectorResult<DestructiveChangeDiagnostics> {
-        let plan = self.plan(steps, before, after);
+        let plan = self.plan(steps, before, after, policy);
 
-        plan.execute(self.conn()).await
+        plan.execute(self.conn(), policy).await
     }
 }
 
 #[async_trait::async_trait]
 impl DestructiveChangeChecker<SqlMigration> for SqlMigrationConnector {
-    async fn check(&self, database_migration: &SqlMigration) -> ConnectorResult<DestructiveChangeDiagnostics> {
-        let plan = self.plan(
+    async fn check_with_policy(
+        &self,
+        database_migration: &SqlMigration,
+        policy: &DestructiveChangePolicy,
+    ) -> ConnectorResult<DestructiveChangeDiagnostics> {
+        self.check_impl(
             &database_migration.steps,
             &database_migration.before,
             &database_migration.after,
-        );
-
-        plan.execute(self.conn()).await
+            policy,
+        )
+        .await
     }
 
-    fn pure_check(&self, database_migration: &SqlMigration) -> DestructiveChangeDiagnostics {
+    fn pure_check_with_policy(
+        &self,
+        database_migration: &SqlMigration,
+        policy: &DestructiveChangePolicy,
+    ) -> DestructiveChangeDiagnostics {
         let plan = self.plan(
             &database_migration.steps,
             &database_migration.before,
             &database_migration.after,
+            policy,
         );
 
-        plan.pure_check()
+        plan.pure_check(policy)
     }
 }
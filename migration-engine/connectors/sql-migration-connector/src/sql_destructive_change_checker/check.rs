@@ -4,6 +4,13 @@ use super::database_inspection_results::DatabaseInspectionResults;
 /// describe what data they need from the current state of the database to be as accurate and
 /// informative as possible.
 pub(super) trait Check {
+    /// The name of the check, as referenced in destructive change policies.
+    fn name(&self) -> &'static str;
+
+    /// The table the check is about, if any. Destructive change policy rules
+    /// can target specific tables.
+    fn table(&self) -> Option<&str>;
+
     /// Indicates that the row count for the table with the returned name should be inspected.
     fn needed_table_row_count(&self) -> Option<&str> {
         None
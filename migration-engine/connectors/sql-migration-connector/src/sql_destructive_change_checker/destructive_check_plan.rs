@@ -4,7 +4,8 @@ use super::{
 };
 use crate::connection_wrapper::Connection;
 use migration_connector::{
-    ConnectorError, ConnectorResult, DestructiveChangeDiagnostics, MigrationWarning, UnexecutableMigration,
+    CheckSeverity, ConnectorError, ConnectorResult, DestructiveChangeDiagnostics, DestructiveChangePolicy,
+    MigrationWarning, UnexecutableMigration,
 };
 use std::time::Duration;
 use tokio::time::{timeout, Elapsed};
@@ -40,17 +41,25 @@ impl DestructiveCheckPlan {
     /// errors.
     ///
     /// For example, dropping a table that has 0 rows can be considered safe.
-    #[tracing::instrument(skip(conn), level = "debug")]
-    pub(super) async fn execute(&self, conn: &Connection) -> ConnectorResult<DestructiveChangeDiagnostics> {
+    #[tracing::instrument(skip(conn, policy), level = "debug")]
+    pub(super) async fn execute(
+        &self,
+        conn: &Connection,
+        policy: &DestructiveChangePolicy,
+    ) -> ConnectorResult<DestructiveChangeDiagnostics> {
         let mut results = DatabaseInspectionResults::default();
 
         let inspection = async {
             for (unexecutable, _idx) in &self.unexecutable_migrations {
-                self.inspect_for_check(unexecutable, &mut results, conn).await?;
+                if !is_allowed(unexecutable, policy, CheckSeverity::Error) {
+                    self.inspect_for_check(unexecutable, &mut results, conn).await?;
+                }
             }
 
             for (warning, _idx) in &self.warnings {
-                self.inspect_for_check(warning, &mut results, conn).await?;
+                if !is_allowed(warning, policy, CheckSeverity::Warn) {
+                    self.inspect_for_check(warning, &mut results, conn).await?;
+                }
             }
 
             Ok::<(), ConnectorError>(())
@@ -62,27 +71,7 @@ impl DestructiveCheckPlan {
             Ok(Err(err)) => return Err(err),
         };
 
-        let mut diagnostics = DestructiveChangeDiagnostics::new();
-
-        for (unexecutable, step_index) in &self.unexecutable_migrations {
-            if let Some(message) = unexecutable.evaluate(&results) {
-                diagnostics.unexecutable_migrations.push(UnexecutableMigration {
-                    description: message,
-                    step_index: *step_index,
-                })
-            }
-        }
-
-        for (warning, step_index) in &self.warnings {
-            if let Some(message) = warning.evaluate(&results) {
-                diagnostics.warnings.push(MigrationWarning {
-                    description: message,
-                    step_index: *step_index,
-                })
-            }
-        }
-
-        Ok(diagnostics)
+        Ok(self.render(&results, policy))
     }
 
     /// Perform the database inspection for a given [`Check`](trait.Check.html).
@@ -114,24 +103,50 @@ impl DestructiveCheckPlan {
     /// database we cannot check directly. For example when we want to emit
     /// warnings about the production database, when creating a migration in
     /// development.
-    pub(super) fn pure_check(&self) -> DestructiveChangeDiagnostics {
-        let results = DatabaseInspectionResults::default();
+    pub(super) fn pure_check(&self, policy: &DestructiveChangePolicy) -> DestructiveChangeDiagnostics {
+        self.render(&DatabaseInspectionResults::default(), policy)
+    }
+
+    /// Render the user-facing messages for the checks, with the severity
+    /// configured by the policy. Allowed checks are skipped, and warnings with
+    /// the `error` severity are reported as unexecutable steps.
+    fn render(
+        &self,
+        results: &DatabaseInspectionResults,
+        policy: &DestructiveChangePolicy,
+    ) -> DestructiveChangeDiagnostics {
         let mut diagnostics = DestructiveChangeDiagnostics::new();
 
-        for (unexecutable, step_index) in &self.unexecutable_migrations {
-            if let Some(message) = unexecutable.evaluate(&results) {
-                diagnostics.unexecutable_migrations.push(UnexecutableMigration {
-                    description: message,
-                    step_index: *step_index,
-                })
+        let unexecutables = self
+            .unexecutable_migrations
+            .iter()
+            .map(|(check, step_index)| (check as &dyn Check, *step_index, CheckSeverity::Error));
+        let warnings = self
+            .warnings
+            .iter()
+            .map(|(check, step_index)| (check as &dyn Check, *step_index, CheckSeverity::Warn));
+
+        for (check, step_index, default_severity) in unexecutables.chain(warnings) {
+            let severity = policy.severity(check.name(), check.table(), default_severity);
+
+            if severity == CheckSeverity::Allow {
+                continue;
             }
-        }
 
-        for (warning, step_index) in &self.warnings {
-            if let Some(message) = warning.evaluate(&results) {
+            let description = match check.evaluate(results) {
+                Some(message) => message,
+                None => continue,
+            };
+
+            if severity == CheckSeverity::Error {
+                diagnostics.unexecutable_migrations.push(UnexecutableMigration {
+                    description,
+                    step_index,
+                })
+            } else {
                 diagnostics.warnings.push(MigrationWarning {
-                    description: message,
-                    step_index: *step_index,
+                    description,
+                    step_index,
                 })
             }
         }
@@ -140,7 +155,11 @@ impl DestructiveCheckPlan {
     }
 }
 
-async fn count_rows_in_table(table_name: &str, conn: &Connection) -> ConnectorResult<i64> {
+fn is_allowed(check: &dyn Check, policy: &DestructiveChangePolicy, default_severity: CheckSeverity) -> bool {
+    policy.severity(check.name(), check.table(), default_severity) == CheckSeverity::Allow
+}
+
+pub(crate) async fn count_rows_in_table(table_name: &str, conn: &Connection) -> ConnectorResult<i64> {
     use quaint::ast::*;
 
     let query = Select::from_table(conn.table_name(table_name)).value(count(asterisk()));
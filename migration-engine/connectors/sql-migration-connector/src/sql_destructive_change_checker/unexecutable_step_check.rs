@@ -9,6 +9,24 @@ pub(crate) enum UnexecutableStepCheck {
 }
 
 impl Check for UnexecutableStepCheck {
+    fn name(&self) -> &'static str {
+        match self {
+            UnexecutableStepCheck::AddedRequiredFieldToTable { .. } => "addedRequiredFieldToTable",
+            UnexecutableStepCheck::MadeOptionalFieldRequired { .. } => "madeOptionalFieldRequired",
+            UnexecutableStepCheck::MadeScalarFieldIntoArrayField { .. } => "madeScalarFieldIntoArrayField",
+            UnexecutableStepCheck::DropAndRecreateRequiredColumn { .. } => "dropAndRecreateRequiredColumn",
+        }
+    }
+
+    fn table(&self) -> Option<&str> {
+        match self {
+            UnexecutableStepCheck::AddedRequiredFieldToTable { table, .. }
+            | UnexecutableStepCheck::MadeOptionalFieldRequired { table, .. }
+            | UnexecutableStepCheck::MadeScalarFieldIntoArrayField { table, .. }
+            | UnexecutableStepCheck::DropAndRecreateRequiredColumn { table, .. } => Some(table),
+        }
+    }
+
     fn needed_table_row_count(&self) -> Option<&str> {
         match self {
             UnexecutableStepCheck::MadeOptionalFieldRequired { table, column: _ }
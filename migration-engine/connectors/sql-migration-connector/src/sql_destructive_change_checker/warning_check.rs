@@ -30,14 +30,46 @@ pub(super) enum SqlMigrationWarningCheck {
         enm: String,
         values: Vec<String>,
     },
+    NonConcurrentIndexOnLargeTable {
+        table: String,
+        index: String,
+        row_count_threshold: i64,
+    },
 }
 
 impl Check for SqlMigrationWarningCheck {
+    fn name(&self) -> &'static str {
+        match self {
+            SqlMigrationWarningCheck::DropAndRecreateColumn { .. } => "dropAndRecreateColumn",
+            SqlMigrationWarningCheck::NonEmptyColumnDrop { .. } => "nonEmptyColumnDrop",
+            SqlMigrationWarningCheck::NonEmptyTableDrop { .. } => "nonEmptyTableDrop",
+            SqlMigrationWarningCheck::RiskyCast { .. } => "riskyCast",
+            SqlMigrationWarningCheck::PrimaryKeyChange { .. } => "primaryKeyChange",
+            SqlMigrationWarningCheck::UniqueConstraintAddition { .. } => "uniqueConstraintAddition",
+            SqlMigrationWarningCheck::EnumValueRemoval { .. } => "enumValueRemoval",
+            SqlMigrationWarningCheck::NonConcurrentIndexOnLargeTable { .. } => "nonConcurrentIndexOnLargeTable",
+        }
+    }
+
+    fn table(&self) -> Option<&str> {
+        match self {
+            SqlMigrationWarningCheck::DropAndRecreateColumn { table, .. }
+            | SqlMigrationWarningCheck::NonEmptyColumnDrop { table, .. }
+            | SqlMigrationWarningCheck::NonEmptyTableDrop { table }
+            | SqlMigrationWarningCheck::RiskyCast { table, .. }
+            | SqlMigrationWarningCheck::PrimaryKeyChange { table }
+            | SqlMigrationWarningCheck::UniqueConstraintAddition { table, .. }
+            | SqlMigrationWarningCheck::NonConcurrentIndexOnLargeTable { table, .. } => Some(table),
+            SqlMigrationWarningCheck::EnumValueRemoval { .. } => None,
+        }
+    }
+
     fn needed_table_row_count(&self) -> Option<&str> {
         match self {
             SqlMigrationWarningCheck::NonEmptyTableDrop { table }
             | SqlMigrationWarningCheck::PrimaryKeyChange { table }
-            | SqlMigrationWarningCheck::DropAndRecreateColumn { table, column: _ } => Some(table),
+            | SqlMigrationWarningCheck::DropAndRecreateColumn { table, column: _ }
+            | SqlMigrationWarningCheck::NonConcurrentIndexOnLargeTable { table, .. } => Some(table),
             SqlMigrationWarningCheck::NonEmptyColumnDrop { .. } | SqlMigrationWarningCheck::RiskyCast { .. } => None,
             _ => None,
         }
@@ -91,6 +123,10 @@ impl Check for SqlMigrationWarningCheck {
             },
             SqlMigrationWarningCheck::UniqueConstraintAddition { table, columns } =>  Some(format!("The migration will add a unique constraint covering the columns `{columns}` on the table `{table}`. If there are existing duplicate values, the migration will fail.", table = table, columns = format!("[{}]",columns.join(",")))),
             SqlMigrationWarningCheck::EnumValueRemoval { enm, values } =>  Some(format!("The migration will remove the values {values} on the enum `{enm}`. If these variants are still used in the database, the migration will fail.", enm = enm, values = format!("[{}]",values.join(",")))),
+            SqlMigrationWarningCheck::NonConcurrentIndexOnLargeTable { table, index, row_count_threshold } => match database_check_results.get_row_count(table) {
+                Some(row_count) if row_count >= *row_count_threshold => Some(format!("The migration will create the index `{index}` on the `{table}` table, which contains {row_count} rows. Writes to the table will be blocked while the index is built.", index = index, table = table, row_count = row_count)),
+                _ => None,
+            },
 
         }
     }
@@ -3,6 +3,7 @@ use migration_connector::{
     ConnectorError, ConnectorResult, ImperativeMigrationsPersistence, MigrationRecord, PersistenceNotInitializedError,
 };
 use quaint::{ast::*, error::ErrorKind as QuaintKind};
+use sql_schema_describer::SqlSchema;
 use uuid::Uuid;
 
 const IMPERATIVE_MIGRATIONS_TABLE_NAME: &str = "_prisma_migrations";
@@ -32,7 +33,7 @@ impl ImperativeMigrationsPersistence for SqlMigrationConnector {
             .iter()
             .any(|table| table.name == IMPERATIVE_MIGRATIONS_TABLE_NAME)
         {
-            return Ok(());
+            return self.upgrade_imperative_migrations_table(&schema).await;
         }
 
         if !schema.is_empty() {
@@ -48,6 +49,18 @@ impl ImperativeMigrationsPersistence for SqlMigrationConnector {
         Ok(())
     }
 
+    async fn initialize_on_existing_schema(&self) -> ConnectorResult<()> {
+        let schema = self.describe_schema().await?;
+
+        if schema.has_table(IMPERATIVE_MIGRATIONS_TABLE_NAME) {
+            return self.upgrade_imperative_migrations_table(&schema).await;
+        }
+
+        self.flavour.create_imperative_migrations_table(&self.conn()).await?;
+
+        Ok(())
+    }
+
     async fn record_migration_started_impl(
         &self,
         migration_name: &str,
@@ -86,9 +99,10 @@ impl ImperativeMigrationsPersistence for SqlMigrationConnector {
         Ok(())
     }
 
-    async fn record_failed_step(&self, id: &str, logs: &str) -> ConnectorResult<()> {
+    async fn record_failed_step(&self, id: &str, step_name: &str, logs: &str) -> ConnectorResult<()> {
         let update = Update::table((self.schema_name(), IMPERATIVE_MIGRATIONS_TABLE_NAME))
             .so_that(Column::from("id").equals(id))
+            .set("failed_step_name", step_name)
             .set("logs", logs);
 
         self.conn().execute(update).await?;
@@ -96,6 +110,16 @@ impl ImperativeMigrationsPersistence for SqlMigrationConnector {
         Ok(())
     }
 
+    async fn record_migration_resumed(&self, id: &str, checksum: &str) -> ConnectorResult<()> {
+        let update = Update::table((self.schema_name(), IMPERATIVE_MIGRATIONS_TABLE_NAME))
+            .so_that(Column::from("id").equals(id))
+            .set("checksum", checksum);
+
+        self.conn().execute(update).await?;
+
+        Ok(())
+    }
+
     async fn record_migration_finished(&self, id: &str) -> ConnectorResult<()> {
         let update = Update::table((self.schema_name(), IMPERATIVE_MIGRATIONS_TABLE_NAME))
             .so_that(Column::from("id").equals(id))
@@ -106,6 +130,16 @@ impl ImperativeMigrationsPersistence for SqlMigrationConnector {
         Ok(())
     }
 
+    async fn record_migration_rolled_back(&self, id: &str) -> ConnectorResult<()> {
+        let update = Update::table((self.schema_name(), IMPERATIVE_MIGRATIONS_TABLE_NAME))
+            .so_that(Column::from("id").equals(id))
+            .set("rolled_back_at", chrono::Utc::now());
+
+        self.conn().execute(update).await?;
+
+        Ok(())
+    }
+
     #[tracing::instrument(skip(self))]
     async fn list_migrations(&self) -> ConnectorResult<Result<Vec<MigrationRecord>, PersistenceNotInitializedError>> {
         let select = Select::from_table((self.schema_name(), IMPERATIVE_MIGRATIONS_TABLE_NAME))
@@ -117,6 +151,7 @@ impl ImperativeMigrationsPersistence for SqlMigrationConnector {
             .column("rolled_back_at")
             .column("started_at")
             .column("applied_steps_count")
+            .column("failed_step_name")
             .column("script")
             .order_by("started_at".ascend());
 
@@ -136,3 +171,19 @@ impl ImperativeMigrationsPersistence for SqlMigrationConnector {
         Ok(Ok(rows))
     }
 }
+
+impl SqlMigrationConnector {
+    /// Add the columns missing from a `_prisma_migrations` table created by a
+    /// previous version of the migration engine, so it can be read and
+    /// written like a new one.
+    pub(crate) async fn upgrade_imperative_migrations_table(&self, schema: &SqlSchema) -> ConnectorResult<()> {
+        match schema.get_table(IMPERATIVE_MIGRATIONS_TABLE_NAME) {
+            Some(table) if !table.has_column("failed_step_name") => {
+                tracing::debug!("Adding the `failed_step_name` column to the migrations table.");
+
+                self.flavour.add_failed_step_name_column(self.conn()).await
+            }
+            _ => Ok(()),
+        }
+    }
+}
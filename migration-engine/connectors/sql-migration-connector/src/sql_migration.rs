@@ -2,12 +2,14 @@ pub(crate) mod expanded_alter_column;
 
 use migration_connector::DatabaseMigrationMarker;
 use serde::{Deserialize, Serialize};
-use sql_schema_describer::{Column, ForeignKey, Index, SqlSchema, Table};
+use sql_schema_describer::{CheckConstraint, Column, ForeignKey, Index, SqlSchema, Table};
 
 use crate::sql_schema_differ::ColumnChanges;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SqlMigration {
+    /// The schema the steps apply to. When tables or columns are renamed, it
+    /// already contains the new names.
     pub before: SqlSchema,
     pub after: SqlSchema,
     pub steps: Vec<SqlMigrationStep>,
@@ -42,8 +44,18 @@ pub enum SqlMigrationStep {
     AlterTable(AlterTable),
     DropForeignKey(DropForeignKey),
     DropTable(DropTable),
-    RenameTable { name: String, new_name: String },
-    RedefineTables { names: Vec<String> },
+    RenameTable {
+        name: String,
+        new_name: String,
+    },
+    RenameColumn {
+        table: String,
+        name: String,
+        new_name: String,
+    },
+    RedefineTables {
+        names: Vec<String>,
+    },
     CreateIndex(CreateIndex),
     DropIndex(DropIndex),
     AlterIndex(AlterIndex),
@@ -61,6 +73,7 @@ impl SqlMigrationStep {
             SqlMigrationStep::DropForeignKey(_) => "DropForeignKey",
             SqlMigrationStep::DropTable(_) => "DropTable",
             SqlMigrationStep::RenameTable { .. } => "RenameTable",
+            SqlMigrationStep::RenameColumn { .. } => "RenameColumn",
             SqlMigrationStep::RedefineTables { .. } => "RedefineTables",
             SqlMigrationStep::CreateIndex(_) => "CreateIndex",
             SqlMigrationStep::DropIndex(_) => "DropIndex",
@@ -108,6 +121,12 @@ pub enum TableChange {
     AddPrimaryKey {
         columns: Vec<String>,
     },
+    AddCheckConstraint {
+        check: CheckConstraint,
+    },
+    DropCheckConstraint {
+        constraint_name: String,
+    },
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
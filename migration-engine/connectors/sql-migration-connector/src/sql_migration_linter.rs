@@ -0,0 +1,318 @@
+//! The SQL implementation of MigrationLinter. It reads the statements of the
+//! migration script, and reports the patterns that lock or rewrite tables, or
+//! that are likely mistakes. The schemas before and after the migration tell
+//! what the statements apply to.
+
+mod statement;
+
+use crate::{
+    sql_database_step_applier::split_script, sql_destructive_change_checker::count_rows_in_table,
+    sql_schema_calculator::SqlSchemaCalculator, SqlMigration, SqlMigrationConnector,
+};
+use migration_connector::{ConnectorResult, LintContext, LintDiagnostic, MigrationLinter};
+use quaint::prelude::SqlFamily;
+use sql_schema_describer::{
+    walkers::{find_column, SqlSchemaExt},
+    ColumnType, SqlSchema,
+};
+use statement::{parse_script, AlterTableAction, DataType, ForeignKey, Statement};
+
+#[async_trait::async_trait]
+impl MigrationLinter<SqlMigration> for SqlMigrationConnector {
+    async fn lint(
+        &self,
+        database_migration: &SqlMigration,
+        script: &str,
+        context: &LintContext<'_>,
+    ) -> ConnectorResult<Vec<LintDiagnostic>> {
+        let mut diagnostics = Vec::new();
+        let previous_schema = &database_migration.before;
+        let next_schema = &database_migration.after;
+
+        let target_schema = context
+            .target_schema
+            .map(|datamodel| SqlSchemaCalculator::calculate(datamodel, self.database_info(), self.flavour()));
+
+        // The row counts can only be queried for the tables that already exist.
+        let live_schema = match context.large_table_row_count {
+            Some(_) => Some(self.describe_schema().await?),
+            None => None,
+        };
+
+        let mut dropped_tables: Vec<&str> = Vec::new();
+
+        for statement in parse_script(script).iter() {
+            match &statement.kind {
+                Statement::Index {
+                    name,
+                    concurrently: true,
+                } if statement.transactional => {
+                    diagnostics.push(LintDiagnostic::new(
+                        "unmarkedNonTransactionalStatement",
+                        format!(
+                            "The `{}` index is created or dropped concurrently, which cannot run in a transaction, but the statement is not marked as non-transactional.",
+                            name
+                        ),
+                    ));
+                }
+                Statement::DropTable { table } => dropped_tables.push(table),
+                // A table is redefined by creating a new table, copying the
+                // rows, dropping the table and renaming the new one.
+                Statement::RenameTable { new_name }
+                    if dropped_tables.contains(&new_name.as_str()) && previous_schema.has_table(new_name) =>
+                {
+                    diagnostics.push(LintDiagnostic::new(
+                        "tableRewrite",
+                        format!(
+                            "The `{}` table is redefined: its rows are copied to a new table while writes are blocked.",
+                            new_name
+                        ),
+                    ));
+
+                    if let (Some(target_schema), Some(previous_table), Some(next_table)) = (
+                        &target_schema,
+                        previous_schema.table_walker(new_name),
+                        next_schema.table_walker(new_name),
+                    ) {
+                        for column in previous_table.columns() {
+                            if next_table.column(column.name()).is_none() {
+                                lint_drop_column(new_name, column.name(), target_schema, &mut diagnostics);
+                            }
+                        }
+                    }
+                }
+                Statement::CreateTable { table, foreign_keys } if self.sql_family() != SqlFamily::Mysql => {
+                    for foreign_key in foreign_keys {
+                        lint_foreign_key(table, foreign_key, next_schema, &mut diagnostics);
+                    }
+                }
+                Statement::AlterTable { table, actions } => {
+                    for action in actions {
+                        match action {
+                            AlterTableAction::AddColumn {
+                                column,
+                                has_volatile_default: true,
+                                ..
+                            } => {
+                                if let (Some(row_count_threshold), Some(live_schema)) =
+                                    (context.large_table_row_count, &live_schema)
+                                {
+                                    self.lint_add_column(
+                                        table,
+                                        column,
+                                        row_count_threshold,
+                                        live_schema,
+                                        &mut diagnostics,
+                                    )
+                                    .await?;
+                                }
+                            }
+                            AlterTableAction::AddForeignKey(foreign_key) if self.sql_family() != SqlFamily::Mysql => {
+                                lint_foreign_key(table, foreign_key, next_schema, &mut diagnostics);
+                            }
+                            AlterTableAction::DropColumn { column } => {
+                                if let Some(target_schema) = &target_schema {
+                                    lint_drop_column(table, column, target_schema, &mut diagnostics);
+                                }
+                            }
+                            AlterTableAction::ChangeColumnType { column, data_type } => {
+                                let previous_column = match find_column(previous_schema, table, column) {
+                                    Some(previous_column) => previous_column,
+                                    None => continue,
+                                };
+
+                                if self.type_change_rewrites_table(previous_column.column_type(), data_type) {
+                                    diagnostics.push(LintDiagnostic::new(
+                                        "tableRewrite",
+                                        format!(
+                                            "The type of the `{}` column on the `{}` table is changed: the table is rewritten while writes are blocked.",
+                                            column, table
+                                        ),
+                                    ));
+                                }
+                            }
+                            _ => (),
+                        }
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        lint_transactionality(script, &mut diagnostics);
+
+        Ok(diagnostics)
+    }
+}
+
+impl SqlMigrationConnector {
+    /// Adding a column with a value computed for each row rewrites the table,
+    /// which takes long on large tables.
+    async fn lint_add_column(
+        &self,
+        table_name: &str,
+        column_name: &str,
+        row_count_threshold: i64,
+        live_schema: &SqlSchema,
+        diagnostics: &mut Vec<LintDiagnostic>,
+    ) -> ConnectorResult<()> {
+        if !live_schema.has_table(table_name) {
+            return Ok(());
+        }
+
+        let row_count = count_rows_in_table(table_name, self.conn()).await?;
+
+        if row_count >= row_count_threshold {
+            diagnostics.push(LintDiagnostic::new(
+                "volatileDefaultOnLargeTable",
+                format!(
+                    "The `{}` column is added to the `{}` table with a default computed for each row, and the table contains {} rows. Every row is rewritten while writes are blocked.",
+                    column_name, table_name, row_count
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Whether changing the type of a column rewrites its table. Only the
+    /// changes the databases make without touching the rows are recognized,
+    /// any other change is assumed to rewrite the table:
+    ///
+    /// - Keeping the same type, for example to change the nullability.
+    /// - On Postgres, changing between `varchar` and `text` without making the
+    ///   column shorter.
+    /// - Making a `varchar` column longer, on MySQL only while its length
+    ///   stays on the same side of 64 characters, since the length prefix of
+    ///   the values grows from one to two bytes at 256 bytes.
+    fn type_change_rewrites_table(&self, previous: &ColumnType, next: &DataType) -> bool {
+        let previous_name = canonical_type_name(match self.sql_family() {
+            SqlFamily::Postgres => &previous.full_data_type,
+            _ => &previous.data_type,
+        });
+        let next_name = canonical_type_name(&next.name);
+        let previous_length = previous.character_maximum_length;
+        let next_length = next.arguments.first().map(|length| length.parse::<u32>().ok());
+
+        match (self.sql_family(), previous_name.as_str(), next_name.as_str()) {
+            (SqlFamily::Postgres, "varchar", "text") => false,
+            (SqlFamily::Postgres, "text", "varchar") => next_length.is_some(),
+            (SqlFamily::Postgres, "varchar", "varchar") => match (previous_length, next_length) {
+                (_, None) => false,
+                (Some(previous_length), Some(Some(next_length))) => next_length < previous_length,
+                _ => true,
+            },
+            (SqlFamily::Mysql, "varchar", "varchar") => match (previous_length, next_length) {
+                (Some(previous_length), Some(Some(next_length))) => {
+                    next_length < previous_length || (previous_length < 64) != (next_length < 64)
+                }
+                _ => true,
+            },
+            (SqlFamily::Mssql, "varchar", "varchar") | (SqlFamily::Mssql, "nvarchar", "nvarchar") => {
+                match (previous_length, next_length) {
+                    // `max`
+                    (_, Some(None)) => false,
+                    (Some(previous_length), Some(Some(next_length))) => next_length < previous_length,
+                    _ => true,
+                }
+            }
+            (_, previous_name, next_name) => previous_name != next_name,
+        }
+    }
+}
+
+/// The name of a type, with the aliases of the common types resolved.
+fn canonical_type_name(name: &str) -> String {
+    let name = name.to_lowercase();
+
+    let canonical_name = match name.as_str() {
+        "int" | "integer" | "int4" => "int4",
+        "bigint" | "int8" => "int8",
+        "smallint" | "int2" => "int2",
+        "character varying" | "varchar" => "varchar",
+        "character" | "char" | "bpchar" => "bpchar",
+        "boolean" | "bool" => "bool",
+        "double precision" | "float8" => "float8",
+        "real" | "float4" => "float4",
+        "decimal" | "numeric" => "numeric",
+        "timestamp" | "timestamp without time zone" => "timestamp",
+        "timestamptz" | "timestamp with time zone" => "timestamptz",
+        _ => return name,
+    };
+
+    canonical_name.to_owned()
+}
+
+/// Dropping a column that is still in the Prisma schema usually means the
+/// migration is out of date with the schema.
+fn lint_drop_column(
+    table_name: &str,
+    column_name: &str,
+    target_schema: &SqlSchema,
+    diagnostics: &mut Vec<LintDiagnostic>,
+) {
+    if find_column(target_schema, table_name, column_name).is_some() {
+        diagnostics.push(LintDiagnostic::new(
+            "droppedColumnStillInSchema",
+            format!(
+                "The `{}` column is dropped from the `{}` table, but it is still in the Prisma schema.",
+                column_name, table_name
+            ),
+        ));
+    }
+}
+
+/// Foreign keys without an index on their columns make every update and
+/// delete on the referenced table scan the referencing table. MySQL creates
+/// these indexes automatically, so it is not linted. The indexes are looked up
+/// after the migration, since they can be created after the foreign key.
+fn lint_foreign_key(
+    table_name: &str,
+    foreign_key: &ForeignKey,
+    next_schema: &SqlSchema,
+    diagnostics: &mut Vec<LintDiagnostic>,
+) {
+    let table = match next_schema.table_walker(table_name) {
+        Some(table) => table,
+        None => return,
+    };
+
+    let foreign_key_columns = &foreign_key.columns;
+    let covers_foreign_key = |columns: &Vec<String>| columns.starts_with(foreign_key_columns);
+
+    let is_indexed = table.indexes().any(|index| covers_foreign_key(&index.index().columns))
+        || table
+            .primary_key()
+            .map(|primary_key| covers_foreign_key(&primary_key.columns))
+            .unwrap_or(false);
+
+    if !is_indexed {
+        diagnostics.push(LintDiagnostic::new(
+            "missingIndexOnForeignKey",
+            format!(
+                "The foreign key on the `{}` table on ({}) has no index on its columns. Updates and deletes on the `{}` table will scan the `{}` table.",
+                table_name,
+                foreign_key_columns
+                    .iter()
+                    .map(|column| format!("`{}`", column))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                foreign_key.referenced_table,
+                table_name
+            ),
+        ));
+    }
+}
+
+/// A script mixing non-transactional statements with other statements cannot
+/// be rolled back as a whole when it fails midway.
+fn lint_transactionality(script: &str, diagnostics: &mut Vec<LintDiagnostic>) {
+    let batches = split_script(script);
+
+    if batches.len() > 1 && batches.iter().any(|(_, transactional)| !transactional) {
+        diagnostics.push(LintDiagnostic::new(
+            "mixedTransactionality",
+            "The migration mixes statements that cannot run in a transaction with other statements. If it fails midway, the database is left partially migrated and has to be fixed by hand.".to_owned(),
+        ));
+    }
+}
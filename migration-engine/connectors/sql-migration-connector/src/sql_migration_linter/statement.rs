@@ -0,0 +1,918 @@
+//! A reader for the statements of migration scripts. It only understands the
+//! statements and clauses the linter looks at, and it is lenient: whatever it
+//! does not recognize is read as `Statement::Other` or
+//! `AlterTableAction::Other`.
+
+use crate::sql_database_step_applier::split_script;
+
+/// A statement of a migration script.
+#[derive(Debug, PartialEq)]
+pub(super) struct ScriptStatement {
+    /// Whether the statement runs in a transaction, i.e. it is not marked as
+    /// non-transactional.
+    pub transactional: bool,
+    pub kind: Statement,
+}
+
+#[derive(Debug, PartialEq)]
+pub(super) enum Statement {
+    CreateTable {
+        table: String,
+        foreign_keys: Vec<ForeignKey>,
+    },
+    DropTable {
+        table: String,
+    },
+    /// `CREATE INDEX` or `DROP INDEX`.
+    Index {
+        name: String,
+        concurrently: bool,
+    },
+    AlterTable {
+        table: String,
+        actions: Vec<AlterTableAction>,
+    },
+    /// `ALTER TABLE ... RENAME TO ...`.
+    RenameTable {
+        new_name: String,
+    },
+    Other,
+}
+
+#[derive(Debug, PartialEq)]
+pub(super) enum AlterTableAction {
+    AddColumn {
+        column: String,
+        /// Whether the value of the column is computed for each row, by a
+        /// volatile default, a sequence or a stored generated column.
+        has_volatile_default: bool,
+    },
+    AddForeignKey(ForeignKey),
+    DropColumn {
+        column: String,
+    },
+    /// `ALTER COLUMN ... TYPE`, or `MODIFY` and `CHANGE` on MySQL.
+    ChangeColumnType {
+        column: String,
+        data_type: DataType,
+    },
+    Other,
+}
+
+#[derive(Debug, PartialEq)]
+pub(super) struct ForeignKey {
+    pub columns: Vec<String>,
+    pub referenced_table: String,
+}
+
+/// A data type as written in the script, for example `varchar` with the
+/// argument `255`. Keyword type names are lowercased.
+#[derive(Debug, PartialEq)]
+pub(super) struct DataType {
+    pub name: String,
+    pub arguments: Vec<String>,
+}
+
+/// The functions returning a different value on each call. A column added
+/// with one of them as default has its value computed for each row.
+const VOLATILE_FUNCTIONS: &[&str] = &[
+    "clock_timestamp",
+    "gen_random_uuid",
+    "newid",
+    "newsequentialid",
+    "nextval",
+    "rand",
+    "random",
+    "timeofday",
+    "uuid",
+    "uuid_generate_v1",
+    "uuid_generate_v1mc",
+    "uuid_generate_v4",
+];
+
+/// The Postgres pseudo-types creating a sequence.
+const SERIAL_TYPES: &[&str] = &["bigserial", "serial", "serial2", "serial4", "serial8", "smallserial"];
+
+/// The words that can follow the first word of a data type.
+const TYPE_NAME_WORDS: &[&str] = &[
+    "precision",
+    "time",
+    "unsigned",
+    "varying",
+    "with",
+    "without",
+    "zerofill",
+    "zone",
+];
+
+/// Read the statements of a migration script.
+pub(super) fn parse_script(script: &str) -> Vec<ScriptStatement> {
+    let mut statements = Vec::new();
+
+    for (batch, transactional) in split_script(script) {
+        let tokens = tokenize(&batch);
+
+        for statement_tokens in tokens.split(|token| *token == Token::Punct(';')) {
+            if statement_tokens.is_empty() {
+                continue;
+            }
+
+            let mut parser = Parser {
+                tokens: statement_tokens,
+                position: 0,
+            };
+
+            statements.push(ScriptStatement {
+                transactional,
+                kind: parser.statement().unwrap_or(Statement::Other),
+            });
+        }
+    }
+
+    statements
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// A keyword or an unquoted identifier.
+    Word(String),
+    /// A quoted identifier.
+    Identifier(String),
+    /// A string literal or a dollar-quoted string.
+    Str(String),
+    Number(String),
+    Punct(char),
+}
+
+fn tokenize(sql: &str) -> Vec<Token> {
+    let chars: Vec<char> = sql.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+
+        if c.is_whitespace() {
+            i += 1;
+        } else if c == '-' && next == Some('-') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if c == '/' && next == Some('*') {
+            i += 2;
+
+            while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                i += 1;
+            }
+
+            i += 2;
+        } else if c == '\'' {
+            let (value, end) = read_quoted(&chars, i, '\'');
+            tokens.push(Token::Str(value));
+            i = end;
+        } else if c == '"' || c == '`' {
+            let (value, end) = read_quoted(&chars, i, c);
+            tokens.push(Token::Identifier(value));
+            i = end;
+        } else if c == '[' && next != Some(']') {
+            let (value, end) = read_quoted(&chars, i, ']');
+            tokens.push(Token::Identifier(value));
+            i = end;
+        } else if let Some(tag) = dollar_quote_tag(&chars, i) {
+            let body_start = i + tag.len();
+            let mut end = body_start;
+
+            while end < chars.len() && !chars[end..].starts_with(&tag) {
+                end += 1;
+            }
+
+            tokens.push(Token::Str(chars[body_start..end].iter().collect()));
+            i = (end + tag.len()).min(chars.len());
+        } else if c.is_ascii_digit() {
+            let start = i;
+
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                i += 1;
+            }
+
+            tokens.push(Token::Number(chars[start..i].iter().collect()));
+        } else if c.is_alphanumeric() || c == '_' {
+            let start = i;
+
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_' || chars[i] == '$') {
+                i += 1;
+            }
+
+            tokens.push(Token::Word(chars[start..i].iter().collect()));
+        } else {
+            tokens.push(Token::Punct(c));
+            i += 1;
+        }
+    }
+
+    tokens
+}
+
+/// Read a quoted string or identifier starting at `start`, up to the closing
+/// quote. A doubled closing quote is an escaped quote.
+fn read_quoted(chars: &[char], start: usize, closing_quote: char) -> (String, usize) {
+    let mut value = String::new();
+    let mut i = start + 1;
+
+    while i < chars.len() {
+        if chars[i] == closing_quote {
+            if chars.get(i + 1) == Some(&closing_quote) {
+                value.push(closing_quote);
+                i += 2;
+                continue;
+            }
+
+            return (value, i + 1);
+        }
+
+        value.push(chars[i]);
+        i += 1;
+    }
+
+    (value, i)
+}
+
+/// The `$tag$` opening a dollar-quoted string at `start`, if there is one.
+fn dollar_quote_tag(chars: &[char], start: usize) -> Option<Vec<char>> {
+    if chars[start] != '$' {
+        return None;
+    }
+
+    let mut end = start + 1;
+
+    while end < chars.len() && (chars[end].is_alphanumeric() || chars[end] == '_') {
+        end += 1;
+    }
+
+    match chars.get(end) {
+        Some('$') if !chars[start + 1..end].first().map(char::is_ascii_digit).unwrap_or(false) => {
+            Some(chars[start..=end].to_vec())
+        }
+        _ => None,
+    }
+}
+
+struct Parser<'a> {
+    tokens: &'a [Token],
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&'a Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<&'a Token> {
+        let token = self.tokens.get(self.position)?;
+        self.position += 1;
+
+        Some(token)
+    }
+
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Word(word)) if word.eq_ignore_ascii_case(keyword))
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let is_keyword = self.peek_keyword(keyword);
+
+        if is_keyword {
+            self.position += 1;
+        }
+
+        is_keyword
+    }
+
+    /// Consume the keywords if they are all next, in order.
+    fn eat_keywords(&mut self, keywords: &[&str]) -> bool {
+        let start = self.position;
+
+        if keywords.iter().all(|keyword| self.eat_keyword(keyword)) {
+            return true;
+        }
+
+        self.position = start;
+
+        false
+    }
+
+    fn eat_punct(&mut self, punct: char) -> bool {
+        let is_punct = self.peek() == Some(&Token::Punct(punct));
+
+        if is_punct {
+            self.position += 1;
+        }
+
+        is_punct
+    }
+
+    fn identifier(&mut self) -> Option<String> {
+        match self.next()? {
+            Token::Word(name) | Token::Identifier(name) => Some(name.clone()),
+            _ => None,
+        }
+    }
+
+    /// A name, possibly qualified with a schema. Only the last part is kept.
+    fn name(&mut self) -> Option<String> {
+        let mut name = self.identifier()?;
+
+        while self.eat_punct('.') {
+            name = self.identifier()?;
+        }
+
+        Some(name)
+    }
+
+    /// A parenthesized list of names.
+    fn name_list(&mut self) -> Option<Vec<String>> {
+        if !self.eat_punct('(') {
+            return None;
+        }
+
+        let mut names = Vec::new();
+
+        loop {
+            names.push(self.name()?);
+
+            if self.eat_punct(')') {
+                return Some(names);
+            }
+
+            if !self.eat_punct(',') {
+                return None;
+            }
+        }
+    }
+
+    /// Skip to the next comma or closing parenthesis outside of parentheses,
+    /// without consuming it, and return the skipped tokens.
+    fn skip_to_delimiter(&mut self) -> &'a [Token] {
+        let start = self.position;
+        let mut depth = 0usize;
+
+        while let Some(token) = self.peek() {
+            match token {
+                Token::Punct('(') => depth += 1,
+                Token::Punct(')') | Token::Punct(',') if depth == 0 => break,
+                Token::Punct(')') => depth -= 1,
+                _ => (),
+            }
+
+            self.position += 1;
+        }
+
+        &self.tokens[start..self.position]
+    }
+
+    fn statement(&mut self) -> Option<Statement> {
+        if self.eat_keyword("CREATE") {
+            self.eat_keyword("UNIQUE");
+            self.eat_keyword("CLUSTERED");
+            self.eat_keyword("NONCLUSTERED");
+
+            if self.eat_keyword("INDEX") {
+                let concurrently = self.eat_keyword("CONCURRENTLY");
+                self.eat_keywords(&["IF", "NOT", "EXISTS"]);
+
+                return Some(Statement::Index {
+                    name: self.name()?,
+                    concurrently,
+                });
+            }
+
+            if self.eat_keyword("TABLE") {
+                self.eat_keywords(&["IF", "NOT", "EXISTS"]);
+
+                let table = self.name()?;
+                let foreign_keys = self.table_foreign_keys()?;
+
+                return Some(Statement::CreateTable { table, foreign_keys });
+            }
+
+            return None;
+        }
+
+        if self.eat_keyword("DROP") {
+            if self.eat_keyword("INDEX") {
+                let concurrently = self.eat_keyword("CONCURRENTLY");
+                self.eat_keywords(&["IF", "EXISTS"]);
+
+                return Some(Statement::Index {
+                    name: self.name()?,
+                    concurrently,
+                });
+            }
+
+            if self.eat_keyword("TABLE") {
+                self.eat_keywords(&["IF", "EXISTS"]);
+
+                return Some(Statement::DropTable { table: self.name()? });
+            }
+
+            return None;
+        }
+
+        if self.eat_keywords(&["ALTER", "TABLE"]) {
+            self.eat_keywords(&["IF", "EXISTS"]);
+            self.eat_keyword("ONLY");
+
+            let table = self.name()?;
+
+            if self.eat_keywords(&["RENAME", "TO"]) {
+                return Some(Statement::RenameTable { new_name: self.name()? });
+            }
+
+            let mut actions = Vec::new();
+
+            loop {
+                let start = self.position;
+                let action = self.alter_table_action();
+
+                if action.is_none() {
+                    self.position = start;
+                }
+
+                self.skip_to_delimiter();
+                actions.push(action.unwrap_or(AlterTableAction::Other));
+
+                if !self.eat_punct(',') {
+                    break;
+                }
+            }
+
+            return Some(Statement::AlterTable { table, actions });
+        }
+
+        None
+    }
+
+    /// The foreign keys among the elements of a `CREATE TABLE` statement.
+    fn table_foreign_keys(&mut self) -> Option<Vec<ForeignKey>> {
+        if !self.eat_punct('(') {
+            return None;
+        }
+
+        let mut foreign_keys = Vec::new();
+
+        loop {
+            if self.eat_keyword("CONSTRAINT") {
+                self.name()?;
+            }
+
+            if self.eat_keywords(&["FOREIGN", "KEY"]) {
+                foreign_keys.push(self.foreign_key()?);
+            }
+
+            self.skip_to_delimiter();
+
+            if !self.eat_punct(',') {
+                return Some(foreign_keys);
+            }
+        }
+    }
+
+    /// The columns and the referenced table of a foreign key, after `FOREIGN KEY`.
+    fn foreign_key(&mut self) -> Option<ForeignKey> {
+        let columns = self.name_list()?;
+
+        if !self.eat_keyword("REFERENCES") {
+            return None;
+        }
+
+        Some(ForeignKey {
+            columns,
+            referenced_table: self.name()?,
+        })
+    }
+
+    fn alter_table_action(&mut self) -> Option<AlterTableAction> {
+        if self.eat_keyword("ADD") {
+            if self.eat_keyword("CONSTRAINT") {
+                self.name()?;
+            }
+
+            if self.eat_keywords(&["FOREIGN", "KEY"]) {
+                return Some(AlterTableAction::AddForeignKey(self.foreign_key()?));
+            }
+
+            let adds_constraint = [
+                "CHECK",
+                "CONSTRAINT",
+                "FULLTEXT",
+                "INDEX",
+                "KEY",
+                "PRIMARY",
+                "SPATIAL",
+                "UNIQUE",
+            ]
+            .iter()
+            .any(|keyword| self.peek_keyword(keyword));
+
+            if adds_constraint {
+                return None;
+            }
+
+            self.eat_keyword("COLUMN");
+            self.eat_keywords(&["IF", "NOT", "EXISTS"]);
+
+            let column = self.name()?;
+            let data_type = self.data_type()?;
+            let constraints = self.skip_to_delimiter();
+            let has_volatile_default = has_volatile_default(&data_type, constraints);
+
+            return Some(AlterTableAction::AddColumn {
+                column,
+                has_volatile_default,
+            });
+        }
+
+        if self.eat_keyword("DROP") {
+            if !self.eat_keyword("COLUMN") {
+                let drops_constraint = ["CHECK", "CONSTRAINT", "DEFAULT", "FOREIGN", "INDEX", "KEY", "PRIMARY"]
+                    .iter()
+                    .any(|keyword| self.peek_keyword(keyword));
+
+                if drops_constraint {
+                    return None;
+                }
+            }
+
+            self.eat_keywords(&["IF", "EXISTS"]);
+
+            return Some(AlterTableAction::DropColumn { column: self.name()? });
+        }
+
+        if self.eat_keyword("ALTER") {
+            self.eat_keyword("COLUMN");
+
+            let column = self.name()?;
+
+            if self.eat_keywords(&["SET", "DATA", "TYPE"]) || self.eat_keyword("TYPE") {
+                return Some(AlterTableAction::ChangeColumnType {
+                    column,
+                    data_type: self.data_type()?,
+                });
+            }
+
+            // On SQL Server, the new definition follows the column name.
+            let changes_definition = !["ADD", "DROP", "RESTART", "SET"]
+                .iter()
+                .any(|keyword| self.peek_keyword(keyword));
+
+            if changes_definition {
+                return Some(AlterTableAction::ChangeColumnType {
+                    column,
+                    data_type: self.data_type()?,
+                });
+            }
+
+            return None;
+        }
+
+        if self.eat_keyword("MODIFY") {
+            self.eat_keyword("COLUMN");
+
+            return Some(AlterTableAction::ChangeColumnType {
+                column: self.name()?,
+                data_type: self.data_type()?,
+            });
+        }
+
+        if self.eat_keyword("CHANGE") {
+            self.eat_keyword("COLUMN");
+
+            let column = self.name()?;
+            // The new name of the column.
+            self.name()?;
+
+            return Some(AlterTableAction::ChangeColumnType {
+                column,
+                data_type: self.data_type()?,
+            });
+        }
+
+        None
+    }
+
+    fn data_type(&mut self) -> Option<DataType> {
+        let mut name = match self.next()? {
+            Token::Word(word) => word.to_lowercase(),
+            Token::Identifier(identifier) => identifier.clone(),
+            _ => return None,
+        };
+
+        // A type qualified with its schema, like an enum.
+        while self.eat_punct('.') {
+            name = self.identifier()?;
+        }
+
+        let mut arguments = Vec::new();
+
+        loop {
+            if self.eat_punct('(') {
+                loop {
+                    match self.next()? {
+                        Token::Word(argument) | Token::Number(argument) | Token::Str(argument) => {
+                            arguments.push(argument.to_lowercase())
+                        }
+                        _ => return None,
+                    }
+
+                    if self.eat_punct(')') {
+                        break;
+                    }
+
+                    if !self.eat_punct(',') {
+                        return None;
+                    }
+                }
+            } else if self.eat_punct('[') {
+                if !self.eat_punct(']') {
+                    return None;
+                }
+
+                name.push_str("[]");
+            } else if let Some(Token::Word(word)) = self.peek() {
+                let word = word.to_lowercase();
+
+                if !TYPE_NAME_WORDS.contains(&word.as_str()) {
+                    break;
+                }
+
+                name.push(' ');
+                name.push_str(&word);
+                self.position += 1;
+            } else {
+                break;
+            }
+        }
+
+        Some(DataType { name, arguments })
+    }
+}
+
+fn has_volatile_default(data_type: &DataType, constraints: &[Token]) -> bool {
+    if SERIAL_TYPES.contains(&data_type.name.as_str()) {
+        return true;
+    }
+
+    constraints.iter().enumerate().any(|(index, token)| match token {
+        Token::Word(word) => {
+            let word = word.to_lowercase();
+            let is_call = constraints.get(index + 1) == Some(&Token::Punct('('));
+
+            matches!(word.as_str(), "auto_increment" | "identity" | "stored")
+                || (is_call && VOLATILE_FUNCTIONS.contains(&word.as_str()))
+        }
+        _ => false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(script: &str) -> Vec<Statement> {
+        parse_script(script)
+            .into_iter()
+            .map(|statement| statement.kind)
+            .collect()
+    }
+
+    fn data_type(name: &str, arguments: &[&str]) -> DataType {
+        DataType {
+            name: name.to_owned(),
+            arguments: arguments.iter().map(|argument| argument.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn quoted_identifiers_are_unquoted_and_unqualified() {
+        let script = r#"
+            DROP TABLE "public"."Ca""t";
+            DROP TABLE `prisma`.`Dog`;
+            DROP TABLE [dbo].[Bird];
+            DROP TABLE IF EXISTS Fish;
+        "#;
+
+        assert_eq!(
+            parse(script),
+            vec![
+                Statement::DropTable { table: "Ca\"t".into() },
+                Statement::DropTable { table: "Dog".into() },
+                Statement::DropTable { table: "Bird".into() },
+                Statement::DropTable { table: "Fish".into() },
+            ]
+        );
+    }
+
+    #[test]
+    fn semicolons_and_keywords_in_string_literals_are_not_statements() {
+        let script = r#"
+            ALTER TABLE "Cat" ADD COLUMN "note" TEXT NOT NULL DEFAULT 'it''s; DROP TABLE "Cat";';
+            DROP TABLE "Dog";
+        "#;
+
+        assert_eq!(
+            parse(script),
+            vec![
+                Statement::AlterTable {
+                    table: "Cat".into(),
+                    actions: vec![AlterTableAction::AddColumn {
+                        column: "note".into(),
+                        has_volatile_default: false,
+                    }],
+                },
+                Statement::DropTable { table: "Dog".into() },
+            ]
+        );
+    }
+
+    #[test]
+    fn dollar_quoted_strings_are_single_tokens() {
+        let script = r#"
+            CREATE FUNCTION "cleanup"() RETURNS trigger AS $body$
+            BEGIN
+                DROP TABLE "Cat";
+                RETURN $1;
+            END;
+            $body$ LANGUAGE plpgsql;
+            DO $$ BEGIN DROP TABLE "Dog"; END $$;
+            DROP TABLE "Bird";
+        "#;
+
+        assert_eq!(
+            parse(script),
+            vec![
+                Statement::Other,
+                Statement::Other,
+                Statement::DropTable { table: "Bird".into() },
+            ]
+        );
+    }
+
+    #[test]
+    fn comments_are_skipped() {
+        let script = r#"
+            -- DROP TABLE "Cat";
+            /* DROP TABLE "Dog";
+               DROP TABLE "Fish"; */
+            DROP TABLE /* the bird */ "Bird"; -- DROP TABLE "Cow";
+        "#;
+
+        assert_eq!(parse(script), vec![Statement::DropTable { table: "Bird".into() }]);
+    }
+
+    #[test]
+    fn alter_table_actions_are_read_in_order() {
+        let script = r#"
+            ALTER TABLE "public"."Cat" ADD COLUMN "id" SERIAL,
+                ADD COLUMN "createdAt" TIMESTAMP(3) NOT NULL DEFAULT now(),
+                ADD COLUMN "token" UUID DEFAULT gen_random_uuid(),
+                DROP CONSTRAINT "Cat_pkey",
+                ADD CONSTRAINT "Cat_ownerId_fkey" FOREIGN KEY ("ownerId") REFERENCES "public"."Person"("id") ON DELETE CASCADE,
+                ALTER COLUMN "age" SET DATA TYPE VARCHAR(10),
+                ALTER COLUMN "name" SET NOT NULL,
+                DROP COLUMN "nickname";
+        "#;
+
+        assert_eq!(
+            parse(script),
+            vec![Statement::AlterTable {
+                table: "Cat".into(),
+                actions: vec![
+                    AlterTableAction::AddColumn {
+                        column: "id".into(),
+                        has_volatile_default: true,
+                    },
+                    AlterTableAction::AddColumn {
+                        column: "createdAt".into(),
+                        has_volatile_default: false,
+                    },
+                    AlterTableAction::AddColumn {
+                        column: "token".into(),
+                        has_volatile_default: true,
+                    },
+                    AlterTableAction::Other,
+                    AlterTableAction::AddForeignKey(ForeignKey {
+                        columns: vec!["ownerId".into()],
+                        referenced_table: "Person".into(),
+                    }),
+                    AlterTableAction::ChangeColumnType {
+                        column: "age".into(),
+                        data_type: data_type("varchar", &["10"]),
+                    },
+                    AlterTableAction::Other,
+                    AlterTableAction::DropColumn {
+                        column: "nickname".into(),
+                    },
+                ],
+            }]
+        );
+    }
+
+    #[test]
+    fn mysql_and_sql_server_column_type_changes_are_read() {
+        let script = r#"
+            ALTER TABLE `Cat` MODIFY `age` INTEGER UNSIGNED NOT NULL, CHANGE `name` `fullName` VARCHAR(191) NOT NULL;
+            ALTER TABLE [dbo].[Dog] ALTER COLUMN [weight] DECIMAL(32,16) NOT NULL;
+            ALTER TABLE "Bird" RENAME TO "Parrot";
+        "#;
+
+        assert_eq!(
+            parse(script),
+            vec![
+                Statement::AlterTable {
+                    table: "Cat".into(),
+                    actions: vec![
+                        AlterTableAction::ChangeColumnType {
+                            column: "age".into(),
+                            data_type: data_type("integer unsigned", &[]),
+                        },
+                        AlterTableAction::ChangeColumnType {
+                            column: "name".into(),
+                            data_type: data_type("varchar", &["191"]),
+                        },
+                    ],
+                },
+                Statement::AlterTable {
+                    table: "Dog".into(),
+                    actions: vec![AlterTableAction::ChangeColumnType {
+                        column: "weight".into(),
+                        data_type: data_type("decimal", &["32", "16"]),
+                    }],
+                },
+                Statement::RenameTable {
+                    new_name: "Parrot".into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn create_table_foreign_keys_are_read() {
+        let script = r#"
+            CREATE TABLE "Dog" (
+                "id" INTEGER NOT NULL,
+                "ownerId" INTEGER,
+                CONSTRAINT "Dog_pkey" PRIMARY KEY ("id"),
+                CONSTRAINT "Dog_ownerId_fkey" FOREIGN KEY ("ownerId") REFERENCES "Cat"("id") ON DELETE SET NULL
+            );
+        "#;
+
+        assert_eq!(
+            parse(script),
+            vec![Statement::CreateTable {
+                table: "Dog".into(),
+                foreign_keys: vec![ForeignKey {
+                    columns: vec!["ownerId".into()],
+                    referenced_table: "Cat".into(),
+                }],
+            }]
+        );
+    }
+
+    #[test]
+    fn concurrent_index_statements_and_their_marker_are_detected() {
+        let script = r#"
+-- prisma:non-transactional
+CREATE INDEX CONCURRENTLY "Cat.name_index" ON "Cat"("name");
+CREATE UNIQUE INDEX IF NOT EXISTS "Cat.email_unique" ON "Cat"("email");
+DROP INDEX CONCURRENTLY IF EXISTS "public"."Cat.old_index";
+"#;
+
+        assert_eq!(
+            parse_script(script),
+            vec![
+                ScriptStatement {
+                    transactional: false,
+                    kind: Statement::Index {
+                        name: "Cat.name_index".into(),
+                        concurrently: true,
+                    },
+                },
+                ScriptStatement {
+                    transactional: true,
+                    kind: Statement::Index {
+                        name: "Cat.email_unique".into(),
+                        concurrently: false,
+                    },
+                },
+                ScriptStatement {
+                    transactional: true,
+                    kind: Statement::Index {
+                        name: "Cat.old_index".into(),
+                        concurrently: true,
+                    },
+                },
+            ]
+        );
+    }
+}
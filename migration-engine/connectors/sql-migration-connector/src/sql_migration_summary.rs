@@ -0,0 +1,117 @@
+//! Human-readable summaries of SQL migrations.
+
+use crate::sql_migration::{SqlMigration, SqlMigrationStep, TableChange};
+use std::fmt::Write as _;
+
+/// Render a summary of the changes performed by the migration, one line per
+/// change. Changes to the columns of a table and to the variants of an enum are
+/// nested under the table or enum.
+pub(crate) fn render_summary(migration: &SqlMigration) -> String {
+    if migration.steps.is_empty() {
+        return "No difference detected.\n".to_owned();
+    }
+
+    let mut out = String::with_capacity(40 * migration.steps.len());
+
+    for step in &migration.steps {
+        render_step(step, &mut out).expect("Writing to a String failed");
+    }
+
+    out
+}
+
+fn render_step(step: &SqlMigrationStep, out: &mut String) -> std::fmt::Result {
+    match step {
+        SqlMigrationStep::CreateTable(create_table) => writeln!(out, "[+] Added table `{}`", create_table.table.name),
+        SqlMigrationStep::DropTable(drop_table) => writeln!(out, "[-] Removed table `{}`", drop_table.name),
+        SqlMigrationStep::RenameTable { name, new_name } => {
+            writeln!(out, "[*] Renamed table `{}` to `{}`", name, new_name)
+        }
+        SqlMigrationStep::RenameColumn { table, name, new_name } => writeln!(
+            out,
+            "[*] Renamed column `{}` to `{}` in table `{}`",
+            name, new_name, table
+        ),
+        SqlMigrationStep::RedefineTables { names } => {
+            for name in names {
+                writeln!(out, "[*] Redefined table `{}`", name)?;
+            }
+
+            Ok(())
+        }
+        SqlMigrationStep::AlterTable(alter_table) => {
+            writeln!(out, "[*] Changed the `{}` table", alter_table.table.name)?;
+
+            for change in &alter_table.changes {
+                render_table_change(change, out)?;
+            }
+
+            Ok(())
+        }
+        SqlMigrationStep::AddForeignKey(add_foreign_key) => writeln!(
+            out,
+            "[+] Added foreign key on columns ({}) of table `{}` referencing table `{}`",
+            add_foreign_key.foreign_key.columns.join(", "),
+            add_foreign_key.table,
+            add_foreign_key.foreign_key.referenced_table
+        ),
+        SqlMigrationStep::DropForeignKey(drop_foreign_key) => writeln!(
+            out,
+            "[-] Removed foreign key `{}` on table `{}`",
+            drop_foreign_key.constraint_name, drop_foreign_key.table
+        ),
+        SqlMigrationStep::CreateIndex(create_index) => writeln!(
+            out,
+            "[+] Added index `{}` on columns ({}) of table `{}`",
+            create_index.index.name,
+            create_index.index.columns.join(", "),
+            create_index.table
+        ),
+        SqlMigrationStep::DropIndex(drop_index) => writeln!(
+            out,
+            "[-] Removed index `{}` on table `{}`",
+            drop_index.name, drop_index.table
+        ),
+        SqlMigrationStep::AlterIndex(alter_index) => writeln!(
+            out,
+            "[*] Renamed index `{}` to `{}` on table `{}`",
+            alter_index.index_name, alter_index.index_new_name, alter_index.table
+        ),
+        SqlMigrationStep::CreateEnum(create_enum) => writeln!(out, "[+] Added enum `{}`", create_enum.name),
+        SqlMigrationStep::DropEnum(drop_enum) => writeln!(out, "[-] Removed enum `{}`", drop_enum.name),
+        SqlMigrationStep::AlterEnum(alter_enum) => {
+            writeln!(out, "[*] Changed the `{}` enum", alter_enum.name)?;
+
+            for variant in &alter_enum.created_variants {
+                writeln!(out, "  [+] Added variant `{}`", variant)?;
+            }
+
+            for variant in &alter_enum.dropped_variants {
+                writeln!(out, "  [-] Removed variant `{}`", variant)?;
+            }
+
+            Ok(())
+        }
+    }
+}
+
+fn render_table_change(change: &TableChange, out: &mut String) -> std::fmt::Result {
+    match change {
+        TableChange::AddColumn(add_column) => writeln!(out, "  [+] Added column `{}`", add_column.column.name),
+        TableChange::DropColumn(drop_column) => writeln!(out, "  [-] Removed column `{}`", drop_column.name),
+        TableChange::AlterColumn(alter_column) => {
+            writeln!(out, "  [*] Altered column `{}`", alter_column.column_name)
+        }
+        TableChange::DropAndRecreateColumn { column_name, .. } => {
+            writeln!(out, "  [*] Dropped and recreated column `{}`", column_name)
+        }
+        TableChange::DropPrimaryKey { .. } => writeln!(out, "  [-] Removed the primary key"),
+        TableChange::AddPrimaryKey { columns } => {
+            writeln!(out, "  [+] Added a primary key on columns ({})", columns.join(", "))
+        }
+        TableChange::AddCheckConstraint { check } => writeln!(out, "  [+] Added check constraint `{}`", check.name),
+        TableChange::DropCheckConstraint { constraint_name } => {
+            writeln!(out, "  [-] Removed check constraint `{}`", constraint_name)
+        }
+    }
+}
@@ -17,15 +17,48 @@ use common::{Quoted, QuotedWithSchema};
 use sql_schema_describer::{
     walkers::ForeignKeyWalker,
     walkers::{ColumnWalker, TableWalker},
-    ColumnTypeFamily, DefaultValue, SqlSchema,
+    CheckConstraint, ColumnTypeFamily, DefaultValue, SqlSchema,
 };
 use std::borrow::Cow;
 
+/// A statement rendered in online mode.
+#[derive(Debug)]
+pub(crate) struct OnlineStatement {
+    /// The rendered SQL.
+    pub(crate) sql: String,
+    /// Whether the statement can run inside a transaction. Statements that
+    /// cannot, like `CREATE INDEX CONCURRENTLY` on Postgres, have to be run on
+    /// their own.
+    pub(crate) transactional: bool,
+}
+
+impl OnlineStatement {
+    pub(crate) fn transactional(sql: String) -> Self {
+        OnlineStatement {
+            sql,
+            transactional: true,
+        }
+    }
+
+    pub(crate) fn non_transactional(sql: String) -> Self {
+        OnlineStatement {
+            sql,
+            transactional: false,
+        }
+    }
+}
+
 pub(crate) trait SqlRenderer {
     fn quote<'a>(&self, name: &'a str) -> Quoted<&'a str>;
 
     fn render_add_foreign_key(&self, foreign_key: &ForeignKeyWalker<'_>) -> String;
 
+    /// Render an `AddForeignKey` step in online mode, without blocking writes
+    /// on the table while existing rows are validated.
+    fn render_add_foreign_key_online(&self, foreign_key: &ForeignKeyWalker<'_>) -> Vec<OnlineStatement> {
+        vec![OnlineStatement::transactional(self.render_add_foreign_key(foreign_key))]
+    }
+
     fn render_alter_enum(&self, alter_enum: &AlterEnum, differ: &SqlSchemaDiffer<'_>) -> Vec<String>;
 
     fn render_column(&self, column: ColumnWalker<'_>) -> String;
@@ -44,12 +77,28 @@ pub(crate) trait SqlRenderer {
 
     fn render_alter_table(&self, alter_table: &AlterTable, differ: &SqlSchemaDiffer<'_>) -> Vec<String>;
 
+    /// Render a check constraint, as part of a table definition or of an `ADD` clause.
+    fn render_check_constraint(&self, check: &CheckConstraint) -> String {
+        // Unnamed constraints are named by the database.
+        if check.name.is_empty() {
+            format!("CHECK ({})", check.definition)
+        } else {
+            format!("CONSTRAINT {} CHECK ({})", self.quote(&check.name), check.definition)
+        }
+    }
+
     /// Render a `CreateEnum` step.
     fn render_create_enum(&self, create_enum: &CreateEnum) -> Vec<String>;
 
     /// Render a `CreateIndex` step.
     fn render_create_index(&self, create_index: &CreateIndex) -> String;
 
+    /// Render a `CreateIndex` step in online mode, without blocking writes on
+    /// the table while the index is built.
+    fn render_create_index_online(&self, create_index: &CreateIndex) -> OnlineStatement {
+        OnlineStatement::transactional(self.render_create_index(create_index))
+    }
+
     /// Render a `CreateTable` step.
     fn render_create_table(&self, table: &TableWalker<'_>) -> String {
         self.render_create_table_as(table, table.name())
@@ -67,6 +116,12 @@ pub(crate) trait SqlRenderer {
     /// Render a `DropIndex` step.
     fn render_drop_index(&self, drop_index: &DropIndex) -> String;
 
+    /// Render a `DropIndex` step in online mode, without blocking writes on
+    /// the table.
+    fn render_drop_index_online(&self, drop_index: &DropIndex) -> OnlineStatement {
+        OnlineStatement::transactional(self.render_drop_index(drop_index))
+    }
+
     /// Render a `DropTable` step.
     fn render_drop_table(&self, table_name: &str) -> Vec<String> {
         vec![format!("DROP TABLE {}", self.quote(&table_name))]
@@ -77,4 +132,7 @@ pub(crate) trait SqlRenderer {
 
     /// Render a table renaming step.
     fn render_rename_table(&self, name: &str, new_name: &str) -> String;
+
+    /// Render a `RenameColumn` step. `column` is the column with its new name.
+    fn render_rename_column(&self, previous_name: &str, column: ColumnWalker<'_>) -> String;
 }
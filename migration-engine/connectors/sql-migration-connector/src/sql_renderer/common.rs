@@ -88,7 +88,7 @@ pub(crate) fn render_nullability(column: &ColumnWalker<'_>) -> &'static str {
 
 pub(crate) fn render_on_delete(on_delete: &ForeignKeyAction) -> &'static str {
     match on_delete {
-        ForeignKeyAction::NoAction => "",
+        ForeignKeyAction::NoAction => "ON DELETE NO ACTION",
         ForeignKeyAction::SetNull => "ON DELETE SET NULL",
         ForeignKeyAction::Cascade => "ON DELETE CASCADE",
         ForeignKeyAction::SetDefault => "ON DELETE SET DEFAULT",
@@ -98,7 +98,7 @@ pub(crate) fn render_on_delete(on_delete: &ForeignKeyAction) -> &'static str {
 
 pub(crate) fn render_on_update(on_update: &ForeignKeyAction) -> &'static str {
     match on_update {
-        ForeignKeyAction::NoAction => "",
+        ForeignKeyAction::NoAction => "ON UPDATE NO ACTION",
         ForeignKeyAction::SetNull => "ON UPDATE SET NULL",
         ForeignKeyAction::Cascade => "ON UPDATE CASCADE",
         ForeignKeyAction::SetDefault => "ON UPDATE SET DEFAULT",
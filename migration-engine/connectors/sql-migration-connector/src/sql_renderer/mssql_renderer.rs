@@ -45,6 +45,12 @@ impl SqlRenderer for MssqlFlavour {
                     let columns = columns.iter().map(|colname| self.quote(colname)).join(", ");
                     lines.push(format!("ADD PRIMARY KEY ({})", columns));
                 }
+                TableChange::AddCheckConstraint { check } => {
+                    lines.push(format!("ADD {}", self.render_check_constraint(check)))
+                }
+                TableChange::DropCheckConstraint { constraint_name } => {
+                    lines.push(format!("DROP CONSTRAINT {}", self.quote(constraint_name)))
+                }
                 TableChange::AddColumn(AddColumn { column }) => {
                     let column = differ
                         .next
@@ -86,13 +92,14 @@ impl SqlRenderer for MssqlFlavour {
         let r#type = match &column.column_type().family {
             ColumnTypeFamily::Boolean => "bit",
             ColumnTypeFamily::DateTime => "datetime2",
-            ColumnTypeFamily::Float => "decimal(32,16)",
+            ColumnTypeFamily::Float => "float(53)",
+            ColumnTypeFamily::Int if column.column_type().full_data_type == "BIGINT" => "bigint",
             ColumnTypeFamily::Int => "int",
             ColumnTypeFamily::String | ColumnTypeFamily::Json => "nvarchar(1000)",
             ColumnTypeFamily::Duration => unimplemented!("Duration not handled yet"),
             ColumnTypeFamily::Enum(_) => unimplemented!("Enum not handled yet"),
-            ColumnTypeFamily::Decimal => unimplemented!("Decimal not handled yet"),
-            ColumnTypeFamily::Binary => unimplemented!("Binary not handled yet"),
+            ColumnTypeFamily::Decimal => "decimal(32,16)",
+            ColumnTypeFamily::Binary => "varbinary(max)",
             ColumnTypeFamily::Uuid => unimplemented!("Uuid not handled yet"),
             ColumnTypeFamily::Xml => unimplemented!("Xml not handled yet"),
             ColumnTypeFamily::Unsupported(x) => unimplemented!("{} not handled yet", x),
@@ -193,6 +200,7 @@ impl SqlRenderer for MssqlFlavour {
         let index_type = match index.tpe {
             IndexType::Unique => "UNIQUE ",
             IndexType::Normal => "",
+            IndexType::Fulltext => unreachable!("Fulltext indexes are only supported on MySQL."),
         };
 
         let index_name = index.name.replace('.', "_");
@@ -245,12 +253,18 @@ impl SqlRenderer for MssqlFlavour {
             String::new()
         };
 
+        let checks: String = table
+            .checks()
+            .map(|check| format!(",\n{}", self.render_check_constraint(check)))
+            .collect();
+
         format!(
-            "CREATE TABLE {} ({columns}{primary_key}{constraints})",
+            "CREATE TABLE {} ({columns}{primary_key}{constraints}{checks})",
             table_name = self.quote_with_schema(table_name),
             columns = columns,
             primary_key = primary_key,
             constraints = constraints,
+            checks = checks,
         )
     }
 
@@ -288,6 +302,16 @@ impl SqlRenderer for MssqlFlavour {
         )
     }
 
+    fn render_rename_column(&self, previous_name: &str, column: ColumnWalker<'_>) -> String {
+        let with_schema = format!("{}.{}.{}", self.schema_name(), column.table().name(), previous_name);
+
+        format!(
+            "EXEC SP_RENAME N{}, N{}, N'COLUMN'",
+            Quoted::Single(with_schema),
+            Quoted::Single(column.name()),
+        )
+    }
+
     fn render_add_foreign_key(&self, foreign_key: &ForeignKeyWalker<'_>) -> String {
         let mut add_constraint = String::with_capacity(120);
 
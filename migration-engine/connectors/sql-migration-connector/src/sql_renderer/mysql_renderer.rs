@@ -1,6 +1,6 @@
 use super::{
-    common::{render_nullability, render_on_delete, Quoted},
-    IteratorJoin, SqlRenderer,
+    common::{render_nullability, render_on_delete, render_on_update, Quoted},
+    IteratorJoin, OnlineStatement, SqlRenderer,
 };
 use crate::{
     database_info::DatabaseInfo,
@@ -23,6 +23,9 @@ use std::borrow::Cow;
 
 const VARCHAR_LENGTH_PREFIX: &str = "(191)";
 
+/// The options making index creation and removal run without blocking writes.
+const ONLINE_DDL_OPTIONS: &str = "ALGORITHM=INPLACE LOCK=NONE";
+
 impl SqlRenderer for MysqlFlavour {
     fn quote<'a>(&self, name: &'a str) -> Quoted<&'a str> {
         Quoted::Backticks(name)
@@ -120,6 +123,13 @@ impl SqlRenderer for MysqlFlavour {
                     "ADD PRIMARY KEY ({})",
                     columns.iter().map(|colname| self.quote(colname)).join(", ")
                 )),
+                TableChange::AddCheckConstraint { check } => {
+                    lines.push(format!("ADD {}", self.render_check_constraint(check)))
+                }
+                // Supported from MySQL 8.0.19 and on MariaDB.
+                TableChange::DropCheckConstraint { constraint_name } => {
+                    lines.push(format!("DROP CONSTRAINT {}", self.quote(constraint_name)))
+                }
                 TableChange::AddColumn(AddColumn { column }) => {
                     let column = differ
                         .next
@@ -210,10 +220,11 @@ impl SqlRenderer for MysqlFlavour {
             .join(",");
 
         format!(
-            " REFERENCES `{table_name}`({column_names}) {on_delete} ON UPDATE CASCADE",
+            " REFERENCES `{table_name}`({column_names}) {on_delete} {on_update}",
             table_name = foreign_key.referenced_table().name(),
             column_names = referenced_columns,
-            on_delete = render_on_delete(foreign_key.on_delete_action())
+            on_delete = render_on_delete(foreign_key.on_delete_action()),
+            on_update = render_on_update(foreign_key.on_update_action()),
         )
     }
 
@@ -246,6 +257,7 @@ impl SqlRenderer for MysqlFlavour {
         let index_type = match tpe {
             IndexType::Unique => "UNIQUE ",
             IndexType::Normal => "",
+            IndexType::Fulltext => "FULLTEXT ",
         };
         let index_name = self.quote(&name);
         let table_reference = self.quote(&create_index.table);
@@ -261,6 +273,17 @@ impl SqlRenderer for MysqlFlavour {
         )
     }
 
+    /// Fulltext indexes cannot be built without locking the table.
+    fn render_create_index_online(&self, create_index: &CreateIndex) -> OnlineStatement {
+        let statement = self.render_create_index(create_index);
+
+        if create_index.index.tpe == IndexType::Fulltext {
+            return OnlineStatement::transactional(statement);
+        }
+
+        OnlineStatement::transactional(format!("{} {}", statement, ONLINE_DDL_OPTIONS))
+    }
+
     fn render_create_table_as(&self, table: &TableWalker<'_>, table_name: &str) -> String {
         let columns: String = table.columns().map(|column| self.render_column(column)).join(",\n");
 
@@ -277,7 +300,11 @@ impl SqlRenderer for MysqlFlavour {
             let indices: String = table
                 .indexes()
                 .map(|index| {
-                    let tpe = if index.index_type().is_unique() { "UNIQUE " } else { "" };
+                    let tpe = match index.index_type() {
+                        IndexType::Unique => "UNIQUE ",
+                        IndexType::Normal => "",
+                        IndexType::Fulltext => "FULLTEXT ",
+                    };
                     let index_name = if index.name().len() > MYSQL_IDENTIFIER_SIZE_LIMIT {
                         &index.name()[0..MYSQL_IDENTIFIER_SIZE_LIMIT]
                     } else {
@@ -298,12 +325,18 @@ impl SqlRenderer for MysqlFlavour {
             String::new()
         };
 
+        let checks: String = table
+            .checks()
+            .map(|check| format!(",\n{}", self.render_check_constraint(check)))
+            .collect();
+
         format!(
-            "CREATE TABLE {} (\n{columns}{indexes}{primary_key}\n) DEFAULT CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci",
+            "CREATE TABLE {} (\n{columns}{indexes}{primary_key}{checks}\n) DEFAULT CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci",
             table_name = self.quote(table_name),
             columns = columns,
             indexes = indexes,
             primary_key = primary_key,
+            checks = checks,
         )
     }
 
@@ -323,6 +356,10 @@ impl SqlRenderer for MysqlFlavour {
         mysql_drop_index(self, &drop_index.table, &drop_index.name)
     }
 
+    fn render_drop_index_online(&self, drop_index: &DropIndex) -> OnlineStatement {
+        OnlineStatement::transactional(format!("{} {}", self.render_drop_index(drop_index), ONLINE_DDL_OPTIONS))
+    }
+
     fn render_drop_table(&self, table_name: &str) -> Vec<String> {
         vec![format!("DROP TABLE {}", self.quote(&table_name))]
     }
@@ -338,6 +375,17 @@ impl SqlRenderer for MysqlFlavour {
             new_name = self.quote(&new_name),
         )
     }
+
+    /// `RENAME COLUMN` is only available from MySQL 8, so we use `CHANGE`, which
+    /// requires the whole column definition.
+    fn render_rename_column(&self, previous_name: &str, column: ColumnWalker<'_>) -> String {
+        format!(
+            "ALTER TABLE {} CHANGE {} {}",
+            self.quote(column.table().name()),
+            self.quote(previous_name),
+            self.render_column(column),
+        )
+    }
 }
 
 fn render_mysql_modify(
@@ -389,7 +437,7 @@ pub(crate) fn render_column_type(column: &ColumnWalker<'_>) -> Cow<'static, str>
     match &column.column_type().family {
         ColumnTypeFamily::Boolean => "boolean".into(),
         ColumnTypeFamily::DateTime => "datetime(3)".into(),
-        ColumnTypeFamily::Float => "decimal(65,30)".into(),
+        ColumnTypeFamily::Float => "double".into(),
         ColumnTypeFamily::Int => "int".into(),
         // we use varchar right now as mediumtext doesn't allow default values
         // a bigger length would not allow to use such a column as primary key
@@ -406,8 +454,8 @@ pub(crate) fn render_column_type(column: &ColumnWalker<'_>) -> Cow<'static, str>
         }
         ColumnTypeFamily::Json => "json".into(),
         ColumnTypeFamily::Duration => unimplemented!("Duration not handled yet"),
-        ColumnTypeFamily::Decimal => unimplemented!("Decimal not handled yet"),
-        ColumnTypeFamily::Binary => unimplemented!("Binary not handled yet"),
+        ColumnTypeFamily::Decimal => "decimal(65,30)".into(),
+        ColumnTypeFamily::Binary => "longblob".into(),
         ColumnTypeFamily::Uuid => unimplemented!("Uuid not handled yet"),
         ColumnTypeFamily::Xml => unimplemented!("Xml not handled yet"),
         ColumnTypeFamily::Unsupported(x) => unimplemented!("{} not handled yet", x),
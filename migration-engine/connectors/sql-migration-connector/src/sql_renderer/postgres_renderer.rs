@@ -1,7 +1,7 @@
-use super::{common::*, SqlRenderer};
+use super::{common::*, OnlineStatement, SqlRenderer};
 use crate::{
     database_info::DatabaseInfo,
-    flavour::PostgresFlavour,
+    flavour::{PostgresFlavour, POSTGRES_IDENTIFIER_SIZE_LIMIT},
     sql_migration::{
         expanded_alter_column::{expand_postgres_alter_column, PostgresAlterColumn},
         AddColumn, AlterColumn, AlterEnum, AlterIndex, AlterTable, CreateEnum, CreateIndex, DropColumn, DropEnum,
@@ -22,6 +22,27 @@ impl PostgresFlavour {
             name: self.quote(name),
         }
     }
+
+    fn render_create_index_with_options(&self, create_index: &CreateIndex, options: &str) -> String {
+        let Index { name, columns, tpe } = &create_index.index;
+        let index_type = match tpe {
+            IndexType::Unique => "UNIQUE ",
+            IndexType::Normal => "",
+            IndexType::Fulltext => unreachable!("Fulltext indexes are only supported on MySQL."),
+        };
+        let index_name = self.quote(&name).to_string();
+        let table_reference = self.quote_with_schema(&create_index.table).to_string();
+        let columns = columns.iter().map(|c| self.quote(c));
+
+        format!(
+            "CREATE {index_type}INDEX {options}{index_name} ON {table_reference}({columns})",
+            index_type = index_type,
+            options = options,
+            index_name = index_name,
+            table_reference = table_reference,
+            columns = columns.join(", ")
+        )
+    }
 }
 
 impl SqlRenderer for PostgresFlavour {
@@ -48,6 +69,46 @@ impl SqlRenderer for PostgresFlavour {
         )
     }
 
+    /// The foreign key is added as `NOT VALID`, which does not check the
+    /// existing rows, then validated in a separate statement that does not
+    /// block writes. Unnamed foreign keys get the name Postgres would give
+    /// them, so they can be validated.
+    fn render_add_foreign_key_online(&self, foreign_key: &ForeignKeyWalker<'_>) -> Vec<OnlineStatement> {
+        let constraint_name = foreign_key.constraint_name().map(String::from).unwrap_or_else(|| {
+            format!(
+                "{}_{}_fkey",
+                foreign_key.table().name(),
+                foreign_key.constrained_column_names().join("_")
+            )
+        });
+
+        // Postgres truncates longer names, so we could not refer to the constraint.
+        if constraint_name.len() > POSTGRES_IDENTIFIER_SIZE_LIMIT {
+            return vec![OnlineStatement::transactional(self.render_add_foreign_key(foreign_key))];
+        }
+
+        let table = self.quote_with_schema(foreign_key.table().name());
+
+        vec![
+            OnlineStatement::transactional(format!(
+                "ALTER TABLE {table} ADD CONSTRAINT {constraint_name} FOREIGN KEY({columns}){references} NOT VALID",
+                table = table,
+                constraint_name = self.quote(&constraint_name),
+                columns = foreign_key
+                    .constrained_column_names()
+                    .iter()
+                    .map(Quoted::postgres_ident)
+                    .join(", "),
+                references = self.render_references(foreign_key),
+            )),
+            OnlineStatement::non_transactional(format!(
+                "ALTER TABLE {table} VALIDATE CONSTRAINT {constraint_name}",
+                table = table,
+                constraint_name = self.quote(&constraint_name),
+            )),
+        ]
+    }
+
     fn render_alter_enum(&self, alter_enum: &AlterEnum, differ: &SqlSchemaDiffer<'_>) -> Vec<String> {
         if alter_enum.dropped_variants.is_empty() {
             let stmts: Vec<String> = alter_enum
@@ -182,6 +243,12 @@ impl SqlRenderer for PostgresFlavour {
                     "ADD PRIMARY KEY ({})",
                     columns.iter().map(|colname| self.quote(colname)).join(", ")
                 )),
+                TableChange::AddCheckConstraint { check } => {
+                    lines.push(format!("ADD {}", self.render_check_constraint(check)))
+                }
+                TableChange::DropCheckConstraint { constraint_name } => {
+                    lines.push(format!("DROP CONSTRAINT {}", self.quote(constraint_name)))
+                }
                 TableChange::AddColumn(AddColumn { column }) => {
                     let column = differ
                         .next
@@ -266,7 +333,9 @@ impl SqlRenderer for PostgresFlavour {
             .unwrap_or_else(String::new);
         let is_serial = column.is_autoincrement();
 
-        if is_serial {
+        if is_serial && column.column_type().full_data_type == "BIGINT" {
+            format!("{} BIGSERIAL", column_name)
+        } else if is_serial {
             format!("{} SERIAL", column_name)
         } else {
             format!("{} {} {} {}", column_name, tpe_str, nullability_str, default_str)
@@ -281,10 +350,11 @@ impl SqlRenderer for PostgresFlavour {
             .join(",");
 
         format!(
-            "REFERENCES {}({}) {} ON UPDATE CASCADE",
+            "REFERENCES {}({}) {} {}",
             self.quote_with_schema(&foreign_key.referenced_table().name()),
             referenced_columns,
-            render_on_delete(&foreign_key.on_delete_action())
+            render_on_delete(&foreign_key.on_delete_action()),
+            render_on_update(&foreign_key.on_update_action())
         )
     }
 
@@ -318,22 +388,11 @@ impl SqlRenderer for PostgresFlavour {
     }
 
     fn render_create_index(&self, create_index: &CreateIndex) -> String {
-        let Index { name, columns, tpe } = &create_index.index;
-        let index_type = match tpe {
-            IndexType::Unique => "UNIQUE ",
-            IndexType::Normal => "",
-        };
-        let index_name = self.quote(&name).to_string();
-        let table_reference = self.quote_with_schema(&create_index.table).to_string();
-        let columns = columns.iter().map(|c| self.quote(c));
+        self.render_create_index_with_options(create_index, "")
+    }
 
-        format!(
-            "CREATE {index_type}INDEX {index_name} ON {table_reference}({columns})",
-            index_type = index_type,
-            index_name = index_name,
-            table_reference = table_reference,
-            columns = columns.join(", ")
-        )
+    fn render_create_index_online(&self, create_index: &CreateIndex) -> OnlineStatement {
+        OnlineStatement::non_transactional(self.render_create_index_with_options(create_index, "CONCURRENTLY "))
     }
 
     fn render_create_table_as(&self, table: &TableWalker<'_>, table_name: &str) -> String {
@@ -351,11 +410,17 @@ impl SqlRenderer for PostgresFlavour {
             String::new()
         };
 
+        let checks: String = table
+            .checks()
+            .map(|check| format!(",\n{}", self.render_check_constraint(check)))
+            .collect();
+
         format!(
-            "CREATE TABLE {table_name} (\n{columns}{primary_key}\n)",
+            "CREATE TABLE {table_name} (\n{columns}{primary_key}{checks}\n)",
             table_name = self.quote_with_schema(table_name),
             columns = columns,
             primary_key = pk,
+            checks = checks,
         )
     }
 
@@ -380,6 +445,13 @@ impl SqlRenderer for PostgresFlavour {
         format!("DROP INDEX {}", self.quote_with_schema(&drop_index.name))
     }
 
+    fn render_drop_index_online(&self, drop_index: &DropIndex) -> OnlineStatement {
+        OnlineStatement::non_transactional(format!(
+            "DROP INDEX CONCURRENTLY {}",
+            self.quote_with_schema(&drop_index.name)
+        ))
+    }
+
     fn render_drop_table(&self, table_name: &str) -> Vec<String> {
         vec![format!("DROP TABLE {}", self.quote_with_schema(&table_name))]
     }
@@ -392,7 +464,16 @@ impl SqlRenderer for PostgresFlavour {
         format!(
             "ALTER TABLE {} RENAME TO {}",
             self.quote_with_schema(&name),
-            new_name = self.quote_with_schema(&new_name).to_string(),
+            new_name = self.quote(&new_name),
+        )
+    }
+
+    fn render_rename_column(&self, previous_name: &str, column: ColumnWalker<'_>) -> String {
+        format!(
+            "ALTER TABLE {} RENAME COLUMN {} TO {}",
+            self.quote_with_schema(column.table().name()),
+            self.quote(previous_name),
+            self.quote(column.name()),
         )
     }
 }
@@ -410,15 +491,15 @@ pub(crate) fn render_column_type(t: &ColumnType) -> String {
     match &t.family {
         ColumnTypeFamily::Boolean => format!("boolean {}", array),
         ColumnTypeFamily::DateTime => format!("timestamp(3) {}", array),
-        ColumnTypeFamily::Float => format!("Decimal(65,30) {}", array),
+        ColumnTypeFamily::Float => format!("double precision {}", array),
         ColumnTypeFamily::Int => format!("integer {}", array),
         ColumnTypeFamily::String => format!("text {}", array),
         ColumnTypeFamily::Enum(name) => format!("{}{}", Quoted::postgres_ident(name), array),
         ColumnTypeFamily::Json => format!("jsonb {}", array),
         ColumnTypeFamily::Xml => format!("xml {}", array),
         ColumnTypeFamily::Duration => unimplemented!("Duration not handled yet"),
-        ColumnTypeFamily::Decimal => unimplemented!("Decimal not handled yet"),
-        ColumnTypeFamily::Binary => unimplemented!("Binary not handled yet"),
+        ColumnTypeFamily::Decimal => format!("decimal(65,30) {}", array),
+        ColumnTypeFamily::Binary => format!("bytea {}", array),
         ColumnTypeFamily::Uuid => unimplemented!("Uuid not handled yet"),
         ColumnTypeFamily::Unsupported(x) => unimplemented!("{} not handled yet", x),
     }
@@ -37,6 +37,7 @@ impl SqlRenderer for SqliteFlavour {
         let index_type = match tpe {
             IndexType::Unique => "UNIQUE ",
             IndexType::Normal => "",
+            IndexType::Fulltext => unreachable!("Fulltext indexes are only supported on MySQL."),
         };
         let index_name = self.quote(&name).to_string();
         let table_reference = self.quote(&create_index.table).to_string();
@@ -85,10 +86,11 @@ impl SqlRenderer for SqliteFlavour {
             .join(",");
 
         format!(
-            "REFERENCES {referenced_table}({referenced_fields}) {on_delete_action} ON UPDATE CASCADE",
+            "REFERENCES {referenced_table}({referenced_fields}) {on_delete_action} {on_update_action}",
             referenced_table = self.quote(foreign_key.referenced_table().name()),
             referenced_fields = referenced_fields,
-            on_delete_action = render_on_delete(foreign_key.on_delete_action())
+            on_delete_action = render_on_delete(foreign_key.on_delete_action()),
+            on_update_action = render_on_update(foreign_key.on_update_action()),
         )
     }
 
@@ -138,9 +140,11 @@ impl SqlRenderer for SqliteFlavour {
                         column_definition = col_sql,
                     ));
                 }
+                TableChange::AddCheckConstraint { .. } => unreachable!("AddCheckConstraint on SQLite"),
                 TableChange::AddPrimaryKey { .. } => unreachable!("AddPrimaryKey on SQLite"),
                 TableChange::AlterColumn(_) => unreachable!("AlterColumn on SQLite"),
                 TableChange::DropAndRecreateColumn { .. } => unreachable!("DropAndRecreateColumn on SQLite"),
+                TableChange::DropCheckConstraint { .. } => unreachable!("DropCheckConstraint on SQLite"),
                 TableChange::DropColumn(_) => unreachable!("DropColumn on SQLite"),
                 TableChange::DropPrimaryKey { .. } => unreachable!("DropPrimaryKey on SQLite"),
             };
@@ -193,12 +197,18 @@ impl SqlRenderer for SqliteFlavour {
             String::new()
         };
 
+        let checks: String = table
+            .checks()
+            .map(|check| format!(",\n{}", self.render_check_constraint(check)))
+            .collect();
+
         format!(
-            "CREATE TABLE {table_name} (\n{columns}{foreign_keys}{primary_key}\n)",
+            "CREATE TABLE {table_name} (\n{columns}{foreign_keys}{primary_key}{checks}\n)",
             table_name = self.quote(table_name),
             columns = columns,
             foreign_keys = foreign_keys,
             primary_key = primary_key,
+            checks = checks,
         )
     }
 
@@ -271,6 +281,15 @@ impl SqlRenderer for SqliteFlavour {
     fn render_rename_table(&self, name: &str, new_name: &str) -> String {
         format!("ALTER TABLE {} RENAME TO {}", self.quote(&name), self.quote(new_name),)
     }
+
+    fn render_rename_column(&self, previous_name: &str, column: ColumnWalker<'_>) -> String {
+        format!(
+            "ALTER TABLE {} RENAME COLUMN {} TO {}",
+            self.quote(column.table().name()),
+            self.quote(previous_name),
+            self.quote(column.name()),
+        )
+    }
 }
 
 fn render_column_type(t: &ColumnType) -> &'static str {
@@ -283,8 +302,8 @@ fn render_column_type(t: &ColumnType) -> &'static str {
         ColumnTypeFamily::Json => unimplemented!("Json not handled yet"),
         ColumnTypeFamily::Enum(_) => unimplemented!("Enum not handled yet"),
         ColumnTypeFamily::Duration => unimplemented!("Duration not handled yet"),
-        ColumnTypeFamily::Decimal => unimplemented!("Decimal not handled yet"),
-        ColumnTypeFamily::Binary => unimplemented!("Binary not handled yet"),
+        ColumnTypeFamily::Decimal => "DECIMAL",
+        ColumnTypeFamily::Binary => "BLOB",
         ColumnTypeFamily::Uuid => unimplemented!("Uuid not handled yet"),
         ColumnTypeFamily::Xml => unimplemented!("Xml not handled yet"),
         ColumnTypeFamily::Unsupported(x) => unimplemented!("{} not handled yet", x),
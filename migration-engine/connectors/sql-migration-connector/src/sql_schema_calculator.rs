@@ -5,7 +5,8 @@ pub(super) use sql_schema_calculator_flavour::SqlSchemaCalculatorFlavour;
 use crate::{flavour::SqlFlavour, sql_renderer::IteratorJoin, DatabaseInfo};
 use datamodel::{
     walkers::{walk_models, walk_relations, ModelWalker, ScalarFieldWalker, TypeWalker},
-    Datamodel, DefaultValue, FieldArity, IndexDefinition, IndexType, ScalarType, ValueGenerator, ValueGeneratorFn,
+    Datamodel, DefaultValue, FieldArity, IndexDefinition, IndexType, ReferentialAction, ScalarType, ValueGenerator,
+    ValueGeneratorFn,
 };
 use prisma_value::PrismaValue;
 use quaint::prelude::SqlFamily;
@@ -49,11 +50,13 @@ impl<'a> SqlSchemaCalculator<'a> {
             tables,
             enums,
             sequences,
+            views: Vec::new(),
         }
     }
 
     fn calculate_model_tables<'iter>(&'iter self) -> impl Iterator<Item = (ModelWalker<'a>, sql::Table)> + 'iter {
-        walk_models(self.data_model).map(move |model| {
+        // Views are not managed by migrations.
+        walk_models(self.data_model).filter(|model| !model.is_view()).map(move |model| {
             let columns = model
                 .scalar_fields()
                 .flat_map(|f| match f.field_type() {
@@ -123,6 +126,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                 let index_type = match index_definition.tpe {
                     IndexType::Unique => sql::IndexType::Unique,
                     IndexType::Normal => sql::IndexType::Normal,
+                    IndexType::Fulltext => sql::IndexType::Fulltext,
                 };
 
                 let index_name = index_definition.name.clone().unwrap_or_else(|| {
@@ -146,12 +150,22 @@ impl<'a> SqlSchemaCalculator<'a> {
                 }
             });
 
+            // Unnamed checks are named by the database.
+            let checks = model
+                .checks()
+                .map(|check| sql::CheckConstraint {
+                    name: check.name.clone().unwrap_or_default(),
+                    definition: check.expression.clone(),
+                })
+                .collect();
+
             let table = sql::Table {
                 name: model.database_name().to_owned(),
                 columns,
                 indices: single_field_indexes.chain(multiple_field_indexes).collect(),
                 primary_key,
                 foreign_keys: Vec::new(),
+                checks,
             };
 
             (model, table)
@@ -178,10 +192,14 @@ impl<'a> SqlSchemaCalculator<'a> {
                     columns: fk_columns,
                     referenced_table: relation_field.referenced_table_name().to_owned(),
                     referenced_columns: relation_field.referenced_columns().map(String::from).collect(),
-                    on_update_action: sql::ForeignKeyAction::Cascade,
-                    on_delete_action: match column_arity(relation_field.arity()) {
-                        ColumnArity::Required => sql::ForeignKeyAction::Cascade,
-                        _ => sql::ForeignKeyAction::SetNull,
+                    on_update_action: relation_field
+                        .on_update()
+                        .map(foreign_key_action)
+                        .unwrap_or(sql::ForeignKeyAction::Cascade),
+                    on_delete_action: match (relation_field.on_delete(), column_arity(relation_field.arity())) {
+                        (Some(action), _) => foreign_key_action(action),
+                        (None, ColumnArity::Required) => sql::ForeignKeyAction::Cascade,
+                        (None, _) => sql::ForeignKeyAction::SetNull,
                     },
                 };
 
@@ -253,11 +271,22 @@ impl<'a> SqlSchemaCalculator<'a> {
                     indices: indexes,
                     primary_key: None,
                     foreign_keys,
+                    checks: Vec::new(),
                 }
             })
     }
 }
 
+fn foreign_key_action(action: ReferentialAction) -> sql::ForeignKeyAction {
+    match action {
+        ReferentialAction::Cascade => sql::ForeignKeyAction::Cascade,
+        ReferentialAction::Restrict => sql::ForeignKeyAction::Restrict,
+        ReferentialAction::NoAction => sql::ForeignKeyAction::NoAction,
+        ReferentialAction::SetNull => sql::ForeignKeyAction::SetNull,
+        ReferentialAction::SetDefault => sql::ForeignKeyAction::SetDefault,
+    }
+}
+
 fn migration_value_new(field: &ScalarFieldWalker<'_>) -> Option<sql_schema_describer::DefaultValue> {
     let value = match &field.default_value()? {
         datamodel::DefaultValue::Single(s) => match field.field_type() {
@@ -331,6 +360,9 @@ fn column_arity(arity: FieldArity) -> sql::ColumnArity {
 fn column_type_for_scalar_type(scalar_type: &ScalarType, column_arity: ColumnArity) -> sql::ColumnType {
     match scalar_type {
         ScalarType::Int => sql::ColumnType::pure(sql::ColumnTypeFamily::Int, column_arity),
+        ScalarType::BigInt => {
+            sql::ColumnType::with_full_data_type(sql::ColumnTypeFamily::Int, column_arity, "BIGINT".to_owned())
+        }
         ScalarType::Float => sql::ColumnType::pure(sql::ColumnTypeFamily::Float, column_arity),
         ScalarType::Boolean => sql::ColumnType::pure(sql::ColumnTypeFamily::Boolean, column_arity),
         ScalarType::String => sql::ColumnType::pure(sql::ColumnTypeFamily::String, column_arity),
@@ -338,7 +370,7 @@ fn column_type_for_scalar_type(scalar_type: &ScalarType, column_arity: ColumnAri
         ScalarType::Json => sql::ColumnType::pure(sql::ColumnTypeFamily::Json, column_arity),
         ScalarType::Bytes => sql::ColumnType::pure(sql::ColumnTypeFamily::Binary, column_arity),
         ScalarType::XML => unreachable!("XML type rendering"),
-        ScalarType::Decimal => unreachable!("Decimal type rendering"),
+        ScalarType::Decimal => sql::ColumnType::pure(sql::ColumnTypeFamily::Decimal, column_arity),
         ScalarType::Duration => unreachable!("Duration type rendering"),
     }
 }
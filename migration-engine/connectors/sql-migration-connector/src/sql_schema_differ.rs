@@ -1,10 +1,12 @@
 mod column;
 mod enums;
 mod index;
+mod rename_hints;
 mod sql_schema_differ_flavour;
 mod table;
 
 pub(crate) use column::{ColumnChange, ColumnChanges, ColumnDiffer, ColumnTypeChange};
+pub(crate) use rename_hints::{apply_rename_hints, reverse_renames};
 pub(crate) use sql_schema_differ_flavour::SqlSchemaDifferFlavour;
 pub(crate) use table::TableDiffer;
 
@@ -187,12 +189,13 @@ impl<'schema> SqlSchemaDiffer<'schema> {
             .filter(|tables| !tables_to_redefine.contains(tables.next.name()))
             .filter_map(|tables| {
                 // Order matters.
-                let changes: Vec<TableChange> = Self::drop_primary_key(&tables)
-                    .into_iter()
+                let changes: Vec<TableChange> = Self::drop_checks(&tables)
+                    .chain(Self::drop_primary_key(&tables))
                     .chain(Self::drop_columns(&tables))
                     .chain(Self::add_columns(&tables))
                     .chain(Self::alter_columns(&tables))
                     .chain(Self::add_primary_key(&tables))
+                    .chain(Self::add_checks(&tables))
                     .collect();
 
                 Some(changes)
@@ -297,6 +300,23 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         })
     }
 
+    fn add_checks<'a>(differ: &'a TableDiffer<'schema>) -> impl Iterator<Item = TableChange> + 'a {
+        differ
+            .created_checks()
+            .map(|check| TableChange::AddCheckConstraint { check: check.clone() })
+    }
+
+    /// Checks calculated from unnamed `@@check` attributes are named by the database, under a name
+    /// we don't know, so they can't be dropped.
+    fn drop_checks<'a>(differ: &'a TableDiffer<'schema>) -> impl Iterator<Item = TableChange> + 'a {
+        differ
+            .dropped_checks()
+            .filter(|check| !check.name.is_empty())
+            .map(|check| TableChange::DropCheckConstraint {
+                constraint_name: check.name.clone(),
+            })
+    }
+
     fn create_indexes(&self, tables_to_redefine: &HashSet<String>) -> Vec<CreateIndex> {
         let mut steps = Vec::new();
 
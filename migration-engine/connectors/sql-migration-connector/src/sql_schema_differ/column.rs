@@ -1,7 +1,7 @@
 use crate::{database_info::DatabaseInfo, flavour::SqlFlavour};
 use enumflags2::BitFlags;
 use prisma_value::PrismaValue;
-use sql_schema_describer::{walkers::ColumnWalker, ColumnTypeFamily, DefaultValue};
+use sql_schema_describer::{walkers::ColumnWalker, ColumnType, ColumnTypeFamily, DefaultValue};
 
 #[derive(Debug)]
 pub(crate) struct ColumnDiffer<'a> {
@@ -48,9 +48,19 @@ impl<'a> ColumnDiffer<'a> {
     fn column_type_change(&self) -> Option<ColumnTypeChange> {
         match (self.previous.column_type_family(), self.next.column_type_family()) {
             (ColumnTypeFamily::Decimal, ColumnTypeFamily::Decimal) => None,
-            (ColumnTypeFamily::Decimal, ColumnTypeFamily::Float) => None,
-            (ColumnTypeFamily::Float, ColumnTypeFamily::Decimal) => None,
             (ColumnTypeFamily::Float, ColumnTypeFamily::Float) => None,
+            // BigInt columns are in the Int family, only their full data type tells them apart. SQLite
+            // integers are all 64 bits wide.
+            (ColumnTypeFamily::Int, ColumnTypeFamily::Int) if !self.database_info.sql_family().is_sqlite() => {
+                match (
+                    is_big_int(self.previous.column_type()),
+                    is_big_int(self.next.column_type()),
+                ) {
+                    (false, true) => Some(ColumnTypeChange::SafeCast),
+                    (true, false) => Some(ColumnTypeChange::RiskyCast),
+                    _ => self.flavour.column_type_change(self),
+                }
+            }
             (_, _) => self.flavour.column_type_change(self),
         }
     }
@@ -81,6 +91,16 @@ impl<'a> ColumnDiffer<'a> {
                 Some(DefaultValue::VALUE(PrismaValue::String(next_json))),
             ) => json_defaults_match(prev_json, next_json),
 
+            // Decimal defaults are described as floats.
+            (
+                Some(DefaultValue::VALUE(PrismaValue::Float(prev))),
+                Some(DefaultValue::VALUE(PrismaValue::Decimal(next))),
+            )
+            | (
+                Some(DefaultValue::VALUE(PrismaValue::Decimal(prev))),
+                Some(DefaultValue::VALUE(PrismaValue::Float(next))),
+            ) => prev == next,
+
             (Some(DefaultValue::VALUE(prev)), Some(DefaultValue::VALUE(next))) => prev == next,
             (Some(DefaultValue::VALUE(_)), Some(DefaultValue::NOW)) => false,
             (Some(DefaultValue::VALUE(_)), None) => false,
@@ -109,6 +129,14 @@ impl<'a> ColumnDiffer<'a> {
     }
 }
 
+/// Whether an Int family column is 64 bits wide. The calculated schemas name the type `BIGINT`, the
+/// described schemas `int8` on Postgres and `bigint(20)` on MySQL.
+fn is_big_int(column_type: &ColumnType) -> bool {
+    let full_data_type = column_type.full_data_type.to_lowercase();
+
+    full_data_type.starts_with("bigint") || full_data_type == "int8"
+}
+
 fn json_defaults_match(previous: &str, next: &str) -> bool {
     serde_json::from_str::<serde_json::Value>(previous)
         .and_then(|previous| serde_json::from_str::<serde_json::Value>(next).map(|next| (previous, next)))
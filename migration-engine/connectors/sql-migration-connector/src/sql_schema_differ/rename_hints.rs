@@ -0,0 +1,178 @@
+use crate::sql_migration::{SqlMigration, SqlMigrationStep};
+use migration_connector::{ConnectorError, ConnectorResult, RenameHint};
+use sql_schema_describer::SqlSchema;
+
+/// Apply the rename hints to the previous schema, and return the steps performing the renames.
+///
+/// The rest of the diff is computed against the renamed previous schema, so the steps that come
+/// after the renames refer to the renamed tables and columns by their new names. Hints that do not
+/// match a table or column in both schemas are an error, listing all of them.
+pub(crate) fn apply_rename_hints(
+    previous: &mut SqlSchema,
+    next: &SqlSchema,
+    hints: &[RenameHint],
+) -> ConnectorResult<Vec<SqlMigrationStep>> {
+    let mut steps = Vec::new();
+    let mut unmatched_hints = Vec::new();
+
+    // Tables are renamed first, so column hints can refer to their table by its new name.
+    for hint in hints {
+        if let RenameHint::Table {
+            previous_name,
+            next_name,
+        } = hint
+        {
+            if previous.has_table(previous_name) && !previous.has_table(next_name) && next.has_table(next_name) {
+                rename_table(previous, previous_name, next_name);
+
+                steps.push(SqlMigrationStep::RenameTable {
+                    name: previous_name.clone(),
+                    new_name: next_name.clone(),
+                });
+            } else {
+                unmatched_hints.push(hint);
+            }
+        }
+    }
+
+    for hint in hints {
+        if let RenameHint::Column {
+            table,
+            previous_name,
+            next_name,
+        } = hint
+        {
+            let applies = match (previous.get_table(table), next.get_table(table)) {
+                (Some(previous_table), Some(next_table)) => {
+                    previous_table.has_column(previous_name)
+                        && !previous_table.has_column(next_name)
+                        && next_table.has_column(next_name)
+                }
+                _ => false,
+            };
+
+            if applies {
+                rename_column(previous, table, previous_name, next_name);
+
+                steps.push(SqlMigrationStep::RenameColumn {
+                    table: table.clone(),
+                    name: previous_name.clone(),
+                    new_name: next_name.clone(),
+                });
+            } else {
+                unmatched_hints.push(hint);
+            }
+        }
+    }
+
+    if !unmatched_hints.is_empty() {
+        return Err(ConnectorError::generic(anyhow::anyhow!(
+            "The following rename hints do not match a table or column of the previous and the next schema: {}.",
+            unmatched_hints
+                .iter()
+                .map(|hint| hint.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )));
+    }
+
+    Ok(steps)
+}
+
+/// Revert the renames performed by the steps of `migration`. Returns the schema the migration
+/// started from, and the rename hints taking the end state of the migration back to it.
+pub(crate) fn reverse_renames(migration: &SqlMigration) -> (SqlSchema, Vec<RenameHint>) {
+    let mut original_schema = migration.before.clone();
+    let mut hints = Vec::new();
+
+    // Columns are renamed back first, because they refer to their table by its new name.
+    for step in migration.steps.iter().rev() {
+        if let SqlMigrationStep::RenameColumn { table, name, new_name } = step {
+            rename_column(&mut original_schema, table, new_name, name);
+
+            let original_table_name = migration
+                .steps
+                .iter()
+                .find_map(|step| match step {
+                    SqlMigrationStep::RenameTable {
+                        name: original_name,
+                        new_name,
+                    } if new_name == table => Some(original_name),
+                    _ => None,
+                })
+                .unwrap_or(table);
+
+            hints.push(RenameHint::Column {
+                table: original_table_name.clone(),
+                previous_name: new_name.clone(),
+                next_name: name.clone(),
+            });
+        }
+    }
+
+    for step in migration.steps.iter().rev() {
+        if let SqlMigrationStep::RenameTable { name, new_name } = step {
+            rename_table(&mut original_schema, new_name, name);
+
+            hints.push(RenameHint::Table {
+                previous_name: new_name.clone(),
+                next_name: name.clone(),
+            });
+        }
+    }
+
+    (original_schema, hints)
+}
+
+fn rename_table(schema: &mut SqlSchema, previous_name: &str, next_name: &str) {
+    for table in schema.tables.iter_mut() {
+        if table.name == previous_name {
+            table.name = next_name.to_owned();
+        }
+
+        for foreign_key in table.foreign_keys.iter_mut() {
+            if foreign_key.referenced_table == previous_name {
+                foreign_key.referenced_table = next_name.to_owned();
+            }
+        }
+    }
+}
+
+fn rename_column(schema: &mut SqlSchema, table_name: &str, previous_name: &str, next_name: &str) {
+    let rename = |column_names: &mut Vec<String>| {
+        for column_name in column_names
+            .iter_mut()
+            .filter(|column_name| *column_name == previous_name)
+        {
+            *column_name = next_name.to_owned();
+        }
+    };
+
+    for table in schema.tables.iter_mut() {
+        if table.name == table_name {
+            for column in table.columns.iter_mut().filter(|column| column.name == previous_name) {
+                column.name = next_name.to_owned();
+            }
+
+            for index in table.indices.iter_mut() {
+                rename(&mut index.columns);
+            }
+
+            if let Some(primary_key) = table.primary_key.as_mut() {
+                rename(&mut primary_key.columns);
+            }
+
+            for foreign_key in table.foreign_keys.iter_mut() {
+                rename(&mut foreign_key.columns);
+            }
+        }
+
+        for foreign_key in table
+            .foreign_keys
+            .iter_mut()
+            .filter(|foreign_key| foreign_key.referenced_table == table_name)
+        {
+            rename(&mut foreign_key.referenced_columns);
+        }
+    }
+}
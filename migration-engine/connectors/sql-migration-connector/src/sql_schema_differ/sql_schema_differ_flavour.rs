@@ -1,4 +1,4 @@
-use sql_schema_describer::walkers::IndexWalker;
+use sql_schema_describer::walkers::{ForeignKeyWalker, IndexWalker};
 
 use super::{ColumnDiffer, ColumnTypeChange, SqlSchemaDiffer};
 use crate::sql_migration::AlterEnum;
@@ -30,6 +30,11 @@ pub(crate) trait SqlSchemaDifferFlavour {
         previous.name() != next.name()
     }
 
+    /// Return whether two foreign keys have the same referential actions.
+    fn referential_actions_match(&self, previous: &ForeignKeyWalker<'_>, next: &ForeignKeyWalker<'_>) -> bool {
+        previous.on_delete_action() == next.on_delete_action() && previous.on_update_action() == next.on_update_action()
+    }
+
     /// Whether `AddForeignKey` steps should be generated for created tables.
     fn should_push_foreign_keys_from_created_tables(&self) -> bool {
         true
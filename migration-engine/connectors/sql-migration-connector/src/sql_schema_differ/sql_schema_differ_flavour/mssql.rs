@@ -1,4 +1,15 @@
 use super::SqlSchemaDifferFlavour;
 use crate::flavour::MssqlFlavour;
+use sql_schema_describer::walkers::ForeignKeyWalker;
 
-impl SqlSchemaDifferFlavour for MssqlFlavour {}
+impl SqlSchemaDifferFlavour for MssqlFlavour {
+    fn referential_actions_match(&self, previous: &ForeignKeyWalker<'_>, next: &ForeignKeyWalker<'_>) -> bool {
+        // Self-relations are always rendered with `NO ACTION`, because SQL Server
+        // rejects cascading actions that could form cycles.
+        let is_self_relation = next.table().name() == next.referenced_table().name();
+
+        is_self_relation
+            || (previous.on_delete_action() == next.on_delete_action()
+                && previous.on_update_action() == next.on_update_action())
+    }
+}
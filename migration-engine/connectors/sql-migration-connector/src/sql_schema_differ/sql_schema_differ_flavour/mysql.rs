@@ -3,7 +3,10 @@ use crate::{
     flavour::MysqlFlavour, flavour::MYSQL_IDENTIFIER_SIZE_LIMIT, sql_schema_differ::ColumnDiffer,
     sql_schema_differ::ColumnTypeChange,
 };
-use sql_schema_describer::{walkers::IndexWalker, ColumnTypeFamily};
+use sql_schema_describer::{
+    walkers::{ForeignKeyWalker, IndexWalker},
+    ColumnTypeFamily, ForeignKeyAction,
+};
 
 /// On MariaDB, JSON is an alias for LONGTEXT. https://mariadb.com/kb/en/json-data-type/
 const MARIADB_ALIASES: &[ColumnTypeFamily] = &[ColumnTypeFamily::String, ColumnTypeFamily::Json];
@@ -55,4 +58,17 @@ impl SqlSchemaDifferFlavour for MysqlFlavour {
             previous.name() != next.name()
         }
     }
+
+    fn referential_actions_match(&self, previous: &ForeignKeyWalker<'_>, next: &ForeignKeyWalker<'_>) -> bool {
+        // MySQL treats `NO ACTION` as `RESTRICT`, and depending on the version reports either.
+        fn normalize(action: &ForeignKeyAction) -> &ForeignKeyAction {
+            match action {
+                ForeignKeyAction::NoAction => &ForeignKeyAction::Restrict,
+                action => action,
+            }
+        }
+
+        normalize(previous.on_delete_action()) == normalize(next.on_delete_action())
+            && normalize(previous.on_update_action()) == normalize(next.on_update_action())
+    }
 }
@@ -1,17 +1,15 @@
 use super::SqlSchemaDifferFlavour;
 use crate::{
-    flavour::PostgresFlavour, sql_migration::AlterEnum, sql_schema_differ::column::ColumnTypeChange,
-    sql_schema_differ::ColumnDiffer, sql_schema_differ::SqlSchemaDiffer,
+    flavour::{PostgresFlavour, POSTGRES_IDENTIFIER_SIZE_LIMIT},
+    sql_migration::AlterEnum,
+    sql_schema_differ::column::ColumnTypeChange,
+    sql_schema_differ::ColumnDiffer,
+    sql_schema_differ::SqlSchemaDiffer,
 };
 use once_cell::sync::Lazy;
 use regex::RegexSet;
 use sql_schema_describer::{walkers::IndexWalker, ColumnTypeFamily};
 
-/// The maximum length of postgres identifiers, in bytes.
-///
-/// Reference: https://www.postgresql.org/docs/12/limits.html
-const POSTGRES_IDENTIFIER_SIZE_LIMIT: usize = 63;
-
 impl SqlSchemaDifferFlavour for PostgresFlavour {
     fn alter_enums(&self, differ: &SqlSchemaDiffer<'_>) -> Vec<AlterEnum> {
         differ
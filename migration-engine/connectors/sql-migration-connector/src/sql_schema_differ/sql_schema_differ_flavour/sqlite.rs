@@ -29,6 +29,9 @@ impl SqlSchemaDifferFlavour for SqliteFlavour {
                     || differ.index_pairs().any(|(previous, next)| self.index_should_be_renamed(&previous, &next))
                     || differ.created_foreign_keys().next().is_some()
                     || differ.dropped_foreign_keys().next().is_some()
+                    // SQLite can only add or drop check constraints by redefining the table
+                    || differ.created_checks().next().is_some()
+                    || differ.dropped_checks().next().is_some()
             })
             .map(|table| table.next.name().to_owned())
             .collect()
@@ -2,7 +2,7 @@ use super::column::ColumnDiffer;
 use crate::{database_info::DatabaseInfo, flavour::SqlFlavour};
 use sql_schema_describer::{
     walkers::{ColumnWalker, ForeignKeyWalker, IndexWalker, TableWalker},
-    PrimaryKey,
+    CheckConstraint, PrimaryKey,
 };
 
 pub(crate) struct TableDiffer<'a> {
@@ -56,7 +56,7 @@ impl<'schema> TableDiffer<'schema> {
     pub(crate) fn created_foreign_keys<'a>(&'a self) -> impl Iterator<Item = ForeignKeyWalker<'schema>> + 'a {
         self.next_foreign_keys().filter(move |next_fk| {
             self.previous_foreign_keys()
-                .find(|previous_fk| super::foreign_keys_match(previous_fk, next_fk))
+                .find(|previous_fk| self.foreign_keys_match(previous_fk, next_fk))
                 .is_none()
         })
     }
@@ -64,11 +64,15 @@ impl<'schema> TableDiffer<'schema> {
     pub(crate) fn dropped_foreign_keys<'a>(&'a self) -> impl Iterator<Item = ForeignKeyWalker<'schema>> + 'a {
         self.previous_foreign_keys().filter(move |previous_fk| {
             self.next_foreign_keys()
-                .find(|next_fk| super::foreign_keys_match(previous_fk, next_fk))
+                .find(|next_fk| self.foreign_keys_match(previous_fk, next_fk))
                 .is_none()
         })
     }
 
+    fn foreign_keys_match(&self, previous: &ForeignKeyWalker<'_>, next: &ForeignKeyWalker<'_>) -> bool {
+        super::foreign_keys_match(previous, next) && self.flavour.referential_actions_match(previous, next)
+    }
+
     pub(crate) fn created_indexes<'a>(&'a self) -> impl Iterator<Item = IndexWalker<'schema>> + 'a {
         self.next_indexes().filter(move |next_index| {
             !self
@@ -93,6 +97,21 @@ impl<'schema> TableDiffer<'schema> {
         })
     }
 
+    pub(crate) fn created_checks<'a>(&'a self) -> impl Iterator<Item = &'schema CheckConstraint> + 'a {
+        self.next.checks().filter(move |next_check| {
+            !self
+                .previous
+                .checks()
+                .any(|previous_check| previous_check.matches(next_check))
+        })
+    }
+
+    pub(crate) fn dropped_checks<'a>(&'a self) -> impl Iterator<Item = &'schema CheckConstraint> + 'a {
+        self.previous
+            .checks()
+            .filter(move |previous_check| !self.next.checks().any(|next_check| previous_check.matches(next_check)))
+    }
+
     /// The primary key present in `next` but not `previous`, if applicable.
     pub(crate) fn created_primary_key(&self) -> Option<&'schema PrimaryKey> {
         match (self.previous.primary_key(), self.next.primary_key()) {
@@ -43,6 +43,7 @@ pub trait GenericApi: Send + Sync + 'static {
     async fn version(&self, input: &serde_json::Value) -> CoreResult<String>;
     async fn apply_migration(&self, input: &ApplyMigrationInput) -> CoreResult<MigrationStepsResultOutput>;
     async fn apply_migrations(&self, input: &ApplyMigrationsInput) -> CoreResult<ApplyMigrationsOutput>;
+    async fn baseline(&self, input: &BaselineInput) -> CoreResult<BaselineOutput>;
     async fn calculate_database_steps(
         &self,
         input: &CalculateDatabaseStepsInput,
@@ -54,14 +55,24 @@ pub trait GenericApi: Send + Sync + 'static {
         &self,
         input: &DiagnoseMigrationHistoryInput,
     ) -> CoreResult<DiagnoseMigrationHistoryOutput>;
+    async fn diff(&self, input: &DiffInput) -> CoreResult<DiffOutput>;
     async fn evaluate_data_loss(&self, input: &EvaluateDataLossInput) -> CoreResult<EvaluateDataLossOutput>;
     async fn infer_migration_steps(&self, input: &InferMigrationStepsInput) -> CoreResult<MigrationStepsResultOutput>;
     async fn initialize(&self, input: &InitializeInput) -> CoreResult<InitializeOutput>;
+    async fn lint_migrations(&self, input: &LintMigrationsInput) -> CoreResult<LintMigrationsOutput>;
     async fn list_migrations(&self, input: &serde_json::Value) -> CoreResult<Vec<ListMigrationsOutput>>;
+    async fn mark_migration_applied(&self, input: &MarkMigrationAppliedInput)
+        -> CoreResult<MarkMigrationAppliedOutput>;
+    async fn mark_migration_rolled_back(
+        &self,
+        input: &MarkMigrationRolledBackInput,
+    ) -> CoreResult<MarkMigrationRolledBackOutput>;
     async fn migration_progress(&self, input: &MigrationProgressInput) -> CoreResult<MigrationProgressOutput>;
     async fn plan_migration(&self, input: &PlanMigrationInput) -> CoreResult<PlanMigrationOutput>;
     async fn reset(&self, input: &()) -> CoreResult<()>;
+    async fn rollback_migrations(&self, input: &RollbackMigrationsInput) -> CoreResult<RollbackMigrationsOutput>;
     async fn schema_push(&self, input: &SchemaPushInput) -> CoreResult<SchemaPushOutput>;
+    async fn squash_migrations(&self, input: &SquashMigrationsInput) -> CoreResult<SquashMigrationsOutput>;
     async fn unapply_migration(&self, input: &UnapplyMigrationInput) -> CoreResult<UnapplyMigrationOutput>;
 }
 
@@ -92,6 +103,15 @@ where
             .await
     }
 
+    async fn baseline(&self, input: &BaselineInput) -> CoreResult<BaselineOutput> {
+        self.handle_command::<BaselineCommand>(input)
+            .instrument(tracing::info_span!(
+                "Baseline",
+                migration_name = input.migration_name.as_str()
+            ))
+            .await
+    }
+
     async fn calculate_database_steps(
         &self,
         input: &CalculateDatabaseStepsInput,
@@ -132,6 +152,12 @@ where
             .await
     }
 
+    async fn diff(&self, input: &DiffInput) -> CoreResult<DiffOutput> {
+        self.handle_command::<DiffCommand>(input)
+            .instrument(tracing::info_span!("Diff", script = input.script))
+            .await
+    }
+
     async fn evaluate_data_loss(&self, input: &EvaluateDataLossInput) -> CoreResult<EvaluateDataLossOutput> {
         self.handle_command::<EvaluateDataLoss>(input)
             .instrument(tracing::info_span!("EvaluateDataLoss"))
@@ -156,12 +182,45 @@ where
             .await
     }
 
+    async fn lint_migrations(&self, input: &LintMigrationsInput) -> CoreResult<LintMigrationsOutput> {
+        self.handle_command::<LintMigrationsCommand>(input)
+            .instrument(tracing::info_span!(
+                "LintMigrations",
+                migrations_directory_path = input.migrations_directory_path.as_str()
+            ))
+            .await
+    }
+
     async fn list_migrations(&self, input: &serde_json::Value) -> CoreResult<Vec<ListMigrationsOutput>> {
         self.handle_command::<ListMigrationsCommand>(input)
             .instrument(tracing::info_span!("ListMigrations"))
             .await
     }
 
+    async fn mark_migration_applied(
+        &self,
+        input: &MarkMigrationAppliedInput,
+    ) -> CoreResult<MarkMigrationAppliedOutput> {
+        self.handle_command::<MarkMigrationAppliedCommand>(input)
+            .instrument(tracing::info_span!(
+                "MarkMigrationApplied",
+                migration_name = input.migration_name.as_str()
+            ))
+            .await
+    }
+
+    async fn mark_migration_rolled_back(
+        &self,
+        input: &MarkMigrationRolledBackInput,
+    ) -> CoreResult<MarkMigrationRolledBackOutput> {
+        self.handle_command::<MarkMigrationRolledBackCommand>(input)
+            .instrument(tracing::info_span!(
+                "MarkMigrationRolledBack",
+                migration_name = input.migration_name.as_str()
+            ))
+            .await
+    }
+
     async fn migration_progress(&self, input: &MigrationProgressInput) -> CoreResult<MigrationProgressOutput> {
         self.handle_command::<MigrationProgressCommand>(input)
             .instrument(tracing::info_span!(
@@ -183,12 +242,27 @@ where
             .await
     }
 
+    async fn rollback_migrations(&self, input: &RollbackMigrationsInput) -> CoreResult<RollbackMigrationsOutput> {
+        self.handle_command::<RollbackMigrationsCommand>(input)
+            .instrument(tracing::info_span!("RollbackMigrations"))
+            .await
+    }
+
     async fn schema_push(&self, input: &SchemaPushInput) -> CoreResult<SchemaPushOutput> {
         self.handle_command::<SchemaPushCommand>(input)
             .instrument(tracing::info_span!("SchemaPush"))
             .await
     }
 
+    async fn squash_migrations(&self, input: &SquashMigrationsInput) -> CoreResult<SquashMigrationsOutput> {
+        self.handle_command::<SquashMigrationsCommand>(input)
+            .instrument(tracing::info_span!(
+                "SquashMigrations",
+                migration_name = input.migration_name.as_str()
+            ))
+            .await
+    }
+
     async fn unapply_migration(&self, input: &UnapplyMigrationInput) -> CoreResult<UnapplyMigrationOutput> {
         self.handle_command::<UnapplyMigrationCommand<'_>>(input)
             .instrument(tracing::info_span!("UnapplyMigration"))
@@ -14,19 +14,26 @@ pub struct RpcApi {
 enum RpcCommand {
     GetDatabaseVersion,
     ApplyMigrations,
+    Baseline,
     CreateMigration,
     DebugPanic,
     DiagnoseMigrationHistory,
+    Diff,
     EvaluateDataLoss,
     InferMigrationSteps,
     Initialize,
+    LintMigrations,
     ListMigrations,
+    MarkMigrationApplied,
+    MarkMigrationRolledBack,
     MigrationProgress,
     PlanMigration,
     ApplyMigration,
     UnapplyMigration,
     Reset,
+    RollbackMigrations,
     SchemaPush,
+    SquashMigrations,
     CalculateDatamodel,
     CalculateDatabaseSteps,
 }
@@ -36,19 +43,26 @@ impl RpcCommand {
         match self {
             RpcCommand::GetDatabaseVersion => "getDatabaseVersion",
             RpcCommand::ApplyMigrations => "applyMigrations",
+            RpcCommand::Baseline => "baseline",
             RpcCommand::CreateMigration => "createMigration",
             RpcCommand::DebugPanic => "debugPanic",
             RpcCommand::DiagnoseMigrationHistory => "diagnoseMigrationHistory",
+            RpcCommand::Diff => "diff",
             RpcCommand::EvaluateDataLoss => "evaluateDataLoss",
             RpcCommand::InferMigrationSteps => "inferMigrationSteps",
+            RpcCommand::LintMigrations => "lintMigrations",
             RpcCommand::ListMigrations => "listMigrations",
+            RpcCommand::MarkMigrationApplied => "markMigrationApplied",
+            RpcCommand::MarkMigrationRolledBack => "markMigrationRolledBack",
             RpcCommand::MigrationProgress => "migrationProgress",
             RpcCommand::ApplyMigration => "applyMigration",
             RpcCommand::UnapplyMigration => "unapplyMigration",
             RpcCommand::Initialize => "initialize",
             RpcCommand::PlanMigration => "planMigration",
             RpcCommand::Reset => "reset",
+            RpcCommand::RollbackMigrations => "rollbackMigrations",
             RpcCommand::SchemaPush => "schemaPush",
+            RpcCommand::SquashMigrations => "squashMigrations",
             RpcCommand::CalculateDatamodel => "calculateDatamodel",
             RpcCommand::CalculateDatabaseSteps => "calculateDatabaseSteps",
         }
@@ -59,18 +73,25 @@ const AVAILABLE_COMMANDS: &[RpcCommand] = &[
     RpcCommand::GetDatabaseVersion,
     RpcCommand::ApplyMigration,
     RpcCommand::ApplyMigrations,
+    RpcCommand::Baseline,
     RpcCommand::CreateMigration,
     RpcCommand::DiagnoseMigrationHistory,
+    RpcCommand::Diff,
     RpcCommand::EvaluateDataLoss,
     RpcCommand::DebugPanic,
     RpcCommand::InferMigrationSteps,
     RpcCommand::Initialize,
+    RpcCommand::LintMigrations,
     RpcCommand::ListMigrations,
+    RpcCommand::MarkMigrationApplied,
+    RpcCommand::MarkMigrationRolledBack,
     RpcCommand::MigrationProgress,
     RpcCommand::PlanMigration,
     RpcCommand::UnapplyMigration,
     RpcCommand::Reset,
+    RpcCommand::RollbackMigrations,
     RpcCommand::SchemaPush,
+    RpcCommand::SquashMigrations,
     RpcCommand::CalculateDatamodel,
     RpcCommand::CalculateDatabaseSteps,
 ];
@@ -124,6 +145,7 @@ impl RpcApi {
         tracing::debug!(?cmd, "running the command");
         match cmd {
             RpcCommand::ApplyMigrations => render(executor.apply_migrations(&params.parse()?).await?),
+            RpcCommand::Baseline => render(executor.baseline(&params.parse()?).await?),
             RpcCommand::CreateMigration => render(executor.create_migration(&params.parse()?).await?),
             RpcCommand::DebugPanic => render(executor.debug_panic(&()).await?),
             RpcCommand::ApplyMigration => render(executor.apply_migration(&params.parse()?).await?),
@@ -132,15 +154,21 @@ impl RpcApi {
             RpcCommand::DiagnoseMigrationHistory => {
                 render(executor.diagnose_migration_history(&params.parse()?).await?)
             }
+            RpcCommand::Diff => render(executor.diff(&params.parse()?).await?),
             RpcCommand::EvaluateDataLoss => render(executor.evaluate_data_loss(&params.parse()?).await?),
             RpcCommand::GetDatabaseVersion => render(executor.version(&serde_json::Value::Null).await?),
             RpcCommand::InferMigrationSteps => render(executor.infer_migration_steps(&params.parse()?).await?),
             RpcCommand::Initialize => render(executor.initialize(&params.parse()?).await?),
+            RpcCommand::LintMigrations => render(executor.lint_migrations(&params.parse()?).await?),
             RpcCommand::ListMigrations => render(executor.list_migrations(&serde_json::Value::Null).await?),
+            RpcCommand::MarkMigrationApplied => render(executor.mark_migration_applied(&params.parse()?).await?),
+            RpcCommand::MarkMigrationRolledBack => render(executor.mark_migration_rolled_back(&params.parse()?).await?),
             RpcCommand::MigrationProgress => render(executor.migration_progress(&params.parse()?).await?),
             RpcCommand::PlanMigration => render(executor.plan_migration(&params.parse()?).await?),
             RpcCommand::Reset => render(executor.reset(&()).await?),
+            RpcCommand::RollbackMigrations => render(executor.rollback_migrations(&params.parse()?).await?),
             RpcCommand::SchemaPush => render(executor.schema_push(&params.parse()?).await?),
+            RpcCommand::SquashMigrations => render(executor.squash_migrations(&params.parse()?).await?),
             RpcCommand::UnapplyMigration => render(executor.unapply_migration(&params.parse()?).await?),
         }
     }
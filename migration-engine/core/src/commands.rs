@@ -6,6 +6,7 @@
 #[allow(missing_docs)]
 mod apply_migration;
 mod apply_migrations;
+mod baseline;
 #[allow(missing_docs)]
 mod calculate_database_steps;
 #[allow(missing_docs)]
@@ -14,23 +15,30 @@ mod command;
 mod create_migration;
 mod debug_panic;
 mod diagnose_migration_history;
+mod diff;
 mod evaluate_data_loss;
 mod get_database_version;
 #[allow(missing_docs)]
 mod infer_migration_steps;
 mod initialize;
+mod lint_migrations;
 #[allow(missing_docs)]
 mod list_migrations;
+mod mark_migration_applied;
+mod mark_migration_rolled_back;
 #[allow(missing_docs)]
 mod migration_progress;
 mod plan_migration;
 mod reset;
+mod rollback_migrations;
 mod schema_push;
+mod squash_migrations;
 #[allow(missing_docs)]
 mod unapply_migration;
 
 pub use apply_migration::*;
 pub use apply_migrations::{ApplyMigrationsCommand, ApplyMigrationsInput, ApplyMigrationsOutput};
+pub use baseline::{BaselineCommand, BaselineInput, BaselineOutput};
 pub use calculate_database_steps::*;
 pub use calculate_datamodel::*;
 pub use command::MigrationCommand;
@@ -40,15 +48,23 @@ pub use diagnose_migration_history::{
     DiagnoseMigrationHistoryCommand, DiagnoseMigrationHistoryInput, DiagnoseMigrationHistoryOutput, DriftDiagnostic,
     HistoryDiagnostic,
 };
+pub use diff::{DiffCommand, DiffInput, DiffOutput, DiffSource};
 pub use evaluate_data_loss::*;
 pub use get_database_version::*;
 pub use infer_migration_steps::*;
 pub use initialize::{InitializeCommand, InitializeInput, InitializeOutput};
+pub use lint_migrations::{LintMigrationsCommand, LintMigrationsInput, LintMigrationsOutput, MigrationLintDiagnostic};
 pub use list_migrations::*;
+pub use mark_migration_applied::{MarkMigrationAppliedCommand, MarkMigrationAppliedInput, MarkMigrationAppliedOutput};
+pub use mark_migration_rolled_back::{
+    MarkMigrationRolledBackCommand, MarkMigrationRolledBackInput, MarkMigrationRolledBackOutput,
+};
 pub use migration_progress::*;
 pub use plan_migration::{PlanMigrationCommand, PlanMigrationInput, PlanMigrationOutput};
 pub use reset::ResetCommand;
+pub use rollback_migrations::{RollbackMigrationsCommand, RollbackMigrationsInput, RollbackMigrationsOutput};
 pub use schema_push::{SchemaPushCommand, SchemaPushInput, SchemaPushOutput};
+pub use squash_migrations::{SquashMigrationsCommand, SquashMigrationsInput, SquashMigrationsOutput};
 pub use unapply_migration::*;
 
 use migration_connector::{MigrationStep, MigrationWarning, PrettyDatabaseMigrationStep, UnexecutableMigration};
@@ -1,6 +1,9 @@
-use super::MigrationCommand;
+use super::{squash_migrations::record_squashed_migration, MigrationCommand};
 use crate::{migration_engine::MigrationEngine, CoreError, CoreResult};
-use migration_connector::{ConnectorError, MigrationDirectory, MigrationRecord, PersistenceNotInitializedError};
+use migration_connector::{
+    ConnectorError, DestructiveChangePolicy, MigrationDirectory, MigrationRecord, MigrationStepScript,
+    PersistenceNotInitializedError, MIGRATION_SCRIPT_FILENAME,
+};
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 
@@ -42,15 +45,47 @@ impl<'a> MigrationCommand for ApplyMigrationsCommand {
 
         migration_persistence.initialize().await?;
 
-        let migrations_from_filesystem =
-            migration_connector::list_migrations(&Path::new(&input.migrations_directory_path))?;
-        let migrations_from_database = migration_persistence
+        let migrations_directory_path = Path::new(&input.migrations_directory_path);
+        let migrations_from_filesystem = migration_connector::list_migrations(&migrations_directory_path)?;
+        let policy = DestructiveChangePolicy::load(&migrations_directory_path)?;
+        // Rolled back migrations can be applied again.
+        let migrations_from_database: Vec<MigrationRecord> = migration_persistence
             .list_migrations()
             .await?
-            .map_err(PersistenceNotInitializedError::into_connector_error)?;
+            .map_err(PersistenceNotInitializedError::into_connector_error)?
+            .into_iter()
+            .filter(|db_migration| !db_migration.is_rolled_back())
+            .collect();
+
+        // Failed migrations with data scripts are resumed from the script that
+        // failed, so a failed backfill can be fixed and retried without
+        // rerunning the schema change.
+        let (resumable_migrations, migrations_from_database): (Vec<MigrationRecord>, Vec<MigrationRecord>) =
+            migrations_from_database.into_iter().partition(|db_migration| {
+                db_migration.is_failed()
+                    && db_migration.failed_step_name.is_some()
+                    && migrations_from_filesystem.iter().any(|fs_migration| {
+                        fs_migration.migration_name() == db_migration.migration_name && fs_migration.has_data_scripts()
+                    })
+            });
 
         diagnose_migration_history(&migrations_from_database, &migrations_from_filesystem)?;
 
+        // The schema changes that are not applied yet are checked against the
+        // policy before anything is applied, each with the policy annotations
+        // of its own script.
+        let mut pending_migrations: Vec<(usize, DestructiveChangePolicy)> = Vec::new();
+
+        for (index, fs_migration) in migrations_from_filesystem.iter().enumerate() {
+            if schema_change_is_pending(fs_migration, &migrations_from_database, &resumable_migrations)? {
+                let script = fs_migration.read_migration_script().map_err(ConnectorError::from)?;
+
+                pending_migrations.push((index, policy.with_script_annotations(&script)?));
+            }
+        }
+
+        check_policy(engine, &migrations_from_filesystem, &pending_migrations).await?;
+
         // We are now on the Happy Path™.
         tracing::debug!("Migration history is OK, applying unapplied migrations.");
         let unapplied_migrations: Vec<&MigrationDirectory> = migrations_from_filesystem
@@ -65,39 +100,109 @@ impl<'a> MigrationCommand for ApplyMigrationsCommand {
         let mut applied_migration_names: Vec<String> = Vec::with_capacity(unapplied_migrations.len());
 
         for unapplied_migration in unapplied_migrations {
+            let steps = unapplied_migration
+                .read_migration_steps()
+                .map_err(ConnectorError::from)?;
             let script = unapplied_migration
                 .read_migration_script()
                 .map_err(ConnectorError::from)?;
 
-            tracing::info!(
-                script = script.as_str(),
-                "Applying `{}`",
-                unapplied_migration.migration_name()
-            );
+            let resumed_migration = resumable_migrations
+                .iter()
+                .find(|db_migration| db_migration.migration_name == unapplied_migration.migration_name());
+            let first_step_index = match resumed_migration {
+                Some(resumed_migration) => failed_step_index(&steps, resumed_migration)?,
+                None => 0,
+            };
+
+            // A squashed migration replacing applied migrations is recorded
+            // as applied in their place, without running its script.
+            let squashed_migration_names = unapplied_migration
+                .read_squashed_migration_names()
+                .map_err(ConnectorError::from)?;
+            let replaced_migrations: Vec<&MigrationRecord> = migrations_from_database
+                .iter()
+                .filter(|db_migration| squashed_migration_names.contains(&db_migration.migration_name))
+                .collect();
+
+            if resumed_migration.is_none() && !replaced_migrations.is_empty() {
+                if replaced_migrations.len() != squashed_migration_names.len() {
+                    return Err(CoreError::Generic(anyhow::anyhow!(
+                        "The squashed migration `{}` replaces migrations that are only partially applied to the database.",
+                        unapplied_migration.migration_name()
+                    )));
+                }
+
+                tracing::info!(
+                    "Recording the squashed migration `{}` as applied",
+                    unapplied_migration.migration_name()
+                );
 
-            let migration_id = migration_persistence
-                .record_migration_started(unapplied_migration.migration_name(), &script)
+                record_squashed_migration(
+                    migration_persistence,
+                    unapplied_migration,
+                    &script,
+                    &replaced_migrations,
+                )
                 .await?;
 
-            match applier.apply_script(&script).await {
-                Ok(()) => {
-                    tracing::debug!("Successfully applied the script.");
+                continue;
+            }
+
+            let migration_id = match resumed_migration {
+                Some(resumed_migration) => {
+                    tracing::info!(
+                        "Resuming `{}` from the `{}` script",
+                        unapplied_migration.migration_name(),
+                        steps[first_step_index].name
+                    );
+
+                    // The failed script may have been fixed since.
+                    let checksum = unapplied_migration.scripts_checksum().map_err(ConnectorError::from)?;
+
                     migration_persistence
-                        .record_successful_step(&migration_id, &script)
+                        .record_migration_resumed(&resumed_migration.id, &checksum)
                         .await?;
-                    migration_persistence.record_migration_finished(&migration_id).await?;
-                    applied_migration_names.push(unapplied_migration.migration_name().to_owned());
-                }
-                Err(err) => {
-                    tracing::debug!("Failed to apply the script.");
 
-                    let logs = format!("script:\n{}\n\nerror:\n{}", script, err);
+                    resumed_migration.id.clone()
+                }
+                None => {
+                    tracing::info!(
+                        script = script.as_str(),
+                        "Applying `{}`",
+                        unapplied_migration.migration_name()
+                    );
 
-                    migration_persistence.record_failed_step(&migration_id, &logs).await?;
+                    migration_persistence
+                        .record_migration_directory_started(unapplied_migration)
+                        .await?
+                }
+            };
 
-                    return Err(err.into()); // todo: give more context
+            for step in steps.iter().skip(first_step_index) {
+                match applier.apply_script(&step.script).await {
+                    Ok(()) => {
+                        tracing::debug!("Successfully applied the `{}` script.", step.name);
+                        migration_persistence
+                            .record_successful_step(&migration_id, &step.script)
+                            .await?;
+                    }
+                    Err(err) => {
+                        tracing::debug!("Failed to apply the `{}` script.", step.name);
+
+                        let logs = format!("{} script:\n{}\n\nerror:\n{}", step.name, step.script, err);
+
+                        migration_persistence
+                            .record_failed_step(&migration_id, step.name, &logs)
+                            .await?;
+
+                        return Err(err.into()); // todo: give more context
+                    }
                 }
             }
+
+            migration_persistence.record_migration_finished(&migration_id).await?;
+            applied_migration_names.push(unapplied_migration.migration_name().to_owned());
         }
 
         Ok(ApplyMigrationsOutput {
@@ -106,6 +211,119 @@ impl<'a> MigrationCommand for ApplyMigrationsCommand {
     }
 }
 
+/// Whether the migration script of a migration from the migrations directory
+/// is still to be applied to the database.
+fn schema_change_is_pending(
+    fs_migration: &MigrationDirectory,
+    migrations_from_database: &[MigrationRecord],
+    resumable_migrations: &[MigrationRecord],
+) -> CoreResult<bool> {
+    let is_recorded = |migration_name: &str| {
+        migrations_from_database
+            .iter()
+            .any(|db_migration| db_migration.migration_name == migration_name)
+    };
+
+    if let Some(resumed_migration) = resumable_migrations
+        .iter()
+        .find(|db_migration| db_migration.migration_name == fs_migration.migration_name())
+    {
+        let steps = fs_migration.read_migration_steps().map_err(ConnectorError::from)?;
+
+        return Ok(steps
+            .iter()
+            .skip(failed_step_index(&steps, resumed_migration)?)
+            .any(|step| step.name == MIGRATION_SCRIPT_FILENAME));
+    }
+
+    if is_recorded(fs_migration.migration_name()) {
+        return Ok(false);
+    }
+
+    // Squashed migrations replacing applied migrations are not applied.
+    let squashed_migration_names = fs_migration
+        .read_squashed_migration_names()
+        .map_err(ConnectorError::from)?;
+
+    Ok(!squashed_migration_names.iter().any(|name| is_recorded(name.as_str())))
+}
+
+/// The index of the step a failed migration is resumed from: the script that
+/// failed.
+fn failed_step_index(steps: &[MigrationStepScript], resumed_migration: &MigrationRecord) -> CoreResult<usize> {
+    let failed_step_name = resumed_migration.failed_step_name.as_deref().unwrap_or_default();
+
+    steps
+        .iter()
+        .position(|step| step.name == failed_step_name)
+        .ok_or_else(|| {
+            CoreError::Generic(anyhow::anyhow!(
+                "The `{}` script that failed in the migration `{}` is not in the migrations directory anymore.",
+                failed_step_name,
+                resumed_migration.migration_name
+            ))
+        })
+}
+
+/// Run the destructive change checks configured by the policy on the schema
+/// change of each pending migration, given by its index in `migrations` with
+/// the policy extended by its own annotations, against the current state of
+/// the database. Unexecutable steps prevent the migrations from being applied,
+/// warnings are only logged.
+///
+/// The checks only run when there is a policy or policy annotations in the
+/// pending migration scripts, since they require replaying the migrations on a
+/// temporary database. The history is replayed once for all the pending
+/// migrations.
+async fn check_policy<C, D>(
+    engine: &MigrationEngine<C, D>,
+    migrations: &[MigrationDirectory],
+    pending_migrations: &[(usize, DestructiveChangePolicy)],
+) -> CoreResult<()>
+where
+    C: migration_connector::MigrationConnector<DatabaseMigration = D>,
+    D: migration_connector::DatabaseMigrationMarker + Send + Sync + 'static,
+{
+    if pending_migrations.iter().all(|(_, policy)| policy.is_default()) {
+        return Ok(());
+    }
+
+    let connector = engine.connector();
+    let first_pending_index = pending_migrations[0].0;
+    let history_migrations = connector
+        .database_migration_inferrer()
+        .infer_history_migrations(migrations, first_pending_index)
+        .await?;
+
+    for (index, policy) in pending_migrations {
+        let migration_name = migrations[*index].migration_name();
+        let diagnostics = connector
+            .destructive_change_checker()
+            .check_with_policy(&history_migrations[index - first_pending_index], policy)
+            .await?;
+
+        for warning in &diagnostics.warnings {
+            tracing::warn!("Migration `{}`: {}", migration_name, warning.description);
+        }
+
+        if !diagnostics.unexecutable_migrations.is_empty() {
+            let descriptions: Vec<&str> = diagnostics
+                .unexecutable_migrations
+                .iter()
+                .map(|unexecutable| unexecutable.description.as_str())
+                .collect();
+
+            return Err(CoreError::Generic(anyhow::anyhow!(
+                "Applying the migration `{}` was rejected by the destructive change policy:\n{}",
+                migration_name,
+                descriptions.join("\n")
+            )));
+        }
+    }
+
+    Ok(())
+}
+
 fn diagnose_migration_history(
     migrations_from_database: &[MigrationRecord],
     migrations_from_filesystem: &[MigrationDirectory],
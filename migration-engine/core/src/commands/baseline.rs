@@ -0,0 +1,111 @@
+use super::MigrationCommand;
+use crate::{migration_engine::MigrationEngine, CoreError, CoreResult};
+use migration_connector::PersistenceNotInitializedError;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// The input to the `baseline` command.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BaselineInput {
+    /// The location of the migrations directory.
+    pub migrations_directory_path: String,
+    /// The user-given name for the baseline migration. This will be used in
+    /// the migration directory.
+    pub migration_name: String,
+}
+
+/// The output of the `baseline` command.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BaselineOutput {
+    /// The name of the generated baseline migration directory.
+    pub generated_migration_name: String,
+}
+
+/// Generate an initial migration creating the current schema of an existing
+/// database, and record it as applied in the migrations table without running
+/// it. The baseline migration has no down migration script, so it cannot be
+/// rolled back: that would drop the existing database.
+///
+/// This is used to start using migrations on a database that was not created
+/// by them.
+pub struct BaselineCommand;
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for BaselineCommand {
+    type Input = BaselineInput;
+
+    type Output = BaselineOutput;
+
+    async fn execute<C, D>(input: &Self::Input, engine: &MigrationEngine<C, D>) -> CoreResult<Self::Output>
+    where
+        C: migration_connector::MigrationConnector<DatabaseMigration = D>,
+        D: migration_connector::DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let connector = engine.connector();
+        let database_migration_inferrer = connector.database_migration_inferrer();
+        let applier = connector.database_migration_step_applier();
+        let checker = connector.destructive_change_checker();
+        let migration_persistence = connector.new_migration_persistence();
+
+        let migrations_from_filesystem =
+            migration_connector::list_migrations(&Path::new(&input.migrations_directory_path))?;
+
+        if !migrations_from_filesystem.is_empty() {
+            return Err(CoreError::Input(anyhow::anyhow!(
+                "The migrations directory already contains migrations. Only databases without migrations can be baselined."
+            )));
+        }
+
+        migration_persistence.initialize_on_existing_schema().await?;
+
+        let applied_migrations = migration_persistence
+            .list_migrations()
+            .await?
+            .map_err(PersistenceNotInitializedError::into_connector_error)?;
+
+        if applied_migrations
+            .iter()
+            .any(|db_migration| !db_migration.is_rolled_back())
+        {
+            return Err(CoreError::Input(anyhow::anyhow!(
+                "The migrations table already contains migrations. Only databases without migrations can be baselined."
+            )));
+        }
+
+        // Infer the migration creating the current database schema from an empty database.
+        let migration = database_migration_inferrer.infer_baseline_migration().await?;
+        let migration_script = applier.render_script(&migration, &checker.pure_check(&migration));
+
+        // Write the migration script to a file.
+        let directory = migration_connector::create_migration_directory(
+            &Path::new(&input.migrations_directory_path),
+            &input.migration_name,
+        )
+        .map_err(|_| CoreError::Generic(anyhow::anyhow!("Failed to create a new migration directory.")))?;
+        directory
+            .write_migration_script(&migration_script, D::FILE_EXTENSION)
+            .map_err(|err| {
+                CoreError::Generic(anyhow::anyhow!(
+                    "Failed to write the migration script to `{:?}`. {}",
+                    directory.path(),
+                    err
+                ))
+            })?;
+
+        // Record the migration as applied, without running it.
+        let migration_id = migration_persistence
+            .record_migration_started(directory.migration_name(), &migration_script)
+            .await?;
+
+        migration_persistence
+            .record_successful_step(&migration_id, "Baselined from the existing database schema.")
+            .await?;
+        migration_persistence.record_migration_finished(&migration_id).await?;
+
+        Ok(BaselineOutput {
+            generated_migration_name: directory.migration_name().to_owned(),
+        })
+    }
+}
@@ -1,5 +1,6 @@
 use super::MigrationCommand;
 use crate::{migration_engine::MigrationEngine, parse_datamodel, CoreError, CoreResult};
+use migration_connector::{DestructiveChangeDiagnostics, DestructiveChangePolicy, RenameHint};
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 
@@ -18,6 +19,13 @@ pub struct CreateMigrationInput {
     pub migration_name: String,
     /// If true, always generate a migration, but do not apply.
     pub draft: bool,
+    /// The tables and columns that should be renamed rather than dropped and recreated.
+    #[serde(default)]
+    pub rename_hints: Vec<RenameHint>,
+    /// If true, render the migration with statements avoiding locks that block
+    /// writes on large tables, where the database supports it.
+    #[serde(default)]
+    pub online: bool,
 }
 
 /// The output of the `createMigration` command.
@@ -48,7 +56,7 @@ impl<'a> MigrationCommand for CreateMigrationCommand {
         let target_schema = parse_datamodel(&input.prisma_schema)?;
 
         let migration = database_migration_inferrer
-            .infer_next_migration(&previous_migrations, &target_schema)
+            .infer_next_migration(&previous_migrations, &target_schema, &input.rename_hints)
             .await?;
 
         if migration.is_empty() && !input.draft {
@@ -57,9 +65,22 @@ impl<'a> MigrationCommand for CreateMigrationCommand {
             });
         }
 
-        let destructive_change_diagnostics = checker.pure_check(&migration);
+        let policy = DestructiveChangePolicy::load(Path::new(&input.migrations_directory_path))?;
+        let destructive_change_diagnostics = checker.pure_check_with_policy(&migration, &policy);
 
-        let migration_script = applier.render_script(&migration, &destructive_change_diagnostics);
+        let render_script = |migration: &D, diagnostics: &DestructiveChangeDiagnostics| {
+            if input.online {
+                applier.render_online_script(migration, diagnostics)
+            } else {
+                applier.render_script(migration, diagnostics)
+            }
+        };
+
+        let migration_script = render_script(&migration, &destructive_change_diagnostics);
+
+        // Infer the down migration, reverting the new migration.
+        let down_migration = database_migration_inferrer.infer_reverse_migration(&migration)?;
+        let down_migration_script = render_script(&down_migration, &checker.pure_check(&down_migration));
 
         // Write the migration script to a file.
         let directory = migration_connector::create_migration_directory(
@@ -76,6 +97,15 @@ impl<'a> MigrationCommand for CreateMigrationCommand {
                     err
                 ))
             })?;
+        directory
+            .write_down_migration_script(&down_migration_script, D::FILE_EXTENSION)
+            .map_err(|err| {
+                CoreError::Generic(anyhow::anyhow!(
+                    "Failed to write the down migration script to `{:?}`. {}",
+                    directory.path(),
+                    err
+                ))
+            })?;
 
         Ok(CreateMigrationOutput {
             generated_migration_name: Some(directory.migration_name().to_owned()),
@@ -2,7 +2,7 @@ use std::path::Path;
 
 use super::MigrationCommand;
 use crate::{migration_engine::MigrationEngine, CoreResult};
-use migration_connector::{MigrationDirectory, MigrationRecord};
+use migration_connector::{ConnectorError, MigrationDirectory, MigrationRecord};
 use serde::{Deserialize, Serialize};
 
 /// The input to the `DiagnoseMigrationHistory` command.
@@ -67,10 +67,41 @@ impl<'a> MigrationCommand for DiagnoseMigrationHistoryCommand {
         // Load the migrations.
         let migrations_from_filesystem =
             migration_connector::list_migrations(&Path::new(&input.migrations_directory_path))?;
-        let migrations_from_database = migration_persistence.list_migrations().await?.unwrap_or_default();
+        // Rolled back migrations are not part of the history anymore.
+        let migrations_from_database: Vec<_> = migration_persistence
+            .list_migrations()
+            .await?
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|db_migration| !db_migration.is_rolled_back())
+            .collect();
 
         let mut diagnostics = Diagnostics::new(&migrations_from_filesystem);
 
+        // A squashed migration replacing migrations that are all applied stands
+        // in for them: `applyMigrations` records it as applied without running it.
+        let is_recorded = |migration_name: &str| {
+            migrations_from_database
+                .iter()
+                .any(|db_migration| db_migration.migration_name == migration_name)
+        };
+        let mut applied_squashed_migration_names: Vec<&str> = Vec::new();
+        let mut replaced_migration_names: Vec<String> = Vec::new();
+
+        for fs_migration in migrations_from_filesystem.iter() {
+            let squashed_migration_names = fs_migration
+                .read_squashed_migration_names()
+                .map_err(ConnectorError::from)?;
+
+            if !is_recorded(fs_migration.migration_name())
+                && !squashed_migration_names.is_empty()
+                && squashed_migration_names.iter().all(|name| is_recorded(name))
+            {
+                applied_squashed_migration_names.push(fs_migration.migration_name());
+                replaced_migration_names.extend(squashed_migration_names);
+            }
+        }
+
         // Check filesystem history against database history.
         for (index, fs_migration) in migrations_from_filesystem.iter().enumerate() {
             let corresponding_db_migration = migrations_from_database
@@ -86,16 +117,24 @@ impl<'a> MigrationCommand for DiagnoseMigrationHistoryCommand {
                     diagnostics.edited_migrations.push(db_migration);
                 }
                 Some(_) => (),
+                None if applied_squashed_migration_names.contains(&fs_migration.migration_name()) => (),
                 None => diagnostics.fs_migrations_not_in_db.push((index, fs_migration)),
             }
         }
 
+        diagnostics.failed_migrations.extend(
+            migrations_from_database
+                .iter()
+                .filter(|db_migration| db_migration.is_failed()),
+        );
+
         for (index, db_migration) in migrations_from_database.iter().enumerate() {
             let corresponding_fs_migration = migrations_from_filesystem
                 .iter()
                 .find(|fs_migration| db_migration.migration_name == fs_migration.migration_name());
 
-            if corresponding_fs_migration.is_none() {
+            if corresponding_fs_migration.is_none() && !replaced_migration_names.contains(&db_migration.migration_name)
+            {
                 diagnostics.db_migrations_not_in_fs.push((index, db_migration))
             }
         }
@@ -104,9 +143,10 @@ impl<'a> MigrationCommand for DiagnoseMigrationHistoryCommand {
         let applied_migrations: Vec<_> = migrations_from_filesystem
             .iter()
             .filter(|fs_migration| {
-                migrations_from_database.iter().any(|db_migration| {
-                    db_migration.migration_name == fs_migration.migration_name() && !db_migration.is_failed()
-                })
+                applied_squashed_migration_names.contains(&fs_migration.migration_name())
+                    || migrations_from_database.iter().any(|db_migration| {
+                        db_migration.migration_name == fs_migration.migration_name() && !db_migration.is_failed()
+                    })
             })
             .cloned()
             .collect();
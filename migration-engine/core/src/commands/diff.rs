@@ -0,0 +1,106 @@
+use super::MigrationCommand;
+use crate::{migration_engine::MigrationEngine, parse_datamodel, CoreResult};
+use migration_connector::DiffTarget;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// The input to the `diff` command.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffInput {
+    /// The schema to diff from.
+    pub from: DiffSource,
+    /// The schema to diff to.
+    pub to: DiffSource,
+    /// Whether to render the diff as a runnable script instead of a
+    /// human-readable summary.
+    #[serde(default)]
+    pub script: bool,
+}
+
+/// One side of a diff.
+#[derive(Deserialize, Debug)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum DiffSource {
+    /// A Prisma schema.
+    Schema {
+        /// The contents of the Prisma schema.
+        schema: String,
+    },
+    /// A migrations directory. The migrations are replayed on a temporary
+    /// database.
+    MigrationsDirectory {
+        /// The location of the migrations directory.
+        path: String,
+    },
+    /// A live database.
+    Database {
+        /// The connection string of the database.
+        url: String,
+    },
+    /// An empty schema.
+    Empty,
+}
+
+/// The output of the `diff` command.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffOutput {
+    /// Whether the two schemas differ.
+    pub has_changes: bool,
+    /// The rendered diff, as a script or as a summary, depending on the input.
+    pub diff: String,
+}
+
+/// Compute the difference between two schemas, each of them coming from a
+/// Prisma schema, a migrations directory, a live database or empty. The diff is
+/// rendered either as the script migrating from the first to the second
+/// schema, or as a human-readable summary of the changes.
+///
+/// Neither side is modified.
+pub struct DiffCommand;
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for DiffCommand {
+    type Input = DiffInput;
+
+    type Output = DiffOutput;
+
+    async fn execute<C, D>(input: &Self::Input, engine: &MigrationEngine<C, D>) -> CoreResult<Self::Output>
+    where
+        C: migration_connector::MigrationConnector<DatabaseMigration = D>,
+        D: migration_connector::DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let connector = engine.connector();
+        let database_migration_inferrer = connector.database_migration_inferrer();
+        let applier = connector.database_migration_step_applier();
+        let checker = connector.destructive_change_checker();
+
+        let from = diff_target(&input.from)?;
+        let to = diff_target(&input.to)?;
+
+        let migration = database_migration_inferrer.infer_diff(&from, &to).await?;
+
+        let diff = if input.script {
+            applier.render_script(&migration, &checker.pure_check(&migration))
+        } else {
+            applier.render_summary(&migration)
+        };
+
+        Ok(DiffOutput {
+            has_changes: !migration.is_empty(),
+            diff,
+        })
+    }
+}
+
+fn diff_target(source: &DiffSource) -> CoreResult<DiffTarget> {
+    match source {
+        DiffSource::Schema { schema } => Ok(DiffTarget::Datamodel(parse_datamodel(schema)?)),
+        DiffSource::MigrationsDirectory { path } => Ok(DiffTarget::Migrations(migration_connector::list_migrations(
+            &Path::new(path),
+        )?)),
+        DiffSource::Database { url } => Ok(DiffTarget::Database(url.clone())),
+        DiffSource::Empty => Ok(DiffTarget::Empty),
+    }
+}
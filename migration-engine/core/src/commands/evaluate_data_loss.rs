@@ -1,6 +1,6 @@
 use super::MigrationCommand;
 use crate::{migration_engine::MigrationEngine, parse_datamodel, CoreResult};
-use migration_connector::list_migrations;
+use migration_connector::{list_migrations, DestructiveChangePolicy, RenameHint};
 use serde::{Deserialize, Serialize};
 
 /// Development command for migrations. Evaluate the data loss induced by the
@@ -18,6 +18,9 @@ pub struct EvaluateDataLossInput {
     pub migrations_directory_path: String,
     /// The prisma schema to migrate to.
     pub prisma_schema: String,
+    /// The tables and columns that should be renamed rather than dropped and recreated.
+    #[serde(default)]
+    pub rename_hints: Vec<RenameHint>,
 }
 
 /// The output of the `evaluateDataLoss` command.
@@ -64,7 +67,7 @@ impl MigrationCommand for EvaluateDataLoss {
         let target_schema = parse_datamodel(&input.prisma_schema)?;
 
         let migration = inferrer
-            .infer_next_migration(&migrations_from_directory, &target_schema)
+            .infer_next_migration(&migrations_from_directory, &target_schema, &input.rename_hints)
             .await?;
 
         let rendered_migration_steps = applier
@@ -73,7 +76,8 @@ impl MigrationCommand for EvaluateDataLoss {
             .map(|pretty_step| pretty_step.raw)
             .collect();
 
-        let diagnostics = checker.check(&migration).await?;
+        let policy = DestructiveChangePolicy::load(input.migrations_directory_path.as_ref())?;
+        let diagnostics = checker.check_with_policy(&migration, &policy).await?;
 
         let warnings = diagnostics
             .warnings
@@ -0,0 +1,153 @@
+use super::MigrationCommand;
+use crate::{migration_engine::MigrationEngine, parse_datamodel, CoreResult};
+use migration_connector::{ConnectorError, DestructiveChangePolicy, LintContext, MigrationDirectory, MigrationRecord};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// The input to the `lintMigrations` command.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LintMigrationsInput {
+    /// The location of the migrations directory.
+    pub migrations_directory_path: String,
+    /// The Prisma schema the migrations should lead to. The lints comparing
+    /// the migrations with the schema only run when it is provided.
+    pub prisma_schema: Option<String>,
+}
+
+/// The output of the `lintMigrations` command.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LintMigrationsOutput {
+    /// The dangerous patterns found in the pending migrations, in migration
+    /// order.
+    pub diagnostics: Vec<MigrationLintDiagnostic>,
+}
+
+/// A dangerous pattern found in a pending migration.
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MigrationLintDiagnostic {
+    /// The name of the migration.
+    pub migration_name: String,
+    /// The name of the lint, for example `tableRewrite`.
+    pub lint: String,
+    /// The human-readable description of the problem.
+    pub message: String,
+}
+
+/// Statically analyze the migrations that are not applied to the database yet,
+/// and report the patterns that are dangerous on a production database: table
+/// rewrites, volatile defaults on large tables, dropped columns that are still
+/// in the Prisma schema, foreign keys without indexes, statements that cannot
+/// run in a transaction without the non-transactional marker, and
+/// non-transactional statements mixed with transactional ones.
+///
+/// Each migration is analyzed against the schema it applies to, obtained by
+/// replaying the migrations once on a temporary database and describing the
+/// schema after each of them. Neither the database nor the migrations
+/// directory are modified.
+pub struct LintMigrationsCommand;
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for LintMigrationsCommand {
+    type Input = LintMigrationsInput;
+
+    type Output = LintMigrationsOutput;
+
+    async fn execute<C, D>(input: &Self::Input, engine: &MigrationEngine<C, D>) -> CoreResult<Self::Output>
+    where
+        C: migration_connector::MigrationConnector<DatabaseMigration = D>,
+        D: migration_connector::DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let connector = engine.connector();
+        let database_migration_inferrer = connector.database_migration_inferrer();
+        let linter = connector.migration_linter();
+        let migration_persistence = connector.new_migration_persistence();
+
+        let migrations_directory_path = Path::new(&input.migrations_directory_path);
+        let migrations_from_filesystem = migration_connector::list_migrations(&migrations_directory_path)?;
+        let policy = DestructiveChangePolicy::load(&migrations_directory_path)?;
+        let target_schema = input.prisma_schema.as_deref().map(parse_datamodel).transpose()?;
+
+        // Nothing is applied if the migrations table does not exist yet.
+        let applied_migrations: Vec<MigrationRecord> = migration_persistence
+            .list_migrations()
+            .await?
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|db_migration| !db_migration.is_rolled_back() && db_migration.finished_at.is_some())
+            .collect();
+
+        let context = LintContext {
+            target_schema: target_schema.as_ref(),
+            large_table_row_count: policy.large_table_row_count,
+        };
+
+        let mut pending_migration_indexes = Vec::new();
+
+        for (migration_index, fs_migration) in migrations_from_filesystem.iter().enumerate() {
+            if is_pending(fs_migration, &applied_migrations)? {
+                pending_migration_indexes.push(migration_index);
+            }
+        }
+
+        let first_pending_index = match pending_migration_indexes.first() {
+            Some(index) => *index,
+            None => {
+                return Ok(LintMigrationsOutput {
+                    diagnostics: Vec::new(),
+                })
+            }
+        };
+
+        let history_migrations = database_migration_inferrer
+            .infer_history_migrations(&migrations_from_filesystem, first_pending_index)
+            .await?;
+
+        let mut diagnostics = Vec::new();
+
+        for migration_index in pending_migration_indexes {
+            let fs_migration = &migrations_from_filesystem[migration_index];
+            let script = fs_migration.read_migration_script().map_err(ConnectorError::from)?;
+            let migration = &history_migrations[migration_index - first_pending_index];
+
+            diagnostics.extend(
+                linter
+                    .lint(migration, &script, &context)
+                    .await?
+                    .into_iter()
+                    .map(|diagnostic| MigrationLintDiagnostic {
+                        migration_name: fs_migration.migration_name().to_owned(),
+                        lint: diagnostic.lint,
+                        message: diagnostic.message,
+                    }),
+            );
+        }
+
+        Ok(LintMigrationsOutput { diagnostics })
+    }
+}
+
+/// A migration is pending when it is not applied, unless it is a squashed
+/// migration replacing applied migrations: `applyMigrations` records those
+/// without running them.
+fn is_pending(fs_migration: &MigrationDirectory, applied_migrations: &[MigrationRecord]) -> CoreResult<bool> {
+    let is_applied = |migration_name: &str| {
+        applied_migrations
+            .iter()
+            .any(|db_migration| db_migration.migration_name == migration_name)
+    };
+
+    if is_applied(fs_migration.migration_name()) {
+        return Ok(false);
+    }
+
+    let squashed_migration_names = fs_migration
+        .read_squashed_migration_names()
+        .map_err(ConnectorError::from)?;
+
+    Ok(!squashed_migration_names
+        .iter()
+        .any(|migration_name| is_applied(migration_name)))
+}
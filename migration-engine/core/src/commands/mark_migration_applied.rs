@@ -0,0 +1,105 @@
+use super::MigrationCommand;
+use crate::{migration_engine::MigrationEngine, CoreError, CoreResult};
+use migration_connector::{ConnectorError, MigrationRecord, PersistenceNotInitializedError};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// The input to the `markMigrationApplied` command.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkMigrationAppliedInput {
+    /// The location of the migrations directory.
+    pub migrations_directory_path: String,
+    /// The name of the migration to mark applied.
+    pub migration_name: String,
+}
+
+/// The output of the `markMigrationApplied` command.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkMigrationAppliedOutput {}
+
+/// Mark a migration from the migrations directory as applied in the migrations
+/// table, without running its script. Failed attempts to apply the migration
+/// are marked as rolled back.
+///
+/// This is used to resolve failed migrations that were fixed and applied
+/// manually. The checksum of the current script is recorded, so a script
+/// that was fixed since the failed attempts is not reported as edited.
+pub struct MarkMigrationAppliedCommand;
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for MarkMigrationAppliedCommand {
+    type Input = MarkMigrationAppliedInput;
+
+    type Output = MarkMigrationAppliedOutput;
+
+    async fn execute<C, D>(input: &Self::Input, engine: &MigrationEngine<C, D>) -> CoreResult<Self::Output>
+    where
+        C: migration_connector::MigrationConnector<DatabaseMigration = D>,
+        D: migration_connector::DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let connector = engine.connector();
+        let migration_persistence = connector.new_migration_persistence();
+
+        let migrations_from_filesystem =
+            migration_connector::list_migrations(&Path::new(&input.migrations_directory_path))?;
+
+        let migration_directory = migrations_from_filesystem
+            .iter()
+            .find(|fs_migration| fs_migration.migration_name() == input.migration_name)
+            .ok_or_else(|| {
+                CoreError::Input(anyhow::anyhow!(
+                    "Migration `{}` cannot be found in the migrations directory.",
+                    input.migration_name
+                ))
+            })?;
+
+        let relevant_migrations: Vec<MigrationRecord> = migration_persistence
+            .list_migrations()
+            .await?
+            .map_err(PersistenceNotInitializedError::into_connector_error)?
+            .into_iter()
+            .filter(|db_migration| {
+                db_migration.migration_name == input.migration_name && !db_migration.is_rolled_back()
+            })
+            .collect();
+
+        if let Some(applied_migration) = relevant_migrations
+            .iter()
+            .find(|db_migration| !db_migration.is_failed())
+        {
+            let diagnostic = if migration_directory
+                .matches_checksum(&applied_migration.checksum)
+                .map_err(ConnectorError::from)?
+            {
+                "It cannot be marked as applied again."
+            } else {
+                "Its script was edited since, it cannot be marked as applied again."
+            };
+
+            return Err(CoreError::Input(anyhow::anyhow!(
+                "Migration `{}` is already recorded as applied in the database. {}",
+                input.migration_name,
+                diagnostic
+            )));
+        }
+
+        for failed_migration in &relevant_migrations {
+            migration_persistence
+                .record_migration_rolled_back(&failed_migration.id)
+                .await?;
+        }
+
+        let migration_id = migration_persistence
+            .record_migration_directory_started(migration_directory)
+            .await?;
+
+        migration_persistence
+            .record_successful_step(&migration_id, "Marked as applied.")
+            .await?;
+        migration_persistence.record_migration_finished(&migration_id).await?;
+
+        Ok(MarkMigrationAppliedOutput {})
+    }
+}
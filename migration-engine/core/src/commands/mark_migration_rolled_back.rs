@@ -0,0 +1,67 @@
+use super::MigrationCommand;
+use crate::{migration_engine::MigrationEngine, CoreError, CoreResult};
+use migration_connector::{MigrationRecord, PersistenceNotInitializedError};
+use serde::{Deserialize, Serialize};
+
+/// The input to the `markMigrationRolledBack` command.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkMigrationRolledBackInput {
+    /// The name of the migration to mark rolled back.
+    pub migration_name: String,
+}
+
+/// The output of the `markMigrationRolledBack` command.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkMigrationRolledBackOutput {}
+
+/// Mark a failed migration as rolled back in the migrations table, without
+/// touching the database schema. The migration is then applied again by the
+/// next `applyMigrations`.
+///
+/// This is used to resolve failed migrations whose changes were reverted
+/// manually.
+pub struct MarkMigrationRolledBackCommand;
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for MarkMigrationRolledBackCommand {
+    type Input = MarkMigrationRolledBackInput;
+
+    type Output = MarkMigrationRolledBackOutput;
+
+    async fn execute<C, D>(input: &Self::Input, engine: &MigrationEngine<C, D>) -> CoreResult<Self::Output>
+    where
+        C: migration_connector::MigrationConnector<DatabaseMigration = D>,
+        D: migration_connector::DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let migration_persistence = engine.connector().new_migration_persistence();
+
+        let failed_migrations: Vec<MigrationRecord> = migration_persistence
+            .list_migrations()
+            .await?
+            .map_err(PersistenceNotInitializedError::into_connector_error)?
+            .into_iter()
+            .filter(|db_migration| {
+                db_migration.migration_name == input.migration_name
+                    && db_migration.is_failed()
+                    && !db_migration.is_rolled_back()
+            })
+            .collect();
+
+        if failed_migrations.is_empty() {
+            return Err(CoreError::Input(anyhow::anyhow!(
+                "Migration `{}` cannot be rolled back because it is not in a failed state.",
+                input.migration_name
+            )));
+        }
+
+        for failed_migration in &failed_migrations {
+            migration_persistence
+                .record_migration_rolled_back(&failed_migration.id)
+                .await?;
+        }
+
+        Ok(MarkMigrationRolledBackOutput {})
+    }
+}
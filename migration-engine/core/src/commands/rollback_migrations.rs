@@ -0,0 +1,120 @@
+use super::MigrationCommand;
+use crate::{migration_engine::MigrationEngine, CoreError, CoreResult};
+use migration_connector::{ConnectorError, MigrationRecord, PersistenceNotInitializedError};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// The input to the `RollbackMigrations` command.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RollbackMigrationsInput {
+    /// The location of the migrations directory.
+    pub migrations_directory_path: String,
+    /// The name of the migration to roll back to. That migration stays
+    /// applied, all the migrations applied after it are rolled back. If
+    /// absent, all applied migrations are rolled back.
+    pub to_migration: Option<String>,
+}
+
+/// The output of the `RollbackMigrations` command.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RollbackMigrationsOutput {
+    /// The names of the migrations that were rolled back, in the order they
+    /// were rolled back in. Empty if no migration was rolled back.
+    pub rolled_back_migration_names: Vec<String>,
+}
+
+/// Roll back applied migrations by applying their down migration scripts, in
+/// reverse order, and record the rollbacks in the migrations table.
+pub struct RollbackMigrationsCommand;
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for RollbackMigrationsCommand {
+    type Input = RollbackMigrationsInput;
+
+    type Output = RollbackMigrationsOutput;
+
+    async fn execute<C, D>(input: &Self::Input, engine: &MigrationEngine<C, D>) -> CoreResult<Self::Output>
+    where
+        C: migration_connector::MigrationConnector<DatabaseMigration = D>,
+        D: migration_connector::DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let connector = engine.connector();
+        let applier = connector.database_migration_step_applier();
+        let migration_persistence = connector.new_migration_persistence();
+
+        let migrations_from_filesystem =
+            migration_connector::list_migrations(&Path::new(&input.migrations_directory_path))?;
+        let applied_migrations: Vec<MigrationRecord> = migration_persistence
+            .list_migrations()
+            .await?
+            .map_err(PersistenceNotInitializedError::into_connector_error)?
+            .into_iter()
+            .filter(|db_migration| !db_migration.is_failed() && !db_migration.is_rolled_back())
+            .collect();
+
+        let first_rolled_back_index = match &input.to_migration {
+            Some(to_migration) => applied_migrations
+                .iter()
+                .position(|db_migration| &db_migration.migration_name == to_migration)
+                .map(|index| index + 1)
+                .ok_or_else(|| {
+                    CoreError::Generic(anyhow::anyhow!(
+                        "The migration `{}` is not applied to the database, it cannot be rolled back to.",
+                        to_migration
+                    ))
+                })?,
+            None => 0,
+        };
+
+        // Read all the down migration scripts before touching the database, so
+        // we do not stop halfway because of a missing script.
+        let mut down_scripts: Vec<(&MigrationRecord, String)> = Vec::new();
+
+        for db_migration in applied_migrations[first_rolled_back_index..].iter().rev() {
+            let fs_migration = migrations_from_filesystem
+                .iter()
+                .find(|fs_migration| fs_migration.migration_name() == db_migration.migration_name)
+                .ok_or_else(|| {
+                    CoreError::Generic(anyhow::anyhow!(
+                        "The migration `{}` was not found in the migrations directory.",
+                        db_migration.migration_name
+                    ))
+                })?;
+
+            let down_script = fs_migration
+                .read_down_migration_script()
+                .map_err(ConnectorError::from)?
+                .ok_or_else(|| {
+                    CoreError::Generic(anyhow::anyhow!(
+                        "The migration `{}` has no down migration script.",
+                        db_migration.migration_name
+                    ))
+                })?;
+
+            down_scripts.push((db_migration, down_script));
+        }
+
+        let mut rolled_back_migration_names: Vec<String> = Vec::with_capacity(down_scripts.len());
+
+        for (db_migration, down_script) in down_scripts {
+            tracing::info!(
+                script = down_script.as_str(),
+                "Rolling back `{}`",
+                db_migration.migration_name
+            );
+
+            applier.apply_script(&down_script).await?;
+            migration_persistence
+                .record_migration_rolled_back(&db_migration.id)
+                .await?;
+
+            rolled_back_migration_names.push(db_migration.migration_name.clone());
+        }
+
+        Ok(RollbackMigrationsOutput {
+            rolled_back_migration_names,
+        })
+    }
+}
@@ -0,0 +1,232 @@
+use super::MigrationCommand;
+use crate::{migration_engine::MigrationEngine, CoreError, CoreResult};
+use migration_connector::{DiffTarget, ImperativeMigrationsPersistence, MigrationDirectory, MigrationRecord};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// The input to the `squashMigrations` command.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SquashMigrationsInput {
+    /// The location of the migrations directory.
+    pub migrations_directory_path: String,
+    /// The name of the first migration to squash. If absent, the range starts
+    /// at the first migration.
+    pub from_migration: Option<String>,
+    /// The name of the last migration to squash. If absent, the range ends at
+    /// the last migration.
+    pub to_migration: Option<String>,
+    /// The user-given name for the squashed migration. This will be used in
+    /// the migration directory.
+    pub migration_name: String,
+}
+
+/// The output of the `squashMigrations` command.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SquashMigrationsOutput {
+    /// The name of the generated squashed migration directory.
+    pub generated_migration_name: String,
+    /// The names of the migrations that were replaced by the squashed
+    /// migration.
+    pub squashed_migration_names: Vec<String>,
+}
+
+/// Replace a range of migrations in the migrations directory with a single
+/// migration taking the database from the schema before the range to the
+/// schema after it.
+///
+/// The squashed migration takes the position of the last migration in the
+/// range, and lists the migrations it replaces. If the replaced migrations are
+/// applied to the database, the squashed migration is recorded as applied in
+/// their place. `applyMigrations` does the same on the other databases.
+pub struct SquashMigrationsCommand;
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for SquashMigrationsCommand {
+    type Input = SquashMigrationsInput;
+
+    type Output = SquashMigrationsOutput;
+
+    async fn execute<C, D>(input: &Self::Input, engine: &MigrationEngine<C, D>) -> CoreResult<Self::Output>
+    where
+        C: migration_connector::MigrationConnector<DatabaseMigration = D>,
+        D: migration_connector::DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let connector = engine.connector();
+        let database_migration_inferrer = connector.database_migration_inferrer();
+        let applier = connector.database_migration_step_applier();
+        let checker = connector.destructive_change_checker();
+        let migration_persistence = connector.new_migration_persistence();
+
+        let migrations_directory_path = Path::new(&input.migrations_directory_path);
+        let migrations_from_filesystem = migration_connector::list_migrations(&migrations_directory_path)?;
+
+        let position = |migration_name: &Option<String>, default: usize| match migration_name {
+            Some(migration_name) => migrations_from_filesystem
+                .iter()
+                .position(|fs_migration| fs_migration.migration_name() == migration_name)
+                .ok_or_else(|| {
+                    CoreError::Input(anyhow::anyhow!(
+                        "Migration `{}` cannot be found in the migrations directory.",
+                        migration_name
+                    ))
+                }),
+            None => Ok(default),
+        };
+
+        let first_index = position(&input.from_migration, 0)?;
+        let last_index = position(&input.to_migration, migrations_from_filesystem.len().saturating_sub(1))?;
+
+        if last_index <= first_index {
+            return Err(CoreError::Input(anyhow::anyhow!(
+                "At least two migrations are needed to squash migrations, and the first one must come before the last one."
+            )));
+        }
+
+        let squashed_migrations = &migrations_from_filesystem[first_index..=last_index];
+        let squashed_migration_names: Vec<String> = squashed_migrations
+            .iter()
+            .map(|fs_migration| fs_migration.migration_name().to_owned())
+            .collect();
+
+        // Rolled back migrations are not part of the history anymore.
+        let applied_migrations: Vec<MigrationRecord> = migration_persistence
+            .list_migrations()
+            .await?
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|db_migration| {
+                !db_migration.is_rolled_back() && squashed_migration_names.contains(&db_migration.migration_name)
+            })
+            .collect();
+
+        if let Some(failed_migration) = applied_migrations.iter().find(|db_migration| db_migration.is_failed()) {
+            return Err(CoreError::Input(anyhow::anyhow!(
+                "Migration `{}` failed to apply to the database. Resolve it before squashing it.",
+                failed_migration.migration_name
+            )));
+        }
+
+        if !applied_migrations.is_empty() && applied_migrations.len() != squashed_migrations.len() {
+            return Err(CoreError::Input(anyhow::anyhow!(
+                "Some of the migrations to squash are applied to the database, but not all of them. Apply them before squashing them."
+            )));
+        }
+
+        // The data scripts of unapplied migrations would not run anymore.
+        if applied_migrations.is_empty() {
+            if let Some(fs_migration) = squashed_migrations
+                .iter()
+                .find(|fs_migration| fs_migration.has_data_scripts())
+            {
+                return Err(CoreError::Input(anyhow::anyhow!(
+                    "Migration `{}` has data migration scripts, they would be lost by squashing it. Apply it before squashing it.",
+                    fs_migration.migration_name()
+                )));
+            }
+        }
+
+        // Infer the migration taking the database from the schema before the range to the schema after it.
+        let migration = database_migration_inferrer
+            .infer_diff(
+                &DiffTarget::Migrations(migrations_from_filesystem[..first_index].to_vec()),
+                &DiffTarget::Migrations(migrations_from_filesystem[..=last_index].to_vec()),
+            )
+            .await?;
+        let migration_script = applier.render_script(&migration, &checker.pure_check(&migration));
+
+        let down_migration = database_migration_inferrer.infer_reverse_migration(&migration)?;
+        let down_migration_script = applier.render_script(&down_migration, &checker.pure_check(&down_migration));
+
+        // Write the squashed migration in place of the last squashed migration.
+        let directory = migration_connector::create_migration_directory_with_timestamp(
+            &migrations_directory_path,
+            migrations_from_filesystem[last_index].timestamp(),
+            &input.migration_name,
+        )
+        .map_err(|_| CoreError::Generic(anyhow::anyhow!("Failed to create a new migration directory.")))?;
+        directory
+            .write_migration_script(&migration_script, D::FILE_EXTENSION)
+            .map_err(|err| {
+                CoreError::Generic(anyhow::anyhow!(
+                    "Failed to write the migration script to `{:?}`. {}",
+                    directory.path(),
+                    err
+                ))
+            })?;
+        directory
+            .write_down_migration_script(&down_migration_script, D::FILE_EXTENSION)
+            .map_err(|err| {
+                CoreError::Generic(anyhow::anyhow!(
+                    "Failed to write the down migration script to `{:?}`. {}",
+                    directory.path(),
+                    err
+                ))
+            })?;
+        directory
+            .write_squashed_migration_names(&squashed_migration_names)
+            .map_err(|err| {
+                CoreError::Generic(anyhow::anyhow!(
+                    "Failed to write the squashed migration names to `{:?}`. {}",
+                    directory.path(),
+                    err
+                ))
+            })?;
+
+        for squashed_migration in squashed_migrations.iter().cloned() {
+            let path = squashed_migration.path().to_owned();
+
+            squashed_migration.delete().map_err(|err| {
+                CoreError::Generic(anyhow::anyhow!(
+                    "Failed to delete the squashed migration directory at `{:?}`. {}",
+                    path,
+                    err
+                ))
+            })?;
+        }
+
+        if !applied_migrations.is_empty() {
+            record_squashed_migration(
+                migration_persistence,
+                &directory,
+                &migration_script,
+                &applied_migrations.iter().collect::<Vec<_>>(),
+            )
+            .await?;
+        }
+
+        Ok(SquashMigrationsOutput {
+            generated_migration_name: directory.migration_name().to_owned(),
+            squashed_migration_names,
+        })
+    }
+}
+
+/// Record the squashed migration as applied without running its script, in
+/// place of the applied migrations it replaces. The records of the replaced
+/// migrations are marked as rolled back, since they are not part of the
+/// history anymore.
+pub(super) async fn record_squashed_migration(
+    migration_persistence: &dyn ImperativeMigrationsPersistence,
+    squashed_migration: &MigrationDirectory,
+    script: &str,
+    replaced_migrations: &[&MigrationRecord],
+) -> CoreResult<()> {
+    let migration_id = migration_persistence
+        .record_migration_started(squashed_migration.migration_name(), script)
+        .await?;
+
+    migration_persistence
+        .record_successful_step(&migration_id, "Squashed from previously applied migrations.")
+        .await?;
+    migration_persistence.record_migration_finished(&migration_id).await?;
+
+    for replaced_migration in replaced_migrations {
+        migration_persistence
+            .record_migration_rolled_back(&replaced_migration.id)
+            .await?;
+    }
+
+    Ok(())
+}
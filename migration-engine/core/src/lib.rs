@@ -162,13 +162,33 @@ pub async fn qe_setup(prisma_schema: &str) -> CoreResult<()> {
 }
 
 fn parse_configuration(datamodel: &str) -> CoreResult<Configuration> {
+    ensure_single_datasource(datamodel)?;
+
     datamodel::parse_configuration(&datamodel)
         .map(|validated_config| validated_config.subject)
         .map_err(|err| CoreError::ReceivedBadDatamodel(err.to_pretty_string("schema.prisma", datamodel)))
 }
 
 pub(crate) fn parse_datamodel(datamodel: &str) -> CoreResult<Datamodel> {
+    ensure_single_datasource(datamodel)?;
+
     datamodel::parse_datamodel(&datamodel)
         .map(|d| d.subject)
         .map_err(|err| CoreError::ReceivedBadDatamodel(err.to_pretty_string("schema.prisma", datamodel)))
 }
+
+/// The migration engine migrates the database of the first datasource only, so the models of
+/// the other datasources would end up in it.
+fn ensure_single_datasource(datamodel: &str) -> CoreResult<()> {
+    let datasources_count = datamodel::parse_schema_ast(datamodel)
+        .map(|ast| ast.sources().len())
+        .unwrap_or(0);
+
+    if datasources_count > 1 {
+        return Err(CoreError::Generic(anyhow::anyhow!(
+            "You defined more than one datasource. Migrations do not support multiple datasources yet."
+        )));
+    }
+
+    Ok(())
+}
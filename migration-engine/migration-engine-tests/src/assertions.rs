@@ -1,7 +1,7 @@
 use pretty_assertions::assert_eq;
 use sql_schema_describer::{
-    Column, ColumnTypeFamily, DefaultValue, Enum, ForeignKey, ForeignKeyAction, Index, IndexType, PrimaryKey,
-    SqlSchema, Table,
+    CheckConstraint, Column, ColumnTypeFamily, DefaultValue, Enum, ForeignKey, ForeignKeyAction, Index, IndexType,
+    PrimaryKey, SqlSchema, Table,
 };
 
 pub(crate) type AssertionResult<T> = Result<T, anyhow::Error>;
@@ -256,6 +256,46 @@ impl<'a> TableAssertion<'a> {
         Ok(self)
     }
 
+    pub fn assert_checks_count(self, n: usize) -> AssertionResult<Self> {
+        let checks_count = self.0.checks.len();
+        anyhow::ensure!(
+            checks_count == n,
+            anyhow::anyhow!("Expected {} check constraints, found {}.", n, checks_count)
+        );
+
+        Ok(self)
+    }
+
+    pub fn assert_check_definition(self, name: &str, definition: &str) -> AssertionResult<Self> {
+        let expected = CheckConstraint {
+            name: name.to_owned(),
+            definition: definition.to_owned(),
+        };
+
+        anyhow::ensure!(
+            self.0.checks.iter().any(|check| check.matches(&expected)),
+            "Assertion failed: check constraint `{}` with definition `{}` not found on `{}`. Existing checks: {:?}",
+            name,
+            definition,
+            self.0.name,
+            self.0.checks
+        );
+
+        Ok(self)
+    }
+
+    pub fn assert_has_check(self, name: &str) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.0.checks.iter().any(|check| check.name == name),
+            "Assertion failed: check constraint `{}` not found on `{}`. Existing checks: {:?}",
+            name,
+            self.0.name,
+            self.0.checks.iter().map(|check| &check.name).collect::<Vec<_>>()
+        );
+
+        Ok(self)
+    }
+
     pub fn assert_index_on_columns<F>(self, columns: &[&str], index_assertions: F) -> AssertionResult<Self>
     where
         F: FnOnce(IndexAssertion<'a>) -> AssertionResult<IndexAssertion<'a>>,
@@ -503,6 +543,28 @@ impl<'a> ForeignKeyAssertion<'a> {
 
         Ok(self)
     }
+
+    pub fn assert_on_delete(self, expected: ForeignKeyAction) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.0.on_delete_action == expected,
+            "Assertion failed: expected foreign key to {:?} on delete, but it is {:?}.",
+            expected,
+            self.0.on_delete_action,
+        );
+
+        Ok(self)
+    }
+
+    pub fn assert_on_update(self, expected: ForeignKeyAction) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.0.on_update_action == expected,
+            "Assertion failed: expected foreign key to {:?} on update, but it is {:?}.",
+            expected,
+            self.0.on_update_action,
+        );
+
+        Ok(self)
+    }
 }
 
 pub struct IndexAssertion<'a>(&'a Index);
@@ -525,4 +587,10 @@ impl<'a> IndexAssertion<'a> {
 
         Ok(self)
     }
+
+    pub fn assert_is_fulltext(self) -> AssertionResult<Self> {
+        assert_eq!(self.0.tpe, IndexType::Fulltext);
+
+        Ok(self)
+    }
 }
@@ -15,6 +15,11 @@ pub(super) async fn mysql_migration_connector(url_str: &str) -> SqlMigrationConn
     SqlMigrationConnector::new(url_str).await.unwrap()
 }
 
+pub(super) async fn mssql_migration_connector(url_str: &str) -> SqlMigrationConnector {
+    create_mssql_database(url_str).await.unwrap();
+    SqlMigrationConnector::new(url_str).await.unwrap()
+}
+
 pub(super) async fn postgres_migration_connector(url_str: &str) -> SqlMigrationConnector {
     create_postgres_database(&url_str.parse().unwrap()).await.unwrap();
     SqlMigrationConnector::new(url_str).await.unwrap()
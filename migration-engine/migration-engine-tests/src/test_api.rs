@@ -1,32 +1,49 @@
 mod apply;
 mod apply_migrations;
+mod baseline;
 mod calculate_database_steps;
 mod create_migration;
 mod diagnose_migration_history;
+mod diff;
 mod evaluate_data_loss;
 mod infer;
 mod infer_apply;
+mod lint_migrations;
+mod mark_migration_applied;
+mod mark_migration_rolled_back;
 mod reset;
+mod rollback_migrations;
 mod schema_push;
+mod squash_migrations;
 mod unapply_migration;
 
 pub use apply::Apply;
 pub use apply_migrations::ApplyMigrations;
+pub use baseline::Baseline;
 pub use calculate_database_steps::CalculateDatabaseSteps;
 pub use create_migration::CreateMigration;
 pub use diagnose_migration_history::DiagnoseMigrationHistory;
+pub use diff::Diff;
 pub use evaluate_data_loss::EvaluateDataLoss;
 pub use infer::Infer;
 pub use infer_apply::InferApply;
+pub use lint_migrations::LintMigrations;
+pub use mark_migration_applied::MarkMigrationApplied;
+pub use mark_migration_rolled_back::MarkMigrationRolledBack;
 pub use reset::Reset;
+pub use rollback_migrations::RollbackMigrations;
 pub use schema_push::SchemaPush;
+pub use squash_migrations::SquashMigrations;
 pub use unapply_migration::UnapplyMigration;
 
 use crate::AssertionResult;
 
 use super::assertions::SchemaAssertion;
 use super::{
-    misc_helpers::{mysql_migration_connector, postgres_migration_connector, sqlite_migration_connector, test_api},
+    misc_helpers::{
+        mssql_migration_connector, mysql_migration_connector, postgres_migration_connector, sqlite_migration_connector,
+        test_api,
+    },
     sql::barrel_migration_executor::BarrelMigrationExecutor,
     InferAndApplyOutput,
 };
@@ -37,7 +54,7 @@ use migration_connector::{
 };
 use migration_core::{
     api::{GenericApi, MigrationApi},
-    commands::ApplyMigrationInput,
+    commands::{ApplyMigrationInput, DiffSource},
 };
 use quaint::{
     prelude::{ConnectionInfo, Queryable, SqlFamily},
@@ -53,6 +70,8 @@ use test_setup::*;
 pub struct TestApi {
     /// More precise than SqlFamily.
     connector_name: &'static str,
+    /// The connection string of the test database.
+    url: String,
     database: Quaint,
     api: MigrationApi<SqlMigrationConnector, SqlMigration>,
     tags: BitFlags<Tags>,
@@ -99,6 +118,11 @@ impl TestApi {
         &self.database.connection_info()
     }
 
+    /// The connection string of the test database.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
     pub fn sql_family(&self) -> SqlFamily {
         self.connection_info().sql_family()
     }
@@ -153,6 +177,20 @@ impl TestApi {
         ApplyMigrations::new(&self.api, migrations_directory)
     }
 
+    /// Builder to call the Baseline command.
+    pub fn baseline<'a>(
+        &'a self,
+        migration_name: impl Into<String>,
+        migrations_directory: &'a TempDir,
+    ) -> Baseline<'a> {
+        Baseline::new(&self.api, migration_name.into(), migrations_directory)
+    }
+
+    /// Builder and assertions to call the RollbackMigrations command.
+    pub fn rollback_migrations<'a>(&'a self, migrations_directory: &'a TempDir) -> RollbackMigrations<'a> {
+        RollbackMigrations::new(&self.api, migrations_directory)
+    }
+
     /// Convenient builder and assertions for the CreateMigration command.
     pub fn create_migration<'a>(
         &'a self,
@@ -168,6 +206,39 @@ impl TestApi {
         DiagnoseMigrationHistory::new(&self.api, migrations_directory)
     }
 
+    /// Builder to call the Diff command.
+    pub fn diff(&self, from: DiffSource, to: DiffSource) -> Diff<'_> {
+        Diff::new(&self.api, from, to)
+    }
+
+    /// Builder to call the LintMigrations command.
+    pub fn lint_migrations<'a>(&'a self, migrations_directory: &'a TempDir) -> LintMigrations<'a> {
+        LintMigrations::new(&self.api, migrations_directory)
+    }
+
+    /// Builder to call the MarkMigrationApplied command.
+    pub fn mark_migration_applied<'a>(
+        &'a self,
+        migration_name: impl Into<String>,
+        migrations_directory: &'a TempDir,
+    ) -> MarkMigrationApplied<'a> {
+        MarkMigrationApplied::new(&self.api, migration_name.into(), migrations_directory)
+    }
+
+    /// Builder to call the MarkMigrationRolledBack command.
+    pub fn mark_migration_rolled_back(&self, migration_name: impl Into<String>) -> MarkMigrationRolledBack<'_> {
+        MarkMigrationRolledBack::new(&self.api, migration_name.into())
+    }
+
+    /// Builder to call the SquashMigrations command.
+    pub fn squash_migrations<'a>(
+        &'a self,
+        migration_name: impl Into<String>,
+        migrations_directory: &'a TempDir,
+    ) -> SquashMigrations<'a> {
+        SquashMigrations::new(&self.api, migration_name.into(), migrations_directory)
+    }
+
     pub fn infer_apply<'a>(&'a self, schema: &'a str) -> InferApply<'a> {
         InferApply::new(&self.api, schema)
     }
@@ -329,6 +400,32 @@ impl<'a> TestApiSelect<'a> {
     }
 }
 
+pub async fn mssql_2017_test_api(args: TestAPIArgs) -> TestApi {
+    let url = mssql_2017_url(args.test_function_name);
+    let connector = mssql_migration_connector(&url).await;
+
+    TestApi {
+        connector_name: "mssql_2017",
+        url,
+        database: connector.quaint().clone(),
+        api: test_api(connector).await,
+        tags: args.test_tag,
+    }
+}
+
+pub async fn mssql_2019_test_api(args: TestAPIArgs) -> TestApi {
+    let url = mssql_2019_url(args.test_function_name);
+    let connector = mssql_migration_connector(&url).await;
+
+    TestApi {
+        connector_name: "mssql_2019",
+        url,
+        database: connector.quaint().clone(),
+        api: test_api(connector).await,
+        tags: args.test_tag,
+    }
+}
+
 pub async fn mysql_8_test_api(args: TestAPIArgs) -> TestApi {
     let db_name = args.test_function_name;
     let url = mysql_8_url(db_name);
@@ -336,6 +433,7 @@ pub async fn mysql_8_test_api(args: TestAPIArgs) -> TestApi {
 
     TestApi {
         connector_name: "mysql_8",
+        url,
         database: connector.quaint().clone(),
         api: test_api(connector).await,
         tags: args.test_tag,
@@ -349,6 +447,7 @@ pub async fn mysql_5_6_test_api(args: TestAPIArgs) -> TestApi {
 
     TestApi {
         connector_name: "mysql_5_6",
+        url,
         database: connector.quaint().clone(),
         api: test_api(connector).await,
         tags: args.test_tag,
@@ -362,6 +461,7 @@ pub async fn mysql_test_api(args: TestAPIArgs) -> TestApi {
 
     TestApi {
         connector_name: "mysql",
+        url,
         database: connector.quaint().clone(),
         api: test_api(connector).await,
         tags: args.test_tag,
@@ -375,6 +475,7 @@ pub async fn mysql_mariadb_test_api(args: TestAPIArgs) -> TestApi {
 
     TestApi {
         connector_name: "mysql_mariadb",
+        url,
         database: connector.quaint().clone(),
         api: test_api(connector).await,
         tags: args.test_tag,
@@ -388,6 +489,7 @@ pub async fn postgres9_test_api(args: TestAPIArgs) -> TestApi {
 
     TestApi {
         connector_name: "postgres9",
+        url,
         database: connector.quaint().clone(),
         api: test_api(connector).await,
         tags: args.test_tag,
@@ -401,6 +503,7 @@ pub async fn postgres_test_api(args: TestAPIArgs) -> TestApi {
 
     TestApi {
         connector_name: "postgres",
+        url,
         database: connector.quaint().clone(),
         api: test_api(connector).await,
         tags: args.test_tag,
@@ -414,6 +517,7 @@ pub async fn postgres11_test_api(args: TestAPIArgs) -> TestApi {
 
     TestApi {
         connector_name: "postgres11",
+        url,
         database: connector.quaint().clone(),
         api: test_api(connector).await,
         tags: args.test_tag,
@@ -426,6 +530,7 @@ pub async fn postgres12_test_api(args: TestAPIArgs) -> TestApi {
 
     TestApi {
         connector_name: "postgres12",
+        url,
         database: connector.quaint().clone(),
         api: test_api(connector).await,
         tags: args.test_tag,
@@ -438,6 +543,7 @@ pub async fn postgres13_test_api(args: TestAPIArgs) -> TestApi {
 
     TestApi {
         connector_name: "postgres13",
+        url,
         database: connector.quaint().clone(),
         api: test_api(connector).await,
         tags: args.test_tag,
@@ -450,6 +556,7 @@ pub async fn sqlite_test_api(args: TestAPIArgs) -> TestApi {
 
     TestApi {
         connector_name: "sqlite",
+        url: sqlite_test_url(db_name),
         database: connector.quaint().clone(),
         api: test_api(connector).await,
         tags: args.test_tag,
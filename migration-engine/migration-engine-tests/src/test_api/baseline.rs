@@ -0,0 +1,31 @@
+use migration_core::{
+    commands::{BaselineInput, BaselineOutput},
+    CoreResult, GenericApi,
+};
+use tempfile::TempDir;
+
+#[must_use = "This struct does nothing on its own. See Baseline::send()"]
+pub struct Baseline<'a> {
+    api: &'a dyn GenericApi,
+    migration_name: String,
+    migrations_directory: &'a TempDir,
+}
+
+impl<'a> Baseline<'a> {
+    pub fn new(api: &'a dyn GenericApi, migration_name: String, migrations_directory: &'a TempDir) -> Self {
+        Baseline {
+            api,
+            migration_name,
+            migrations_directory,
+        }
+    }
+
+    pub async fn send(self) -> CoreResult<BaselineOutput> {
+        self.api
+            .baseline(&BaselineInput {
+                migrations_directory_path: self.migrations_directory.path().to_str().unwrap().to_owned(),
+                migration_name: self.migration_name,
+            })
+            .await
+    }
+}
@@ -1,4 +1,5 @@
 use anyhow::Context;
+use migration_connector::RenameHint;
 use migration_core::{commands::CreateMigrationInput, commands::CreateMigrationOutput, GenericApi};
 use pretty_assertions::assert_eq;
 use std::path::Path;
@@ -12,6 +13,8 @@ pub struct CreateMigration<'a> {
     migrations_directory: &'a TempDir,
     draft: bool,
     name: &'a str,
+    rename_hints: Vec<RenameHint>,
+    online: bool,
 }
 
 impl<'a> CreateMigration<'a> {
@@ -22,6 +25,8 @@ impl<'a> CreateMigration<'a> {
             migrations_directory,
             draft: false,
             name,
+            rename_hints: Vec::new(),
+            online: false,
         }
     }
 
@@ -31,6 +36,31 @@ impl<'a> CreateMigration<'a> {
         self
     }
 
+    pub fn online(mut self) -> Self {
+        self.online = true;
+
+        self
+    }
+
+    pub fn rename_table(mut self, previous_name: &str, next_name: &str) -> Self {
+        self.rename_hints.push(RenameHint::Table {
+            previous_name: previous_name.to_owned(),
+            next_name: next_name.to_owned(),
+        });
+
+        self
+    }
+
+    pub fn rename_column(mut self, table: &str, previous_name: &str, next_name: &str) -> Self {
+        self.rename_hints.push(RenameHint::Column {
+            table: table.to_owned(),
+            previous_name: previous_name.to_owned(),
+            next_name: next_name.to_owned(),
+        });
+
+        self
+    }
+
     pub async fn send(self) -> anyhow::Result<CreateMigrationAssertion<'a>> {
         let output = self
             .api
@@ -39,6 +69,8 @@ impl<'a> CreateMigration<'a> {
                 prisma_schema: self.schema.to_owned(),
                 draft: self.draft,
                 migration_name: self.name.to_owned(),
+                rename_hints: self.rename_hints,
+                online: self.online,
             })
             .await?;
 
@@ -163,4 +195,19 @@ impl MigrationAssertion<'_> {
 
         Ok(self)
     }
+
+    pub fn assert_contains(self, expected_substring: &str) -> AssertionResult<Self> {
+        let migration_file_path = self.path.join("migration.sql");
+        let contents: String = std::fs::read_to_string(&migration_file_path)
+            .with_context(|| format!("Trying to read migration file at {:?}", migration_file_path))?;
+
+        anyhow::ensure!(
+            contents.contains(expected_substring),
+            "Assertion failed. Expected the migration script to contain `{}`, but it was:\n{}",
+            expected_substring,
+            contents
+        );
+
+        Ok(self)
+    }
 }
@@ -0,0 +1,40 @@
+use migration_core::{
+    commands::{DiffInput, DiffOutput, DiffSource},
+    CoreResult, GenericApi,
+};
+
+#[must_use = "This struct does nothing on its own. See Diff::send()"]
+pub struct Diff<'a> {
+    api: &'a dyn GenericApi,
+    from: DiffSource,
+    to: DiffSource,
+    script: bool,
+}
+
+impl<'a> Diff<'a> {
+    pub fn new(api: &'a dyn GenericApi, from: DiffSource, to: DiffSource) -> Self {
+        Diff {
+            api,
+            from,
+            to,
+            script: false,
+        }
+    }
+
+    /// Render the diff as a script instead of a summary.
+    pub fn script(mut self) -> Self {
+        self.script = true;
+
+        self
+    }
+
+    pub async fn send(self) -> CoreResult<DiffOutput> {
+        self.api
+            .diff(&DiffInput {
+                from: self.from,
+                to: self.to,
+                script: self.script,
+            })
+            .await
+    }
+}
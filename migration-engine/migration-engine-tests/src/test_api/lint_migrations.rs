@@ -0,0 +1,37 @@
+use migration_core::{
+    commands::{LintMigrationsInput, LintMigrationsOutput},
+    CoreResult, GenericApi,
+};
+use tempfile::TempDir;
+
+#[must_use = "This struct does nothing on its own. See LintMigrations::send()"]
+pub struct LintMigrations<'a> {
+    api: &'a dyn GenericApi,
+    migrations_directory: &'a TempDir,
+    prisma_schema: Option<String>,
+}
+
+impl<'a> LintMigrations<'a> {
+    pub fn new(api: &'a dyn GenericApi, migrations_directory: &'a TempDir) -> Self {
+        LintMigrations {
+            api,
+            migrations_directory,
+            prisma_schema: None,
+        }
+    }
+
+    pub fn prisma_schema(mut self, prisma_schema: impl Into<String>) -> Self {
+        self.prisma_schema = Some(prisma_schema.into());
+
+        self
+    }
+
+    pub async fn send(self) -> CoreResult<LintMigrationsOutput> {
+        self.api
+            .lint_migrations(&LintMigrationsInput {
+                migrations_directory_path: self.migrations_directory.path().to_str().unwrap().to_owned(),
+                prisma_schema: self.prisma_schema,
+            })
+            .await
+    }
+}
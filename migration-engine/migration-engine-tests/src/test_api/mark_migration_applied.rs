@@ -0,0 +1,31 @@
+use migration_core::{
+    commands::{MarkMigrationAppliedInput, MarkMigrationAppliedOutput},
+    CoreResult, GenericApi,
+};
+use tempfile::TempDir;
+
+#[must_use = "This struct does nothing on its own. See MarkMigrationApplied::send()"]
+pub struct MarkMigrationApplied<'a> {
+    api: &'a dyn GenericApi,
+    migration_name: String,
+    migrations_directory: &'a TempDir,
+}
+
+impl<'a> MarkMigrationApplied<'a> {
+    pub fn new(api: &'a dyn GenericApi, migration_name: String, migrations_directory: &'a TempDir) -> Self {
+        MarkMigrationApplied {
+            api,
+            migration_name,
+            migrations_directory,
+        }
+    }
+
+    pub async fn send(self) -> CoreResult<MarkMigrationAppliedOutput> {
+        self.api
+            .mark_migration_applied(&MarkMigrationAppliedInput {
+                migrations_directory_path: self.migrations_directory.path().to_str().unwrap().to_owned(),
+                migration_name: self.migration_name,
+            })
+            .await
+    }
+}
@@ -0,0 +1,24 @@
+use migration_core::{
+    commands::{MarkMigrationRolledBackInput, MarkMigrationRolledBackOutput},
+    CoreResult, GenericApi,
+};
+
+#[must_use = "This struct does nothing on its own. See MarkMigrationRolledBack::send()"]
+pub struct MarkMigrationRolledBack<'a> {
+    api: &'a dyn GenericApi,
+    migration_name: String,
+}
+
+impl<'a> MarkMigrationRolledBack<'a> {
+    pub fn new(api: &'a dyn GenericApi, migration_name: String) -> Self {
+        MarkMigrationRolledBack { api, migration_name }
+    }
+
+    pub async fn send(self) -> CoreResult<MarkMigrationRolledBackOutput> {
+        self.api
+            .mark_migration_rolled_back(&MarkMigrationRolledBackInput {
+                migration_name: self.migration_name,
+            })
+            .await
+    }
+}
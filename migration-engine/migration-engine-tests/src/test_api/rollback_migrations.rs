@@ -0,0 +1,75 @@
+use migration_core::{commands::RollbackMigrationsInput, commands::RollbackMigrationsOutput, CoreResult, GenericApi};
+use tempfile::TempDir;
+
+use crate::AssertionResult;
+
+#[must_use = "This struct does nothing on its own. See RollbackMigrations::send()"]
+pub struct RollbackMigrations<'a> {
+    api: &'a dyn GenericApi,
+    migrations_directory: &'a TempDir,
+    to_migration: Option<String>,
+}
+
+impl<'a> RollbackMigrations<'a> {
+    pub fn new(api: &'a dyn GenericApi, migrations_directory: &'a TempDir) -> Self {
+        RollbackMigrations {
+            api,
+            migrations_directory,
+            to_migration: None,
+        }
+    }
+
+    pub fn to_migration(mut self, to_migration: impl Into<String>) -> Self {
+        self.to_migration = Some(to_migration.into());
+
+        self
+    }
+
+    pub async fn send(self) -> CoreResult<RollbackMigrationsAssertion<'a>> {
+        let output = self
+            .api
+            .rollback_migrations(&RollbackMigrationsInput {
+                migrations_directory_path: self.migrations_directory.path().to_str().unwrap().to_owned(),
+                to_migration: self.to_migration,
+            })
+            .await?;
+
+        Ok(RollbackMigrationsAssertion {
+            output,
+            _api: self.api,
+            _migrations_directory: self.migrations_directory,
+        })
+    }
+}
+
+pub struct RollbackMigrationsAssertion<'a> {
+    output: RollbackMigrationsOutput,
+    _api: &'a dyn GenericApi,
+    _migrations_directory: &'a TempDir,
+}
+
+impl std::fmt::Debug for RollbackMigrationsAssertion<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RollbackMigrationsAssertion {{ .. }}")
+    }
+}
+
+impl<'a> RollbackMigrationsAssertion<'a> {
+    pub fn assert_rolled_back_migrations(self, names: &[&str]) -> AssertionResult<Self> {
+        let found_names: Vec<&str> = self
+            .output
+            .rolled_back_migration_names
+            .iter()
+            .map(|name| &name[15..])
+            .collect();
+
+        anyhow::ensure!(
+            found_names == names,
+            "Assertion failed. The rolled back migrations do not match the expectations. ({:?} vs {:?})",
+            found_names,
+            names
+        );
+
+        Ok(self)
+    }
+}
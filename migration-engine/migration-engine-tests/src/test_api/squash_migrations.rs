@@ -0,0 +1,49 @@
+use migration_core::{
+    commands::{SquashMigrationsInput, SquashMigrationsOutput},
+    CoreResult, GenericApi,
+};
+use tempfile::TempDir;
+
+#[must_use = "This struct does nothing on its own. See SquashMigrations::send()"]
+pub struct SquashMigrations<'a> {
+    api: &'a dyn GenericApi,
+    migration_name: String,
+    migrations_directory: &'a TempDir,
+    from_migration: Option<String>,
+    to_migration: Option<String>,
+}
+
+impl<'a> SquashMigrations<'a> {
+    pub fn new(api: &'a dyn GenericApi, migration_name: String, migrations_directory: &'a TempDir) -> Self {
+        SquashMigrations {
+            api,
+            migration_name,
+            migrations_directory,
+            from_migration: None,
+            to_migration: None,
+        }
+    }
+
+    pub fn from_migration(mut self, from_migration: impl Into<String>) -> Self {
+        self.from_migration = Some(from_migration.into());
+
+        self
+    }
+
+    pub fn to_migration(mut self, to_migration: impl Into<String>) -> Self {
+        self.to_migration = Some(to_migration.into());
+
+        self
+    }
+
+    pub async fn send(self) -> CoreResult<SquashMigrationsOutput> {
+        self.api
+            .squash_migrations(&SquashMigrationsInput {
+                migrations_directory_path: self.migrations_directory.path().to_str().unwrap().to_owned(),
+                from_migration: self.from_migration,
+                to_migration: self.to_migration,
+                migration_name: self.migration_name,
+            })
+            .await
+    }
+}
@@ -217,3 +217,199 @@ async fn migrations_should_fail_on_an_uninitialized_nonempty_database(api: &Test
 
     Ok(())
 }
+
+fn write_data_script(migration_path: &std::path::Path, file_name: &str, script: &str) -> std::io::Result<()> {
+    std::fs::write(migration_path.join(format!("{}.sql", file_name)), script)
+}
+
+fn backfill_nickname_script(api: &TestApi) -> String {
+    match api.sql_family() {
+        SqlFamily::Mysql => "UPDATE `Cat` SET `nickname` = `name`;".to_owned(),
+        _ => r#"UPDATE "Cat" SET "nickname" = "name";"#.to_owned(),
+    }
+}
+
+const CAT_DM: &str = r#"
+    model Cat {
+        id      Int @id
+        name    String
+    }
+"#;
+
+const CAT_WITH_NICKNAME_DM: &str = r#"
+    model Cat {
+        id          Int @id
+        name        String
+        nickname    String?
+    }
+"#;
+
+#[test_each_connector]
+async fn data_migration_scripts_run_around_the_migration_script(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", CAT_DM, &migrations_directory)
+        .send()
+        .await?;
+    api.apply_migrations(&migrations_directory).send().await?;
+
+    api.insert("Cat")
+        .value("id", 1)
+        .value("name", "Musti")
+        .result_raw()
+        .await?;
+
+    let migration_name = api
+        .create_migration("second-migration", CAT_WITH_NICKNAME_DM, &migrations_directory)
+        .send()
+        .await?
+        .into_output()
+        .generated_migration_name
+        .unwrap();
+    let migration_path = migrations_directory.path().join(&migration_name);
+
+    write_data_script(&migration_path, "pre", "SELECT 1;")?;
+    write_data_script(&migration_path, "post", &backfill_nickname_script(api))?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["second-migration"])?;
+
+    api.select("Cat")
+        .column("nickname")
+        .send()
+        .await?
+        .assert_single_row(|row| row.assert_text_value("nickname", "Musti"))?;
+
+    let migration = api
+        .imperative_migration_persistence()
+        .list_migrations()
+        .await?
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    assert!(!migration.is_failed());
+    migration
+        .assert_migration_name("second-migration")?
+        .assert_applied_steps_count(3)?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn failed_data_migration_scripts_can_be_retried_without_rerunning_the_schema_change(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", CAT_DM, &migrations_directory)
+        .send()
+        .await?;
+    api.apply_migrations(&migrations_directory).send().await?;
+
+    api.insert("Cat")
+        .value("id", 1)
+        .value("name", "Musti")
+        .result_raw()
+        .await?;
+
+    let migration_name = api
+        .create_migration("second-migration", CAT_WITH_NICKNAME_DM, &migrations_directory)
+        .send()
+        .await?
+        .into_output()
+        .generated_migration_name
+        .unwrap();
+    let migration_path = migrations_directory.path().join(&migration_name);
+
+    write_data_script(&migration_path, "post", "SELECT (^.^)_n;")?;
+
+    assert!(api.apply_migrations(&migrations_directory).send().await.is_err());
+
+    let migration = api
+        .imperative_migration_persistence()
+        .list_migrations()
+        .await?
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    assert!(migration.is_failed());
+    assert_eq!(migration.failed_step_name.as_deref(), Some("post"));
+    migration
+        .assert_migration_name("second-migration")?
+        .assert_applied_steps_count(1)?;
+
+    // The schema change was applied, running it again would fail.
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_has_column("nickname"))?;
+
+    write_data_script(&migration_path, "post", &backfill_nickname_script(api))?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["second-migration"])?;
+
+    api.select("Cat")
+        .column("nickname")
+        .send()
+        .await?
+        .assert_single_row(|row| row.assert_text_value("nickname", "Musti"))?;
+
+    let mut migrations = api.imperative_migration_persistence().list_migrations().await?.unwrap();
+
+    assert_eq!(migrations.len(), 2);
+
+    let migration = migrations.pop().unwrap();
+
+    assert!(!migration.is_failed());
+    migration
+        .assert_migration_name("second-migration")?
+        .assert_applied_steps_count(2)?;
+
+    // The fixed script is recorded as applied.
+    let output = api
+        .diagnose_migration_history(&migrations_directory)
+        .send()
+        .await?
+        .into_output();
+
+    assert!(output.edited_migration_names.is_empty());
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn editing_an_applied_data_migration_script_is_detected(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    let migration_name = api
+        .create_migration("initial", CAT_DM, &migrations_directory)
+        .send()
+        .await?
+        .into_output()
+        .generated_migration_name
+        .unwrap();
+    let migration_path = migrations_directory.path().join(&migration_name);
+
+    write_data_script(&migration_path, "post", "SELECT 1;")?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial"])?;
+
+    write_data_script(&migration_path, "post", "SELECT 2;")?;
+
+    let output = api
+        .diagnose_migration_history(&migrations_directory)
+        .send()
+        .await?
+        .into_output();
+
+    assert_eq!(output.edited_migration_names, &[migration_name.as_str()]);
+
+    Ok(())
+}
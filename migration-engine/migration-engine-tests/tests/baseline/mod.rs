@@ -0,0 +1,108 @@
+use crate::*;
+use barrel::types;
+
+#[test_each_connector]
+async fn baseline_records_the_existing_schema_as_applied(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("Cat", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("name", types::text());
+            });
+        })
+        .await?;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    let output = api.baseline("baseline", &migrations_directory).send().await?;
+
+    let migrations = api.imperative_migration_persistence().list_migrations().await?.unwrap();
+
+    assert_eq!(migrations.len(), 1);
+    assert_eq!(migrations[0].migration_name, output.generated_migration_name);
+    assert!(migrations[0].finished_at.is_some());
+
+    let script = std::fs::read_to_string(
+        migrations_directory
+            .path()
+            .join(&output.generated_migration_name)
+            .join("migration.sql"),
+    )?;
+
+    assert!(script.contains("Cat"));
+    assert!(!script.contains("_prisma_migrations"));
+
+    // The baseline migration cannot be rolled back.
+    assert!(!migrations_directory
+        .path()
+        .join(&output.generated_migration_name)
+        .join("down.sql")
+        .exists());
+
+    let error = api
+        .rollback_migrations(&migrations_directory)
+        .send()
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(
+        error.contains("has no down migration script"),
+        "Unexpected error: {}",
+        error
+    );
+
+    // The baseline migration is not applied again.
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&[])?;
+
+    let output = api
+        .diagnose_migration_history(&migrations_directory)
+        .send()
+        .await?
+        .into_output();
+
+    assert!(output.failed_migration_names.is_empty());
+    assert!(output.edited_migration_names.is_empty());
+    assert!(output.history.is_none());
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_has_column("name"))?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn baseline_fails_if_the_migrations_directory_is_not_empty(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm, &migrations_directory)
+        .send()
+        .await?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial"])?;
+
+    let err = api
+        .baseline("baseline", &migrations_directory)
+        .send()
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(err.contains("The migrations directory already contains migrations."));
+
+    Ok(())
+}
@@ -248,3 +248,297 @@ async fn creating_a_migration_with_a_non_existent_migrations_directory_should_wo
 
     Ok(())
 }
+
+#[test_each_connector]
+async fn create_migration_with_a_column_rename_hint_preserves_data(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let dir = api.create_migrations_directory()?;
+
+    let initial_migration_name = api
+        .create_migration("initial", dm1, &dir)
+        .send()
+        .await?
+        .into_output()
+        .generated_migration_name
+        .unwrap();
+
+    api.apply_migrations(&dir)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial"])?;
+
+    api.insert("Cat")
+        .value("id", 1)
+        .value("name", "Musti")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id          Int @id
+            fullName    String
+        }
+    "#;
+
+    api.create_migration("rename-name", dm2, &dir)
+        .rename_column("Cat", "name", "fullName")
+        .send()
+        .await?;
+
+    api.apply_migrations(&dir)
+        .send()
+        .await?
+        .assert_applied_migrations(&["rename-name"])?;
+
+    api.select("Cat")
+        .column("fullName")
+        .send()
+        .await?
+        .assert_single_row(|row| row.assert_text_value("fullName", "Musti"))?;
+
+    // The down migration renames the column back.
+    api.rollback_migrations(&dir)
+        .to_migration(initial_migration_name)
+        .send()
+        .await?
+        .assert_rolled_back_migrations(&["rename-name"])?;
+
+    api.select("Cat")
+        .column("name")
+        .send()
+        .await?
+        .assert_single_row(|row| row.assert_text_value("name", "Musti"))?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn create_migration_with_a_table_rename_hint_preserves_data(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let dir = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm1, &dir).send().await?;
+
+    api.apply_migrations(&dir)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial"])?;
+
+    api.insert("Cat")
+        .value("id", 1)
+        .value("name", "Musti")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Feline {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    api.create_migration("rename-cat", dm2, &dir)
+        .rename_table("Cat", "Feline")
+        .send()
+        .await?;
+
+    api.apply_migrations(&dir)
+        .send()
+        .await?
+        .assert_applied_migrations(&["rename-cat"])?;
+
+    api.select("Feline")
+        .column("name")
+        .send()
+        .await?
+        .assert_single_row(|row| row.assert_text_value("name", "Musti"))?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn create_migration_with_unmatched_rename_hints_fails(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let dir = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm1, &dir).send().await?;
+
+    let dm2 = r#"
+        model Cat {
+            id       Int @id
+            fullName String
+        }
+    "#;
+
+    let error = api
+        .create_migration("rename-name", dm2, &dir)
+        .rename_column("Cat", "nickname", "fullName")
+        .rename_table("Dog", "Cat")
+        .send()
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.contains("table `Dog` to `Cat`"), error);
+    assert!(error.contains("column `nickname` to `fullName` on table `Cat`"), error);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn online_create_migration_builds_indexes_concurrently_and_validates_foreign_keys_separately(
+    api: &TestApi,
+) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+
+        model Dog {
+            id      Int @id
+        }
+    "#;
+
+    let dir = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm1, &dir).send().await?;
+
+    let dm2 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+            dogs    Dog[]
+
+            @@index([name])
+        }
+
+        model Dog {
+            id      Int @id
+            ownerId Int?
+            owner   Cat? @relation(fields: [ownerId], references: [id])
+        }
+    "#;
+
+    api.create_migration("online", dm2, &dir)
+        .online()
+        .send()
+        .await?
+        .assert_migration("online", |migration| {
+            migration
+                .assert_contains(
+                    "-- prisma:non-transactional\nCREATE INDEX CONCURRENTLY \"Cat.name_index\" ON \"prisma-tests\".\"Cat\"(\"name\");\n",
+                )?
+                .assert_contains("ADD CONSTRAINT \"Dog_ownerId_fkey\" FOREIGN KEY(\"ownerId\")")?
+                .assert_contains(
+                    "-- prisma:non-transactional\nALTER TABLE \"prisma-tests\".\"Dog\" VALIDATE CONSTRAINT \"Dog_ownerId_fkey\";\n",
+                )
+        })?;
+
+    api.apply_migrations(&dir)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial", "online"])?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| {
+            table.assert_index_on_columns(&["name"], |index| Ok(index))
+        })?
+        .assert_table("Dog", |table| {
+            table.assert_fk_on_columns(&["ownerId"], |fk| fk.assert_references("Cat", &["id"]))
+        })?;
+
+    // The history with the non-transactional statements replays cleanly on
+    // the temporary database.
+    let dm3 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+            dogs    Dog[]
+
+            @@index([name])
+        }
+
+        model Dog {
+            id      Int @id
+            breed   String?
+            ownerId Int?
+            owner   Cat? @relation(fields: [ownerId], references: [id])
+        }
+    "#;
+
+    api.create_migration("after-online", dm3, &dir)
+        .send()
+        .await?
+        .assert_migration("after-online", |migration| {
+            migration.assert_contains("ADD COLUMN \"breed\"")
+        })?;
+
+    api.apply_migrations(&dir)
+        .send()
+        .await?
+        .assert_applied_migrations(&["after-online"])?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn online_create_migration_creates_indexes_in_place_on_mysql(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let dir = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm1, &dir).send().await?;
+
+    let dm2 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+
+            @@index([name])
+        }
+    "#;
+
+    api.create_migration("online", dm2, &dir)
+        .online()
+        .send()
+        .await?
+        .assert_migration("online", |migration| {
+            migration.assert_contains("CREATE INDEX `Cat.name_index` ON `Cat`(`name`) ALGORITHM=INPLACE LOCK=NONE;\n")
+        })?;
+
+    api.apply_migrations(&dir)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial", "online"])?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_index_on_columns(&["name"], |index| Ok(index))
+    })?;
+
+    Ok(())
+}
@@ -0,0 +1,358 @@
+use crate::*;
+use migration_connector::DESTRUCTIVE_CHANGE_POLICY_FILENAME;
+use tempfile::TempDir;
+
+const DM1: &str = r#"
+    model Cat {
+        id      Int @id
+        name    String
+    }
+"#;
+
+const DM2: &str = r#"
+    model Cat {
+        id      Int @id
+    }
+"#;
+
+fn write_policy(migrations_directory: &TempDir, policy: &str) -> std::io::Result<()> {
+    std::fs::write(
+        migrations_directory.path().join(DESTRUCTIVE_CHANGE_POLICY_FILENAME),
+        policy,
+    )
+}
+
+#[test_each_connector]
+async fn policies_can_turn_warnings_into_errors(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", DM1, &migrations_directory)
+        .send()
+        .await?;
+    api.apply_migrations(&migrations_directory).send().await?;
+
+    api.insert("Cat")
+        .value("id", 1)
+        .value("name", "Felix")
+        .result_raw()
+        .await?;
+
+    write_policy(
+        &migrations_directory,
+        r#"{ "rules": [{ "check": "nonEmptyColumnDrop", "severity": "error" }] }"#,
+    )?;
+
+    api.evaluate_data_loss(&migrations_directory, DM2)
+        .send()
+        .await?
+        .assert_warnings(&[])?
+        .assert_unexecutable(&[
+            "You are about to drop the column `name` on the `Cat` table, which still contains 1 non-null values."
+                .into(),
+        ])?;
+
+    api.create_migration("second-migration", DM2, &migrations_directory)
+        .send()
+        .await?;
+
+    let err = api
+        .apply_migrations(&migrations_directory)
+        .send()
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(err.contains("was rejected by the destructive change policy"));
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_has_column("name"))?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn policies_can_allow_checks_on_specific_tables(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", DM1, &migrations_directory)
+        .send()
+        .await?;
+    api.apply_migrations(&migrations_directory).send().await?;
+
+    api.insert("Cat")
+        .value("id", 1)
+        .value("name", "Felix")
+        .result_raw()
+        .await?;
+
+    write_policy(
+        &migrations_directory,
+        r#"{ "rules": [{ "check": "nonEmptyColumnDrop", "tables": ["Dog"], "severity": "allow" }] }"#,
+    )?;
+
+    api.evaluate_data_loss(&migrations_directory, DM2)
+        .send()
+        .await?
+        .assert_warnings(&[
+            "You are about to drop the column `name` on the `Cat` table, which still contains 1 non-null values."
+                .into(),
+        ])?;
+
+    write_policy(
+        &migrations_directory,
+        r#"{ "rules": [{ "check": "nonEmptyColumnDrop", "tables": ["Cat"], "severity": "allow" }] }"#,
+    )?;
+
+    api.evaluate_data_loss(&migrations_directory, DM2)
+        .send()
+        .await?
+        .assert_warnings(&[])?
+        .assert_unexecutable(&[])?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn policy_annotations_in_migration_scripts_override_the_policy(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", DM1, &migrations_directory)
+        .send()
+        .await?;
+    api.apply_migrations(&migrations_directory).send().await?;
+
+    api.insert("Cat")
+        .value("id", 1)
+        .value("name", "Felix")
+        .result_raw()
+        .await?;
+
+    write_policy(
+        &migrations_directory,
+        r#"{ "rules": [{ "check": "nonEmptyColumnDrop", "severity": "error" }] }"#,
+    )?;
+
+    api.create_migration("second-migration", DM2, &migrations_directory)
+        .send()
+        .await?
+        .modify_migration(|script| script.insert_str(0, "-- prisma-policy: allow nonEmptyColumnDrop Cat\n"))?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["second-migration"])?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_columns_count(1))?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn policy_annotations_only_apply_to_their_own_migration(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    let dm1 = r#"
+        model Cat {
+            id          Int @id
+            name        String
+            nickname    String?
+        }
+    "#;
+
+    let dm2 = r#"
+        model Cat {
+            id          Int @id
+            nickname    String?
+        }
+    "#;
+
+    let dm3 = r#"
+        model Cat {
+            id          Int @id
+        }
+    "#;
+
+    api.create_migration("initial", dm1, &migrations_directory)
+        .send()
+        .await?;
+    api.apply_migrations(&migrations_directory).send().await?;
+
+    api.insert("Cat")
+        .value("id", 1)
+        .value("name", "Felix")
+        .value("nickname", "Fefe")
+        .result_raw()
+        .await?;
+
+    write_policy(
+        &migrations_directory,
+        r#"{ "rules": [{ "check": "nonEmptyColumnDrop", "severity": "error" }] }"#,
+    )?;
+
+    api.create_migration("drop-name", dm2, &migrations_directory)
+        .send()
+        .await?
+        .modify_migration(|script| script.insert_str(0, "-- prisma-policy: allow nonEmptyColumnDrop Cat\n"))?;
+
+    api.create_migration("drop-nickname", dm3, &migrations_directory)
+        .send()
+        .await?;
+
+    let err = api
+        .apply_migrations(&migrations_directory)
+        .send()
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(
+        err.contains("was rejected by the destructive change policy") && err.contains("`nickname`"),
+        "Unexpected error: {}",
+        err
+    );
+    assert!(!err.contains("`name`"), "Unexpected error: {}", err);
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_has_column("name")?.assert_has_column("nickname")
+    })?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn policies_with_unknown_checks_are_rejected(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    write_policy(
+        &migrations_directory,
+        r#"{ "rules": [{ "check": "nonEmptyColumDrop", "severity": "error" }] }"#,
+    )?;
+
+    let err = api
+        .evaluate_data_loss(&migrations_directory, DM1)
+        .send()
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(
+        err.contains("Unknown check `nonEmptyColumDrop`"),
+        "Unexpected error: {}",
+        err
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn policy_annotations_with_unknown_checks_are_rejected(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", DM1, &migrations_directory)
+        .send()
+        .await?
+        .modify_migration(|script| script.insert_str(0, "-- prisma-policy: allow nonEmptyColumDrop Cat\n"))?;
+
+    let err = api
+        .apply_migrations(&migrations_directory)
+        .send()
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(
+        err.contains("Unknown check `nonEmptyColumDrop`"),
+        "Unexpected error: {}",
+        err
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn policy_annotations_with_unknown_severities_are_rejected(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", DM1, &migrations_directory)
+        .send()
+        .await?
+        .modify_migration(|script| script.insert_str(0, "-- prisma-policy: alow nonEmptyColumnDrop Cat\n"))?;
+
+    let err = api
+        .apply_migrations(&migrations_directory)
+        .send()
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(err.contains("Unknown severity `alow`"), "Unexpected error: {}", err);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn indexes_created_concurrently_are_not_reported_on_large_tables(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", DM1, &migrations_directory)
+        .send()
+        .await?;
+    api.apply_migrations(&migrations_directory).send().await?;
+
+    api.insert("Cat")
+        .value("id", 1)
+        .value("name", "Felix")
+        .result_raw()
+        .await?;
+
+    write_policy(
+        &migrations_directory,
+        r#"{
+            "largeTableRowCount": 1,
+            "rules": [{ "check": "nonConcurrentIndexOnLargeTable", "severity": "error" }]
+        }"#,
+    )?;
+
+    let dm2 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+
+            @@index([name])
+        }
+    "#;
+
+    let migration_name = api
+        .create_migration("add-index", dm2, &migrations_directory)
+        .send()
+        .await?
+        .into_output()
+        .generated_migration_name
+        .unwrap();
+
+    let err = api
+        .apply_migrations(&migrations_directory)
+        .send()
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(
+        err.contains("was rejected by the destructive change policy"),
+        "Unexpected error: {}",
+        err
+    );
+
+    let script_path = migrations_directory.path().join(&migration_name).join("migration.sql");
+    let script = std::fs::read_to_string(&script_path)?.replacen("CREATE INDEX", "CREATE INDEX CONCURRENTLY", 1);
+    std::fs::write(&script_path, script)?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["add-index"])?;
+
+    Ok(())
+}
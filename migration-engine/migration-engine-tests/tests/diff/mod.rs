@@ -0,0 +1,121 @@
+use crate::*;
+use migration_core::commands::DiffSource;
+
+const DM1: &str = r#"
+    model Cat {
+        id      Int @id
+        name    String
+    }
+"#;
+
+// The added column is optional, so that SQLite adds it instead of redefining the table.
+const DM2: &str = r#"
+    model Cat {
+        id          Int @id
+        name        String
+        fluffiness  Float?
+    }
+"#;
+
+fn schema(dm: &str) -> DiffSource {
+    DiffSource::Schema { schema: dm.to_owned() }
+}
+
+#[test_each_connector]
+async fn diff_from_empty_to_a_schema_renders_a_script(api: &TestApi) -> TestResult {
+    let output = api.diff(DiffSource::Empty, schema(DM1)).script().send().await?;
+
+    assert!(output.has_changes);
+    assert!(output.diff.contains("-- CreateTable"));
+    assert!(output.diff.contains("CREATE TABLE"));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn diff_renders_a_summary_of_the_changes(api: &TestApi) -> TestResult {
+    let output = api.diff(DiffSource::Empty, schema(DM1)).send().await?;
+
+    assert!(output.has_changes);
+    assert_eq!(output.diff, "[+] Added table `Cat`\n");
+
+    let output = api.diff(schema(DM1), schema(DM2)).send().await?;
+
+    assert!(output.has_changes);
+    assert_eq!(
+        output.diff,
+        "[*] Changed the `Cat` table\n  [+] Added column `fluffiness`\n"
+    );
+
+    let output = api.diff(schema(DM1), DiffSource::Empty).send().await?;
+
+    assert!(output.has_changes);
+    assert_eq!(output.diff, "[-] Removed table `Cat`\n");
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn diff_from_a_migrations_directory_replays_the_migrations(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", DM1, &migrations_directory)
+        .send()
+        .await?;
+
+    let migrations_directory_source = || DiffSource::MigrationsDirectory {
+        path: migrations_directory.path().to_str().unwrap().to_owned(),
+    };
+
+    let output = api.diff(migrations_directory_source(), schema(DM1)).send().await?;
+
+    assert!(!output.has_changes);
+    assert_eq!(output.diff, "No difference detected.\n");
+
+    let output = api.diff(migrations_directory_source(), schema(DM2)).send().await?;
+
+    assert!(output.has_changes);
+    assert_eq!(
+        output.diff,
+        "[*] Changed the `Cat` table\n  [+] Added column `fluffiness`\n"
+    );
+
+    // The migrations are not applied to the database.
+    assert!(!api.describe_database().await?.has_table("Cat"));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn diff_from_a_database_describes_its_schema(api: &TestApi) -> TestResult {
+    api.schema_push(DM1).send().await?.assert_green()?;
+
+    let database_source = || DiffSource::Database {
+        url: api.url().to_owned(),
+    };
+
+    let output = api.diff(database_source(), schema(DM1)).send().await?;
+
+    assert!(!output.has_changes);
+    assert_eq!(output.diff, "No difference detected.\n");
+
+    let output = api.diff(database_source(), schema(DM2)).send().await?;
+
+    assert!(output.has_changes);
+    assert_eq!(
+        output.diff,
+        "[*] Changed the `Cat` table\n  [+] Added column `fluffiness`\n"
+    );
+
+    let output = api.diff(database_source(), DiffSource::Empty).send().await?;
+
+    assert!(output.has_changes);
+    assert_eq!(output.diff, "[-] Removed table `Cat`\n");
+
+    // The database is only described, not migrated.
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_columns_count(2))?;
+
+    Ok(())
+}
@@ -358,7 +358,7 @@ async fn renaming_a_field_where_the_column_was_already_renamed_must_work(api: &T
 
     let final_column = final_result.table_bang("Blog").column_bang("new_title");
 
-    assert_eq!(final_column.tpe.family, ColumnTypeFamily::Decimal);
+    assert_eq!(final_column.tpe.family, ColumnTypeFamily::Float);
     assert!(final_result.table_bang("Blog").column("title").is_none());
 
     Ok(())
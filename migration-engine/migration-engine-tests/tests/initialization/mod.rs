@@ -85,3 +85,26 @@ async fn connecting_to_a_postgres_database_with_missing_schema_creates_it() {
         assert!(schema_exists)
     }
 }
+
+#[tokio::test]
+async fn schemas_with_multiple_datasources_are_rejected() {
+    let datamodel = r#"
+        datasource db {
+            provider = "sqlite"
+            url = "file:dev.db"
+        }
+
+        datasource analytics {
+            provider = "sqlite"
+            url = "file:analytics.db"
+        }
+    "#;
+
+    let err = migration_api(datamodel).await.map(drop).unwrap_err().to_string();
+
+    assert!(
+        err.contains("Migrations do not support multiple datasources yet."),
+        "Unexpected error: {}",
+        err
+    );
+}
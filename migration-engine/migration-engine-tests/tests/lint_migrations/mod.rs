@@ -0,0 +1,271 @@
+use crate::*;
+
+const DM1: &str = r#"
+    model Cat {
+        id      Int @id
+        name    String
+    }
+"#;
+
+const DM2: &str = r#"
+    model Cat {
+        id      Int @id
+    }
+"#;
+
+fn lints(output: &migration_core::commands::LintMigrationsOutput) -> Vec<(&str, &str)> {
+    output
+        .diagnostics
+        .iter()
+        .map(|diagnostic| (diagnostic.migration_name.as_str(), diagnostic.lint.as_str()))
+        .collect()
+}
+
+#[test_each_connector(tags("postgres", "mysql"))]
+async fn lint_migrations_reports_dropped_columns_still_in_the_prisma_schema(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", DM1, &migrations_directory)
+        .send()
+        .await?;
+    api.apply_migrations(&migrations_directory).send().await?;
+
+    let second_migration_name = api
+        .create_migration("second-migration", DM2, &migrations_directory)
+        .send()
+        .await?
+        .into_output()
+        .generated_migration_name
+        .unwrap();
+
+    let output = api.lint_migrations(&migrations_directory).send().await?;
+
+    assert!(output.diagnostics.is_empty());
+
+    let output = api
+        .lint_migrations(&migrations_directory)
+        .prisma_schema(DM1)
+        .send()
+        .await?;
+
+    assert_eq!(
+        lints(&output),
+        &[(second_migration_name.as_str(), "droppedColumnStillInSchema")]
+    );
+    assert_eq!(
+        output.diagnostics[0].message,
+        "The `name` column is dropped from the `Cat` table, but it is still in the Prisma schema."
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn lint_migrations_reports_foreign_keys_without_index(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id      Int @id
+            dogs    Dog[]
+        }
+
+        model Dog {
+            id      Int @id
+            ownerId Int
+            owner   Cat @relation(fields: [ownerId], references: [id])
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    let migration_name = api
+        .create_migration("initial", dm, &migrations_directory)
+        .send()
+        .await?
+        .into_output()
+        .generated_migration_name
+        .unwrap();
+
+    let output = api.lint_migrations(&migrations_directory).send().await?;
+
+    assert_eq!(lints(&output), &[(migration_name.as_str(), "missingIndexOnForeignKey")]);
+
+    let indexed_dm = r#"
+        model Cat {
+            id      Int @id
+            dogs    Dog[]
+        }
+
+        model Dog {
+            id      Int @id
+            ownerId Int
+            owner   Cat @relation(fields: [ownerId], references: [id])
+
+            @@index([ownerId])
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", indexed_dm, &migrations_directory)
+        .send()
+        .await?;
+
+    let output = api.lint_migrations(&migrations_directory).send().await?;
+
+    assert!(output.diagnostics.is_empty());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn lint_migrations_reports_table_rewrites_and_mixed_transactionality(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+            age     Int
+        }
+    "#;
+
+    let dm2 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+            age     String
+
+            @@index([name])
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm1, &migrations_directory)
+        .send()
+        .await?;
+    api.apply_migrations(&migrations_directory).send().await?;
+
+    let migration_name = api
+        .create_migration("online", dm2, &migrations_directory)
+        .online()
+        .send()
+        .await?
+        .into_output()
+        .generated_migration_name
+        .unwrap();
+
+    let output = api.lint_migrations(&migrations_directory).send().await?;
+
+    assert_eq!(
+        lints(&output),
+        &[
+            (migration_name.as_str(), "tableRewrite"),
+            (migration_name.as_str(), "mixedTransactionality"),
+        ]
+    );
+
+    // Applied migrations are not linted.
+    api.apply_migrations(&migrations_directory).send().await?;
+
+    let output = api.lint_migrations(&migrations_directory).send().await?;
+
+    assert!(output.diagnostics.is_empty());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn lint_migrations_reports_unmarked_concurrent_index_creation(api: &TestApi) -> TestResult {
+    let dm2 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+
+            @@index([name])
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", DM1, &migrations_directory)
+        .send()
+        .await?;
+
+    let migration_name = api
+        .create_migration("add-index", dm2, &migrations_directory)
+        .send()
+        .await?
+        .modify_migration(|script| {
+            *script = script.replacen("CREATE INDEX", "CREATE INDEX CONCURRENTLY", 1);
+        })?
+        .into_output()
+        .generated_migration_name
+        .unwrap();
+
+    let output = api.lint_migrations(&migrations_directory).send().await?;
+
+    assert_eq!(
+        lints(&output),
+        &[(migration_name.as_str(), "unmarkedNonTransactionalStatement")]
+    );
+    assert_eq!(
+        output.diagnostics[0].message,
+        "The `Cat.name_index` index is created or dropped concurrently, which cannot run in a transaction, but the statement is not marked as non-transactional."
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn lint_migrations_only_reports_changes_that_rewrite_rows(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", DM1, &migrations_directory)
+        .send()
+        .await?;
+    api.apply_migrations(&migrations_directory).send().await?;
+
+    api.insert("Cat")
+        .value("id", 1)
+        .value("name", "Felix")
+        .result_raw()
+        .await?;
+
+    std::fs::write(
+        migrations_directory
+            .path()
+            .join(migration_connector::DESTRUCTIVE_CHANGE_POLICY_FILENAME),
+        r#"{ "largeTableRowCount": 1 }"#,
+    )?;
+
+    let dm2 = r#"
+        model Cat {
+            id          Int @id
+            name        String
+            createdAt   DateTime @default(now())
+        }
+    "#;
+
+    // The default of `createdAt` is computed once for all rows, and turning
+    // `name` into an unbounded varchar does not touch the rows either.
+    let migration_name = api
+        .create_migration("changes", dm2, &migrations_directory)
+        .send()
+        .await?
+        .modify_migration(|script| {
+            script.push_str("\nALTER TABLE \"Cat\" ALTER COLUMN \"name\" TYPE VARCHAR;\n");
+            script.push_str("\nALTER TABLE \"Cat\" ADD COLUMN \"score\" DOUBLE PRECISION NOT NULL DEFAULT random();\n");
+        })?
+        .into_output()
+        .generated_migration_name
+        .unwrap();
+
+    let output = api.lint_migrations(&migrations_directory).send().await?;
+
+    assert_eq!(
+        lints(&output),
+        &[(migration_name.as_str(), "volatileDefaultOnLargeTable")]
+    );
+    assert!(output.diagnostics[0].message.starts_with("The `score` column"));
+
+    Ok(())
+}
@@ -0,0 +1,187 @@
+use crate::*;
+
+const BROKEN_STATEMENT: &str = "SELECT (^.^)_n;\n";
+
+#[test_each_connector]
+async fn mark_migration_applied_resolves_a_failed_migration(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm1, &migrations_directory)
+        .send()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id          Int @id
+            name        String
+            fluffiness  Float
+        }
+    "#;
+
+    let second_migration_name = api
+        .create_migration("second-migration", dm2, &migrations_directory)
+        .send()
+        .await?
+        .modify_migration(|contents| contents.insert_str(0, BROKEN_STATEMENT))?
+        .into_output()
+        .generated_migration_name
+        .unwrap();
+
+    assert!(api.apply_migrations(&migrations_directory).send().await.is_err());
+
+    let output = api
+        .diagnose_migration_history(&migrations_directory)
+        .send()
+        .await?
+        .into_output();
+
+    assert_eq!(output.failed_migration_names, &[second_migration_name.as_str()]);
+
+    // The migration is fixed and applied manually.
+    api.mark_migration_applied(&second_migration_name, &migrations_directory)
+        .send()
+        .await?;
+
+    let output = api
+        .diagnose_migration_history(&migrations_directory)
+        .send()
+        .await?
+        .into_output();
+
+    assert!(output.failed_migration_names.is_empty());
+    assert!(output.edited_migration_names.is_empty());
+    assert!(output.history.is_none());
+
+    let migrations = api.imperative_migration_persistence().list_migrations().await?.unwrap();
+
+    assert_eq!(migrations.len(), 3);
+    assert!(migrations[1].is_failed() && migrations[1].is_rolled_back());
+    assert!(!migrations[2].is_failed() && !migrations[2].is_rolled_back());
+
+    // Nothing is left to apply.
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&[])?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn mark_migration_applied_on_an_applied_migration_fails(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    let migration_name = api
+        .create_migration("initial", dm, &migrations_directory)
+        .send()
+        .await?
+        .into_output()
+        .generated_migration_name
+        .unwrap();
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial"])?;
+
+    let error = api
+        .mark_migration_applied(&migration_name, &migrations_directory)
+        .send()
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(
+        error.contains("is already recorded as applied in the database"),
+        "Unexpected error: {}",
+        error
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn mark_migration_applied_with_an_unknown_migration_fails(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.imperative_migration_persistence().initialize().await?;
+
+    let error = api
+        .mark_migration_applied("20201010101010_unknown", &migrations_directory)
+        .send()
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(
+        error.contains("cannot be found in the migrations directory"),
+        "Unexpected error: {}",
+        error
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn mark_migration_applied_on_a_migration_edited_since_it_failed_records_the_edited_script(
+    api: &TestApi,
+) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    let migration_name = api
+        .create_migration("initial", dm, &migrations_directory)
+        .send()
+        .await?
+        .modify_migration(|contents| contents.insert_str(0, BROKEN_STATEMENT))?
+        .into_output()
+        .generated_migration_name
+        .unwrap();
+
+    assert!(api.apply_migrations(&migrations_directory).send().await.is_err());
+
+    // The script is fixed and applied manually.
+    let script_path = migrations_directory.path().join(&migration_name).join("migration.sql");
+    let fixed_script = std::fs::read_to_string(&script_path)?.replacen(BROKEN_STATEMENT, "", 1);
+    std::fs::write(&script_path, fixed_script)?;
+
+    api.mark_migration_applied(&migration_name, &migrations_directory)
+        .send()
+        .await?;
+
+    let output = api
+        .diagnose_migration_history(&migrations_directory)
+        .send()
+        .await?
+        .into_output();
+
+    assert!(output.failed_migration_names.is_empty());
+    assert!(output.edited_migration_names.is_empty());
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&[])?;
+
+    Ok(())
+}
@@ -0,0 +1,148 @@
+use crate::*;
+
+const BROKEN_STATEMENT: &str = "SELECT (^.^)_n;\n";
+
+#[test_each_connector]
+async fn mark_migration_rolled_back_resolves_a_failed_migration(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm1, &migrations_directory)
+        .send()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id          Int @id
+            name        String
+            fluffiness  Float
+        }
+    "#;
+
+    let second_migration_name = api
+        .create_migration("second-migration", dm2, &migrations_directory)
+        .send()
+        .await?
+        .modify_migration(|contents| contents.insert_str(0, BROKEN_STATEMENT))?
+        .into_output()
+        .generated_migration_name
+        .unwrap();
+
+    assert!(api.apply_migrations(&migrations_directory).send().await.is_err());
+
+    api.mark_migration_rolled_back(&second_migration_name).send().await?;
+
+    let output = api
+        .diagnose_migration_history(&migrations_directory)
+        .send()
+        .await?
+        .into_output();
+
+    assert!(output.failed_migration_names.is_empty());
+
+    // Fix the migration script and apply it again.
+    let script_path = migrations_directory
+        .path()
+        .join(&second_migration_name)
+        .join("migration.sql");
+    let fixed_script = std::fs::read_to_string(&script_path)?.replacen(BROKEN_STATEMENT, "", 1);
+    std::fs::write(&script_path, fixed_script)?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["second-migration"])?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_has_column("fluffiness"))?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn mark_migration_rolled_back_on_a_migration_that_did_not_fail_fails(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    let migration_name = api
+        .create_migration("initial", dm, &migrations_directory)
+        .send()
+        .await?
+        .into_output()
+        .generated_migration_name
+        .unwrap();
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial"])?;
+
+    let error = api
+        .mark_migration_rolled_back(&migration_name)
+        .send()
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(
+        error.contains("is not in a failed state"),
+        "Unexpected error: {}",
+        error
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn mark_migration_rolled_back_on_a_migration_edited_since_it_failed_works(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    let migration_name = api
+        .create_migration("initial", dm, &migrations_directory)
+        .send()
+        .await?
+        .modify_migration(|contents| contents.insert_str(0, BROKEN_STATEMENT))?
+        .into_output()
+        .generated_migration_name
+        .unwrap();
+
+    assert!(api.apply_migrations(&migrations_directory).send().await.is_err());
+
+    // The script is fixed before the failed attempt is marked as rolled back.
+    let script_path = migrations_directory.path().join(&migration_name).join("migration.sql");
+    let fixed_script = std::fs::read_to_string(&script_path)?.replacen(BROKEN_STATEMENT, "", 1);
+    std::fs::write(&script_path, fixed_script)?;
+
+    api.mark_migration_rolled_back(&migration_name).send().await?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial"])?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_has_column("name"))?;
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use chrono::Duration;
 use migration_engine_tests::*;
 use pretty_assertions::assert_eq;
+use quaint::prelude::{Queryable, SqlFamily};
 
 #[test_each_connector]
 async fn starting_a_migration_works(api: &TestApi) -> TestResult {
@@ -202,3 +203,99 @@ async fn multiple_successive_migrations_work(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_each_connector]
+async fn migrations_tables_without_the_failed_step_name_column_are_upgraded(api: &TestApi) -> TestResult {
+    // The layout of the migrations table before the `failed_step_name` column.
+    let create_table = match api.sql_family() {
+        SqlFamily::Postgres => {
+            r#"
+            CREATE TABLE _prisma_migrations (
+                id                      VARCHAR(36) PRIMARY KEY NOT NULL,
+                checksum                VARCHAR(64) NOT NULL,
+                finished_at             TIMESTAMPTZ,
+                migration_name          TEXT NOT NULL,
+                logs                    TEXT NOT NULL,
+                rolled_back_at          TIMESTAMPTZ,
+                started_at              TIMESTAMPTZ NOT NULL DEFAULT now(),
+                applied_steps_count     INTEGER NOT NULL DEFAULT 0,
+                script                  TEXT NOT NULL
+            );
+            "#
+        }
+        SqlFamily::Mysql => {
+            r#"
+            CREATE TABLE _prisma_migrations (
+                id                      VARCHAR(36) PRIMARY KEY NOT NULL,
+                checksum                VARCHAR(64) NOT NULL,
+                finished_at             DATETIME(3),
+                migration_name          TEXT NOT NULL,
+                logs                    TEXT NOT NULL,
+                rolled_back_at          DATETIME(3),
+                started_at              DATETIME(3) NOT NULL DEFAULT CURRENT_TIMESTAMP(3),
+                applied_steps_count     INTEGER UNSIGNED NOT NULL DEFAULT 0,
+                script                  TEXT NOT NULL
+            ) DEFAULT CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci;
+            "#
+        }
+        SqlFamily::Sqlite => {
+            r#"
+            CREATE TABLE "_prisma_migrations" (
+                "id"                    TEXT PRIMARY KEY NOT NULL,
+                "checksum"              TEXT NOT NULL,
+                "finished_at"           DATETIME,
+                "migration_name"        TEXT NOT NULL,
+                "logs"                  TEXT NOT NULL,
+                "rolled_back_at"        DATETIME,
+                "started_at"            DATETIME NOT NULL DEFAULT current_timestamp,
+                "applied_steps_count"   INTEGER UNSIGNED NOT NULL DEFAULT 0,
+                "script"                TEXT NOT NULL
+            );
+            "#
+        }
+        SqlFamily::Mssql => {
+            r#"
+            CREATE TABLE [_prisma_migrations] (
+                id                      VARCHAR(36) PRIMARY KEY NOT NULL,
+                checksum                VARCHAR(64) NOT NULL,
+                finished_at             DATETIMEOFFSET,
+                migration_name          NVARCHAR(MAX) NOT NULL,
+                logs                    NVARCHAR(MAX) NOT NULL,
+                rolled_back_at          DATETIMEOFFSET,
+                started_at              DATETIMEOFFSET NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                applied_steps_count     INT NOT NULL DEFAULT 0,
+                script                  NVARCHAR(MAX) NOT NULL
+            );
+            "#
+        }
+    };
+
+    api.database().raw_cmd(create_table).await?;
+
+    let persistence = api.imperative_migration_persistence();
+
+    // Recording a migration does not touch the new column.
+    let old_id = persistence
+        .record_migration_started("old_migration", "-- old migration")
+        .await?;
+    persistence.record_migration_finished(&old_id).await?;
+
+    persistence.initialize().await?;
+
+    let id = persistence
+        .record_migration_started("new_migration", "-- new migration")
+        .await?;
+    persistence
+        .record_failed_step(&id, "migration.sql", "logs for the new migration")
+        .await?;
+
+    let migrations = persistence.list_migrations().await?.unwrap();
+
+    assert_eq!(migrations.len(), 2);
+    assert_eq!(migrations[0].id, old_id);
+    assert_eq!(migrations[0].failed_step_name, None);
+    assert_eq!(migrations[1].id, id);
+    assert_eq!(migrations[1].failed_step_name.as_deref(), Some("migration.sql"));
+
+    Ok(())
+}
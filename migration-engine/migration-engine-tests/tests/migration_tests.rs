@@ -1,20 +1,28 @@
 mod apply_migration;
 mod apply_migrations;
+mod baseline;
 mod calculate_database_steps;
 mod create_migration;
 mod datamodel_calculator;
 mod datamodel_steps_inferrer;
+mod destructive_change_policy;
 mod diagnose_migration_history;
+mod diff;
 mod errors;
 mod evaluate_data_loss;
 mod existing_data;
 mod existing_databases;
 mod infer_migration_steps;
 mod initialization;
+mod lint_migrations;
+mod mark_migration_applied;
+mod mark_migration_rolled_back;
 mod migration_persistence;
 mod migrations;
 mod reset;
+mod rollback_migrations;
 mod schema_push;
+mod squash_migrations;
 mod unapply_migration;
 
 use migration_engine_tests::sql::*;
@@ -45,9 +53,7 @@ async fn adding_a_scalar_field_must_work(api: &TestApi) -> TestResult {
                 c.assert_is_required()?.assert_type_family(ColumnTypeFamily::Int)
             })?
             .assert_column("float", |c| {
-                //The native types work made the inferrence more correct on the describer level.
-                // But unless the feature is activated, this will be mapped to float like before in the datamodel level
-                c.assert_is_required()?.assert_type_family(ColumnTypeFamily::Decimal)
+                c.assert_is_required()?.assert_type_family(ColumnTypeFamily::Float)
             })?
             .assert_column("boolean", |c| {
                 c.assert_is_required()?.assert_type_family(ColumnTypeFamily::Boolean)
@@ -528,6 +534,43 @@ async fn changing_the_type_of_an_id_field_must_work(api: &TestApi) -> TestResult
     Ok(())
 }
 
+#[test_each_connector(tags("postgres"))]
+async fn changing_an_int_field_to_big_int_must_work(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id      Int @id
+            weight  Int
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_column("weight", |col| col.assert_full_data_type("int4"))
+    })?;
+
+    let dm2 = r#"
+        model Cat {
+            id      Int @id
+            weight  BigInt
+        }
+    "#;
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_column("weight", |col| col.assert_full_data_type("int8"))
+    })?;
+
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
 #[test_each_connector]
 async fn changing_the_type_of_a_field_referenced_by_a_fk_must_work(api: &TestApi) -> TestResult {
     let dm1 = r#"
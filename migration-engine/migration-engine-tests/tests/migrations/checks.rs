@@ -0,0 +1,164 @@
+use migration_engine_tests::sql::*;
+use test_macros::test_each_connector_mssql as test_each_connector;
+
+#[test_each_connector(tags("postgres", "sqlite", "mysql_8", "mssql_2017", "mssql_2019"))]
+async fn check_constraints_can_be_added_and_dropped(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Product {
+            id    Int @id
+            price Int
+
+            @@check("price > 0", name: "positive_price")
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Product", |table| {
+        table.assert_checks_count(1)?.assert_has_check("positive_price")
+    })?;
+
+    // Check that the migration is idempotent.
+    api.schema_push(dm1).send().await?.assert_green()?.assert_no_steps()?;
+
+    let dm2 = r#"
+        model Product {
+            id    Int @id
+            price Int
+        }
+    "#;
+
+    api.schema_push(dm2).send().await?.assert_green()?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Product", |table| table.assert_checks_count(0))?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "sqlite", "mysql_8", "mssql_2017", "mssql_2019"))]
+async fn check_constraints_can_be_added_and_replaced(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Product {
+            id    Int @id
+            price Int
+
+            @@check("price > 0", name: "positive_price")
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Product", |table| {
+        table.assert_checks_count(1)?.assert_has_check("positive_price")
+    })?;
+
+    // Check that the migration is idempotent.
+    api.schema_push(dm1).send().await?.assert_green()?.assert_no_steps()?;
+
+    let dm2 = r#"
+        model Product {
+            id    Int @id
+            price Int
+
+            @@check("price >= 0", name: "positive_price")
+        }
+    "#;
+
+    api.schema_push(dm2).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Product", |table| {
+        table
+            .assert_checks_count(1)?
+            .assert_check_definition("positive_price", "price >= 0")
+    })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "sqlite", "mysql_8", "mssql_2017", "mssql_2019"))]
+async fn unnamed_check_constraints_are_named_by_the_database(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Product {
+            id    Int @id
+            price Int
+
+            @@check("price > 0 AND price < 100")
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Product", |table| table.assert_checks_count(1))?;
+
+    // The database rewrites the expression and names the constraint, it still matches the attribute.
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn check_constraints_without_check_attributes_are_dropped(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Product {
+            id    Int @id
+            price Int
+
+            @@check("price > 0", name: "positive_price")
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.database()
+        .raw_cmd(r#"ALTER TABLE "prisma-tests"."Product" ADD CONSTRAINT "cheap" CHECK (price < 1000)"#)
+        .await?;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Product", |table| {
+        table.assert_checks_count(1)?.assert_has_check("positive_price")
+    })?;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn check_constraints_are_preserved_when_redefining_tables_on_sqlite(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Product {
+            id    Int @id
+            price Int
+
+            @@check("price > 0", name: "positive_price")
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Product {
+            id    Int    @id
+            price Int
+            name  String
+
+            @@check("price > 0", name: "positive_price")
+        }
+    "#;
+
+    api.schema_push(dm2).force(true).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Product", |table| {
+        table
+            .assert_has_column("name")?
+            .assert_checks_count(1)?
+            .assert_has_check("positive_price")
+    })?;
+
+    Ok(())
+}
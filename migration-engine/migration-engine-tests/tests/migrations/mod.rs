@@ -1,8 +1,10 @@
+mod checks;
 mod enums;
 mod indexes;
 mod json;
 mod mariadb;
 mod mysql;
 mod postgres;
+mod referential_actions;
 mod sql;
 mod sqlite;
@@ -286,3 +286,31 @@ async fn native_type_columns_can_be_created(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_each_connector(tags("mysql"))]
+async fn fulltext_indexes_can_be_created_and_are_idempotent(api: &TestApi) -> TestResult {
+    let dm = format!(
+        r#"
+        {datasource_block}
+
+        model Post {{
+            id      Int    @id
+            title   String
+            content String
+
+            @@fulltext([title, content], name: "post_search")
+        }}
+        "#,
+        datasource_block = api.datasource(),
+    );
+
+    api.schema_push(&dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Post", |table| {
+        table.assert_index_on_columns(&["title", "content"], |idx| idx.assert_is_fulltext())
+    })?;
+
+    api.schema_push(&dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use barrel::types;
+use migration_engine_tests::sql::*;
+use sql_schema_describer::ForeignKeyAction;
+
+#[test_each_connector(tags("postgres", "mysql", "sqlite"))]
+async fn referential_actions_are_rendered_on_foreign_keys(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model User {
+            id    Int    @id
+            posts Post[]
+        }
+
+        model Post {
+            id     Int   @id
+            userId Int?
+            user   User? @relation(fields: [userId], references: [id], onDelete: Restrict, onUpdate: SetNull)
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Post", |table| {
+        table.assert_fk_on_columns(&["userId"], |fk| {
+            fk.assert_on_delete(ForeignKeyAction::Restrict)?
+                .assert_on_update(ForeignKeyAction::SetNull)
+        })
+    })?;
+
+    // Check that the migration is idempotent.
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "sqlite"))]
+async fn changing_referential_actions_recreates_the_foreign_key(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model User {
+            id    Int    @id
+            posts Post[]
+        }
+
+        model Post {
+            id     Int  @id
+            userId Int
+            user   User @relation(fields: [userId], references: [id])
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Post", |table| {
+        table.assert_fk_on_columns(&["userId"], |fk| {
+            fk.assert_on_delete(ForeignKeyAction::Cascade)?
+                .assert_on_update(ForeignKeyAction::Cascade)
+        })
+    })?;
+
+    let dm2 = r#"
+        model User {
+            id    Int    @id
+            posts Post[]
+        }
+
+        model Post {
+            id     Int  @id
+            userId Int
+            user   User @relation(fields: [userId], references: [id], onDelete: NoAction)
+        }
+    "#;
+
+    api.schema_push(dm2).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Post", |table| {
+        table.assert_fk_on_columns(&["userId"], |fk| fk.assert_on_delete(ForeignKeyAction::NoAction))
+    })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql", "sqlite"))]
+async fn foreign_keys_without_referential_actions_are_not_recreated_after_introspection(api: &TestApi) -> TestResult {
+    let sql_schema = api
+        .barrel()
+        .execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+            });
+
+            migration.create_table("Post", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("userId", types::integer().nullable(false));
+                t.add_foreign_key(&["userId"], "User", &["id"]);
+            });
+        })
+        .await?;
+
+    // MySQL creates an index for the foreign key.
+    let index = if api.is_mysql() {
+        r#"@@index([userId], name: "userId")"#
+    } else {
+        ""
+    };
+
+    // The data model introspection renders for the tables above.
+    let dm = format!(
+        r#"
+        model User {{
+            id   Int    @id @default(autoincrement())
+            Post Post[]
+        }}
+
+        model Post {{
+            id     Int  @id @default(autoincrement())
+            userId Int
+            User   User @relation(fields: [userId], references: [id], onDelete: NoAction, onUpdate: NoAction)
+
+            {index}
+        }}
+    "#,
+        index = index
+    );
+
+    api.schema_push(&dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    api.assert_schema().await?.assert_equals(&sql_schema)?;
+
+    Ok(())
+}
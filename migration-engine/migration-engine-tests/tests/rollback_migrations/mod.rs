@@ -0,0 +1,145 @@
+use crate::*;
+
+const DM1: &str = r#"
+    model Cat {
+        id      Int @id
+        name    String
+    }
+"#;
+
+const DM2: &str = r#"
+    model Cat {
+        id          Int @id
+        name        String
+        fluffiness  Float
+    }
+"#;
+
+#[test_each_connector]
+async fn rolling_back_to_a_migration_works(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    let initial_migration_name = api
+        .create_migration("initial", DM1, &migrations_directory)
+        .send()
+        .await?
+        .into_output()
+        .generated_migration_name
+        .unwrap();
+
+    api.create_migration("second-migration", DM2, &migrations_directory)
+        .send()
+        .await?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial", "second-migration"])?;
+
+    api.rollback_migrations(&migrations_directory)
+        .to_migration(initial_migration_name)
+        .send()
+        .await?
+        .assert_rolled_back_migrations(&["second-migration"])?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_does_not_have_column("fluffiness"))?;
+
+    // The rolled back migration can be applied again.
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["second-migration"])?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_has_column("fluffiness"))?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn rolling_back_all_migrations_works(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", DM1, &migrations_directory)
+        .send()
+        .await?;
+
+    api.create_migration("second-migration", DM2, &migrations_directory)
+        .send()
+        .await?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial", "second-migration"])?;
+
+    api.rollback_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_rolled_back_migrations(&["second-migration", "initial"])?;
+
+    let migrations = api.imperative_migration_persistence().list_migrations().await?.unwrap();
+
+    assert_eq!(migrations.len(), 2);
+    assert!(migrations.iter().all(|migration| migration.is_rolled_back()));
+
+    // Nothing is left to roll back.
+    api.rollback_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_rolled_back_migrations(&[])?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn rolling_back_fails_without_a_down_migration_script(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", DM1, &migrations_directory)
+        .send()
+        .await?;
+
+    let second_migration_name = api
+        .create_migration("second-migration", DM2, &migrations_directory)
+        .send()
+        .await?
+        .into_output()
+        .generated_migration_name
+        .unwrap();
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial", "second-migration"])?;
+
+    std::fs::remove_file(
+        migrations_directory
+            .path()
+            .join(&second_migration_name)
+            .join("down.sql"),
+    )?;
+
+    let error = api
+        .rollback_migrations(&migrations_directory)
+        .send()
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(
+        error.contains("has no down migration script"),
+        "Unexpected error: {}",
+        error
+    );
+
+    // Nothing was rolled back.
+    let migrations = api.imperative_migration_persistence().list_migrations().await?.unwrap();
+
+    assert!(migrations.iter().all(|migration| !migration.is_rolled_back()));
+
+    Ok(())
+}
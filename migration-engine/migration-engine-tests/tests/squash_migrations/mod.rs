@@ -0,0 +1,254 @@
+use crate::*;
+use migration_core::commands::HistoryDiagnostic;
+use std::path::Path;
+
+const DM1: &str = r#"
+    model Cat {
+        id      Int @id
+        name    String
+    }
+"#;
+
+const DM2: &str = r#"
+    model Cat {
+        id          Int @id
+        name        String
+        fluffiness  Float
+    }
+"#;
+
+const DM3: &str = r#"
+    model Cat {
+        id          Int @id
+        name        String
+        fluffiness  Float
+    }
+
+    model Dog {
+        id          Int @id
+    }
+"#;
+
+fn copy_migrations_directory(from: &Path, to: &Path) -> std::io::Result<()> {
+    for migration_directory in std::fs::read_dir(from)? {
+        let migration_directory = migration_directory?;
+        let target = to.join(migration_directory.file_name());
+
+        std::fs::create_dir_all(&target)?;
+
+        for file in std::fs::read_dir(migration_directory.path())? {
+            let file = file?;
+            std::fs::copy(file.path(), target.join(file.file_name()))?;
+        }
+    }
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn squashing_applied_migrations_records_the_squashed_migration_as_applied(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", DM1, &migrations_directory)
+        .send()
+        .await?;
+    api.create_migration("second-migration", DM2, &migrations_directory)
+        .send()
+        .await?;
+    api.create_migration("third-migration", DM3, &migrations_directory)
+        .send()
+        .await?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial", "second-migration", "third-migration"])?;
+
+    let output = api.squash_migrations("squashed", &migrations_directory).send().await?;
+
+    assert_eq!(output.squashed_migration_names.len(), 3);
+
+    let migration_names: Vec<String> = std::fs::read_dir(migrations_directory.path())?
+        .map(|entry| entry.map(|entry| entry.file_name().to_string_lossy().into_owned()))
+        .collect::<Result<_, _>>()?;
+
+    assert_eq!(migration_names, &[output.generated_migration_name.as_str()]);
+
+    let applied_migrations: Vec<String> = api
+        .imperative_migration_persistence()
+        .list_migrations()
+        .await?
+        .unwrap()
+        .into_iter()
+        .filter(|migration| !migration.is_rolled_back())
+        .map(|migration| migration.migration_name)
+        .collect();
+
+    assert_eq!(applied_migrations, &[output.generated_migration_name.clone()]);
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&[])?;
+
+    let output = api
+        .diagnose_migration_history(&migrations_directory)
+        .send()
+        .await?
+        .into_output();
+
+    assert!(output.failed_migration_names.is_empty());
+    assert!(output.edited_migration_names.is_empty());
+    assert!(output.history.is_none());
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn squashing_a_range_of_unapplied_migrations_works(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", DM1, &migrations_directory)
+        .send()
+        .await?;
+    let second_migration_name = api
+        .create_migration("second-migration", DM2, &migrations_directory)
+        .send()
+        .await?
+        .into_output()
+        .generated_migration_name
+        .unwrap();
+    api.create_migration("third-migration", DM3, &migrations_directory)
+        .send()
+        .await?;
+
+    api.squash_migrations("squashed", &migrations_directory)
+        .to_migration(second_migration_name)
+        .send()
+        .await?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["squashed", "third-migration"])?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_has_column("fluffiness"))?
+        .assert_has_table("Dog")?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn apply_migrations_records_squashed_migrations_replacing_applied_migrations(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", DM1, &migrations_directory)
+        .send()
+        .await?;
+    api.create_migration("second-migration", DM2, &migrations_directory)
+        .send()
+        .await?;
+
+    // Squash a copy of the migrations, as another developer would.
+    let squashed_migrations_directory = api.create_migrations_directory()?;
+    copy_migrations_directory(migrations_directory.path(), squashed_migrations_directory.path())?;
+
+    let output = api
+        .squash_migrations("squashed", &squashed_migrations_directory)
+        .send()
+        .await?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial", "second-migration"])?;
+
+    // The squashed migration is recorded as applied without being run.
+    api.apply_migrations(&squashed_migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&[])?;
+
+    let applied_migrations: Vec<String> = api
+        .imperative_migration_persistence()
+        .list_migrations()
+        .await?
+        .unwrap()
+        .into_iter()
+        .filter(|migration| !migration.is_rolled_back())
+        .map(|migration| migration.migration_name)
+        .collect();
+
+    assert_eq!(applied_migrations, &[output.generated_migration_name]);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn diagnose_migration_history_understands_squashed_migrations_replacing_applied_migrations(
+    api: &TestApi,
+) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", DM1, &migrations_directory)
+        .send()
+        .await?;
+    api.create_migration("second-migration", DM2, &migrations_directory)
+        .send()
+        .await?;
+
+    // Squash a copy of the migrations, as another developer would.
+    let squashed_migrations_directory = api.create_migrations_directory()?;
+    copy_migrations_directory(migrations_directory.path(), squashed_migrations_directory.path())?;
+
+    api.squash_migrations("squashed", &squashed_migrations_directory)
+        .send()
+        .await?;
+
+    let third_migration_name = api
+        .create_migration("third-migration", DM3, &squashed_migrations_directory)
+        .send()
+        .await?
+        .into_output()
+        .generated_migration_name
+        .unwrap();
+
+    // This database applied the original migrations.
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial", "second-migration"])?;
+
+    let output = api
+        .diagnose_migration_history(&squashed_migrations_directory)
+        .send()
+        .await?
+        .into_output();
+
+    assert!(output.drift.is_none());
+    assert!(output.failed_migration_names.is_empty());
+    assert!(output.edited_migration_names.is_empty());
+    assert_eq!(
+        output.history,
+        Some(HistoryDiagnostic::DatabaseIsBehind {
+            unapplied_migration_names: vec![third_migration_name],
+        })
+    );
+
+    api.apply_migrations(&squashed_migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["third-migration"])?;
+
+    let output = api
+        .diagnose_migration_history(&squashed_migrations_directory)
+        .send()
+        .await?
+        .into_output();
+
+    assert!(output.is_empty());
+
+    Ok(())
+}
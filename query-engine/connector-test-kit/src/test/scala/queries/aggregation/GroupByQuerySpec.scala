@@ -0,0 +1,161 @@
+package queries.aggregation
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class GroupByQuerySpec extends FlatSpec with Matchers with ApiSpecBase {
+  val project = SchemaDsl.fromStringV11() {
+    """model Item {
+      |  id    String @id @default(cuid())
+      |  float Float
+      |  int   Int
+      |  str   String
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeEach(): Unit = {
+    super.beforeEach()
+    database.setup(project)
+  }
+
+  def createItem(float: Double, int: Int, str: String) = {
+    server.query(
+      s"""mutation {
+         |  createItem(data: { float: $float, int: $int, str: "$str" }) {
+         |    id
+         |  }
+         |}""".stripMargin,
+      project
+    )
+  }
+
+  "Grouping with no records in the database" should "return no groups" in {
+    val result = server.query(
+      s"""{
+         |  groupByItem(by: [str]) {
+         |    str
+         |    count
+         |  }
+         |}""".stripMargin,
+      project
+    )
+
+    result.toString should be("""{"data":{"groupByItem":[]}}""")
+  }
+
+  "Grouping by a field" should "return one aggregated row per group" in {
+    createItem(1.5, 1, "a")
+    createItem(2.5, 3, "a")
+    createItem(4.0, 10, "b")
+
+    val result = server.query(
+      s"""{
+         |  groupByItem(by: [str], orderBy: { str: asc }) {
+         |    str
+         |    count
+         |    avg { int }
+         |    sum { float int }
+         |    min { int }
+         |    max { int }
+         |  }
+         |}""".stripMargin,
+      project
+    )
+
+    result.toString should be(
+      """{"data":{"groupByItem":[{"str":"a","count":2,"avg":{"int":2.0},"sum":{"float":4.0,"int":4},"min":{"int":1},"max":{"int":3}},{"str":"b","count":1,"avg":{"int":10.0},"sum":{"float":4.0,"int":10},"min":{"int":10},"max":{"int":10}}]}}""")
+  }
+
+  "Grouping with a having filter" should "only return groups matching the aggregate condition" in {
+    createItem(1.5, 1, "a")
+    createItem(2.5, 3, "a")
+    createItem(4.0, 10, "b")
+    createItem(4.0, 20, "c")
+
+    val result = server.query(
+      s"""{
+         |  groupByItem(by: [str], orderBy: { str: asc }, having: { int: { avg: { gt: 5 } }, str: { not: "c" } }) {
+         |    str
+         |  }
+         |}""".stripMargin,
+      project
+    )
+
+    result.toString should be("""{"data":{"groupByItem":[{"str":"b"}]}}""")
+  }
+
+  "A having filter with a non-scalar condition on an aggregate" should "fail" in {
+    createItem(1.5, 1, "a")
+
+    server.queryThatMustFail(
+      s"""{
+         |  groupByItem(by: [str], having: { int: { sum: { contains: "1" } } }) {
+         |    str
+         |  }
+         |}""".stripMargin,
+      project,
+      errorCode = 2009
+    )
+  }
+
+  "Grouping with an aggregate ordering and pagination" should "order and paginate the groups" in {
+    createItem(1.5, 1, "a")
+    createItem(2.5, 3, "a")
+    createItem(4.0, 10, "b")
+    createItem(4.0, 20, "c")
+
+    val result = server.query(
+      s"""{
+         |  groupByItem(by: [str], orderBy: { sum: { int: desc } }, take: 2, skip: 1) {
+         |    str
+         |  }
+         |}""".stripMargin,
+      project
+    )
+
+    result.toString should be("""{"data":{"groupByItem":[{"str":"b"},{"str":"a"}]}}""")
+  }
+
+  "Grouping with a where filter" should "only aggregate matching records" in {
+    createItem(1.5, 1, "a")
+    createItem(2.5, 3, "a")
+    createItem(4.0, 10, "b")
+
+    val result = server.query(
+      s"""{
+         |  groupByItem(by: [str], orderBy: { str: asc }, where: { int: { gt: 1 } }) {
+         |    str
+         |    count
+         |  }
+         |}""".stripMargin,
+      project
+    )
+
+    result.toString should be("""{"data":{"groupByItem":[{"str":"a","count":1},{"str":"b","count":1}]}}""")
+  }
+
+  "Selecting a field that is not grouped by" should "fail" in {
+    server.queryThatMustFail(
+      s"""{
+         |  groupByItem(by: [str]) {
+         |    int
+         |  }
+         |}""".stripMargin,
+      project,
+      errorCode = 2019
+    )
+  }
+
+  "Paginating without an ordering" should "fail" in {
+    server.queryThatMustFail(
+      s"""{
+         |  groupByItem(by: [str], take: 1) {
+         |    str
+         |  }
+         |}""".stripMargin,
+      project,
+      errorCode = 2019
+    )
+  }
+}
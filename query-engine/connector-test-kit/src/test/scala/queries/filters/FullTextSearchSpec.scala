@@ -0,0 +1,93 @@
+package queries.filters
+
+import org.scalatest.{FlatSpec, Matchers, Suite}
+import util.ConnectorTag.{MySqlConnectorTag, PostgresConnectorTag}
+import util._
+
+trait FullTextSearchBehaviour extends Matchers with ApiSpecBase { self: Suite =>
+  def project: Project
+
+  def createPosts(): Unit = {
+    create(1, "The cat sat on the mat")
+    create(2, "A dog barked at the mailman")
+    create(3, "The cat chased the dog")
+  }
+
+  def searchFilterShouldWork(): Unit = {
+    createPosts()
+
+    server
+      .query("""query { findManyPost(where: { title: { search: "cat" }}, orderBy: { id: asc }) { id }}""", project, legacy = false)
+      .toString should be("""{"data":{"findManyPost":[{"id":1},{"id":3}]}}""")
+
+    server
+      .query("""query { findManyPost(where: { title: { not: { search: "cat" }}}) { id }}""", project, legacy = false)
+      .toString should be("""{"data":{"findManyPost":[{"id":2}]}}""")
+  }
+
+  def relevanceOrderingShouldWork(): Unit = {
+    createPosts()
+
+    server
+      .query(
+        """query { findManyPost(orderBy: [{ _relevance: { fields: [title], search: "mailman", sort: desc }}, { id: asc }]) { id }}""",
+        project,
+        legacy = false
+      )
+      .toString should be("""{"data":{"findManyPost":[{"id":2},{"id":1},{"id":3}]}}""")
+  }
+
+  def create(id: Int, title: String): Unit = {
+    server.query(s"""mutation { createOnePost(data: { id: $id, title: "$title" }) { id }}""", project, legacy = false)
+  }
+}
+
+class PostgresFullTextSearchSpec extends FlatSpec with FullTextSearchBehaviour with ConnectorAwareTest {
+  override def runOnlyForConnectors: Set[ConnectorTag] = Set(PostgresConnectorTag)
+
+  val project = SchemaDsl.fromStringV11() {
+    """model Post {
+      |  id    Int    @id
+      |  title String
+      |}""".stripMargin
+  }
+
+  override def beforeEach(): Unit = {
+    database.setup(project)
+    super.beforeEach()
+  }
+
+  "The search filter" should "only return records matching the search query" in {
+    searchFilterShouldWork()
+  }
+
+  "Ordering by relevance" should "return the most relevant records first" in {
+    relevanceOrderingShouldWork()
+  }
+}
+
+class MySqlFullTextSearchSpec extends FlatSpec with FullTextSearchBehaviour with ConnectorAwareTest {
+  override def runOnlyForConnectors: Set[ConnectorTag] = Set(MySqlConnectorTag)
+
+  val project = SchemaDsl.fromStringV11() {
+    """model Post {
+      |  id    Int    @id
+      |  title String
+      |
+      |  @@fulltext([title])
+      |}""".stripMargin
+  }
+
+  override def beforeEach(): Unit = {
+    database.setup(project)
+    super.beforeEach()
+  }
+
+  "The search filter" should "only return records matching the search query using the fulltext index" in {
+    searchFilterShouldWork()
+  }
+
+  "Ordering by relevance" should "return the most relevant records first using the fulltext index" in {
+    relevanceOrderingShouldWork()
+  }
+}
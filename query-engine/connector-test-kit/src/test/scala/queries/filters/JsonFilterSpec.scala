@@ -1,11 +1,11 @@
 package queries.filters
 
 import org.scalatest.{FlatSpec, Matchers}
-import util.ConnectorTag.PostgresConnectorTag
+import util.ConnectorTag.{MySqlConnectorTag, PostgresConnectorTag}
 import util._
 
 class JsonFilterSpec extends FlatSpec with Matchers with ApiSpecBase with ConnectorAwareTest {
-  override def runOnlyForConnectors: Set[ConnectorTag] = Set(PostgresConnectorTag)
+  override def runOnlyForConnectors: Set[ConnectorTag] = Set(PostgresConnectorTag, MySqlConnectorTag)
 
   val project = SchemaDsl.fromStringV11() {
     s"""model Model {
@@ -81,6 +81,67 @@ class JsonFilterSpec extends FlatSpec with Matchers with ApiSpecBase with Connec
 
   }
 
+  "Using a path in a Json filter" should "filter on the value at the path" in {
+    create(1, Some("""{\"a\":{\"b\":\"c\"}}"""))
+    create(2, Some("""{\"a\":{\"b\":\"d\"}}"""))
+    create(3, Some("""{\"a\":1}"""))
+
+    server
+      .query("""query { findManyModel(where: { json: { path: ["a", "b"], equals: "\"c\"" }}) { id }}""", project, legacy = false)
+      .toString should be("""{"data":{"findManyModel":[{"id":1}]}}""")
+
+    server
+      .query("""query { findManyModel(where: { json: { path: ["a", "b"], not: "\"c\"" }}) { id }}""", project, legacy = false)
+      .toString should be("""{"data":{"findManyModel":[{"id":2}]}}""")
+
+    server
+      .query("""query { findManyModel(where: { json: { path: ["a"], equals: "1" }}) { id }}""", project, legacy = false)
+      .toString should be("""{"data":{"findManyModel":[{"id":3}]}}""")
+  }
+
+  "Using string filters on a Json path" should "match the contents of the string" in {
+    create(1, Some("""{\"name\":\"prisma engines\"}"""))
+    create(2, Some("""{\"name\":\"query engine\"}"""))
+
+    server
+      .query("""query { findManyModel(where: { json: { path: ["name"], string_contains: "engine" }}) { id }}""", project, legacy = false)
+      .toString should be("""{"data":{"findManyModel":[{"id":1},{"id":2}]}}""")
+
+    server
+      .query("""query { findManyModel(where: { json: { path: ["name"], string_starts_with: "prisma" }}) { id }}""", project, legacy = false)
+      .toString should be("""{"data":{"findManyModel":[{"id":1}]}}""")
+
+    server
+      .query("""query { findManyModel(where: { json: { path: ["name"], string_ends_with: "engine" }}) { id }}""", project, legacy = false)
+      .toString should be("""{"data":{"findManyModel":[{"id":2}]}}""")
+  }
+
+  "Using array filters on a Json field" should "match the contents of the array" in {
+    create(1, Some("""{\"tags\":[\"a\",\"b\",\"c\"]}"""))
+    create(2, Some("""{\"tags\":[\"c\",\"d\"]}"""))
+
+    server
+      .query("""query { findManyModel(where: { json: { path: ["tags"], array_contains: "[\"c\"]" }}) { id }}""", project, legacy = false)
+      .toString should be("""{"data":{"findManyModel":[{"id":1},{"id":2}]}}""")
+
+    server
+      .query("""query { findManyModel(where: { json: { path: ["tags"], array_starts_with: "\"a\"" }}) { id }}""", project, legacy = false)
+      .toString should be("""{"data":{"findManyModel":[{"id":1}]}}""")
+
+    server
+      .query("""query { findManyModel(where: { json: { path: ["tags"], array_ends_with: "\"d\"" }}) { id }}""", project, legacy = false)
+      .toString should be("""{"data":{"findManyModel":[{"id":2}]}}""")
+  }
+
+  "Using a path with quotes in a Json filter" should "match the quoted key" in {
+    create(1, Some("""{\"it's\":\"a\"}"""))
+    create(2, Some("""{\"its\":\"a\"}"""))
+
+    server
+      .query("""query { findManyModel(where: { json: { path: ["it's"], string_contains: "a" }}) { id }}""", project, legacy = false)
+      .toString should be("""{"data":{"findManyModel":[{"id":1}]}}""")
+  }
+
   def create(id: Int, json: Option[String]): Unit = {
     val j = json match {
       case Some(x) => s""""$x""""
@@ -0,0 +1,108 @@
+package queries.orderAndPagination
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class OrderByRelationSpec extends FlatSpec with Matchers with ApiSpecBase {
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model User {
+      |  id    Int    @id
+      |  name  String
+      |  posts Post[]
+      |}
+      |
+      |model Post {
+      |  id       Int  @id
+      |  authorId Int
+      |  author   User @relation(fields: [authorId], references: [id])
+      |}
+    """.stripMargin
+  }
+
+  /*
+   * Creates 3 users with 3, 2 and 1 posts, whose names are not in the order of their IDs.
+   */
+  private def createTestData(): Unit = {
+    val users = Seq((1, "Bob"), (2, "Alice"), (3, "Carl"))
+    val posts = Seq((1, 1), (2, 2), (3, 3), (4, 1), (5, 1), (6, 2))
+
+    users.foreach {
+      case (id, name) =>
+        server.query(s"""mutation { createOneUser(data: { id: $id, name: "$name" }) { id } }""", project, legacy = false)
+    }
+
+    posts.foreach {
+      case (id, authorId) =>
+        server.query(s"""mutation { createOnePost(data: { id: $id, author: { connect: { id: $authorId } } }) { id } }""",
+                     project,
+                     legacy = false)
+    }
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+    createTestData()
+  }
+
+  "Ordering by a field of a to-one relation" should "order the records by the related records" in {
+    val result = server.query(
+      """{
+        |  findManyPost(orderBy: [{ author: { name: asc } }, { id: asc }]) {
+        |    id
+        |  }
+        |}
+      """.stripMargin,
+      project,
+      legacy = false
+    )
+
+    result.toString should be("""{"data":{"findManyPost":[{"id":2},{"id":6},{"id":1},{"id":4},{"id":5},{"id":3}]}}""")
+  }
+
+  "Ordering by a relation count" should "order the records by the number of related records" in {
+    val result = server.query(
+      """{
+        |  findManyUser(orderBy: { posts: { count: desc } }) {
+        |    id
+        |  }
+        |}
+      """.stripMargin,
+      project,
+      legacy = false
+    )
+
+    result.toString should be("""{"data":{"findManyUser":[{"id":1},{"id":2},{"id":3}]}}""")
+  }
+
+  "A cursor query ordered by a field of a to-one relation" should "return the records after the cursor" in {
+    val result = server.query(
+      """{
+        |  findManyPost(orderBy: [{ author: { name: asc } }, { id: asc }], cursor: { id: 4 }, take: 3) {
+        |    id
+        |  }
+        |}
+      """.stripMargin,
+      project,
+      legacy = false
+    )
+
+    result.toString should be("""{"data":{"findManyPost":[{"id":4},{"id":5},{"id":3}]}}""")
+  }
+
+  "A cursor query ordered by a relation count" should "return the records after the cursor" in {
+    val result = server.query(
+      """{
+        |  findManyUser(orderBy: { posts: { count: desc } }, cursor: { id: 2 }) {
+        |    id
+        |  }
+        |}
+      """.stripMargin,
+      project,
+      legacy = false
+    )
+
+    result.toString should be("""{"data":{"findManyUser":[{"id":2},{"id":3}]}}""")
+  }
+}
@@ -0,0 +1,66 @@
+package writes.dataTypes.bigint
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class BigIntSpec extends FlatSpec with Matchers with ApiSpecBase {
+  "Using a BigInt field" should "work" taggedAs (IgnoreMsSql) in {
+    val project = ProjectDsl.fromString {
+      """|model Model {
+         | id    Int    @id
+         | field BigInt
+         |}"""
+    }
+
+    database.setup(project)
+
+    var res = server.query(
+      s"""
+         |mutation {
+         |  createOneModel(
+         |    data: {
+         |      id: 1
+         |      field: "9223372036854775807"
+         |    }
+         |  ) {
+         |    field
+         |  }
+         |}""".stripMargin,
+      project,
+      legacy = false
+    )
+
+    res.toString should be("""{"data":{"createOneModel":{"field":"9223372036854775807"}}}""")
+
+    res = server.query(
+      s"""
+         |mutation {
+         |  updateOneModel(
+         |    where: { id: 1 }
+         |    data: {
+         |      field: { decrement: 7 }
+         |    }
+         |  ) {
+         |    field
+         |  }
+         |}""".stripMargin,
+      project,
+      legacy = false
+    )
+
+    res.toString should be("""{"data":{"updateOneModel":{"field":"9223372036854775800"}}}""")
+
+    res = server.query(
+      s"""
+         |{
+         |  findManyModel(where: { field: { gt: "9223372036854775000" } }) {
+         |    id
+         |  }
+         |}""".stripMargin,
+      project,
+      legacy = false
+    )
+
+    res.toString should be("""{"data":{"findManyModel":[{"id":1}]}}""")
+  }
+}
@@ -0,0 +1,65 @@
+package writes.dataTypes.bytes
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class BytesSpec extends FlatSpec with Matchers with ApiSpecBase {
+  "Using a Bytes field" should "work" taggedAs (IgnoreMsSql) in {
+    val project = ProjectDsl.fromString {
+      """|model Model {
+         | id    Int    @id
+         | field Bytes
+         |}"""
+    }
+
+    database.setup(project)
+
+    var res = server.query(
+      s"""
+         |mutation {
+         |  createOneModel(
+         |    data: {
+         |      id: 1
+         |      field: "AQID/w=="
+         |    }
+         |  ) {
+         |    field
+         |  }
+         |}""".stripMargin,
+      project,
+      legacy = false
+    )
+
+    res.toString should be("""{"data":{"createOneModel":{"field":"AQID/w=="}}}""")
+
+    res = server.query(
+      s"""
+         |{
+         |  findManyModel(where: { field: { equals: "AQID/w==" } }) {
+         |    id
+         |  }
+         |}""".stripMargin,
+      project,
+      legacy = false
+    )
+
+    res.toString should be("""{"data":{"findManyModel":[{"id":1}]}}""")
+
+    server.queryThatMustFail(
+      s"""
+         |mutation {
+         |  createOneModel(
+         |    data: {
+         |      id: 2
+         |      field: "not base64!"
+         |    }
+         |  ) {
+         |    field
+         |  }
+         |}""".stripMargin,
+      project,
+      errorCode = 2009,
+      legacy = false
+    )
+  }
+}
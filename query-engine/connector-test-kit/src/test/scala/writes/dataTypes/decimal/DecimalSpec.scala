@@ -0,0 +1,66 @@
+package writes.dataTypes.decimal
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class DecimalSpec extends FlatSpec with Matchers with ApiSpecBase {
+  "Using a Decimal field" should "keep exact precision" taggedAs (IgnoreSQLite, IgnoreMsSql) in {
+    val project = ProjectDsl.fromString {
+      """|model Model {
+         | id    Int     @id
+         | field Decimal
+         |}"""
+    }
+
+    database.setup(project)
+
+    var res = server.query(
+      s"""
+         |mutation {
+         |  createOneModel(
+         |    data: {
+         |      id: 1
+         |      field: "0.1"
+         |    }
+         |  ) {
+         |    field
+         |  }
+         |}""".stripMargin,
+      project,
+      legacy = false
+    )
+
+    res.toString should be("""{"data":{"createOneModel":{"field":"0.100000000000000000000000000000"}}}""")
+
+    res = server.query(
+      s"""
+         |mutation {
+         |  updateOneModel(
+         |    where: { id: 1 }
+         |    data: {
+         |      field: { increment: "0.2" }
+         |    }
+         |  ) {
+         |    field
+         |  }
+         |}""".stripMargin,
+      project,
+      legacy = false
+    )
+
+    res.toString should be("""{"data":{"updateOneModel":{"field":"0.300000000000000000000000000000"}}}""")
+
+    res = server.query(
+      s"""
+         |{
+         |  findManyModel(where: { field: { equals: "0.3" } }) {
+         |    id
+         |  }
+         |}""".stripMargin,
+      project,
+      legacy = false
+    )
+
+    res.toString should be("""{"data":{"findManyModel":[{"id":1}]}}""")
+  }
+}
@@ -0,0 +1,58 @@
+package writes.relations
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class ReferentialActionsSpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  "Deleting a parent with onDelete: Cascade" should "delete the related records" in {
+    val project = SchemaDsl.fromStringV11() {
+      """
+        |model User {
+        |  id    Int    @id
+        |  posts Post[]
+        |}
+        |
+        |model Post {
+        |  id       Int  @id
+        |  authorId Int
+        |  author   User @relation(fields: [authorId], references: [id], onDelete: Cascade)
+        |}
+      """.stripMargin
+    }
+    database.setup(project)
+
+    server.query("""mutation { createOneUser(data: { id: 1, posts: { create: [{ id: 1 }, { id: 2 }] } }) { id } }""", project, legacy = false)
+    server.query("""mutation { deleteOneUser(where: { id: 1 }) { id } }""", project, legacy = false)
+
+    server.query("""{ findManyPost { id } }""", project, legacy = false).toString should be("""{"data":{"findManyPost":[]}}""")
+  }
+
+  "Deleting a parent with onDelete: Restrict" should "fail if related records exist" in {
+    val project = SchemaDsl.fromStringV11() {
+      """
+        |model User {
+        |  id    Int    @id
+        |  posts Post[]
+        |}
+        |
+        |model Post {
+        |  id       Int  @id
+        |  authorId Int
+        |  author   User @relation(fields: [authorId], references: [id], onDelete: Restrict)
+        |}
+      """.stripMargin
+    }
+    database.setup(project)
+
+    server.query("""mutation { createOneUser(data: { id: 1, posts: { create: [{ id: 1 }] } }) { id } }""", project, legacy = false)
+
+    server.queryThatMustFail(
+      """mutation { deleteOneUser(where: { id: 1 }) { id } }""",
+      project,
+      errorCode = 2014,
+      errorContains = """The change you are trying to make would violate the required relation 'PostToUser' between the `Post` and `User` models.""",
+      legacy = false
+    )
+  }
+}
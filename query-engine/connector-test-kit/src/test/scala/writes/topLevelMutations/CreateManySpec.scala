@@ -0,0 +1,110 @@
+package writes.topLevelMutations
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class CreateManySpec extends FlatSpec with Matchers with ApiSpecBase {
+  val project = ProjectDsl.fromString {
+    """model Test {
+      |  id       Int      @id
+      |  str1     String
+      |  str2     String?
+      |  str3     String?  @default("SOME_DEFAULT")
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+
+  override def beforeEach(): Unit = database.truncateProjectTables(project)
+
+  "A basic createMany" should "work" in {
+    val result = server.query(
+      """mutation {
+        |  createManyTest(data: [
+        |    { id: 1, str1: "1", str2: "1", str3: "1"},
+        |    { id: 2, str1: "2",            str3: null},
+        |    { id: 3, str1: "1"},
+        |  ]) {
+        |    count
+        |  }
+        |}
+      """.stripMargin,
+      project,
+      legacy = false,
+    )
+
+    result.pathAsLong("data.createManyTest.count") should equal(3)
+
+    val records = server.query(
+      """{
+        |  findManyTest(orderBy: { id: asc }) {
+        |    id
+        |    str2
+        |    str3
+        |  }
+        |}
+      """.stripMargin,
+      project,
+      legacy = false,
+    )
+
+    records.pathAsJsValue("data.findManyTest").toString should be(
+      """[{"id":1,"str2":"1","str3":"1"},{"id":2,"str2":null,"str3":null},{"id":3,"str2":null,"str3":"SOME_DEFAULT"}]""")
+  }
+
+  "createMany" should "error on duplicates by default" in {
+    server.queryThatMustFail(
+      """mutation {
+        |  createManyTest(data: [
+        |    { id: 1, str1: "1" },
+        |    { id: 1, str1: "2" },
+        |  ]) {
+        |    count
+        |  }
+        |}
+      """.stripMargin,
+      project,
+      errorCode = 2002,
+      legacy = false,
+    )
+  }
+
+  "createMany" should "not error on duplicates with skipDuplicates true" in {
+    val result = server.query(
+      """mutation {
+        |  createManyTest(skipDuplicates: true, data: [
+        |    { id: 1, str1: "1" },
+        |    { id: 1, str1: "2" },
+        |  ]) {
+        |    count
+        |  }
+        |}
+      """.stripMargin,
+      project,
+      legacy = false,
+    )
+
+    result.pathAsLong("data.createManyTest.count") should equal(1)
+  }
+
+  // Exceeds the bind parameter limit of every supported database, forcing the connector to split the insert.
+  "createMany" should "allow creating a large number of records" in {
+    val records: Seq[String] = for (i <- 1 to 10000) yield { s"""{ id: $i, str1: "$i" }""" }
+    val result = server.query(
+      s"""mutation {
+        |  createManyTest(data: [${records.mkString(", ")}]) {
+        |    count
+        |  }
+        |}
+      """.stripMargin,
+      project,
+      legacy = false,
+    )
+
+    result.pathAsLong("data.createManyTest.count") should equal(10000)
+  }
+}
@@ -1,4 +1,4 @@
-use crate::filter::Filter;
+use crate::filter::{Filter, JsonFilterCondition};
 use prisma_models::PrismaValue;
 
 /// Comparing methods for scalar fields.
@@ -58,6 +58,14 @@ pub trait ScalarCompare {
     fn greater_than_or_equals<T>(&self, val: T) -> Filter
     where
         T: Into<PrismaValue>;
+
+    fn search<T>(&self, val: T) -> Filter
+    where
+        T: Into<PrismaValue>;
+
+    fn not_search<T>(&self, val: T) -> Filter
+    where
+        T: Into<PrismaValue>;
 }
 
 /// Comparison methods for relational fields.
@@ -81,6 +89,11 @@ pub trait RelationCompare {
     fn one_relation_is_null(&self) -> Filter;
 }
 
+/// Comparison methods for the contents of Json fields.
+pub trait JsonCompare {
+    fn json_compare(&self, path: Vec<String>, condition: JsonFilterCondition) -> Filter;
+}
+
 /// Comparison methods for scalar list fields.
 pub trait ScalarListCompare {
     fn contains_element<T>(&self, value: T) -> Filter
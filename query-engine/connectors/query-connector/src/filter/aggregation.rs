@@ -0,0 +1,37 @@
+use super::Filter;
+
+/// Filtering on the result of an aggregation over groups of records. From a
+/// GraphQL point of view this is in the `having` argument of a group by query:
+///
+/// ```graphql
+/// groupByUser(by: [country], having: { age: { avg: { gt: 30 } } })
+/// ```
+///
+/// The wrapped filter describes the condition, its scalar leaves are applied
+/// to the aggregated value of the field instead of the field itself.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AggregationFilter {
+    Count(Box<Filter>),
+    Average(Box<Filter>),
+    Sum(Box<Filter>),
+    Min(Box<Filter>),
+    Max(Box<Filter>),
+}
+
+impl AggregationFilter {
+    pub fn filter(&self) -> &Filter {
+        match self {
+            AggregationFilter::Count(f) => f,
+            AggregationFilter::Average(f) => f,
+            AggregationFilter::Sum(f) => f,
+            AggregationFilter::Min(f) => f,
+            AggregationFilter::Max(f) => f,
+        }
+    }
+}
+
+impl From<AggregationFilter> for Filter {
+    fn from(filter: AggregationFilter) -> Self {
+        Filter::Aggregation(filter)
+    }
+}
@@ -0,0 +1,40 @@
+use super::{Filter, QueryMode, ScalarCondition, ScalarFilter, ScalarProjection};
+use crate::compare::JsonCompare;
+use prisma_models::{PrismaValue, ScalarFieldRef};
+use std::sync::Arc;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Filtering on the contents of a Json field, optionally on a value nested in the document:
+///
+/// ```graphql
+/// findManyUser(where: { meta: { path: ["address", "city"], string_starts_with: "Ber" } })
+/// ```
+pub struct JsonCondition {
+    /// Object keys or array indices leading to the value the condition applies to.
+    /// Empty if the condition applies to the whole document.
+    pub path: Vec<String>,
+    pub condition: JsonFilterCondition,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum JsonFilterCondition {
+    Equals(PrismaValue),
+    NotEquals(PrismaValue),
+    StringContains(String),
+    StringStartsWith(String),
+    StringEndsWith(String),
+    ArrayContains(PrismaValue),
+    ArrayStartsWith(PrismaValue),
+    ArrayEndsWith(PrismaValue),
+}
+
+impl JsonCompare for ScalarFieldRef {
+    /// The value of the Json field at the given path matches the condition.
+    fn json_compare(&self, path: Vec<String>, condition: JsonFilterCondition) -> Filter {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Single(Arc::clone(self)),
+            condition: ScalarCondition::JsonCompare(JsonCondition { path, condition }),
+            mode: QueryMode::Default,
+        })
+    }
+}
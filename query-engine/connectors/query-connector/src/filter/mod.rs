@@ -4,12 +4,16 @@
 //! [ScalarCompare](/query-connector/trait.ScalarCompare.html) and
 //! [RelationCompare](/query-connector/trait.RelationCompare.html).
 
+mod aggregation;
 mod id_filter;
+mod json;
 mod list;
 mod relation;
 mod scalar;
 
+pub use aggregation::*;
 pub use id_filter::*;
+pub use json::*;
 pub use list::*;
 pub use relation::*;
 pub use scalar::*;
@@ -23,6 +27,7 @@ pub enum Filter {
     ScalarList(ScalarListFilter),
     OneRelationIsNull(OneRelationIsNullFilter),
     Relation(RelationFilter),
+    Aggregation(AggregationFilter),
     NodeSubscription,
     BoolFilter(bool),
     Empty,
@@ -129,6 +134,7 @@ impl Filter {
             Filter::ScalarList(_) => {}
             Filter::OneRelationIsNull(_) => {}
             Filter::Relation(_) => {}
+            Filter::Aggregation(_) => {}
             Filter::NodeSubscription => {}
             Filter::BoolFilter(_) => {}
             Filter::Empty => {}
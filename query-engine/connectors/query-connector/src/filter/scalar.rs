@@ -1,4 +1,4 @@
-use super::Filter;
+use super::{Filter, JsonCondition};
 use crate::compare::ScalarCompare;
 use once_cell::sync::Lazy;
 use prisma_models::{ModelProjection, PrismaListValue, PrismaValue, ScalarFieldRef};
@@ -126,6 +126,10 @@ pub enum ScalarCondition {
     GreaterThanOrEquals(PrismaValue),
     In(PrismaListValue),
     NotIn(PrismaListValue),
+    JsonCompare(JsonCondition),
+    /// Full-text search of the given query on the field, using the native search of the database.
+    Search(PrismaValue),
+    NotSearch(PrismaValue),
 }
 
 impl ScalarCompare for ScalarFieldRef {
@@ -296,6 +300,30 @@ impl ScalarCompare for ScalarFieldRef {
             mode: QueryMode::Default,
         })
     }
+
+    /// Field matches the given full-text search query.
+    fn search<T>(&self, val: T) -> Filter
+    where
+        T: Into<PrismaValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Single(Arc::clone(self)),
+            condition: ScalarCondition::Search(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
+    /// Field does not match the given full-text search query.
+    fn not_search<T>(&self, val: T) -> Filter
+    where
+        T: Into<PrismaValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Single(Arc::clone(self)),
+            condition: ScalarCondition::NotSearch(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
 }
 
 impl ScalarCompare for ModelProjection {
@@ -466,4 +494,28 @@ impl ScalarCompare for ModelProjection {
             mode: QueryMode::Default,
         })
     }
+
+    /// Field matches the given full-text search query.
+    fn search<T>(&self, val: T) -> Filter
+    where
+        T: Into<PrismaValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.scalar_fields().collect()),
+            condition: ScalarCondition::Search(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
+    /// Field does not match the given full-text search query.
+    fn not_search<T>(&self, val: T) -> Filter
+    where
+        T: Into<PrismaValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.scalar_fields().collect()),
+            condition: ScalarCondition::NotSearch(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
 }
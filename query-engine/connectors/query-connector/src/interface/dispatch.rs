@@ -50,6 +50,26 @@ impl<'conn, 'tx> ReadOperations for ConnectionLike<'conn, 'tx> {
             Self::Transaction(tx) => tx.aggregate_records(model, aggregators, query_arguments).await,
         }
     }
+
+    async fn group_by_records(
+        &self,
+        model: &ModelRef,
+        group_by: Vec<ScalarFieldRef>,
+        aggregators: Vec<Aggregator>,
+        query_arguments: QueryArguments,
+        having: Option<Filter>,
+    ) -> crate::Result<Vec<Vec<AggregationResult>>> {
+        match self {
+            Self::Connection(c) => {
+                c.group_by_records(model, group_by, aggregators, query_arguments, having)
+                    .await
+            }
+            Self::Transaction(tx) => {
+                tx.group_by_records(model, group_by, aggregators, query_arguments, having)
+                    .await
+            }
+        }
+    }
 }
 
 #[async_trait]
@@ -61,6 +81,18 @@ impl<'conn, 'tx> WriteOperations for ConnectionLike<'conn, 'tx> {
         }
     }
 
+    async fn create_records(
+        &self,
+        model: &ModelRef,
+        args: Vec<WriteArgs>,
+        skip_duplicates: bool,
+    ) -> crate::Result<usize> {
+        match self {
+            Self::Connection(c) => c.create_records(model, args, skip_duplicates).await,
+            Self::Transaction(tx) => tx.create_records(model, args, skip_duplicates).await,
+        }
+    }
+
     async fn update_records(
         &self,
         model: &ModelRef,
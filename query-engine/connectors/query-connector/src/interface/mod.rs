@@ -1,5 +1,8 @@
 mod dispatch;
+mod stream;
+
 pub use dispatch::*;
+pub use stream::*;
 
 use crate::{Filter, QueryArguments, WriteArgs};
 use async_trait::async_trait;
@@ -19,6 +22,10 @@ pub trait Connector {
 #[async_trait]
 pub trait Connection: ReadOperations + WriteOperations + Send + Sync {
     async fn start_transaction<'a>(&'a self) -> crate::Result<Box<dyn Transaction + 'a>>;
+
+    /// Starts a transaction that takes ownership of the connection. Unlike `start_transaction`,
+    /// the transaction isn't bound to the lifetime of the connection and can be kept open across requests.
+    async fn start_owned_transaction(self: Box<Self>) -> crate::Result<Box<dyn Transaction>>;
 }
 
 #[async_trait]
@@ -132,8 +139,10 @@ impl Aggregator {
 /// It is expected that the type of a `PrismaValue` matches the `TypeIdentifier`
 /// of the accompanying `ScalarFieldRef` for `Sum`, `Min` and `Max`.
 /// `Count` and `Average` are expected to be of `int` and `float` types, respectively.
+/// `Field` holds the value of a field the records have been grouped by.
 #[derive(Debug, Clone)]
 pub enum AggregationResult {
+    Field(ScalarFieldRef, PrismaValue),
     Count(PrismaValue),
     Average(ScalarFieldRef, PrismaValue),
     Sum(ScalarFieldRef, PrismaValue),
@@ -192,6 +201,22 @@ pub trait ReadOperations {
         aggregators: Vec<Aggregator>,
         query_arguments: QueryArguments,
     ) -> crate::Result<Vec<AggregationResult>>;
+
+    /// Groups the records of a model by the given fields and aggregates each
+    /// group based on the given aggregators. Groups are filtered with the
+    /// `having` filter after aggregation, the `QueryArguments` filter and
+    /// paginate as usual.
+    ///
+    /// Returns one row of results per group, each containing the values of
+    /// the grouped fields followed by the aggregation results.
+    async fn group_by_records(
+        &self,
+        model: &ModelRef,
+        group_by: Vec<ScalarFieldRef>,
+        aggregators: Vec<Aggregator>,
+        query_arguments: QueryArguments,
+        having: Option<Filter>,
+    ) -> crate::Result<Vec<Vec<AggregationResult>>>;
 }
 
 #[async_trait]
@@ -199,6 +224,18 @@ pub trait WriteOperations {
     /// Insert a single record to the database.
     async fn create_record(&self, model: &ModelRef, args: WriteArgs) -> crate::Result<RecordProjection>;
 
+    /// Inserts many records at once into the database.
+    /// If `skip_duplicates` is set, records violating a unique constraint are
+    /// silently skipped instead of failing the whole operation.
+    ///
+    /// Returns the number of inserted records.
+    async fn create_records(
+        &self,
+        model: &ModelRef,
+        args: Vec<WriteArgs>,
+        skip_duplicates: bool,
+    ) -> crate::Result<usize>;
+
     /// Update records in the `Model` with the given `WriteArgs` filtered by the
     /// `Filter`.
     async fn update_records(
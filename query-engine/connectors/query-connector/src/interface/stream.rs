@@ -0,0 +1,112 @@
+use crate::{QueryArguments, ReadOperations};
+use futures::stream::{self, BoxStream, StreamExt};
+use prisma_models::*;
+use std::ops::Deref;
+
+/// A stream of record batches, as returned by `stream_many_records`.
+pub type RecordBatchStream<'a> = BoxStream<'a, crate::Result<ManyRecords>>;
+
+/// Streams the records matching the query arguments in batches of at most `batch_size` records,
+/// instead of materializing all of them at once.
+///
+/// The records are paged through with a cursor: every batch is fetched starting after the last
+/// record of the previous batch. To make the cursor unambiguous, the primary identifier of the
+/// model is appended to the ordering. The stream takes ownership of the connection, so that it
+/// can outlive the function creating it.
+///
+/// Query arguments that are processed in memory by the core, like `distinct`, negative `take`
+/// and cursors on nullable orderings, are not supported.
+pub fn stream_many_records<'a, C>(
+    conn: C,
+    query_arguments: QueryArguments,
+    selected_fields: ModelProjection,
+    batch_size: usize,
+) -> RecordBatchStream<'a>
+where
+    C: Deref + Send + Sync + 'a,
+    C::Target: ReadOperations + Send + Sync,
+{
+    let state = BatchState {
+        conn,
+        model: query_arguments.model.clone(),
+        remaining: query_arguments.take.map(|take| take as usize),
+        query_arguments: Some(with_unique_ordering(query_arguments)),
+        selected_fields,
+        batch_size,
+    };
+
+    stream::unfold(state, |mut state| async move {
+        let mut args = state.query_arguments.take()?;
+
+        let take = match state.remaining {
+            Some(0) => return None,
+            Some(remaining) => remaining.min(state.batch_size),
+            None => state.batch_size,
+        };
+
+        args.take = Some(take as i64);
+
+        let batch = match state
+            .conn
+            .get_many_records(&state.model, args.clone(), &state.selected_fields)
+            .await
+        {
+            Ok(batch) => batch,
+            Err(err) => return Some((Err(err), state)),
+        };
+
+        if batch.records.is_empty() {
+            return None;
+        }
+
+        // A full batch means that there might be more records after it.
+        if batch.records.len() == take {
+            let cursor = batch
+                .records
+                .last()
+                .unwrap()
+                .projection(&batch.field_names, &state.model.primary_identifier());
+
+            match cursor {
+                Ok(cursor) => {
+                    args.cursor = Some(cursor);
+                    args.skip = Some(1);
+                    state.query_arguments = Some(args);
+                    state.remaining = state.remaining.map(|remaining| remaining - take);
+                }
+                Err(err) => return Some((Err(err.into()), state)),
+            }
+        }
+
+        Some((Ok(batch), state))
+    })
+    .boxed()
+}
+
+struct BatchState<C> {
+    conn: C,
+    model: ModelRef,
+    query_arguments: Option<QueryArguments>,
+    selected_fields: ModelProjection,
+    remaining: Option<usize>,
+    batch_size: usize,
+}
+
+/// Appends the fields of the primary identifier to the ordering, so that every record
+/// has a unique position that a cursor can point to.
+fn with_unique_ordering(mut query_arguments: QueryArguments) -> QueryArguments {
+    let primary_identifier = query_arguments.model.primary_identifier();
+
+    for field in primary_identifier.scalar_fields() {
+        let is_ordered = query_arguments
+            .order_by
+            .iter()
+            .any(|order_by| order_by.is_scalar() && order_by.field == field);
+
+        if !is_ordered {
+            query_arguments.order_by.push(OrderBy::new(field, SortOrder::Ascending));
+        }
+    }
+
+    query_arguments
+}
@@ -2,6 +2,7 @@
 
 pub mod error;
 pub mod filter;
+pub mod metrics;
 
 mod compare;
 mod interface;
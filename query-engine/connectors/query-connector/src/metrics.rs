@@ -0,0 +1,452 @@
+//! Operational metrics of the query engine. Metrics are declared as statics next to the code
+//! that records them, registered in a process-wide registry when they are first recorded, and
+//! rendered in the Prometheus text exposition format.
+//!
+//! The values of a series are atomics. Recording looks the series up under a read lock of its
+//! metric, and a series resolved once with `with_labels` is recorded without any lookup.
+//!
+//! A metric only shows up in the rendered output once it has been recorded.
+
+use once_cell::sync::{Lazy, OnceCell};
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    fmt::Write,
+    hash::{Hash, Hasher},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, MutexGuard, RwLock, RwLockReadGuard, RwLockWriteGuard,
+    },
+    time::Duration,
+};
+
+/// Bucket upper bounds in seconds, for histograms of durations.
+pub const DURATION_BUCKETS: &[f64] = &[0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+/// The metrics recorded so far. Only locked to register a metric and to render.
+static REGISTRY: Lazy<Mutex<Vec<&'static Family>>> = Lazy::new(Default::default);
+
+/// All series of a metric.
+struct Family {
+    name: &'static str,
+    help: &'static str,
+    type_name: &'static str,
+
+    /// The series by the hash of their label values. Created on the first recording, which
+    /// registers the family.
+    series: OnceCell<RwLock<HashMap<u64, Vec<Arc<Series>>>>>,
+}
+
+impl Family {
+    const fn new(name: &'static str, help: &'static str, type_name: &'static str) -> Self {
+        Self {
+            name,
+            help,
+            type_name,
+            series: OnceCell::new(),
+        }
+    }
+
+    /// Returns the series with the label values, created with `empty` if there is none yet.
+    fn resolve(&'static self, labels: &[(&str, &str)], empty: impl FnOnce() -> Value) -> Arc<Series> {
+        let series = self.series.get_or_init(|| {
+            lock(&REGISTRY).push(self);
+            Default::default()
+        });
+
+        let hash = hash_labels(labels);
+
+        if let Some(found) = find_series(&read(series), hash, labels) {
+            return found;
+        }
+
+        let mut series = write(series);
+
+        if let Some(found) = find_series(&series, hash, labels) {
+            return found;
+        }
+
+        let created = Arc::new(Series {
+            labels: labels
+                .iter()
+                .map(|(name, value)| ((*name).to_owned(), (*value).to_owned()))
+                .collect(),
+            value: empty(),
+        });
+
+        series.entry(hash).or_default().push(Arc::clone(&created));
+        created
+    }
+}
+
+fn find_series(series: &HashMap<u64, Vec<Arc<Series>>>, hash: u64, labels: &[(&str, &str)]) -> Option<Arc<Series>> {
+    series
+        .get(&hash)
+        .and_then(|candidates| candidates.iter().find(|candidate| candidate.has_labels(labels)))
+        .cloned()
+}
+
+fn hash_labels(labels: &[(&str, &str)]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    labels.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// A panic while holding a lock cannot leave a series half-updated, so poisoned locks are still usable.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn read<T>(rw_lock: &RwLock<T>) -> RwLockReadGuard<'_, T> {
+    rw_lock.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn write<T>(rw_lock: &RwLock<T>) -> RwLockWriteGuard<'_, T> {
+    rw_lock.write().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+struct Series {
+    labels: Vec<(String, String)>,
+    value: Value,
+}
+
+impl Series {
+    fn has_labels(&self, labels: &[(&str, &str)]) -> bool {
+        self.labels.len() == labels.len()
+            && self
+                .labels
+                .iter()
+                .zip(labels)
+                .all(|((name, value), (other_name, other_value))| name == other_name && value == other_value)
+    }
+}
+
+enum Value {
+    Counter(AtomicU64),
+    Gauge(AtomicF64),
+    Histogram {
+        bucket_bounds: &'static [f64],
+        bucket_counts: Vec<AtomicU64>,
+        sum: AtomicF64,
+        count: AtomicU64,
+    },
+}
+
+/// An `f64` that is updated atomically, stored as its bits.
+struct AtomicF64(AtomicU64);
+
+impl AtomicF64 {
+    fn new(value: f64) -> Self {
+        Self(AtomicU64::new(value.to_bits()))
+    }
+
+    fn add(&self, delta: f64) {
+        let _ = self.0.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |bits| {
+            Some((f64::from_bits(bits) + delta).to_bits())
+        });
+    }
+
+    fn get(&self) -> f64 {
+        f64::from_bits(self.0.load(Ordering::Relaxed))
+    }
+}
+
+/// A monotonically increasing count, for example of executed operations.
+pub struct Counter {
+    family: Family,
+}
+
+impl Counter {
+    pub const fn new(name: &'static str, help: &'static str) -> Self {
+        Self {
+            family: Family::new(name, help, "counter"),
+        }
+    }
+
+    /// The series with the label values, to record it without looking it up every time.
+    pub fn with_labels(&'static self, labels: &[(&str, &str)]) -> CounterSeries {
+        CounterSeries(self.family.resolve(labels, || Value::Counter(AtomicU64::new(0))))
+    }
+
+    pub fn increment(&'static self, labels: &[(&str, &str)]) {
+        self.with_labels(labels).increment();
+    }
+}
+
+/// A series of a counter.
+#[derive(Clone)]
+pub struct CounterSeries(Arc<Series>);
+
+impl CounterSeries {
+    pub fn increment(&self) {
+        if let Value::Counter(count) = &self.0.value {
+            count.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+/// A value that goes up and down, for example the connections in use.
+pub struct Gauge {
+    family: Family,
+}
+
+impl Gauge {
+    pub const fn new(name: &'static str, help: &'static str) -> Self {
+        Self {
+            family: Family::new(name, help, "gauge"),
+        }
+    }
+
+    /// The series with the label values, to record it without looking it up every time.
+    pub fn with_labels(&'static self, labels: &[(&str, &str)]) -> GaugeSeries {
+        GaugeSeries(self.family.resolve(labels, || Value::Gauge(AtomicF64::new(0.0))))
+    }
+
+    pub fn add(&'static self, labels: &[(&str, &str)], delta: f64) {
+        self.with_labels(labels).add(delta);
+    }
+
+    pub fn increment(&'static self, labels: &[(&str, &str)]) {
+        self.add(labels, 1.0);
+    }
+
+    pub fn decrement(&'static self, labels: &[(&str, &str)]) {
+        self.add(labels, -1.0);
+    }
+}
+
+/// A series of a gauge.
+#[derive(Clone)]
+pub struct GaugeSeries(Arc<Series>);
+
+impl GaugeSeries {
+    pub fn add(&self, delta: f64) {
+        if let Value::Gauge(gauge) = &self.0.value {
+            gauge.add(delta);
+        }
+    }
+
+    pub fn increment(&self) {
+        self.add(1.0);
+    }
+
+    pub fn decrement(&self) {
+        self.add(-1.0);
+    }
+}
+
+/// The distribution of observed values over fixed buckets, for example of query durations.
+pub struct Histogram {
+    family: Family,
+    buckets: &'static [f64],
+}
+
+impl Histogram {
+    /// `buckets` are the upper bounds of the buckets, in ascending order.
+    pub const fn new(name: &'static str, help: &'static str, buckets: &'static [f64]) -> Self {
+        Self {
+            family: Family::new(name, help, "histogram"),
+            buckets,
+        }
+    }
+
+    /// The series with the label values, to record it without looking it up every time.
+    pub fn with_labels(&'static self, labels: &[(&str, &str)]) -> HistogramSeries {
+        let buckets = self.buckets;
+        let empty = || Value::Histogram {
+            bucket_bounds: buckets,
+            bucket_counts: buckets.iter().map(|_| AtomicU64::new(0)).collect(),
+            sum: AtomicF64::new(0.0),
+            count: AtomicU64::new(0),
+        };
+
+        HistogramSeries(self.family.resolve(labels, empty))
+    }
+
+    pub fn observe(&'static self, labels: &[(&str, &str)], observed: f64) {
+        self.with_labels(labels).observe(observed);
+    }
+
+    /// Observes a duration in seconds.
+    pub fn observe_duration(&'static self, labels: &[(&str, &str)], duration: Duration) {
+        self.with_labels(labels).observe_duration(duration);
+    }
+}
+
+/// A series of a histogram. The buckets, sum and count of an observation are updated one after
+/// the other, so a concurrent rendering can see them apart.
+#[derive(Clone)]
+pub struct HistogramSeries(Arc<Series>);
+
+impl HistogramSeries {
+    pub fn observe(&self, observed: f64) {
+        if let Value::Histogram {
+            bucket_bounds,
+            bucket_counts,
+            sum,
+            count,
+        } = &self.0.value
+        {
+            if let Some(index) = bucket_bounds.iter().position(|bound| observed <= *bound) {
+                bucket_counts[index].fetch_add(1, Ordering::Relaxed);
+            }
+
+            sum.add(observed);
+            count.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Observes a duration in seconds.
+    pub fn observe_duration(&self, duration: Duration) {
+        self.observe(duration.as_secs_f64());
+    }
+}
+
+/// Renders all recorded metrics in the Prometheus text exposition format.
+pub fn render() -> String {
+    let mut families = lock(&REGISTRY).clone();
+    let mut out = String::new();
+
+    families.sort_by_key(|family| family.name);
+
+    for family in families {
+        let series = match family.series.get() {
+            Some(series) => read(series),
+            None => continue,
+        };
+
+        let mut series: Vec<&Series> = series.values().flatten().map(|series| series.as_ref()).collect();
+
+        if series.is_empty() {
+            continue;
+        }
+
+        series.sort_by(|a, b| a.labels.cmp(&b.labels));
+
+        let name = family.name;
+
+        writeln!(out, "# HELP {} {}", name, family.help).unwrap();
+        writeln!(out, "# TYPE {} {}", name, family.type_name).unwrap();
+
+        for Series { labels, value } in series {
+            match value {
+                Value::Counter(count) => writeln!(
+                    out,
+                    "{}{} {}",
+                    name,
+                    render_labels(labels, None),
+                    count.load(Ordering::Relaxed)
+                )
+                .unwrap(),
+                Value::Gauge(gauge) => {
+                    writeln!(out, "{}{} {}", name, render_labels(labels, None), gauge.get()).unwrap()
+                }
+                Value::Histogram {
+                    bucket_bounds,
+                    bucket_counts,
+                    sum,
+                    count,
+                } => {
+                    let count = count.load(Ordering::Relaxed);
+                    let mut cumulative_count = 0;
+
+                    for (bound, bucket_count) in bucket_bounds.iter().zip(bucket_counts.iter()) {
+                        cumulative_count += bucket_count.load(Ordering::Relaxed);
+                        let le = bound.to_string();
+
+                        writeln!(
+                            out,
+                            "{}_bucket{} {}",
+                            name,
+                            render_labels(labels, Some(&le)),
+                            cumulative_count
+                        )
+                        .unwrap();
+                    }
+
+                    writeln!(out, "{}_bucket{} {}", name, render_labels(labels, Some("+Inf")), count).unwrap();
+                    writeln!(out, "{}_sum{} {}", name, render_labels(labels, None), sum.get()).unwrap();
+                    writeln!(out, "{}_count{} {}", name, render_labels(labels, None), count).unwrap();
+                }
+            }
+        }
+    }
+
+    out
+}
+
+/// Renders the labels of a series, with the `le` label of a histogram bucket last.
+fn render_labels(labels: &[(String, String)], le: Option<&str>) -> String {
+    let mut rendered: Vec<String> = labels
+        .iter()
+        .map(|(name, value)| format!("{}=\"{}\"", name, escape_label_value(value)))
+        .collect();
+
+    if let Some(le) = le {
+        rendered.push(format!("le=\"{}\"", le));
+    }
+
+    if rendered.is_empty() {
+        String::new()
+    } else {
+        format!("{{{}}}", rendered.join(","))
+    }
+}
+
+fn escape_label_value(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TEST_COUNTER: Counter = Counter::new("test_counter_total", "A test counter.");
+    static TEST_HISTOGRAM: Histogram = Histogram::new("test_histogram_seconds", "A test histogram.", &[0.1, 1.0]);
+
+    static TEST_GAUGE: Gauge = Gauge::new("test_gauge", "A test gauge.");
+
+    #[test]
+    fn resolved_series_are_recorded_into_the_series_with_their_labels() {
+        let series = TEST_GAUGE.with_labels(&[("pool", "primary")]);
+
+        series.increment();
+        series.increment();
+        TEST_GAUGE.decrement(&[("pool", "primary")]);
+        TEST_GAUGE.increment(&[("pool", "replica-1")]);
+
+        let rendered = render();
+
+        let expected_gauge = "# HELP test_gauge A test gauge.\n\
+             # TYPE test_gauge gauge\n\
+             test_gauge{pool=\"primary\"} 1\n\
+             test_gauge{pool=\"replica-1\"} 1\n";
+
+        assert!(rendered.contains(expected_gauge), "{}", rendered);
+    }
+
+    #[test]
+    fn metrics_are_rendered_in_the_prometheus_text_format() {
+        TEST_COUNTER.increment(&[("action", "findMany"), ("model", "Us\"er")]);
+        TEST_COUNTER.increment(&[("action", "findMany"), ("model", "Us\"er")]);
+        TEST_HISTOGRAM.observe(&[("connector", "postgres")], 0.0625);
+        TEST_HISTOGRAM.observe(&[("connector", "postgres")], 0.5);
+        TEST_HISTOGRAM.observe(&[("connector", "postgres")], 4.0);
+
+        let rendered = render();
+
+        let expected_counter = "# HELP test_counter_total A test counter.\n\
+             # TYPE test_counter_total counter\n\
+             test_counter_total{action=\"findMany\",model=\"Us\\\"er\"} 2\n";
+
+        let expected_histogram = "# HELP test_histogram_seconds A test histogram.\n\
+             # TYPE test_histogram_seconds histogram\n\
+             test_histogram_seconds_bucket{connector=\"postgres\",le=\"0.1\"} 1\n\
+             test_histogram_seconds_bucket{connector=\"postgres\",le=\"1\"} 2\n\
+             test_histogram_seconds_bucket{connector=\"postgres\",le=\"+Inf\"} 3\n\
+             test_histogram_seconds_sum{connector=\"postgres\"} 4.5625\n\
+             test_histogram_seconds_count{connector=\"postgres\"} 3\n";
+
+        assert!(rendered.contains(expected_counter), "{}", rendered);
+        assert!(rendered.contains(expected_histogram), "{}", rendered);
+    }
+}
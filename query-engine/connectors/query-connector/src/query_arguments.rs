@@ -63,18 +63,23 @@ impl QueryArguments {
 
     /// A null cursor is a cursor that is used in conjunction with a nullable order by (i.e. a field is optional).
     pub fn contains_null_cursor(&self) -> bool {
-        self.cursor.is_some() && self.order_by.iter().any(|o| !o.field.is_required)
+        self.cursor.is_some() && self.order_by.iter().any(|o| o.is_nullable())
     }
 
     /// Checks if the orderBy provided is guaranteeing a stable ordering of records for the model. Assumes that `model`
     /// is the same as the model used
     /// `true` if at least one unique field is present, or contains a combination of fields that is marked as unique.
-    /// `false` otherwise.
+    /// `false` otherwise. Orderings on related records never make an ordering stable.
     pub fn is_stable_ordering(&self) -> bool {
-        let order_fields: Vec<_> = self.order_by.iter().map(|o| &o.field).collect();
+        let order_fields: Vec<_> = self
+            .order_by
+            .iter()
+            .filter(|o| o.is_scalar())
+            .map(|o| &o.field)
+            .collect();
 
         !self.order_by.is_empty()
-            && (self.order_by.iter().any(|o| o.field.unique())
+            && (order_fields.iter().any(|field| field.unique())
                 || self
                     .model
                     .unique_indexes()
@@ -86,8 +91,11 @@ impl QueryArguments {
         self.take.clone().map(|t| if t < 0 { t * -1 } else { t })
     }
 
+    /// Batched results are ordered in memory, which is only possible for orderings on fields of the queried model.
     pub fn can_batch(&self) -> bool {
-        self.filter.as_ref().map(|filter| filter.can_batch()).unwrap_or(false) && self.cursor.is_none()
+        self.filter.as_ref().map(|filter| filter.can_batch()).unwrap_or(false)
+            && self.cursor.is_none()
+            && self.order_by.iter().all(|o| o.is_scalar())
     }
 
     pub fn batched(self) -> Vec<Self> {
@@ -1,7 +1,7 @@
-use crate::query_arguments_ext::QueryArgumentsExt;
+use crate::{ordering, query_arguments_ext::QueryArgumentsExt};
 use connector_interface::QueryArguments;
 use prisma_models::*;
-use quaint::ast::*;
+use quaint::{ast::*, prelude::SqlFamily};
 
 static ORDER_TABLE_ALIAS: &str = "order_cmp";
 
@@ -43,6 +43,10 @@ static ORDER_TABLE_ALIAS: &str = "order_cmp";
 ///   `TestModel`.`fieldD` DESC;
 /// ```
 ///
+/// Orderings on fields of related records or on relation counts compare the correlated subquery built for the
+/// `ORDER BY` (see `ordering::order_expression`) instead of a plain column. The values for those are selected
+/// under a positional alias (`order_cmp`.`order_cmp_<n>`) in part #1.
+///
 /// The above assumes that all field are non-nullable. If a field is nullable, #2 conditions slighty change:
 /// ```sql
 ///   -- ... The first (4 - condition) block:
@@ -62,7 +66,11 @@ static ORDER_TABLE_ALIAS: &str = "order_cmp";
 ///   )
 ///   -- ...
 /// ```
-pub fn build(query_arguments: &QueryArguments, model: &ModelRef) -> (Option<Table<'static>>, ConditionTree<'static>) {
+pub fn build(
+    query_arguments: &QueryArguments,
+    model: &ModelRef,
+    sql_family: SqlFamily,
+) -> (Option<Table<'static>>, ConditionTree<'static>) {
     match query_arguments.cursor {
         None => (None, ConditionTree::NoCondition),
         Some(ref cursor) => {
@@ -78,13 +86,13 @@ pub fn build(query_arguments: &QueryArguments, model: &ModelRef) -> (Option<Tabl
             let cursor_condition = cursor_row.clone().equals(cursor_values.clone());
 
             // Orderings for this query. Influences which fields we need to fetch for comparing order fields.
-            let mut order_definitions = order_definitions(query_arguments, model);
+            let mut order_definitions = order_definitions(query_arguments, model, sql_family);
 
             // Subquery to find the value of the order field(s) that we need for comparison. Builds part #1 of the query example in the docs.
             let order_subquery = order_definitions
                 .iter()
-                .fold(Select::from_table(model.as_table()), |select, (field, _)| {
-                    select.column(field.as_column())
+                .fold(Select::from_table(model.as_table()), |select, definition| {
+                    select.value(definition.order_expression.clone().alias(definition.cmp_alias.clone()))
                 })
                 .so_that(cursor_condition);
 
@@ -95,15 +103,15 @@ pub fn build(query_arguments: &QueryArguments, model: &ModelRef) -> (Option<Tabl
             // Builds part #2 of the example query.
             // If we only have one ordering, we only want a single, slightly different, condition of (orderField [<= / >=] cmp_field).
             let condition_tree = if len == 1 {
-                let (field, order) = order_definitions.pop().unwrap();
-                ConditionTree::Single(Box::new(map_orderby_condition(&field, &order, reverse, true)))
+                let definition = order_definitions.pop().unwrap();
+                ConditionTree::Single(Box::new(map_orderby_condition(&definition, reverse, true)))
             } else {
                 let or_conditions = (0..len).fold(Vec::with_capacity(len), |mut conditions_acc, n| {
                     let (head, tail) = order_definitions.split_at(len - n - 1);
                     let mut and_conditions = Vec::with_capacity(head.len() + 1);
 
-                    for (field, _) in head {
-                        and_conditions.push(map_equality_condition(field));
+                    for definition in head {
+                        and_conditions.push(map_equality_condition(definition));
                     }
 
                     if head.len() == len - 1 {
@@ -131,12 +139,12 @@ pub fn build(query_arguments: &QueryArguments, model: &ModelRef) -> (Option<Tabl
                         //
                         // Said differently, we handle all the cases in which the prefixes are equal to len - 1 to account for possible identical comparators,
                         // but everything else must come strictly "after" the cursor.
-                        let (field, order) = tail.first().unwrap();
+                        let definition = tail.first().unwrap();
 
-                        and_conditions.push(map_orderby_condition(field, order, reverse, true));
+                        and_conditions.push(map_orderby_condition(definition, reverse, true));
                     } else {
-                        let (field, order) = tail.first().unwrap();
-                        and_conditions.push(map_orderby_condition(field, order, reverse, false));
+                        let definition = tail.first().unwrap();
+                        and_conditions.push(map_orderby_condition(definition, reverse, false));
                     }
 
                     conditions_acc.push(ConditionTree::And(and_conditions));
@@ -153,16 +161,11 @@ pub fn build(query_arguments: &QueryArguments, model: &ModelRef) -> (Option<Tabl
 
 // A negative `take` value signifies that values should be taken before the cursor,
 // requiring the correct comarison operator to be used to fit the reversed order.
-fn map_orderby_condition(
-    field: &ScalarFieldRef,
-    order: &SortOrder,
-    reverse: bool,
-    include_eq: bool,
-) -> Expression<'static> {
-    let order_column = field.as_column();
-    let cmp_column = Column::from((ORDER_TABLE_ALIAS, field.db_name().to_owned()));
-
-    let order_expr: Expression<'static> = match order {
+fn map_orderby_condition(definition: &OrderDefinition, reverse: bool, include_eq: bool) -> Expression<'static> {
+    let order_column = definition.order_expression.clone();
+    let cmp_column = definition.cmp_column();
+
+    let order_expr: Expression<'static> = match definition.sort_order {
         // If it's ASC but we want to take from the back, the ORDER BY will be DESC, meaning that comparisons done need to be lt(e).
         SortOrder::Ascending if reverse => {
             if include_eq {
@@ -201,23 +204,23 @@ fn map_orderby_condition(
 
     // If we have null values in the ordering or comparison row, those are automatically included because we can't make a
     // statement over their order relative to the cursor.
-    if !field.is_required {
+    if definition.is_nullable {
         order_expr
-            .or(field.as_column().is_null())
-            .or(Column::from((ORDER_TABLE_ALIAS, field.db_name().to_owned())).is_null())
+            .or(definition.order_expression.clone().is_null())
+            .or(definition.cmp_column().is_null())
             .into()
     } else {
         order_expr
     }
 }
 
-fn map_equality_condition(field: &ScalarFieldRef) -> Expression<'static> {
-    let order_column = field.as_column();
-    let cmp_column = Column::from((ORDER_TABLE_ALIAS, field.db_name().to_owned()));
+fn map_equality_condition(definition: &OrderDefinition) -> Expression<'static> {
+    let order_column = definition.order_expression.clone();
+    let cmp_column = definition.cmp_column();
 
     // If we have null values in the ordering or comparison row, those are automatically included because we can't make a
     // statement over their order relative to the cursor.
-    if !field.is_required {
+    if definition.is_nullable {
         order_column
             .clone()
             .equals(cmp_column.clone())
@@ -229,20 +232,39 @@ fn map_equality_condition(field: &ScalarFieldRef) -> Expression<'static> {
     }
 }
 
-fn order_definitions(query_arguments: &QueryArguments, model: &ModelRef) -> Vec<(ScalarFieldRef, SortOrder)> {
-    let defined_ordering: Vec<_> = query_arguments
-        .order_by
-        .iter()
-        .map(|o| (o.field.clone(), o.sort_order))
-        .collect();
-
-    if defined_ordering.is_empty() {
-        model
-            .primary_identifier()
-            .scalar_fields()
-            .map(|f| (f, SortOrder::Ascending))
-            .collect()
-    } else {
-        defined_ordering
+/// An ordering of the query, with the alias its value is selected as in the comparison subquery.
+struct OrderDefinition {
+    order_expression: Expression<'static>,
+    cmp_alias: String,
+    sort_order: SortOrder,
+    is_nullable: bool,
+}
+
+impl OrderDefinition {
+    fn cmp_column(&self) -> Column<'static> {
+        Column::from((ORDER_TABLE_ALIAS, self.cmp_alias.clone()))
     }
 }
+
+fn order_definitions(
+    query_arguments: &QueryArguments,
+    model: &ModelRef,
+    sql_family: SqlFamily,
+) -> Vec<OrderDefinition> {
+    let defined_ordering = if query_arguments.order_by.is_empty() {
+        model.primary_identifier().scalar_fields().map(OrderBy::from).collect()
+    } else {
+        query_arguments.order_by.clone()
+    };
+
+    defined_ordering
+        .iter()
+        .enumerate()
+        .map(|(i, order_by)| OrderDefinition {
+            order_expression: ordering::order_expression(order_by, sql_family),
+            cmp_alias: format!("{}_{}", ORDER_TABLE_ALIAS, i),
+            sort_order: order_by.sort_order,
+            is_nullable: order_by.is_nullable(),
+        })
+        .collect()
+}
@@ -1,4 +1,8 @@
-use super::transaction::SqlConnectorTransaction;
+use super::{
+    metrics::{self, CheckedOutConnection, TRANSACTIONS_STARTED},
+    owned_transaction::SqlConnectorOwnedTransaction,
+    transaction::SqlConnectorTransaction,
+};
 use crate::{database::operations::*, QueryExt, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
@@ -13,22 +17,27 @@ use std::future::Future;
 pub struct SqlConnection<C> {
     inner: C,
     connection_info: ConnectionInfo,
+    checked_out: CheckedOutConnection,
 }
 
 impl<C> SqlConnection<C>
 where
     C: QueryExt + Send + Sync + 'static,
 {
-    pub fn new(inner: C, connection_info: &ConnectionInfo) -> Self {
+    pub fn new(inner: C, connection_info: &ConnectionInfo, checked_out: CheckedOutConnection) -> Self {
         let connection_info = connection_info.clone();
-        Self { inner, connection_info }
+        Self {
+            inner,
+            connection_info,
+            checked_out,
+        }
     }
 
     async fn catch<O>(
         &self,
         fut: impl Future<Output = Result<O, SqlError>>,
     ) -> Result<O, connector_interface::error::ConnectorError> {
-        match fut.await {
+        match metrics::timed(&self.connection_info, fut).await {
             Ok(o) => Ok(o),
             Err(err) => Err(err.into_connector_error(&self.connection_info)),
         }
@@ -45,10 +54,17 @@ where
         let connection_info = &self.connection_info;
         self.catch(async move {
             let tx: quaint::connector::Transaction = fut_tx.await.map_err(SqlError::from)?;
+            TRANSACTIONS_STARTED.increment(&[("connector", metrics::connector_label(connection_info))]);
+
             Ok(Box::new(SqlConnectorTransaction::new(tx, &connection_info)) as Box<dyn Transaction>)
         })
         .await
     }
+
+    async fn start_owned_transaction(self: Box<Self>) -> connector::Result<Box<dyn Transaction>> {
+        let tx = SqlConnectorOwnedTransaction::begin(self.inner, &self.connection_info, self.checked_out).await?;
+        Ok(Box::new(tx) as Box<dyn Transaction>)
+    }
 }
 
 #[async_trait]
@@ -62,8 +78,10 @@ where
         filter: &Filter,
         selected_fields: &ModelProjection,
     ) -> connector::Result<Option<SingleRecord>> {
-        self.catch(async move { read::get_single_record(&self.inner, model, filter, selected_fields).await })
-            .await
+        self.catch(async move {
+            read::get_single_record(&self.inner, &self.connection_info, model, filter, selected_fields).await
+        })
+        .await
     }
 
     async fn get_many_records(
@@ -72,8 +90,17 @@ where
         query_arguments: QueryArguments,
         selected_fields: &ModelProjection,
     ) -> connector::Result<ManyRecords> {
-        self.catch(async move { read::get_many_records(&self.inner, model, query_arguments, selected_fields).await })
+        self.catch(async move {
+            read::get_many_records(
+                &self.inner,
+                &self.connection_info,
+                model,
+                query_arguments,
+                selected_fields,
+            )
             .await
+        })
+        .await
     }
 
     async fn get_related_m2m_record_ids(
@@ -91,8 +118,33 @@ where
         aggregators: Vec<Aggregator>,
         query_arguments: QueryArguments,
     ) -> connector::Result<Vec<AggregationResult>> {
-        self.catch(async move { read::aggregate(&self.inner, model, aggregators, query_arguments).await })
+        self.catch(async move {
+            read::aggregate(&self.inner, &self.connection_info, model, aggregators, query_arguments).await
+        })
+        .await
+    }
+
+    async fn group_by_records(
+        &self,
+        model: &ModelRef,
+        group_by: Vec<ScalarFieldRef>,
+        aggregators: Vec<Aggregator>,
+        query_arguments: QueryArguments,
+        having: Option<Filter>,
+    ) -> connector::Result<Vec<Vec<AggregationResult>>> {
+        self.catch(async move {
+            read::group_by_aggregate(
+                &self.inner,
+                &self.connection_info,
+                model,
+                group_by,
+                aggregators,
+                query_arguments,
+                having,
+            )
             .await
+        })
+        .await
     }
 }
 
@@ -106,18 +158,32 @@ where
             .await
     }
 
+    async fn create_records(
+        &self,
+        model: &ModelRef,
+        args: Vec<WriteArgs>,
+        skip_duplicates: bool,
+    ) -> connector::Result<usize> {
+        self.catch(async move {
+            write::create_records(&self.inner, &self.connection_info, model, args, skip_duplicates).await
+        })
+        .await
+    }
+
     async fn update_records(
         &self,
         model: &ModelRef,
         record_filter: RecordFilter,
         args: WriteArgs,
     ) -> connector::Result<Vec<RecordProjection>> {
-        self.catch(async move { write::update_records(&self.inner, model, record_filter, args).await })
-            .await
+        self.catch(async move {
+            write::update_records(&self.inner, &self.connection_info, model, record_filter, args).await
+        })
+        .await
     }
 
     async fn delete_records(&self, model: &ModelRef, record_filter: RecordFilter) -> connector::Result<usize> {
-        self.catch(async move { write::delete_records(&self.inner, model, record_filter).await })
+        self.catch(async move { write::delete_records(&self.inner, &self.connection_info, model, record_filter).await })
             .await
     }
 
@@ -0,0 +1,120 @@
+//! Metrics of the SQL connectors. Quaint does not report how many pooled connections are in use or
+//! idle, so the connectors count the connections they check out themselves.
+
+use connector_interface::metrics::{Counter, Gauge, GaugeSeries, Histogram, DURATION_BUCKETS};
+use quaint::{
+    pooled::Quaint,
+    prelude::{ConnectionInfo, SqlFamily},
+};
+use std::{
+    future::Future,
+    sync::atomic::{AtomicBool, Ordering},
+    time::Instant,
+};
+
+static SQL_QUERY_DURATION: Histogram = Histogram::new(
+    "prisma_sql_query_duration_seconds",
+    "Time spent in the database per connector operation, which runs one or more SQL queries, by connector.",
+    DURATION_BUCKETS,
+);
+
+static POOL_CONNECTIONS_ACTIVE: Gauge = Gauge::new(
+    "prisma_pool_connections_active",
+    "Connections checked out of the connection pools, by connector, datasource and pool.",
+);
+
+static POOL_CONNECTIONS_AVAILABLE: Gauge = Gauge::new(
+    "prisma_pool_connections_available",
+    "Capacity of the connection pools minus their checked out connections, by connector, datasource and pool.",
+);
+
+pub(crate) static TRANSACTIONS_STARTED: Counter = Counter::new(
+    "prisma_transactions_started_total",
+    "Transactions started, by connector.",
+);
+
+pub(crate) static TRANSACTIONS_COMMITTED: Counter = Counter::new(
+    "prisma_transactions_committed_total",
+    "Transactions committed, by connector.",
+);
+
+pub(crate) static TRANSACTIONS_ROLLED_BACK: Counter = Counter::new(
+    "prisma_transactions_rolled_back_total",
+    "Transactions rolled back, including the ones dropped without being finished, by connector.",
+);
+
+/// The value of the `connector` label of the metrics recorded on a connection.
+pub(crate) fn connector_label(connection_info: &ConnectionInfo) -> &'static str {
+    match connection_info.sql_family() {
+        SqlFamily::Postgres => "postgres",
+        SqlFamily::Mysql => "mysql",
+        SqlFamily::Sqlite => "sqlite",
+        SqlFamily::Mssql => "mssql",
+    }
+}
+
+/// Runs a connector operation, recording its duration.
+pub(crate) async fn timed<O>(connection_info: &ConnectionInfo, fut: impl Future<Output = O>) -> O {
+    let start = Instant::now();
+    let result = fut.await;
+
+    SQL_QUERY_DURATION.observe_duration(&[("connector", connector_label(connection_info))], start.elapsed());
+
+    result
+}
+
+/// Counts the connections of a pool in the pool metrics. A datasource has a `primary` pool, and a
+/// `replica-<n>` pool per read replica, numbered from 1.
+///
+/// The available connections are not the idle ones: they include the connections the pool has yet
+/// to open.
+pub struct PoolMetrics {
+    active: GaugeSeries,
+    available: GaugeSeries,
+    capacity_recorded: AtomicBool,
+}
+
+impl PoolMetrics {
+    pub fn new(connection_info: &ConnectionInfo, datasource: &str, pool: &str) -> Self {
+        let labels = [
+            ("connector", connector_label(connection_info)),
+            ("datasource", datasource),
+            ("pool", pool),
+        ];
+
+        Self {
+            active: POOL_CONNECTIONS_ACTIVE.with_labels(&labels),
+            available: POOL_CONNECTIONS_AVAILABLE.with_labels(&labels),
+            capacity_recorded: AtomicBool::new(false),
+        }
+    }
+
+    /// Records a connection checked out of the pool, until the returned guard is dropped. The
+    /// capacity of the pool is added to the available connections on the first check out.
+    pub async fn check_out(&self, pool: &Quaint) -> CheckedOutConnection {
+        if !self.capacity_recorded.swap(true, Ordering::SeqCst) {
+            self.available.add(f64::from(pool.capacity().await));
+        }
+
+        self.active.increment();
+        self.available.decrement();
+
+        CheckedOutConnection {
+            active: self.active.clone(),
+            available: self.available.clone(),
+        }
+    }
+}
+
+/// A connection checked out of the pool. Dropping it records the connection as returned.
+pub struct CheckedOutConnection {
+    active: GaugeSeries,
+    available: GaugeSeries,
+}
+
+impl Drop for CheckedOutConnection {
+    fn drop(&mut self) {
+        self.active.decrement();
+        self.available.increment();
+    }
+}
@@ -1,6 +1,8 @@
 mod connection;
+mod metrics;
 mod mssql;
 mod mysql;
+mod owned_transaction;
 mod postgresql;
 mod sqlite;
 mod transaction;
@@ -1,4 +1,4 @@
-use super::connection::SqlConnection;
+use super::{connection::SqlConnection, metrics::PoolMetrics};
 use crate::{FromSource, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
@@ -12,6 +12,7 @@ use std::time::Duration;
 
 pub struct Mssql {
     pool: Quaint,
+    pool_metrics: PoolMetrics,
     connection_info: ConnectionInfo,
 }
 
@@ -31,8 +32,13 @@ impl FromSource for Mssql {
 
         let pool = builder.build();
         let connection_info = pool.connection_info().to_owned();
+        let pool_metrics = PoolMetrics::new(&connection_info, &source.name, "primary");
 
-        Ok(Self { pool, connection_info })
+        Ok(Self {
+            pool,
+            pool_metrics,
+            connection_info,
+        })
     }
 }
 
@@ -41,7 +47,8 @@ impl Connector for Mssql {
     async fn get_connection<'a>(&'a self) -> connector::Result<Box<dyn Connection + 'static>> {
         super::catch(&self.connection_info, async move {
             let conn = self.pool.check_out().await.map_err(SqlError::from)?;
-            let conn = SqlConnection::new(conn, &self.connection_info);
+            let checked_out = self.pool_metrics.check_out(&self.pool).await;
+            let conn = SqlConnection::new(conn, &self.connection_info, checked_out);
 
             Ok(Box::new(conn) as Box<dyn Connection>)
         })
@@ -1,4 +1,4 @@
-use super::connection::SqlConnection;
+use super::{connection::SqlConnection, metrics::PoolMetrics};
 use crate::{FromSource, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
@@ -12,16 +12,29 @@ use std::time::Duration;
 
 pub struct Mysql {
     pool: Quaint,
+    pool_metrics: PoolMetrics,
     connection_info: ConnectionInfo,
 }
 
-#[async_trait]
-impl FromSource for Mysql {
-    async fn from_source(source: &Datasource) -> connector_interface::Result<Self> {
-        let connection_info = ConnectionInfo::from_url(&source.url().value)
+impl Mysql {
+    /// Builds a connector for each read replica of the datasource.
+    pub fn replicas_from_source(source: &Datasource) -> connector_interface::Result<Vec<Self>> {
+        source
+            .replica_urls
+            .iter()
+            .enumerate()
+            .map(|(index, replica_url)| {
+                Self::from_url(&replica_url.value, &source.name, &format!("replica-{}", index + 1))
+            })
+            .collect()
+    }
+
+    /// Builds a connector for the pool named `pool` of the datasource.
+    fn from_url(url: &str, datasource: &str, pool: &str) -> connector_interface::Result<Self> {
+        let connection_info = ConnectionInfo::from_url(url)
             .map_err(|err| ConnectorError::from_kind(ErrorKind::ConnectionError(err.into())))?;
 
-        let mut builder = Quaint::builder(&source.url().value)
+        let mut builder = Quaint::builder(url)
             .map_err(SqlError::from)
             .map_err(|sql_error| sql_error.into_connector_error(&connection_info))?;
 
@@ -31,8 +44,20 @@ impl FromSource for Mysql {
 
         let pool = builder.build();
         let connection_info = pool.connection_info().to_owned();
+        let pool_metrics = PoolMetrics::new(&connection_info, datasource, pool);
+
+        Ok(Mysql {
+            pool,
+            pool_metrics,
+            connection_info,
+        })
+    }
+}
 
-        Ok(Mysql { pool, connection_info })
+#[async_trait]
+impl FromSource for Mysql {
+    async fn from_source(source: &Datasource) -> connector_interface::Result<Self> {
+        Self::from_url(&source.url().value, &source.name, "primary")
     }
 }
 
@@ -41,7 +66,8 @@ impl Connector for Mysql {
     async fn get_connection<'a>(&'a self) -> connector::Result<Box<dyn Connection + 'static>> {
         super::catch(&self.connection_info, async move {
             let conn = self.pool.check_out().await.map_err(SqlError::from)?;
-            let conn = SqlConnection::new(conn, &self.connection_info);
+            let checked_out = self.pool_metrics.check_out(&self.pool).await;
+            let conn = SqlConnection::new(conn, &self.connection_info, checked_out);
 
             Ok(Box::new(conn) as Box<dyn Connection>)
         })
@@ -6,15 +6,21 @@ use crate::{
 use connector_interface::*;
 use futures::stream::{FuturesUnordered, StreamExt};
 use prisma_models::*;
-use quaint::ast::*;
+use quaint::{ast::*, prelude::ConnectionInfo};
 
 pub async fn get_single_record(
     conn: &dyn QueryExt,
+    connection_info: &ConnectionInfo,
     model: &ModelRef,
     filter: &Filter,
     selected_fields: &ModelProjection,
 ) -> crate::Result<Option<SingleRecord>> {
-    let query = read::get_records(&model, selected_fields.as_columns(), filter);
+    let query = read::get_records(
+        &model,
+        selected_fields.as_columns(),
+        filter,
+        connection_info.sql_family(),
+    );
     let field_names = selected_fields.db_names().map(String::from).collect();
     let idents: Vec<_> = selected_fields.type_identifiers_with_arities();
 
@@ -32,6 +38,7 @@ pub async fn get_single_record(
 
 pub async fn get_many_records(
     conn: &dyn QueryExt,
+    connection_info: &ConnectionInfo,
     model: &ModelRef,
     mut query_arguments: QueryArguments,
     selected_fields: &ModelProjection,
@@ -56,7 +63,7 @@ pub async fn get_many_records(
         let mut futures = FuturesUnordered::new();
 
         for args in batches.into_iter() {
-            let query = read::get_records(model, selected_fields.as_columns(), args);
+            let query = read::get_records(model, selected_fields.as_columns(), args, connection_info.sql_family());
             futures.push(conn.filter(query.into(), idents.as_slice()));
         }
 
@@ -70,7 +77,12 @@ pub async fn get_many_records(
             records.order_by(&order)
         }
     } else {
-        let query = read::get_records(model, selected_fields.as_columns(), query_arguments);
+        let query = read::get_records(
+            model,
+            selected_fields.as_columns(),
+            query_arguments,
+            connection_info.sql_family(),
+        );
 
         for item in conn.filter(query.into(), idents.as_slice()).await?.into_iter() {
             records.push(Record::from(item))
@@ -147,11 +159,12 @@ pub async fn get_related_m2m_record_ids(
 
 pub async fn aggregate(
     conn: &dyn QueryExt,
+    connection_info: &ConnectionInfo,
     model: &ModelRef,
     aggregators: Vec<Aggregator>,
     query_arguments: QueryArguments,
 ) -> crate::Result<Vec<AggregationResult>> {
-    let query = read::aggregate(model, &aggregators, query_arguments);
+    let query = read::aggregate(model, &aggregators, query_arguments, connection_info.sql_family());
     let idents: Vec<_> = aggregators
         .iter()
         .flat_map(|aggregator| aggregator.identifiers())
@@ -164,3 +177,35 @@ pub async fn aggregate(
 
     Ok(row.into_aggregation_results(&aggregators))
 }
+
+pub async fn group_by_aggregate(
+    conn: &dyn QueryExt,
+    connection_info: &ConnectionInfo,
+    model: &ModelRef,
+    group_by: Vec<ScalarFieldRef>,
+    aggregators: Vec<Aggregator>,
+    query_arguments: QueryArguments,
+    having: Option<Filter>,
+) -> crate::Result<Vec<Vec<AggregationResult>>> {
+    let mut idents: Vec<_> = group_by
+        .iter()
+        .map(|field| field.type_identifier_with_arity())
+        .collect();
+
+    idents.extend(aggregators.iter().flat_map(|aggregator| aggregator.identifiers()));
+
+    let query = read::group_by_aggregate(
+        model,
+        query_arguments,
+        &aggregators,
+        group_by.clone(),
+        having,
+        connection_info.sql_family(),
+    );
+    let rows = conn.filter(query.into(), idents.as_slice()).await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| row.into_group_by_results(&group_by, &aggregators))
+        .collect())
+}
@@ -2,7 +2,10 @@ use crate::{error::SqlError, query_builder::write, QueryExt};
 use connector_interface::*;
 use prisma_models::*;
 use prisma_value::PrismaValue;
-use quaint::error::ErrorKind;
+use quaint::{
+    error::ErrorKind,
+    prelude::{ConnectionInfo, SqlFamily},
+};
 use std::{collections::HashMap, convert::TryFrom};
 use user_facing_errors::query_engine::DatabaseConstraint;
 
@@ -63,16 +66,50 @@ pub async fn create_record(conn: &dyn QueryExt, model: &ModelRef, args: WriteArg
     }
 }
 
+/// Create multiple records in the database defined in `conn` with as few
+/// multi-row inserts as the parameter limits of the database allow. With
+/// `skip_duplicates`, records violating a unique constraint are ignored.
+/// Result is the number of inserted records.
+pub async fn create_records(
+    conn: &dyn QueryExt,
+    connection_info: &ConnectionInfo,
+    model: &ModelRef,
+    args: Vec<WriteArgs>,
+    skip_duplicates: bool,
+) -> crate::Result<usize> {
+    if args.is_empty() {
+        return Ok(0);
+    }
+
+    let (max_bind_values, max_rows) = match connection_info.sql_family() {
+        SqlFamily::Postgres => (32767, None),
+        SqlFamily::Mysql => (65535, None),
+        SqlFamily::Sqlite => (999, None),
+        SqlFamily::Mssql => (2099, Some(1000)),
+    };
+
+    let mut count = 0;
+
+    for insert in write::create_records(model, args, skip_duplicates, max_bind_values, max_rows) {
+        count += conn.execute(insert.into()).await?;
+    }
+
+    Ok(count as usize)
+}
+
 /// Update multiple records in a database defined in `conn` and the records
 /// defined in `args`, resulting the identifiers that were modified in the
 /// operation.
 pub async fn update_records(
     conn: &dyn QueryExt,
+    connection_info: &ConnectionInfo,
     model: &ModelRef,
     record_filter: RecordFilter,
     args: WriteArgs,
 ) -> crate::Result<Vec<RecordProjection>> {
-    let ids = conn.filter_selectors(model, record_filter).await?;
+    let ids = conn
+        .filter_selectors(model, record_filter, connection_info.sql_family())
+        .await?;
     let id_args = pick_args(&model.primary_identifier(), &args);
 
     if ids.is_empty() {
@@ -94,10 +131,13 @@ pub async fn update_records(
 /// Delete multiple records in `conn`, defined in the `Filter`. Result is the number of items deleted.
 pub async fn delete_records(
     conn: &dyn QueryExt,
+    connection_info: &ConnectionInfo,
     model: &ModelRef,
     record_filter: RecordFilter,
 ) -> crate::Result<usize> {
-    let ids = conn.filter_selectors(model, record_filter).await?;
+    let ids = conn
+        .filter_selectors(model, record_filter, connection_info.sql_family())
+        .await?;
     let ids: Vec<&RecordProjection> = ids.iter().map(|id| &*id).collect();
     let count = ids.len();
 
@@ -0,0 +1,283 @@
+use super::metrics::{
+    self, CheckedOutConnection, TRANSACTIONS_COMMITTED, TRANSACTIONS_ROLLED_BACK, TRANSACTIONS_STARTED,
+};
+use crate::{database::operations::*, QueryExt, SqlError};
+use async_trait::async_trait;
+use connector_interface::{
+    self as connector, filter::Filter, AggregationResult, Aggregator, QueryArguments, ReadOperations, RecordFilter,
+    Transaction, WriteArgs, WriteOperations,
+};
+use prisma_models::prelude::*;
+use prisma_value::PrismaValue;
+use quaint::prelude::{ConnectionInfo, Queryable, SqlFamily};
+use std::{
+    future::Future,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+/// A transaction that owns its connection instead of borrowing it, which allows it to
+/// be held open across requests. The transaction is controlled with plain SQL statements,
+/// as `quaint::connector::Transaction` can only borrow its connection.
+///
+/// Dropping the transaction without committing or rolling it back, for example when it expires
+/// or the client goes away, rolls it back in the background before the connection is returned
+/// to the pool.
+pub struct SqlConnectorOwnedTransaction<C>
+where
+    C: QueryExt + Send + Sync + 'static,
+{
+    /// Only `None` once the transaction is dropped.
+    inner: Option<C>,
+    connection_info: ConnectionInfo,
+    /// Whether the transaction is committed or rolled back, or was never opened.
+    finished: AtomicBool,
+    /// The connection stays checked out of the pool until the transaction is dropped.
+    checked_out: Option<CheckedOutConnection>,
+}
+
+impl<C> SqlConnectorOwnedTransaction<C>
+where
+    C: QueryExt + Send + Sync + 'static,
+{
+    /// Opens a transaction on the given connection.
+    pub async fn begin(
+        inner: C,
+        connection_info: &ConnectionInfo,
+        checked_out: CheckedOutConnection,
+    ) -> connector::Result<Self> {
+        let tx = Self {
+            inner: Some(inner),
+            connection_info: connection_info.clone(),
+            finished: AtomicBool::new(true),
+            checked_out: Some(checked_out),
+        };
+
+        let begin_statement = match tx.connection_info.sql_family() {
+            SqlFamily::Mssql => "BEGIN TRAN",
+            _ => "BEGIN",
+        };
+
+        tx.catch(async { Ok(tx.conn().raw_cmd(begin_statement).await.map_err(SqlError::from)?) })
+            .await?;
+
+        tx.finished.store(false, Ordering::SeqCst);
+        TRANSACTIONS_STARTED.increment(&tx.labels());
+
+        Ok(tx)
+    }
+
+    fn conn(&self) -> &C {
+        self.inner.as_ref().expect("The connection is only taken on drop.")
+    }
+
+    async fn catch<O>(
+        &self,
+        fut: impl Future<Output = Result<O, SqlError>>,
+    ) -> Result<O, connector_interface::error::ConnectorError> {
+        match metrics::timed(&self.connection_info, fut).await {
+            Ok(o) => Ok(o),
+            Err(err) => Err(err.into_connector_error(&self.connection_info)),
+        }
+    }
+
+    fn labels(&self) -> [(&'static str, &'static str); 1] {
+        [("connector", metrics::connector_label(&self.connection_info))]
+    }
+}
+
+#[async_trait]
+impl<C> Transaction for SqlConnectorOwnedTransaction<C>
+where
+    C: QueryExt + Send + Sync + 'static,
+{
+    async fn commit(&self) -> connector::Result<()> {
+        self.catch(async move { Ok(self.conn().raw_cmd("COMMIT").await.map_err(SqlError::from)?) })
+            .await?;
+
+        self.finished.store(true, Ordering::SeqCst);
+        TRANSACTIONS_COMMITTED.increment(&self.labels());
+        Ok(())
+    }
+
+    async fn rollback(&self) -> connector::Result<()> {
+        self.catch(async move { Ok(self.conn().raw_cmd("ROLLBACK").await.map_err(SqlError::from)?) })
+            .await?;
+
+        self.finished.store(true, Ordering::SeqCst);
+        TRANSACTIONS_ROLLED_BACK.increment(&self.labels());
+        Ok(())
+    }
+}
+
+impl<C> Drop for SqlConnectorOwnedTransaction<C>
+where
+    C: QueryExt + Send + Sync + 'static,
+{
+    fn drop(&mut self) {
+        if self.finished.load(Ordering::SeqCst) {
+            return;
+        }
+
+        TRANSACTIONS_ROLLED_BACK.increment(&self.labels());
+
+        if let (Some(conn), checked_out) = (self.inner.take(), self.checked_out.take()) {
+            tokio::spawn(async move {
+                // A failing rollback means the connection is broken, and the database rolls the
+                // transaction back when it closes.
+                let _ = conn.raw_cmd("ROLLBACK").await;
+                drop(checked_out);
+            });
+        }
+    }
+}
+
+#[async_trait]
+impl<C> ReadOperations for SqlConnectorOwnedTransaction<C>
+where
+    C: QueryExt + Send + Sync + 'static,
+{
+    async fn get_single_record(
+        &self,
+        model: &ModelRef,
+        filter: &Filter,
+        selected_fields: &ModelProjection,
+    ) -> connector::Result<Option<SingleRecord>> {
+        self.catch(async move {
+            read::get_single_record(self.conn(), &self.connection_info, model, filter, selected_fields).await
+        })
+        .await
+    }
+
+    async fn get_many_records(
+        &self,
+        model: &ModelRef,
+        query_arguments: QueryArguments,
+        selected_fields: &ModelProjection,
+    ) -> connector::Result<ManyRecords> {
+        self.catch(async move {
+            read::get_many_records(
+                self.conn(),
+                &self.connection_info,
+                model,
+                query_arguments,
+                selected_fields,
+            )
+            .await
+        })
+        .await
+    }
+
+    async fn get_related_m2m_record_ids(
+        &self,
+        from_field: &RelationFieldRef,
+        from_record_ids: &[RecordProjection],
+    ) -> connector::Result<Vec<(RecordProjection, RecordProjection)>> {
+        self.catch(async move { read::get_related_m2m_record_ids(self.conn(), from_field, from_record_ids).await })
+            .await
+    }
+
+    async fn aggregate_records(
+        &self,
+        model: &ModelRef,
+        aggregators: Vec<Aggregator>,
+        query_arguments: QueryArguments,
+    ) -> connector::Result<Vec<AggregationResult>> {
+        self.catch(async move {
+            read::aggregate(self.conn(), &self.connection_info, model, aggregators, query_arguments).await
+        })
+        .await
+    }
+
+    async fn group_by_records(
+        &self,
+        model: &ModelRef,
+        group_by: Vec<ScalarFieldRef>,
+        aggregators: Vec<Aggregator>,
+        query_arguments: QueryArguments,
+        having: Option<Filter>,
+    ) -> connector::Result<Vec<Vec<AggregationResult>>> {
+        self.catch(async move {
+            read::group_by_aggregate(
+                self.conn(),
+                &self.connection_info,
+                model,
+                group_by,
+                aggregators,
+                query_arguments,
+                having,
+            )
+            .await
+        })
+        .await
+    }
+}
+
+#[async_trait]
+impl<C> WriteOperations for SqlConnectorOwnedTransaction<C>
+where
+    C: QueryExt + Send + Sync + 'static,
+{
+    async fn create_record(&self, model: &ModelRef, args: WriteArgs) -> connector::Result<RecordProjection> {
+        self.catch(async move { write::create_record(self.conn(), model, args).await })
+            .await
+    }
+
+    async fn create_records(
+        &self,
+        model: &ModelRef,
+        args: Vec<WriteArgs>,
+        skip_duplicates: bool,
+    ) -> connector::Result<usize> {
+        self.catch(async move {
+            write::create_records(self.conn(), &self.connection_info, model, args, skip_duplicates).await
+        })
+        .await
+    }
+
+    async fn update_records(
+        &self,
+        model: &ModelRef,
+        record_filter: RecordFilter,
+        args: WriteArgs,
+    ) -> connector::Result<Vec<RecordProjection>> {
+        self.catch(async move {
+            write::update_records(self.conn(), &self.connection_info, model, record_filter, args).await
+        })
+        .await
+    }
+
+    async fn delete_records(&self, model: &ModelRef, record_filter: RecordFilter) -> connector::Result<usize> {
+        self.catch(async move { write::delete_records(self.conn(), &self.connection_info, model, record_filter).await })
+            .await
+    }
+
+    async fn connect(
+        &self,
+        field: &RelationFieldRef,
+        parent_id: &RecordProjection,
+        child_ids: &[RecordProjection],
+    ) -> connector::Result<()> {
+        self.catch(async move { write::connect(self.conn(), field, parent_id, child_ids).await })
+            .await
+    }
+
+    async fn disconnect(
+        &self,
+        field: &RelationFieldRef,
+        parent_id: &RecordProjection,
+        child_ids: &[RecordProjection],
+    ) -> connector::Result<()> {
+        self.catch(async move { write::disconnect(self.conn(), field, parent_id, child_ids).await })
+            .await
+    }
+
+    async fn execute_raw(&self, query: String, parameters: Vec<PrismaValue>) -> connector::Result<usize> {
+        self.catch(async move { write::execute_raw(self.conn(), query, parameters).await })
+            .await
+    }
+
+    async fn query_raw(&self, query: String, parameters: Vec<PrismaValue>) -> connector::Result<serde_json::Value> {
+        self.catch(async move { write::query_raw(self.conn(), query, parameters).await })
+            .await
+    }
+}
@@ -1,4 +1,4 @@
-use super::connection::SqlConnection;
+use super::{connection::SqlConnection, metrics::PoolMetrics};
 use crate::{FromSource, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
@@ -12,6 +12,7 @@ use std::{convert::TryFrom, time::Duration};
 
 pub struct Sqlite {
     pool: Quaint,
+    pool_metrics: PoolMetrics,
     file_path: String,
 }
 
@@ -68,8 +69,13 @@ impl FromSource for Sqlite {
         builder.test_on_check_out(true);
 
         let pool = builder.build();
+        let pool_metrics = PoolMetrics::new(pool.connection_info(), &source.name, "primary");
 
-        Ok(Sqlite { pool, file_path })
+        Ok(Sqlite {
+            pool,
+            pool_metrics,
+            file_path,
+        })
     }
 }
 
@@ -86,7 +92,8 @@ impl Connector for Sqlite {
     async fn get_connection<'a>(&'a self) -> connector::Result<Box<dyn Connection + 'static>> {
         super::catch(&self.connection_info(), async move {
             let conn = self.pool.check_out().await.map_err(SqlError::from)?;
-            let conn = SqlConnection::new(conn, self.connection_info());
+            let checked_out = self.pool_metrics.check_out(&self.pool).await;
+            let conn = SqlConnection::new(conn, self.connection_info(), checked_out);
 
             Ok(Box::new(conn) as Box<dyn Connection>)
         })
@@ -1,3 +1,4 @@
+use super::metrics::{self, TRANSACTIONS_COMMITTED, TRANSACTIONS_ROLLED_BACK};
 use crate::database::operations::*;
 use crate::SqlError;
 use async_trait::async_trait;
@@ -8,10 +9,15 @@ use connector_interface::{
 use prisma_models::prelude::*;
 use prisma_value::PrismaValue;
 use quaint::prelude::ConnectionInfo;
+use std::sync::atomic::{AtomicBool, Ordering};
 
+/// A transaction borrowing its connection. Dropping it without committing or rolling it back
+/// counts it as rolled back, as the database discards its changes.
 pub struct SqlConnectorTransaction<'tx> {
     inner: quaint::connector::Transaction<'tx>,
     connection_info: ConnectionInfo,
+    /// Whether the transaction is committed or rolled back.
+    finished: AtomicBool,
 }
 
 impl<'tx> SqlConnectorTransaction<'tx> {
@@ -20,6 +26,7 @@ impl<'tx> SqlConnectorTransaction<'tx> {
         Self {
             inner: tx,
             connection_info,
+            finished: AtomicBool::new(false),
         }
     }
 
@@ -27,23 +34,43 @@ impl<'tx> SqlConnectorTransaction<'tx> {
         &self,
         fut: impl std::future::Future<Output = Result<O, SqlError>>,
     ) -> Result<O, connector_interface::error::ConnectorError> {
-        match fut.await {
+        match metrics::timed(&self.connection_info, fut).await {
             Ok(o) => Ok(o),
             Err(err) => Err(err.into_connector_error(&self.connection_info)),
         }
     }
+
+    fn labels(&self) -> [(&'static str, &'static str); 1] {
+        [("connector", metrics::connector_label(&self.connection_info))]
+    }
 }
 
 #[async_trait]
 impl<'tx> Transaction for SqlConnectorTransaction<'tx> {
     async fn commit(&self) -> connector::Result<()> {
         self.catch(async move { Ok(self.inner.commit().await.map_err(SqlError::from)?) })
-            .await
+            .await?;
+
+        self.finished.store(true, Ordering::SeqCst);
+        TRANSACTIONS_COMMITTED.increment(&self.labels());
+        Ok(())
     }
 
     async fn rollback(&self) -> connector::Result<()> {
         self.catch(async move { Ok(self.inner.rollback().await.map_err(SqlError::from)?) })
-            .await
+            .await?;
+
+        self.finished.store(true, Ordering::SeqCst);
+        TRANSACTIONS_ROLLED_BACK.increment(&self.labels());
+        Ok(())
+    }
+}
+
+impl<'tx> Drop for SqlConnectorTransaction<'tx> {
+    fn drop(&mut self) {
+        if !self.finished.load(Ordering::SeqCst) {
+            TRANSACTIONS_ROLLED_BACK.increment(&self.labels());
+        }
     }
 }
 
@@ -55,8 +82,10 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
         filter: &Filter,
         selected_fields: &ModelProjection,
     ) -> connector::Result<Option<SingleRecord>> {
-        self.catch(async move { read::get_single_record(&self.inner, model, filter, selected_fields).await })
-            .await
+        self.catch(async move {
+            read::get_single_record(&self.inner, &self.connection_info, model, filter, selected_fields).await
+        })
+        .await
     }
 
     async fn get_many_records(
@@ -65,8 +94,17 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
         query_arguments: QueryArguments,
         selected_fields: &ModelProjection,
     ) -> connector::Result<ManyRecords> {
-        self.catch(async move { read::get_many_records(&self.inner, model, query_arguments, selected_fields).await })
+        self.catch(async move {
+            read::get_many_records(
+                &self.inner,
+                &self.connection_info,
+                model,
+                query_arguments,
+                selected_fields,
+            )
             .await
+        })
+        .await
     }
 
     async fn get_related_m2m_record_ids(
@@ -84,8 +122,33 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
         aggregators: Vec<Aggregator>,
         query_arguments: QueryArguments,
     ) -> connector::Result<Vec<AggregationResult>> {
-        self.catch(async move { read::aggregate(&self.inner, model, aggregators, query_arguments).await })
+        self.catch(async move {
+            read::aggregate(&self.inner, &self.connection_info, model, aggregators, query_arguments).await
+        })
+        .await
+    }
+
+    async fn group_by_records(
+        &self,
+        model: &ModelRef,
+        group_by: Vec<ScalarFieldRef>,
+        aggregators: Vec<Aggregator>,
+        query_arguments: QueryArguments,
+        having: Option<Filter>,
+    ) -> connector::Result<Vec<Vec<AggregationResult>>> {
+        self.catch(async move {
+            read::group_by_aggregate(
+                &self.inner,
+                &self.connection_info,
+                model,
+                group_by,
+                aggregators,
+                query_arguments,
+                having,
+            )
             .await
+        })
+        .await
     }
 }
 
@@ -96,18 +159,32 @@ impl<'tx> WriteOperations for SqlConnectorTransaction<'tx> {
             .await
     }
 
+    async fn create_records(
+        &self,
+        model: &ModelRef,
+        args: Vec<WriteArgs>,
+        skip_duplicates: bool,
+    ) -> connector::Result<usize> {
+        self.catch(async move {
+            write::create_records(&self.inner, &self.connection_info, model, args, skip_duplicates).await
+        })
+        .await
+    }
+
     async fn update_records(
         &self,
         model: &ModelRef,
         record_filter: RecordFilter,
         args: WriteArgs,
     ) -> connector::Result<Vec<RecordProjection>> {
-        self.catch(async move { write::update_records(&self.inner, model, record_filter, args).await })
-            .await
+        self.catch(async move {
+            write::update_records(&self.inner, &self.connection_info, model, record_filter, args).await
+        })
+        .await
     }
 
     async fn delete_records(&self, model: &ModelRef, record_filter: RecordFilter) -> connector::Result<usize> {
-        self.catch(async move { write::delete_records(&self.inner, model, record_filter).await })
+        self.catch(async move { write::delete_records(&self.inner, &self.connection_info, model, record_filter).await })
             .await
     }
 
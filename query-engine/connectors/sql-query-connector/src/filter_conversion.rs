@@ -1,6 +1,7 @@
+use crate::full_text_search;
 use connector_interface::filter::*;
 use prisma_models::prelude::*;
-use quaint::ast::*;
+use quaint::{ast::*, prelude::SqlFamily};
 
 #[derive(Clone, Copy, Debug)]
 /// A distinction in aliasing to separate the parent table and the joined data
@@ -59,8 +60,9 @@ pub trait AliasedCondition {
     /// alias if provided, otherwise using the fully qualified path.
     ///
     /// Alias should be used only when nesting, making the top level queries
-    /// more explicit.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static>;
+    /// more explicit. Conditions without a common SQL syntax, like Json
+    /// filters, are rendered for the given `sql_family`.
+    fn aliased_cond(self, alias: Option<Alias>, sql_family: SqlFamily) -> ConditionTree<'static>;
 }
 
 trait AliasedSelect {
@@ -69,20 +71,20 @@ trait AliasedSelect {
     ///
     /// Alias should be used only when nesting, making the top level queries
     /// more explicit.
-    fn aliased_sel(self, alias: Option<Alias>) -> Select<'static>;
+    fn aliased_sel(self, alias: Option<Alias>, sql_family: SqlFamily) -> Select<'static>;
 }
 
 impl AliasedCondition for Filter {
     /// Conversion from a `Filter` to a query condition tree. Aliased when in a nested `SELECT`.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+    fn aliased_cond(self, alias: Option<Alias>, sql_family: SqlFamily) -> ConditionTree<'static> {
         match self {
             Filter::And(mut filters) => match filters.len() {
                 n if n == 0 => ConditionTree::NoCondition,
-                n if n == 1 => filters.pop().unwrap().aliased_cond(alias),
+                n if n == 1 => filters.pop().unwrap().aliased_cond(alias, sql_family),
                 _ => {
                     let exprs = filters
                         .into_iter()
-                        .map(|f| f.aliased_cond(alias))
+                        .map(|f| f.aliased_cond(alias, sql_family))
                         .map(Expression::from)
                         .collect();
 
@@ -91,11 +93,11 @@ impl AliasedCondition for Filter {
             },
             Filter::Or(mut filters) => match filters.len() {
                 n if n == 0 => ConditionTree::NegativeCondition,
-                n if n == 1 => filters.pop().unwrap().aliased_cond(alias),
+                n if n == 1 => filters.pop().unwrap().aliased_cond(alias, sql_family),
                 _ => {
                     let exprs = filters
                         .into_iter()
-                        .map(|f| f.aliased_cond(alias))
+                        .map(|f| f.aliased_cond(alias, sql_family))
                         .map(Expression::from)
                         .collect();
 
@@ -104,20 +106,21 @@ impl AliasedCondition for Filter {
             },
             Filter::Not(mut filters) => match filters.len() {
                 n if n == 0 => ConditionTree::NoCondition,
-                n if n == 1 => filters.pop().unwrap().aliased_cond(alias).not(),
+                n if n == 1 => filters.pop().unwrap().aliased_cond(alias, sql_family).not(),
                 _ => {
                     let exprs = filters
                         .into_iter()
-                        .map(|f| f.aliased_cond(alias).not())
+                        .map(|f| f.aliased_cond(alias, sql_family).not())
                         .map(Expression::from)
                         .collect();
 
                     ConditionTree::And(exprs)
                 }
             },
-            Filter::Scalar(filter) => filter.aliased_cond(alias),
-            Filter::OneRelationIsNull(filter) => filter.aliased_cond(alias),
-            Filter::Relation(filter) => filter.aliased_cond(alias),
+            Filter::Scalar(filter) => filter.aliased_cond(alias, sql_family),
+            Filter::OneRelationIsNull(filter) => filter.aliased_cond(alias, sql_family),
+            Filter::Relation(filter) => filter.aliased_cond(alias, sql_family),
+            Filter::Aggregation(filter) => filter.aliased_cond(alias, sql_family),
             Filter::BoolFilter(b) => {
                 if b {
                     ConditionTree::NoCondition
@@ -133,7 +136,38 @@ impl AliasedCondition for Filter {
 
 impl AliasedCondition for ScalarFilter {
     /// Conversion from a `ScalarFilter` to a query condition tree. Aliased when in a nested `SELECT`.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+    fn aliased_cond(self, alias: Option<Alias>, sql_family: SqlFamily) -> ConditionTree<'static> {
+        if let ScalarCondition::JsonCompare(json_condition) = self.condition {
+            let field = match self.projection {
+                ScalarProjection::Single(field) => field,
+                ScalarProjection::Compound(_) => unreachable!("Json filters can only be applied to a single field."),
+            };
+
+            let column = match alias {
+                Some(alias) => field.as_column().table(alias.to_string(None)),
+                None => field.as_column(),
+            };
+
+            return convert_json_filter(column, json_condition, &[field], sql_family);
+        }
+
+        if let ScalarCondition::Search(_) | ScalarCondition::NotSearch(_) = self.condition {
+            let fields = match self.projection {
+                ScalarProjection::Single(field) => vec![field],
+                ScalarProjection::Compound(fields) => fields,
+            };
+
+            let columns: Vec<Column<'static>> = fields
+                .iter()
+                .map(|field| match alias {
+                    Some(alias) => field.as_column().table(alias.to_string(None)),
+                    None => field.as_column(),
+                })
+                .collect();
+
+            return convert_search_filter(columns, self.condition, sql_family);
+        }
+
         match (alias, self.projection) {
             (Some(alias), ScalarProjection::Single(field)) => {
                 let comparable: Expression = match self.mode {
@@ -169,9 +203,68 @@ impl AliasedCondition for ScalarFilter {
     }
 }
 
+impl AliasedCondition for AggregationFilter {
+    /// Conversion from an `AggregationFilter` to a query condition tree. Meant to be used in the `HAVING` clause
+    /// of a grouped query, the scalar leaves of the filter are compared against the aggregated column.
+    fn aliased_cond(self, alias: Option<Alias>, _sql_family: SqlFamily) -> ConditionTree<'static> {
+        match self {
+            AggregationFilter::Count(filter) => aggregate_conditions(*filter, alias, &|column| count(column).into()),
+            AggregationFilter::Average(filter) => aggregate_conditions(*filter, alias, &|column| avg(column).into()),
+            AggregationFilter::Sum(filter) => aggregate_conditions(*filter, alias, &|column| sum(column).into()),
+            AggregationFilter::Min(filter) => aggregate_conditions(*filter, alias, &|column| min(column).into()),
+            AggregationFilter::Max(filter) => aggregate_conditions(*filter, alias, &|column| max(column).into()),
+        }
+    }
+}
+
+fn aggregate_conditions(
+    filter: Filter,
+    alias: Option<Alias>,
+    aggregate: &dyn Fn(Column<'static>) -> Expression<'static>,
+) -> ConditionTree<'static> {
+    match filter {
+        Filter::And(filters) => ConditionTree::And(
+            filters
+                .into_iter()
+                .map(|f| aggregate_conditions(f, alias, aggregate).into())
+                .collect(),
+        ),
+        Filter::Or(filters) => ConditionTree::Or(
+            filters
+                .into_iter()
+                .map(|f| aggregate_conditions(f, alias, aggregate).into())
+                .collect(),
+        ),
+        Filter::Not(filters) => ConditionTree::And(
+            filters
+                .into_iter()
+                .map(|f| aggregate_conditions(f, alias, aggregate).not().into())
+                .collect(),
+        ),
+        Filter::Scalar(filter) => match filter.projection {
+            ScalarProjection::Single(field) => {
+                let column = match alias {
+                    Some(alias) => field.as_column().table(alias.to_string(None)),
+                    None => field.as_column(),
+                };
+
+                convert_scalar_filter(aggregate(column), filter.condition, filter.mode, &[field])
+            }
+            ScalarProjection::Compound(_) => {
+                unreachable!("Aggregation filters can only be applied to a single field.")
+            }
+        },
+        Filter::Empty => ConditionTree::NoCondition,
+        filter => unreachable!(
+            "Only scalar conditions can be applied to aggregates, the query graph builder rejects {:?}.",
+            filter
+        ),
+    }
+}
+
 impl AliasedCondition for RelationFilter {
     /// Conversion from a `RelationFilter` to a query condition tree. Aliased when in a nested `SELECT`.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+    fn aliased_cond(self, alias: Option<Alias>, sql_family: SqlFamily) -> ConditionTree<'static> {
         let ids = self.field.model().primary_identifier().as_columns();
         let columns: Vec<Column<'static>> = match alias {
             Some(alias) => ids.map(|c| c.table(alias.to_string(None))).collect(),
@@ -179,7 +272,7 @@ impl AliasedCondition for RelationFilter {
         };
 
         let condition = self.condition;
-        let sub_select = self.aliased_sel(alias.map(|a| a.inc(AliasMode::Table)));
+        let sub_select = self.aliased_sel(alias.map(|a| a.inc(AliasMode::Table)), sql_family);
 
         let comparison = match condition {
             RelationCondition::AtLeastOneRelatedRecord => Row::from(columns).in_selection(sub_select),
@@ -194,7 +287,7 @@ impl AliasedCondition for RelationFilter {
 
 impl AliasedSelect for RelationFilter {
     /// The subselect part of the `RelationFilter` `ConditionTree`.
-    fn aliased_sel<'a>(self, alias: Option<Alias>) -> Select<'static> {
+    fn aliased_sel<'a>(self, alias: Option<Alias>, sql_family: SqlFamily) -> Select<'static> {
         let alias = alias.unwrap_or_default();
         let condition = self.condition;
 
@@ -222,7 +315,7 @@ impl AliasedSelect for RelationFilter {
 
         let nested_conditions = self
             .nested_filter
-            .aliased_cond(Some(alias.flip(AliasMode::Join)))
+            .aliased_cond(Some(alias.flip(AliasMode::Join)), sql_family)
             .invert_if(condition.invert_of_subselect());
 
         let conditions = selected_identifier
@@ -243,7 +336,7 @@ impl AliasedSelect for RelationFilter {
 
 impl AliasedCondition for OneRelationIsNullFilter {
     /// Conversion from a `OneRelationIsNullFilter` to a query condition tree. Aliased when in a nested `SELECT`.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+    fn aliased_cond(self, alias: Option<Alias>, _sql_family: SqlFamily) -> ConditionTree<'static> {
         let alias = alias.map(|a| a.to_string(None));
 
         let condition = if self.field.relation_is_inlined_in_parent() {
@@ -353,6 +446,10 @@ fn default_scalar_filter(
             }
             _ => comparable.not_in_selection(convert_values(fields, values)),
         },
+        ScalarCondition::JsonCompare(_) => unreachable!("Json filters are converted with `convert_json_filter`."),
+        ScalarCondition::Search(_) | ScalarCondition::NotSearch(_) => {
+            unreachable!("Search filters are converted with `convert_search_filter`.")
+        }
     };
 
     ConditionTree::single(condition)
@@ -424,11 +521,179 @@ fn insensitive_scalar_filter(
                     .collect::<Vec<_>>(),
             ),
         },
+        ScalarCondition::JsonCompare(_) => unreachable!("Json filters are converted with `convert_json_filter`."),
+        ScalarCondition::Search(_) | ScalarCondition::NotSearch(_) => {
+            unreachable!("Search filters are converted with `convert_search_filter`.")
+        }
     };
 
     ConditionTree::single(condition)
 }
 
+/// Full-text search filters are rendered with the native search of the database, see `full_text_search`.
+fn convert_search_filter(
+    columns: Vec<Column<'static>>,
+    condition: ScalarCondition,
+    sql_family: SqlFamily,
+) -> ConditionTree<'static> {
+    let (query, negated) = match condition {
+        ScalarCondition::Search(query) => (query, false),
+        ScalarCondition::NotSearch(query) => (query, true),
+        _ => unreachable!("Expected a search condition."),
+    };
+
+    let condition = ConditionTree::single(full_text_search::search_condition(
+        columns,
+        format!("{}", query),
+        sql_family,
+    ));
+
+    if negated {
+        condition.not()
+    } else {
+        condition
+    }
+}
+
+/// Json filters are rendered with the JSON operators of Postgres and the JSON functions of MySQL, the databases
+/// that allow filtering on Json fields.
+fn convert_json_filter(
+    column: Column<'static>,
+    json_condition: JsonCondition,
+    fields: &[ScalarFieldRef],
+    sql_family: SqlFamily,
+) -> ConditionTree<'static> {
+    let JsonCondition { path, condition } = json_condition;
+
+    if sql_family == SqlFamily::Mysql {
+        return ConditionTree::single(convert_mysql_json_filter(column, path, condition, fields));
+    }
+
+    let condition = match condition {
+        JsonFilterCondition::Equals(value) => json_at_path(column, path).equals(convert_value(fields, value)),
+        JsonFilterCondition::NotEquals(value) => json_at_path(column, path).not_equals(convert_value(fields, value)),
+        JsonFilterCondition::StringContains(value) => text_at_path(column, path).like(value),
+        JsonFilterCondition::StringStartsWith(value) => text_at_path(column, path).begins_with(value),
+        JsonFilterCondition::StringEndsWith(value) => text_at_path(column, path).ends_into(value),
+        JsonFilterCondition::ArrayContains(value) => {
+            json_at_path(column, path).compare_raw("@>", convert_value(fields, value))
+        }
+        JsonFilterCondition::ArrayStartsWith(value) => {
+            json_array_element(column, path, 0).equals(convert_value(fields, value))
+        }
+        JsonFilterCondition::ArrayEndsWith(value) => {
+            json_array_element(column, path, -1).equals(convert_value(fields, value))
+        }
+    };
+
+    ConditionTree::single(condition)
+}
+
+/// The Json value at the given path of the document (`"column" #> '{a,b}'`).
+fn json_at_path(column: Column<'static>, path: Vec<String>) -> Expression<'static> {
+    if path.is_empty() {
+        column.into()
+    } else {
+        column.compare_raw("#>", Value::array(path)).into()
+    }
+}
+
+/// The element at `index` of the Json array at the given path, negative indices count from the end.
+fn json_array_element(column: Column<'static>, path: Vec<String>, index: i64) -> Expression<'static> {
+    json_at_path(column, path)
+        .compare_raw("->", Value::integer(index))
+        .into()
+}
+
+/// The value at the given path of the document as text (`"column" #>> '{a,b}'`).
+/// Strings are unquoted, which allows matching their contents with `LIKE`.
+fn text_at_path(column: Column<'static>, path: Vec<String>) -> Expression<'static> {
+    column.compare_raw("#>>", Value::array(path)).into()
+}
+
+/// Quaint has no AST for the JSON functions of MySQL, so the conditions are written into the operator of a raw
+/// comparison. The `->` and `->>` operators only accept a string literal as the path, which is why the path is
+/// rendered as an escaped literal instead of a parameter. Values are parsed with `JSON_EXTRACT(?, '$')` to
+/// compare them as Json, a plain string parameter would be compared as a Json string.
+fn convert_mysql_json_filter(
+    column: Column<'static>,
+    path: Vec<String>,
+    condition: JsonFilterCondition,
+    fields: &[ScalarFieldRef],
+) -> Compare<'static> {
+    let json_path = mysql_json_path(&path);
+    let json_value = |value| {
+        Row::from(vec![
+            Expression::from(convert_value(fields, value)),
+            Value::text("$").into(),
+        ])
+    };
+
+    match condition {
+        JsonFilterCondition::Equals(value) => column.compare_raw(
+            format!("->{} = JSON_EXTRACT", mysql_string_literal(&json_path)),
+            json_value(value),
+        ),
+        JsonFilterCondition::NotEquals(value) => column.compare_raw(
+            format!("->{} <> JSON_EXTRACT", mysql_string_literal(&json_path)),
+            json_value(value),
+        ),
+        JsonFilterCondition::StringContains(value) => column.compare_raw(
+            format!("->>{} LIKE", mysql_string_literal(&json_path)),
+            format!("%{}%", value),
+        ),
+        JsonFilterCondition::StringStartsWith(value) => column.compare_raw(
+            format!("->>{} LIKE", mysql_string_literal(&json_path)),
+            format!("{}%", value),
+        ),
+        JsonFilterCondition::StringEndsWith(value) => column.compare_raw(
+            format!("->>{} LIKE", mysql_string_literal(&json_path)),
+            format!("%{}", value),
+        ),
+        // `JSON_CONTAINS(column, candidate, path)`, compared with true to make it a condition.
+        JsonFilterCondition::ArrayContains(value) => Expression::from(Value::boolean(true)).compare_raw(
+            "= JSON_CONTAINS",
+            Row::from(vec![
+                Expression::from(column),
+                convert_value(fields, value).into(),
+                Value::text(json_path).into(),
+            ]),
+        ),
+        JsonFilterCondition::ArrayStartsWith(value) => column.compare_raw(
+            format!(
+                "->{} = JSON_EXTRACT",
+                mysql_string_literal(&format!("{}[0]", json_path))
+            ),
+            json_value(value),
+        ),
+        JsonFilterCondition::ArrayEndsWith(value) => column.compare_raw(
+            format!(
+                "->{} = JSON_EXTRACT",
+                mysql_string_literal(&format!("{}[last]", json_path))
+            ),
+            json_value(value),
+        ),
+    }
+}
+
+/// A MySQL Json path (`$."a"[0]`), numeric path elements are array indices.
+fn mysql_json_path(path: &[String]) -> String {
+    path.iter().fold(String::from("$"), |mut json_path, element| {
+        if element.parse::<usize>().is_ok() {
+            json_path.push_str(&format!("[{}]", element));
+        } else {
+            json_path.push_str(&format!(".\"{}\"", element.replace('\\', "\\\\").replace('"', "\\\"")));
+        }
+
+        json_path
+    })
+}
+
+/// A MySQL string literal, escaped for the default SQL mode where backslashes are escape characters.
+fn mysql_string_literal(s: &str) -> String {
+    format!("'{}'", s.replace('\\', "\\\\").replace('\'', "''"))
+}
+
 fn convert_value<'a>(fields: &[ScalarFieldRef], value: PrismaValue) -> Value<'a> {
     fields.first().unwrap().value(value)
 }
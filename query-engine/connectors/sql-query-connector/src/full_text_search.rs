@@ -0,0 +1,127 @@
+//! Full-text search with the native search of the database. Quaint has no expressions for it, so
+//! the SQL is assembled from raw comparisons that each render a complete fragment: Postgres
+//! functions are called through their quoted names, see `postgres_function`, and MySQL's
+//! `MATCH (...) AGAINST (...)` is written around rows of the columns and the query.
+//!
+//! Only Postgres and MySQL have the `FullTextSearch` capability. Like the Json filters, the other
+//! databases get the Postgres syntax, which they reject.
+
+use quaint::{ast::*, prelude::SqlFamily};
+
+/// The condition of a `search` filter on the given columns.
+///
+/// Postgres: `"to_tsvector"  ("concat_ws"  ($1, "a", "b")) @@ "to_tsquery"  ($2)`, with `' '`.
+///
+/// MySQL: ``? < MATCH (`a`, `b`) AGAINST (?)``, with `0`, in natural language mode. The columns need
+/// a fulltext index on exactly them.
+pub(crate) fn search_condition(
+    columns: Vec<Column<'static>>,
+    query: String,
+    sql_family: SqlFamily,
+) -> Compare<'static> {
+    if sql_family == SqlFamily::Mysql {
+        return Expression::from(Value::integer(0)).compare_raw("< MATCH", mysql_match_against(columns, query));
+    }
+
+    postgres_document(columns).compare_raw("@@", postgres_query(query))
+}
+
+/// The relevance of the records for a search on the given columns, to order by.
+///
+/// Postgres: `"ts_rank"  ("to_tsvector"  ("concat_ws"  ($1, "a", "b")), "to_tsquery"  ($2))`, with `' '`.
+///
+/// MySQL: ``? + MATCH (`a`, `b`) AGAINST (?)``, with `0`.
+pub(crate) fn relevance(columns: Vec<Column<'static>>, query: String, sql_family: SqlFamily) -> Expression<'static> {
+    if sql_family == SqlFamily::Mysql {
+        return Expression::from(Value::integer(0))
+            .compare_raw("+ MATCH", mysql_match_against(columns, query))
+            .into();
+    }
+
+    postgres_function("ts_rank", vec![postgres_document(columns), postgres_query(query)])
+}
+
+/// The searched document: the columns joined by spaces. `concat_ws` skips null columns.
+fn postgres_document(columns: Vec<Column<'static>>) -> Expression<'static> {
+    let mut arguments: Vec<Expression<'static>> = vec![Value::text(" ").into()];
+    arguments.extend(columns.into_iter().map(Expression::from));
+
+    postgres_function("to_tsvector", vec![postgres_function("concat_ws", arguments)])
+}
+
+fn postgres_query(query: String) -> Expression<'static> {
+    postgres_function("to_tsquery", vec![Value::text(query).into()])
+}
+
+/// A call of a built-in Postgres function. Quoted identifiers are valid function names, so the
+/// name is rendered as a column, followed by the row of arguments through an empty raw operator.
+fn postgres_function(name: &'static str, arguments: Vec<Expression<'static>>) -> Expression<'static> {
+    Column::from(name).compare_raw("", Row::from(arguments)).into()
+}
+
+/// Renders ``(`a`, `b`) AGAINST (?)``.
+fn mysql_match_against(columns: Vec<Column<'static>>, query: String) -> Compare<'static> {
+    let columns: Vec<Expression<'static>> = columns.into_iter().map(Expression::from).collect();
+
+    Row::from(columns).compare_raw("AGAINST", Row::from(vec![Expression::from(Value::text(query))]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quaint::visitor::{self, Visitor};
+
+    fn columns() -> Vec<Column<'static>> {
+        vec![Column::from("title"), Column::from("body")]
+    }
+
+    #[test]
+    fn postgres_search_conditions_match_a_document_of_all_columns() {
+        let select = Select::from_table("Post").so_that(search_condition(columns(), "cat".into(), SqlFamily::Postgres));
+        let (sql, params) = visitor::Postgres::build(select).unwrap();
+
+        assert_eq!(
+            r#"SELECT "Post".* FROM "Post" WHERE "to_tsvector"  ("concat_ws"  ($1, "title", "body")) @@ "to_tsquery"  ($2)"#,
+            sql
+        );
+        assert_eq!(vec![Value::text(" "), Value::text("cat")], params);
+    }
+
+    #[test]
+    fn postgres_relevance_ranks_the_document_of_all_columns() {
+        let select =
+            Select::from_table("Post").order_by(relevance(columns(), "cat".into(), SqlFamily::Postgres).descend());
+        let (sql, params) = visitor::Postgres::build(select).unwrap();
+
+        assert_eq!(
+            r#"SELECT "Post".* FROM "Post" ORDER BY "ts_rank"  ("to_tsvector"  ("concat_ws"  ($1, "title", "body")), "to_tsquery"  ($2)) DESC"#,
+            sql
+        );
+        assert_eq!(vec![Value::text(" "), Value::text("cat")], params);
+    }
+
+    #[test]
+    fn mysql_search_conditions_match_against_all_columns() {
+        let select = Select::from_table("Post").so_that(search_condition(columns(), "cat".into(), SqlFamily::Mysql));
+        let (sql, params) = visitor::Mysql::build(select).unwrap();
+
+        assert_eq!(
+            "SELECT `Post`.* FROM `Post` WHERE ? < MATCH (`title`, `body`) AGAINST (?)",
+            sql
+        );
+        assert_eq!(vec![Value::integer(0), Value::text("cat")], params);
+    }
+
+    #[test]
+    fn mysql_relevance_is_the_match_score() {
+        let select =
+            Select::from_table("Post").order_by(relevance(columns(), "cat".into(), SqlFamily::Mysql).descend());
+        let (sql, params) = visitor::Mysql::build(select).unwrap();
+
+        assert_eq!(
+            "SELECT `Post`.* FROM `Post` ORDER BY ? + MATCH (`title`, `body`) AGAINST (?) DESC",
+            sql
+        );
+        assert_eq!(vec![Value::integer(0), Value::text("cat")], params);
+    }
+}
@@ -3,6 +3,7 @@ mod cursor_condition;
 mod database;
 mod error;
 mod filter_conversion;
+mod full_text_search;
 mod ordering;
 mod query_arguments_ext;
 mod query_builder;
@@ -1,20 +1,139 @@
-use crate::query_arguments_ext::QueryArgumentsExt;
+use crate::{full_text_search, query_arguments_ext::QueryArgumentsExt};
 use connector_interface::QueryArguments;
 use prisma_models::*;
-use quaint::ast::*;
+use quaint::{ast::*, prelude::SqlFamily};
 
 /// Builds all expressions for an `ORDER BY` clause based on the query arguments.
-pub fn build(query_arguments: &QueryArguments) -> Vec<OrderDefinition<'static>> {
+pub fn build(query_arguments: &QueryArguments, sql_family: SqlFamily) -> Vec<OrderDefinition<'static>> {
     let needs_reversed_order = query_arguments.needs_reversed_order();
 
     query_arguments.order_by.iter().fold(vec![], |mut acc, next_order_by| {
+        let order_expr = order_expression(next_order_by, sql_family);
+
         match (next_order_by.sort_order, needs_reversed_order) {
-            (SortOrder::Ascending, true) => acc.push(next_order_by.field.as_column().descend()),
-            (SortOrder::Descending, true) => acc.push(next_order_by.field.as_column().ascend()),
-            (SortOrder::Ascending, false) => acc.push(next_order_by.field.as_column().ascend()),
-            (SortOrder::Descending, false) => acc.push(next_order_by.field.as_column().descend()),
+            (SortOrder::Ascending, true) => acc.push(order_expr.descend()),
+            (SortOrder::Descending, true) => acc.push(order_expr.ascend()),
+            (SortOrder::Ascending, false) => acc.push(order_expr.ascend()),
+            (SortOrder::Descending, false) => acc.push(order_expr.descend()),
         }
 
         acc
     })
 }
+
+/// Alias prefix for the tables of related records in ordering subqueries.
+static ORDER_JOIN_PREFIX: &str = "orderby";
+
+/// The expression to order by. Either the column of the field, an aggregate
+/// of it if the records are grouped, a subquery on related records, or the
+/// relevance of the records for a full-text search.
+pub fn order_expression(order_by: &OrderBy, sql_family: SqlFamily) -> Expression<'static> {
+    if !order_by.path.is_empty() {
+        return relation_order_expression(order_by);
+    }
+
+    if let Some(relevance) = &order_by.relevance {
+        let columns: Vec<Column<'static>> = relevance.fields.iter().map(|field| field.as_column()).collect();
+
+        return full_text_search::relevance(columns, relevance.search.clone(), sql_family);
+    }
+
+    let column = order_by.field.as_column();
+
+    match order_by.sort_aggregation {
+        None => column.into(),
+        Some(SortAggregation::Count) => count(column).into(),
+        Some(SortAggregation::Avg) => avg(column).into(),
+        Some(SortAggregation::Sum) => sum(column).into(),
+        Some(SortAggregation::Min) => min(column).into(),
+        Some(SortAggregation::Max) => max(column).into(),
+    }
+}
+
+/// Builds a correlated subquery selecting the value to order by from the related records.
+///
+/// Ordering posts by the name of their author (`orderBy: { author: { name: asc } }`):
+/// ```sql
+/// (
+///   SELECT `orderby_0`.`name`
+///   FROM `User` AS `orderby_0`
+///   WHERE (`orderby_0`.`id`) = (`Post`.`authorId`)
+/// )
+/// ```
+///
+/// Ordering users by the number of their posts (`orderBy: { posts: { count: desc } }`):
+/// ```sql
+/// (
+///   SELECT COUNT(*)
+///   FROM `Post` AS `orderby_count`
+///   WHERE (`orderby_count`.`authorId`) = (`User`.`id`)
+/// )
+/// ```
+///
+/// Every additional to-one relation on the path adds an inner join to the subquery.
+fn relation_order_expression(order_by: &OrderBy) -> Expression<'static> {
+    let (to_one_path, count_field) = match order_by.sort_aggregation {
+        Some(SortAggregation::Count) => {
+            let (last, rest) = order_by.path.split_last().unwrap();
+            (rest, Some(last))
+        }
+        _ => (order_by.path.as_slice(), None),
+    };
+
+    let mut previous_alias: Option<String> = None;
+    let mut select: Option<Select<'static>> = None;
+
+    for (i, rf) in to_one_path.iter().enumerate() {
+        let alias = format!("{}_{}", ORDER_JOIN_PREFIX, i);
+        let related_table = rf.related_model().as_table().alias(alias.clone());
+        let related_columns = aliased_columns(rf.related_field().linking_fields().as_columns(), &Some(alias.clone()));
+        let parent_columns = aliased_columns(rf.linking_fields().as_columns(), &previous_alias);
+        let join_condition = Row::from(related_columns).equals(Row::from(parent_columns));
+
+        select = Some(match select {
+            None => Select::from_table(related_table).so_that(join_condition),
+            Some(select) => select.inner_join(related_table.on(join_condition)),
+        });
+
+        previous_alias = Some(alias);
+    }
+
+    match (select, count_field) {
+        (None, Some(rf)) => count_select(rf, &None).into(),
+        (Some(select), Some(rf)) => select.value(count_select(rf, &previous_alias)).into(),
+        (Some(select), None) => {
+            let column = order_by.field.as_column().table(previous_alias.unwrap());
+            select.column(column).into()
+        }
+        (None, None) => unreachable!("Relation orderings require a relation path."),
+    }
+}
+
+/// Counts the records related to the parent record via the given to-many relation field.
+/// If no parent alias is given, the subquery refers to the table of the queried model.
+fn count_select(rf: &RelationFieldRef, parent_alias: &Option<String>) -> Select<'static> {
+    let alias = format!("{}_count", ORDER_JOIN_PREFIX);
+    let parent_columns = aliased_columns(rf.linking_fields().as_columns(), parent_alias);
+
+    let (table, related_columns) = if rf.relation().is_many_to_many() {
+        (rf.relation().as_table(), rf.identifier_columns())
+    } else {
+        (
+            rf.related_model().as_table(),
+            rf.related_field().linking_fields().as_columns(),
+        )
+    };
+
+    let related_columns = aliased_columns(related_columns, &Some(alias.clone()));
+
+    Select::from_table(table.alias(alias))
+        .value(count(asterisk()))
+        .so_that(Row::from(related_columns).equals(Row::from(parent_columns)))
+}
+
+fn aliased_columns(columns: ColumnIterator, alias: &Option<String>) -> Vec<Column<'static>> {
+    match alias {
+        Some(alias) => columns.map(|c| c.table(alias.clone())).collect(),
+        None => columns.collect(),
+    }
+}
@@ -2,42 +2,42 @@ use crate::{cursor_condition, filter_conversion::AliasedCondition, ordering};
 use connector_interface::{filter::Filter, Aggregator, QueryArguments};
 use itertools::Itertools;
 use prisma_models::*;
-use quaint::ast::*;
+use quaint::{ast::*, prelude::SqlFamily};
 
 pub trait SelectDefinition {
-    fn into_select(self, _: &ModelRef) -> Select<'static>;
+    fn into_select(self, _: &ModelRef, _: SqlFamily) -> Select<'static>;
 }
 
 impl SelectDefinition for Filter {
-    fn into_select(self, model: &ModelRef) -> Select<'static> {
+    fn into_select(self, model: &ModelRef, sql_family: SqlFamily) -> Select<'static> {
         let args = QueryArguments::from((model.clone(), self));
-        args.into_select(model)
+        args.into_select(model, sql_family)
     }
 }
 
 impl SelectDefinition for &Filter {
-    fn into_select(self, model: &ModelRef) -> Select<'static> {
-        self.clone().into_select(model)
+    fn into_select(self, model: &ModelRef, sql_family: SqlFamily) -> Select<'static> {
+        self.clone().into_select(model, sql_family)
     }
 }
 
 impl SelectDefinition for Select<'static> {
-    fn into_select(self, _: &ModelRef) -> Select<'static> {
+    fn into_select(self, _: &ModelRef, _: SqlFamily) -> Select<'static> {
         self
     }
 }
 
 impl SelectDefinition for QueryArguments {
-    fn into_select(self, model: &ModelRef) -> Select<'static> {
-        let (table_opt, cursor_condition) = cursor_condition::build(&self, &model);
-        let orderings = ordering::build(&self);
+    fn into_select(self, model: &ModelRef, sql_family: SqlFamily) -> Select<'static> {
+        let (table_opt, cursor_condition) = cursor_condition::build(&self, &model, sql_family);
+        let orderings = ordering::build(&self, sql_family);
 
         let limit = if self.ignore_take { None } else { self.take_abs() };
         let skip = if self.ignore_skip { 0 } else { self.skip.unwrap_or(0) };
 
         let filter: ConditionTree = self
             .filter
-            .map(|f| f.aliased_cond(None))
+            .map(|f| f.aliased_cond(None, sql_family))
             .unwrap_or(ConditionTree::NoCondition);
 
         let conditions = match (filter, cursor_condition) {
@@ -65,11 +65,16 @@ impl SelectDefinition for QueryArguments {
     }
 }
 
-pub fn get_records<T>(model: &ModelRef, columns: impl Iterator<Item = Column<'static>>, query: T) -> Select<'static>
+pub fn get_records<T>(
+    model: &ModelRef,
+    columns: impl Iterator<Item = Column<'static>>,
+    query: T,
+    sql_family: SqlFamily,
+) -> Select<'static>
 where
     T: SelectDefinition,
 {
-    columns.fold(query.into_select(model), |acc, col| acc.column(col))
+    columns.fold(query.into_select(model, sql_family), |acc, col| acc.column(col))
 }
 
 /// Generates a query of the form:
@@ -96,9 +101,14 @@ where
 ///             1 = 1
 ///     ) AS `sub`;
 /// ```
-pub fn aggregate(model: &ModelRef, aggregators: &[Aggregator], args: QueryArguments) -> Select<'static> {
+pub fn aggregate(
+    model: &ModelRef,
+    aggregators: &[Aggregator],
+    args: QueryArguments,
+    sql_family: SqlFamily,
+) -> Select<'static> {
     let columns = extract_columns(model, &aggregators);
-    let sub_query = get_records(model, columns.into_iter(), args);
+    let sub_query = get_records(model, columns.into_iter(), args, sql_family);
     let sub_table = Table::from(sub_query).alias("sub");
 
     aggregators
@@ -124,6 +134,67 @@ pub fn aggregate(model: &ModelRef, aggregators: &[Aggregator], args: QueryArgume
         })
 }
 
+/// Generates a query of the form:
+/// ```sql
+/// SELECT
+///     `Table`.`country`,
+///     COUNT(*),
+///     AVG(`Table`.`age`)
+/// FROM
+///     `Table`
+/// WHERE
+///     1 = 1
+/// GROUP BY
+///     `Table`.`country`
+/// HAVING
+///     AVG(`Table`.`age`) > 30
+/// ORDER BY
+///     `Table`.`country` ASC
+/// ```
+pub fn group_by_aggregate(
+    model: &ModelRef,
+    args: QueryArguments,
+    aggregators: &[Aggregator],
+    group_by: Vec<ScalarFieldRef>,
+    having: Option<Filter>,
+    sql_family: SqlFamily,
+) -> Select<'static> {
+    let select_query = group_by
+        .iter()
+        .fold(args.into_select(model, sql_family), |select, next_field| {
+            select.column(next_field.as_column())
+        });
+
+    let select_query = aggregators.iter().fold(select_query, |select, next_op| match next_op {
+        Aggregator::Count => select.value(count(asterisk())),
+
+        Aggregator::Average(fields) => fields
+            .iter()
+            .fold(select, |select, next_field| select.value(avg(next_field.as_column()))),
+
+        Aggregator::Sum(fields) => fields
+            .iter()
+            .fold(select, |select, next_field| select.value(sum(next_field.as_column()))),
+
+        Aggregator::Min(fields) => fields
+            .iter()
+            .fold(select, |select, next_field| select.value(min(next_field.as_column()))),
+
+        Aggregator::Max(fields) => fields
+            .iter()
+            .fold(select, |select, next_field| select.value(max(next_field.as_column()))),
+    });
+
+    let grouped = group_by
+        .into_iter()
+        .fold(select_query, |query, field| query.group_by(field.as_column()));
+
+    match having {
+        Some(filter) => grouped.having(filter.aliased_cond(None, sql_family)),
+        None => grouped,
+    }
+}
+
 fn extract_columns(model: &ModelRef, aggregators: &[Aggregator]) -> Vec<Column<'static>> {
     let fields: Vec<_> = aggregators
         .iter()
@@ -1,7 +1,7 @@
 use connector_interface::{DatasourceFieldName, WriteArgs, WriteExpression};
 use prisma_models::*;
 use quaint::ast::*;
-use std::convert::TryInto;
+use std::{cmp, collections::BTreeMap, convert::TryInto};
 
 /// `INSERT` a new record to the database. Resulting an `INSERT` ast and an
 /// optional `RecordProjection` if available from the arguments or model.
@@ -33,6 +33,98 @@ pub fn create_record(model: &ModelRef, mut args: WriteArgs) -> (Insert<'static>,
     )
 }
 
+/// `INSERT` many records to the database in as few statements as possible.
+///
+/// Records are grouped by the set of fields they carry values for, so every
+/// statement has a uniform column list and omitted fields fall back to their
+/// database defaults. Each group is then split into multi-row inserts holding
+/// at most `max_bind_values` parameters and, if given, `max_rows` rows.
+pub fn create_records(
+    model: &ModelRef,
+    args: Vec<WriteArgs>,
+    skip_duplicates: bool,
+    max_bind_values: usize,
+    max_rows: Option<usize>,
+) -> Vec<Insert<'static>> {
+    let scalar_fields = model.fields().scalar();
+    let mut groups: BTreeMap<Vec<String>, Vec<WriteArgs>> = BTreeMap::new();
+
+    for arg in args {
+        let mut columns: Vec<String> = arg.keys().map(|name| name.0.clone()).collect();
+        columns.sort();
+
+        groups.entry(columns).or_default().push(arg);
+    }
+
+    let mut inserts = Vec::new();
+
+    for (columns, mut rows) in groups {
+        // Nothing but defaults, which can only be expressed one row at a time.
+        if columns.is_empty() {
+            for _ in rows {
+                let insert = Insert::from(Insert::single_into(model.as_table()));
+                inserts.push(on_conflict(insert, skip_duplicates));
+            }
+
+            continue;
+        }
+
+        let fields: Vec<ScalarFieldRef> = columns
+            .iter()
+            .map(|column| {
+                scalar_fields
+                    .iter()
+                    .find(|f| f.db_name() == column)
+                    .cloned()
+                    .expect("Expected field to be valid")
+            })
+            .collect();
+
+        let mut chunk_size = cmp::max(1, max_bind_values / fields.len());
+
+        if let Some(max_rows) = max_rows {
+            chunk_size = cmp::min(chunk_size, max_rows);
+        }
+
+        while !rows.is_empty() {
+            let rest = rows.split_off(cmp::min(chunk_size, rows.len()));
+            let chunk = std::mem::replace(&mut rows, rest);
+
+            let columns: Vec<Column<'static>> = fields.iter().map(|f| Column::from(f.db_name().to_owned())).collect();
+            let insert = chunk
+                .into_iter()
+                .fold(Insert::multi_into(model.as_table(), columns), |insert, mut arg| {
+                    let values: Vec<Value<'static>> = fields
+                        .iter()
+                        .map(|field| {
+                            let value: PrismaValue = arg
+                                .take_field_value(field.db_name())
+                                .unwrap()
+                                .try_into()
+                                .expect("Create calls can only use PrismaValue write expressions (right now).");
+
+                            field.value(value)
+                        })
+                        .collect();
+
+                    insert.values(values)
+                });
+
+            inserts.push(on_conflict(insert.build(), skip_duplicates));
+        }
+    }
+
+    inserts
+}
+
+fn on_conflict(insert: Insert<'static>, skip_duplicates: bool) -> Insert<'static> {
+    if skip_duplicates {
+        insert.on_conflict(OnConflict::DoNothing)
+    } else {
+        insert
+    }
+}
+
 pub fn update_many(model: &ModelRef, ids: &[&RecordProjection], args: WriteArgs) -> crate::Result<Vec<Query<'static>>> {
     if args.args.is_empty() || ids.is_empty() {
         return Ok(Vec::new());
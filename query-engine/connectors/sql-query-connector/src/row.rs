@@ -2,12 +2,12 @@ use crate::error::SqlError;
 use chrono::{DateTime, NaiveDate, Utc};
 use connector_interface::{AggregationResult, Aggregator};
 use datamodel::FieldArity;
-use prisma_models::{PrismaValue, Record, TypeIdentifier};
+use prisma_models::{PrismaValue, Record, ScalarFieldRef, TypeIdentifier};
 use quaint::{
     ast::{Expression, Value},
     connector::ResultRow,
 };
-use rust_decimal::Decimal;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 use std::{borrow::Borrow, io, str::FromStr};
 use uuid::Uuid;
 
@@ -59,6 +59,29 @@ impl SqlRow {
             })
             .collect()
     }
+
+    /// Splits a row of a grouped query into the values of the grouped fields,
+    /// followed by the results of the aggregators.
+    pub fn into_group_by_results(
+        mut self,
+        group_by: &[ScalarFieldRef],
+        aggregators: &[Aggregator],
+    ) -> Vec<AggregationResult> {
+        let aggregation_values = self.values.split_off(group_by.len());
+
+        let mut results: Vec<_> = group_by
+            .iter()
+            .zip(self.values)
+            .map(|(field, value)| AggregationResult::Field(field.clone(), value))
+            .collect();
+
+        let aggregation_row = SqlRow {
+            values: aggregation_values,
+        };
+
+        results.extend(aggregation_row.into_aggregation_results(aggregators));
+        results
+    }
 }
 
 fn coerce_null_to_zero_value(value: PrismaValue) -> PrismaValue {
@@ -203,9 +226,7 @@ pub fn row_value_to_prisma_value(p_value: Value, type_identifier: &TypeIdentifie
                 PrismaValue::Float(Decimal::from_str(&(i as f64).to_string()).expect("f64 was not a Decimal."))
             }
             Value::Text(_) | Value::Bytes(_) => {
-                let dec: Decimal = p_value
-                    .as_str()
-                    .expect("text/bytes as str")
+                let dec: Decimal = text_or_bytes_as_str(&p_value)?
                     .parse()
                     .map_err(|err: rust_decimal::Error| SqlError::ColumnReadFailure(err.into()))?;
 
@@ -219,6 +240,26 @@ pub fn row_value_to_prisma_value(p_value: Value, type_identifier: &TypeIdentifie
                 return Err(SqlError::ConversionError(error.into()));
             }
         },
+        // Unlike floats, decimals are not normalized, to keep the scale of the column.
+        TypeIdentifier::Decimal => match p_value {
+            value if value.is_null() => PrismaValue::Null,
+            Value::Real(Some(dec)) => PrismaValue::Decimal(dec),
+            Value::Integer(Some(i)) => PrismaValue::Decimal(Decimal::from(i)),
+            Value::Text(_) | Value::Bytes(_) => {
+                let dec: Decimal = text_or_bytes_as_str(&p_value)?
+                    .parse()
+                    .map_err(|err: rust_decimal::Error| SqlError::ColumnReadFailure(err.into()))?;
+
+                PrismaValue::Decimal(dec)
+            }
+            _ => {
+                let error = io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "Decimal value not stored as decimal, int or text",
+                );
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
         TypeIdentifier::Int => match p_value {
             Value::Integer(Some(i)) => PrismaValue::Int(i),
             Value::Bytes(Some(bytes)) => PrismaValue::Int(interpret_bytes_as_i64(&bytes)),
@@ -227,6 +268,38 @@ pub fn row_value_to_prisma_value(p_value: Value, type_identifier: &TypeIdentifie
             ),
             other => PrismaValue::from(other),
         },
+        TypeIdentifier::BigInt => match p_value {
+            value if value.is_null() => PrismaValue::Null,
+            Value::Integer(Some(i)) => PrismaValue::BigInt(i),
+            Value::Bytes(Some(bytes)) => PrismaValue::BigInt(interpret_bytes_as_i64(&bytes)),
+            Value::Text(Some(txt)) => PrismaValue::BigInt(
+                i64::from_str(txt.trim_start_matches('\0')).map_err(|err| SqlError::ConversionError(err.into()))?,
+            ),
+            // Sums of 64 bit integers are returned as decimals.
+            Value::Real(Some(dec)) => match dec.to_i64() {
+                Some(i) => PrismaValue::BigInt(i),
+                None => {
+                    let error = io::Error::new(io::ErrorKind::InvalidData, "BigInt value does not fit in 64 bits");
+                    return Err(SqlError::ConversionError(error.into()));
+                }
+            },
+            _ => {
+                let error = io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "BigInt value not stored as int, decimal or text",
+                );
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
+        TypeIdentifier::Bytes => match p_value {
+            value if value.is_null() => PrismaValue::Null,
+            Value::Bytes(Some(bytes)) => PrismaValue::Bytes(bytes.into_owned()),
+            Value::Text(Some(txt)) => PrismaValue::Bytes(txt.into_owned().into_bytes()),
+            _ => {
+                let error = io::Error::new(io::ErrorKind::InvalidData, "Bytes value not stored as bytes or text");
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
         TypeIdentifier::String => match p_value {
             value if value.is_null() => PrismaValue::Null,
             Value::Uuid(Some(uuid)) => PrismaValue::String(uuid.to_string()),
@@ -261,6 +334,18 @@ impl From<&SqlId> for Expression<'static> {
     }
 }
 
+/// Text, or bytes holding UTF-8 text, for example the decimals MySQL returns as text.
+fn text_or_bytes_as_str<'a>(value: &'a Value<'_>) -> Result<&'a str, SqlError> {
+    value.as_str().ok_or_else(|| {
+        let error = io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Value is not valid UTF-8 text: {:?}", value),
+        );
+
+        SqlError::ConversionError(error.into())
+    })
+}
+
 // We assume the bytes are stored as a big endian signed integer, because that is what
 // mysql does if you enter a numeric value for a bits column.
 fn interpret_bytes_as_i64(bytes: &[u8]) -> i64 {
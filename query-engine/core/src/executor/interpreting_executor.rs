@@ -1,17 +1,46 @@
-use super::{pipeline::QueryPipeline, QueryExecutor};
-use crate::{Operation, QueryGraphBuilder, QueryInterpreter, QuerySchemaRef, ResponseData};
+use super::{metrics::OperationMetrics, pipeline::QueryPipeline, QueryExecutor, ReadPreference, ResponseStream};
+use crate::{
+    CoreError, DatasourceConnections, ExpressionResult, ManyRecordsQuery, Operation, QueryGraphBuilder,
+    QueryInterpreter, QueryResult, QuerySchemaRef, RecordSelection, ResponseData,
+};
 use async_trait::async_trait;
-use connector::{Connection, ConnectionLike, Connector};
-use futures::future;
+use connector::{Connection, ConnectionLike, Connector, Transaction};
+use futures::{future, StreamExt};
+use prisma_models::ModelRef;
+use std::{
+    collections::{BTreeSet, HashMap},
+    iter,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+/// Default number of records fetched from the database per batch when streaming.
+pub const DEFAULT_STREAM_BATCH_SIZE: usize = 1000;
 
 /// Central query executor and main entry point into the query core.
 pub struct InterpretingExecutor<C> {
     /// The loaded connector
     connector: C,
 
+    /// Connectors to the read replicas of the database, if any.
+    /// Read operations are spread across them in turn.
+    replicas: Vec<C>,
+
+    /// Index of the replica that executes the next read operation.
+    next_replica: AtomicUsize,
+
+    /// Connectors to the datasources other than the first one, by datasource name.
+    /// The reads and writes of the models stored in them are executed on these connectors.
+    datasources: Arc<HashMap<String, Box<dyn Connector + Send + Sync>>>,
+
     /// Flag that forces individual operations to run in a transaction.
     /// Does _not_ force batches to use transactions.
     force_transactions: bool,
+
+    /// Number of records fetched from the database per batch when streaming.
+    stream_batch_size: usize,
 }
 
 impl<C> InterpretingExecutor<C>
@@ -21,36 +50,115 @@ where
     pub fn new(connector: C, force_transactions: bool) -> Self {
         InterpretingExecutor {
             connector,
+            replicas: Vec::new(),
+            next_replica: AtomicUsize::new(0),
+            datasources: Arc::new(HashMap::new()),
             force_transactions,
+            stream_batch_size: DEFAULT_STREAM_BATCH_SIZE,
+        }
+    }
+
+    /// Sets the connectors to the read replicas of the database.
+    pub fn with_replicas(mut self, replicas: Vec<C>) -> Self {
+        self.replicas = replicas;
+        self
+    }
+
+    /// Sets the connectors to the datasources other than the first one, by datasource name.
+    pub fn with_datasources(mut self, datasources: HashMap<String, Box<dyn Connector + Send + Sync>>) -> Self {
+        self.datasources = Arc::new(datasources);
+        self
+    }
+
+    /// Sets the number of records fetched from the database per batch when streaming.
+    pub fn with_stream_batch_size(mut self, stream_batch_size: usize) -> Self {
+        self.stream_batch_size = stream_batch_size.max(1);
+        self
+    }
+
+    /// Returns the connector to the datasource the model is stored in, if it is not the first one.
+    fn datasource_connector(&self, model: &ModelRef) -> Option<&dyn Connector> {
+        model
+            .datasource
+            .as_ref()
+            .and_then(|datasource| self.datasources.get(datasource))
+            .map(|connector| connector.as_ref() as &dyn Connector)
+    }
+
+    /// Returns the connector that executes `operations`, in a single transaction if `transactional`.
+    /// Read operations are executed on the replicas in turn, unless they are pinned to the primary.
+    /// Writes and transactional batches always go to the primary.
+    fn connector_for(&self, operations: &[Operation], transactional: bool, read_preference: ReadPreference) -> &C {
+        let reads_only = operations.iter().all(|operation| operation.is_read());
+
+        if self.replicas.is_empty() || transactional || !reads_only || read_preference == ReadPreference::Primary {
+            return &self.connector;
         }
+
+        let index = self.next_replica.fetch_add(1, Ordering::Relaxed) % self.replicas.len();
+        &self.replicas[index]
     }
 
     /// Async wrapper for executing an individual operation to allow code sharing with `execute_batch`.
     async fn execute_single_operation(
         operation: Operation,
         conn: Box<dyn Connection>,
+        datasources: Arc<HashMap<String, Box<dyn Connector + Send + Sync>>>,
+        force_transactions: bool,
+        query_schema: QuerySchemaRef,
+    ) -> crate::Result<ResponseData> {
+        let metrics = OperationMetrics::new(&operation, &query_schema);
+        let result = Self::execute_operation(
+            operation,
+            conn,
+            &datasources,
+            force_transactions,
+            query_schema,
+            &metrics,
+        )
+        .await;
+
+        metrics.record_outcome(&result);
+        result
+    }
+
+    /// Executes an individual operation, in a transaction if the query needs one.
+    ///
+    /// A transaction spanning several datasources is committed on one datasource after the other,
+    /// so a failing commit can leave the changes on the datasources committed before it.
+    async fn execute_operation(
+        operation: Operation,
+        conn: Box<dyn Connection>,
+        datasources: &HashMap<String, Box<dyn Connector + Send + Sync>>,
         force_transactions: bool,
         query_schema: QuerySchemaRef,
+        metrics: &OperationMetrics,
     ) -> crate::Result<ResponseData> {
         // Parse, validate, and extract query graph from query document.
         let (query, serializer) = QueryGraphBuilder::new(query_schema).build(operation)?;
         let needs_transaction = force_transactions || query.needs_transaction();
+        let datasource_conns = datasource_connections(datasources, query.models()).await?;
 
         if needs_transaction {
-            let tx = conn.start_transaction().await?;
-            let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx.as_ref()));
-            let result = QueryPipeline::new(query, interpreter, serializer).execute().await;
-
-            if result.is_ok() {
-                tx.commit().await?;
-            } else {
-                tx.rollback().await?;
-            }
+            let txs = start_transactions(conn.as_ref(), &datasource_conns).await?;
+            let interpreter = QueryInterpreter::new(transaction_connections(&txs, &datasource_conns));
+            let result = metrics
+                .time_query_graph(QueryPipeline::new(query, interpreter, serializer).execute())
+                .await;
 
-            result
+            // The error of a failed operation is returned over the one of its rollback.
+            let finished = finish_transactions(&txs, result.is_ok()).await;
+            result.and_then(|response| finished.map(|_| response))
         } else {
-            let interpreter = QueryInterpreter::new(ConnectionLike::Connection(conn.as_ref()));
-            QueryPipeline::new(query, interpreter, serializer).execute().await
+            let conns = datasource_conns.iter().fold(
+                DatasourceConnections::new(ConnectionLike::Connection(conn.as_ref())),
+                |conns, (name, conn)| conns.with_datasource(name.clone(), ConnectionLike::Connection(conn.as_ref())),
+            );
+
+            let interpreter = QueryInterpreter::new(conns);
+            metrics
+                .time_query_graph(QueryPipeline::new(query, interpreter, serializer).execute())
+                .await
         }
     }
 }
@@ -71,11 +179,14 @@ where
     /// A failing operation does not fail the batch, instead, an error is returned alongside other responses.
     /// Note that individual operations executed in non-transactional mode can still be transactions in themselves
     /// if the query (e.g. a write op) requires it.
+    ///
+    /// Transactional batches are executed on the primary, non-transactional batches route every operation on its own.
     async fn execute_batch(
         &self,
         operations: Vec<Operation>,
         transactional: bool,
         query_schema: QuerySchemaRef,
+        read_preference: ReadPreference,
     ) -> crate::Result<Vec<crate::Result<ResponseData>>> {
         if transactional {
             // Transactional batches are currently experimental
@@ -85,36 +196,57 @@ where
                 ));
             }
 
+            let connector = self.connector_for(&operations, true, read_preference);
+            let operation_metrics: Vec<_> = operations
+                .iter()
+                .map(|op| OperationMetrics::new(op, &query_schema))
+                .collect();
+
             let queries = operations
                 .into_iter()
                 .map(|op| QueryGraphBuilder::new(query_schema.clone()).build(op))
                 .collect::<std::result::Result<Vec<_>, _>>()?;
 
-            let conn = self.connector.get_connection().await?;
-            let tx = conn.start_transaction().await?;
+            let models = queries.iter().flat_map(|(query, _)| query.models()).collect();
+            let conn = connector.get_connection().await?;
+            let datasource_conns = datasource_connections(&self.datasources, models).await?;
+            let txs = start_transactions(conn.as_ref(), &datasource_conns).await?;
             let mut results = Vec::with_capacity(queries.len());
 
-            for (query, info) in queries {
-                let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx.as_ref()));
-                let result = QueryPipeline::new(query, interpreter, info).execute().await;
+            for ((query, info), metrics) in queries.into_iter().zip(operation_metrics.iter()) {
+                let interpreter = QueryInterpreter::new(transaction_connections(&txs, &datasource_conns));
+                let result = metrics
+                    .time_query_graph(QueryPipeline::new(query, interpreter, info).execute())
+                    .await;
 
-                if result.is_err() {
-                    tx.rollback().await?;
-                }
+                metrics.record_outcome(&result);
 
-                results.push(Ok(result?));
+                match result {
+                    Ok(response) => results.push(Ok(response)),
+                    Err(err) => {
+                        // The error of the failed operation is returned over the one of the rollback.
+                        let _ = finish_transactions(&txs, false).await;
+                        return Err(err);
+                    }
+                }
             }
 
-            tx.commit().await?;
+            finish_transactions(&txs, true).await?;
+
             Ok(results)
         } else {
             let mut futures = Vec::with_capacity(operations.len());
 
             for operation in operations {
-                let conn = self.connector.get_connection().await?;
+                let conn = self
+                    .connector_for(std::slice::from_ref(&operation), false, read_preference)
+                    .get_connection()
+                    .await?;
+
                 futures.push(tokio::spawn(Self::execute_single_operation(
                     operation,
                     conn,
+                    Arc::clone(&self.datasources),
                     self.force_transactions,
                     query_schema.clone(),
                 )));
@@ -131,12 +263,281 @@ where
     }
 
     /// Executes a single operation. Execution will be inside of a transaction or not depending on the needs of the query.
-    async fn execute(&self, operation: Operation, query_schema: QuerySchemaRef) -> crate::Result<ResponseData> {
+    async fn execute(
+        &self,
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+        read_preference: ReadPreference,
+    ) -> crate::Result<ResponseData> {
+        let conn = self
+            .connector_for(std::slice::from_ref(&operation), false, read_preference)
+            .get_connection()
+            .await?;
+
+        Self::execute_single_operation(
+            operation,
+            conn,
+            Arc::clone(&self.datasources),
+            self.force_transactions,
+            query_schema.clone(),
+        )
+        .await
+    }
+
+    /// Streams the records of a flat `findMany` query. The records are fetched in batches on a dedicated
+    /// connection, and every batch is serialized on its own. Batches are not read in a transaction, so
+    /// concurrent writes can be visible in later batches.
+    async fn execute_stream(
+        &self,
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+        read_preference: ReadPreference,
+    ) -> crate::Result<ResponseStream> {
+        let primary_connector: &dyn Connector =
+            self.connector_for(std::slice::from_ref(&operation), false, read_preference);
+
+        // Streams are counted when their query is built, the batches are not timed.
+        let metrics = OperationMetrics::new(&operation, &query_schema);
+        let query = QueryGraphBuilder::new(query_schema).build_stream(operation);
+
+        metrics.record_outcome(&query);
+
+        let (query, serializer) = query?;
+        let conn = self
+            .datasource_connector(&query.model)
+            .unwrap_or(primary_connector)
+            .get_connection()
+            .await?;
+
+        let ManyRecordsQuery {
+            name,
+            model,
+            args,
+            selected_fields,
+            selection_order,
+            ..
+        } = query;
+
+        let batches = connector::stream_many_records(conn, args.clone(), selected_fields, self.stream_batch_size);
+
+        let stream = batches.map(move |batch| {
+            let result = QueryResult::RecordSelection(RecordSelection {
+                name: name.clone(),
+                fields: selection_order.clone(),
+                scalars: batch?,
+                nested: vec![],
+                query_arguments: args.clone(),
+                model_id: model.primary_identifier(),
+            });
+
+            let response = serializer.serialize(ExpressionResult::Query(result))?;
+
+            match response.data.into_list() {
+                Some(items) => Ok(items.into_iter().collect()),
+                None => Err(CoreError::SerializationError(
+                    "Expected a list of records for a streamed query.".to_owned(),
+                )),
+            }
+        });
+
+        Ok(stream.boxed())
+    }
+
+    async fn start_transaction(&self) -> crate::Result<Box<dyn Transaction>> {
+        if !self.datasources.is_empty() {
+            return Err(CoreError::UnsupportedFeatureError(
+                "Interactive transactions with multiple datasources.".to_owned(),
+            ));
+        }
+
         let conn = self.connector.get_connection().await?;
-        Self::execute_single_operation(operation, conn, self.force_transactions, query_schema.clone()).await
+        Ok(conn.start_owned_transaction().await?)
+    }
+
+    async fn execute_in_transaction(
+        &self,
+        operation: Operation,
+        tx: &dyn Transaction,
+        query_schema: QuerySchemaRef,
+    ) -> crate::Result<ResponseData> {
+        let metrics = OperationMetrics::new(&operation, &query_schema);
+        let result = match QueryGraphBuilder::new(query_schema).build(operation) {
+            Ok((query, serializer)) => {
+                let interpreter = QueryInterpreter::new(DatasourceConnections::new(ConnectionLike::Transaction(tx)));
+
+                metrics
+                    .time_query_graph(QueryPipeline::new(query, interpreter, serializer).execute())
+                    .await
+            }
+            Err(err) => Err(err.into()),
+        };
+
+        metrics.record_outcome(&result);
+        result
     }
 
     fn primary_connector(&self) -> &dyn Connector {
         &self.connector
     }
 }
+
+/// Checks out a connection to every datasource other than the first one that stores one of the
+/// `models`, so operations only connect to the datasources they read or write.
+async fn datasource_connections(
+    datasources: &HashMap<String, Box<dyn Connector + Send + Sync>>,
+    models: Vec<ModelRef>,
+) -> crate::Result<Vec<(String, Box<dyn Connection>)>> {
+    let names: BTreeSet<String> = models
+        .into_iter()
+        .filter_map(|model| model.datasource.clone())
+        .collect();
+
+    let mut conns = Vec::with_capacity(names.len());
+
+    for name in names {
+        if let Some(connector) = datasources.get(&name) {
+            let conn = connector.get_connection().await?;
+            conns.push((name, conn));
+        }
+    }
+
+    Ok(conns)
+}
+
+/// Starts a transaction on the connection to the first datasource, then on the connections to the
+/// other datasources. If one fails to start, the transactions started before it are rolled back.
+async fn start_transactions<'conn>(
+    conn: &'conn dyn Connection,
+    datasource_conns: &'conn [(String, Box<dyn Connection>)],
+) -> crate::Result<Vec<Box<dyn Transaction + 'conn>>> {
+    let conns = iter::once(conn).chain(
+        datasource_conns
+            .iter()
+            .map(|(_, conn)| conn.as_ref() as &dyn Connection),
+    );
+    let mut txs = Vec::with_capacity(datasource_conns.len() + 1);
+
+    for conn in conns {
+        match conn.start_transaction().await {
+            Ok(tx) => txs.push(tx),
+            Err(err) => {
+                let _ = finish_transactions(&txs, false).await;
+                return Err(err.into());
+            }
+        }
+    }
+
+    Ok(txs)
+}
+
+/// The transactions started by `start_transactions`, by datasource.
+fn transaction_connections<'conn, 'tx>(
+    txs: &'conn [Box<dyn Transaction + 'tx>],
+    datasource_conns: &[(String, Box<dyn Connection>)],
+) -> DatasourceConnections<'conn, 'tx> {
+    let (tx, datasource_txs) = txs.split_first().expect("A transaction on the first datasource.");
+
+    datasource_conns.iter().zip(datasource_txs).fold(
+        DatasourceConnections::new(ConnectionLike::Transaction(tx.as_ref())),
+        |conns, ((name, _), tx)| conns.with_datasource(name.clone(), ConnectionLike::Transaction(tx.as_ref())),
+    )
+}
+
+/// Commits the transactions one after the other, or rolls them back. Once a commit fails, the
+/// remaining transactions are rolled back instead. Every transaction is finished before the
+/// first error is returned.
+async fn finish_transactions(txs: &[Box<dyn Transaction + '_>], commit: bool) -> crate::Result<()> {
+    let mut result = Ok(());
+
+    for tx in txs {
+        let finished = if commit && result.is_ok() {
+            tx.commit().await
+        } else {
+            tx.rollback().await
+        };
+
+        result = result.and(finished.map_err(CoreError::from));
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Selection;
+
+    /// A connector that is only told apart from the others by its name.
+    struct NamedConnector(&'static str);
+
+    #[async_trait]
+    impl Connector for NamedConnector {
+        async fn get_connection(&self) -> connector::Result<Box<dyn Connection>> {
+            unreachable!("The routing tests do not connect.")
+        }
+
+        fn name(&self) -> String {
+            self.0.to_owned()
+        }
+    }
+
+    fn executor() -> InterpretingExecutor<NamedConnector> {
+        InterpretingExecutor::new(NamedConnector("primary"), false)
+            .with_replicas(vec![NamedConnector("replica-1"), NamedConnector("replica-2")])
+    }
+
+    fn read() -> Operation {
+        Operation::Read(Selection::builder("findManyUser").build())
+    }
+
+    fn write() -> Operation {
+        Operation::Write(Selection::builder("createOneUser").build())
+    }
+
+    #[test]
+    fn reads_are_spread_across_the_replicas_in_turn() {
+        let executor = executor();
+
+        let names: Vec<String> = (0..4)
+            .map(|_| executor.connector_for(&[read()], false, ReadPreference::Replica).name())
+            .collect();
+
+        assert_eq!(names, &["replica-1", "replica-2", "replica-1", "replica-2"]);
+    }
+
+    #[test]
+    fn writes_and_transactional_batches_are_executed_on_the_primary() {
+        let executor = executor();
+
+        let write_connector = executor.connector_for(&[write()], false, ReadPreference::Replica);
+        let mixed_batch_connector = executor.connector_for(&[read(), write()], false, ReadPreference::Replica);
+        let transactional_batch_connector = executor.connector_for(&[read(), read()], true, ReadPreference::Replica);
+
+        assert_eq!(write_connector.name(), "primary");
+        assert_eq!(mixed_batch_connector.name(), "primary");
+        assert_eq!(transactional_batch_connector.name(), "primary");
+    }
+
+    #[test]
+    fn reads_pinned_to_the_primary_are_executed_on_the_primary() {
+        let executor = executor();
+
+        let connector = executor.connector_for(&[read()], false, ReadPreference::Primary);
+
+        assert_eq!(connector.name(), "primary");
+
+        // Pinned reads do not take the turn of a replica.
+        let connector = executor.connector_for(&[read()], false, ReadPreference::Replica);
+
+        assert_eq!(connector.name(), "replica-1");
+    }
+
+    #[test]
+    fn reads_are_executed_on_the_primary_without_replicas() {
+        let executor = InterpretingExecutor::new(NamedConnector("primary"), false);
+
+        let connector = executor.connector_for(&[read()], false, ReadPreference::Replica);
+
+        assert_eq!(connector.name(), "primary");
+    }
+}
@@ -0,0 +1,66 @@
+use crate::{schema::SchemaQueryBuilder, Operation, QuerySchemaRef};
+use connector::metrics::{Counter, Histogram, DURATION_BUCKETS};
+use std::{future::Future, time::Instant};
+
+static OPERATIONS: Counter = Counter::new(
+    "prisma_operations_total",
+    "Operations executed, by model, action and outcome.",
+);
+
+static QUERY_GRAPH_DURATION: Histogram = Histogram::new(
+    "prisma_query_graph_duration_seconds",
+    "Time spent executing the query graphs of operations, by model and action.",
+    DURATION_BUCKETS,
+);
+
+/// Records the metrics of a single operation, labeled with its model and action, for example
+/// `User` and `findMany`. Operations that do not belong to a model, like raw queries, are labeled
+/// with the name of their query field and an empty model.
+pub(crate) struct OperationMetrics {
+    model: String,
+    action: String,
+}
+
+impl OperationMetrics {
+    pub fn new(operation: &Operation, query_schema: &QuerySchemaRef) -> Self {
+        let field = match operation {
+            Operation::Read(selection) => query_schema.find_query_field(selection.name()),
+            Operation::Write(selection) => query_schema.find_mutation_field(selection.name()),
+        };
+
+        match field.as_ref().and_then(|field| field.query_builder()) {
+            Some(SchemaQueryBuilder::ModelQueryBuilder(builder)) => Self {
+                model: builder.model.name.clone(),
+                action: builder.tag.to_string(),
+            },
+            _ => Self {
+                model: String::new(),
+                action: operation.name().to_owned(),
+            },
+        }
+    }
+
+    /// Executes the query graph of the operation, recording how long it took.
+    pub async fn time_query_graph<T>(&self, fut: impl Future<Output = T>) -> T {
+        let start = Instant::now();
+        let result = fut.await;
+
+        QUERY_GRAPH_DURATION.observe_duration(
+            &[("model", self.model.as_str()), ("action", self.action.as_str())],
+            start.elapsed(),
+        );
+
+        result
+    }
+
+    /// Counts the operation as executed, with the outcome of `result`.
+    pub fn record_outcome<T, E>(&self, result: &Result<T, E>) {
+        let outcome = if result.is_ok() { "success" } else { "error" };
+
+        OPERATIONS.increment(&[
+            ("model", self.model.as_str()),
+            ("action", self.action.as_str()),
+            ("outcome", outcome),
+        ]);
+    }
+}
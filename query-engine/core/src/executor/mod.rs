@@ -6,18 +6,48 @@
 //! What the executor module DOES NOT DO:
 //! - Define low level execution of queries. This is considered an implementation detail of the modules used by the executors.
 mod interpreting_executor;
+mod metrics;
 mod pipeline;
 
 pub use interpreting_executor::*;
 
-use crate::{query_document::Operation, response_ir::ResponseData, schema::QuerySchemaRef};
+use crate::{
+    query_document::Operation,
+    response_ir::{Item, ResponseData},
+    schema::QuerySchemaRef,
+};
 use async_trait::async_trait;
-use connector::Connector;
+use connector::{Connector, Transaction};
+use futures::stream::BoxStream;
+
+/// A stream of serialized records, yielded in batches.
+pub type ResponseStream = BoxStream<'static, crate::Result<Vec<Item>>>;
+
+/// Where read operations are executed when the datasource has read replicas.
+/// Writes and transactions are always executed on the primary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadPreference {
+    /// Reads are spread across the replicas.
+    Replica,
+    /// Reads are executed on the primary, so that they see the result of previous writes.
+    Primary,
+}
+
+impl Default for ReadPreference {
+    fn default() -> Self {
+        ReadPreference::Replica
+    }
+}
 
 #[async_trait]
 pub trait QueryExecutor {
     /// Executes a single operation and returns its result.
-    async fn execute(&self, operation: Operation, query_schema: QuerySchemaRef) -> crate::Result<ResponseData>;
+    async fn execute(
+        &self,
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+        read_preference: ReadPreference,
+    ) -> crate::Result<ResponseData>;
 
     // Executes a batch of operations as either a fanout of individual operations (non-transactional), or in series (transactional).
     async fn execute_batch(
@@ -25,7 +55,31 @@ pub trait QueryExecutor {
         operations: Vec<Operation>,
         transactional: bool,
         query_schema: QuerySchemaRef,
+        read_preference: ReadPreference,
     ) -> crate::Result<Vec<crate::Result<ResponseData>>>;
 
+    /// Executes a flat `findMany` operation and streams the serialized records in batches,
+    /// instead of building the whole response in memory.
+    async fn execute_stream(
+        &self,
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+        read_preference: ReadPreference,
+    ) -> crate::Result<ResponseStream>;
+
+    /// Starts a transaction that owns its connection and can be held open across operations,
+    /// which are then executed with `execute_in_transaction`. The caller is responsible for
+    /// committing or rolling back the transaction.
+    async fn start_transaction(&self) -> crate::Result<Box<dyn Transaction>>;
+
+    /// Executes a single operation within the given open transaction. The transaction is
+    /// neither committed nor rolled back, regardless of the result.
+    async fn execute_in_transaction(
+        &self,
+        operation: Operation,
+        tx: &dyn Transaction,
+        query_schema: QuerySchemaRef,
+    ) -> crate::Result<ResponseData>;
+
     fn primary_connector(&self) -> &dyn Connector;
 }
@@ -0,0 +1,50 @@
+use connector::ConnectionLike;
+use prisma_models::{ModelRef, RelationRef};
+use std::collections::HashMap;
+
+/// The connections a query is interpreted on: the one to the first datasource of the schema,
+/// and one per additional datasource. Every read and write is executed on the connection of
+/// the datasource its model is stored in.
+///
+/// Relations spanning datasources are resolved in memory, like many-to-many relations. Filters
+/// on such relations are not in the query schema, as they would be part of the query on the
+/// parent model.
+pub struct DatasourceConnections<'conn, 'tx> {
+    primary: ConnectionLike<'conn, 'tx>,
+    datasources: HashMap<String, ConnectionLike<'conn, 'tx>>,
+}
+
+impl<'conn, 'tx> DatasourceConnections<'conn, 'tx> {
+    pub fn new(primary: ConnectionLike<'conn, 'tx>) -> Self {
+        Self {
+            primary,
+            datasources: HashMap::new(),
+        }
+    }
+
+    /// Adds the connection to an additional datasource.
+    pub fn with_datasource(mut self, name: String, conn: ConnectionLike<'conn, 'tx>) -> Self {
+        self.datasources.insert(name, conn);
+        self
+    }
+
+    /// The connection to the first datasource, which executes raw queries.
+    pub fn primary(&self) -> &ConnectionLike<'conn, 'tx> {
+        &self.primary
+    }
+
+    /// The connection to the datasource the model is stored in.
+    pub fn for_model(&self, model: &ModelRef) -> &ConnectionLike<'conn, 'tx> {
+        model
+            .datasource
+            .as_ref()
+            .and_then(|datasource| self.datasources.get(datasource))
+            .unwrap_or(&self.primary)
+    }
+
+    /// The connection to the datasource the relation table of a many-to-many relation is stored
+    /// in, which is the one of the `A` model of the relation.
+    pub fn for_relation(&self, relation: &RelationRef) -> &ConnectionLike<'conn, 'tx> {
+        self.for_model(&relation.model_a())
+    }
+}
@@ -1,10 +1,9 @@
 use super::{
     expression::*,
     query_interpreters::{read, write},
-    InterpretationResult, InterpreterError,
+    DatasourceConnections, InterpretationResult, InterpreterError,
 };
 use crate::{Query, QueryResult};
-use connector::ConnectionLike;
 use crossbeam_queue::SegQueue;
 use futures::future::{BoxFuture, FutureExt};
 use im::HashMap;
@@ -121,7 +120,7 @@ impl Env {
     }
 }
 pub struct QueryInterpreter<'conn, 'tx> {
-    pub(crate) conn: ConnectionLike<'conn, 'tx>,
+    pub(crate) conns: DatasourceConnections<'conn, 'tx>,
     log: SegQueue<String>,
 }
 
@@ -133,14 +132,14 @@ where
         tracing::level_filters::STATIC_MAX_LEVEL == tracing::level_filters::LevelFilter::TRACE
     }
 
-    pub fn new(conn: ConnectionLike<'conn, 'tx>) -> QueryInterpreter<'conn, 'tx> {
+    pub fn new(conns: DatasourceConnections<'conn, 'tx>) -> QueryInterpreter<'conn, 'tx> {
         let log = SegQueue::new();
 
         if Self::log_enabled() {
             log.push("\n".to_string());
         }
 
-        Self { conn, log }
+        Self { conns, log }
     }
 
     pub fn interpret(
@@ -208,14 +207,14 @@ where
                     match query {
                         Query::Read(read) => {
                             self.log_line(level, || format!("READ {}", read));
-                            Ok(read::execute(&self.conn, read, None)
+                            Ok(read::execute(&self.conns, read, None)
                                 .await
                                 .map(ExpressionResult::Query)?)
                         }
 
                         Query::Write(write) => {
                             self.log_line(level, || format!("WRITE {}", write));
-                            Ok(write::execute(&self.conn, write).await.map(ExpressionResult::Query)?)
+                            Ok(write::execute(&self.conns, write).await.map(ExpressionResult::Query)?)
                         }
                     }
                 };
@@ -1,3 +1,4 @@
+mod datasource_connections;
 mod error;
 mod expression;
 mod expressionista;
@@ -6,6 +7,7 @@ mod interpreter;
 
 pub(self) mod query_interpreters;
 
+pub use datasource_connections::*;
 pub use error::*;
 pub use expression::*;
 pub use expressionista::*;
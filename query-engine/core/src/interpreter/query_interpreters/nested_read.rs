@@ -1,12 +1,17 @@
 use super::inmemory_record_processor::InMemoryRecordProcessor;
-use crate::{interpreter::InterpretationResult, query_ast::*};
+use crate::{
+    interpreter::{DatasourceConnections, InterpretationResult},
+    query_ast::*,
+};
 use connector::{self, filter::Filter, ConnectionLike, QueryArguments, ReadOperations, ScalarCompare};
 use prisma_models::{ManyRecords, ModelProjection, Record, RecordProjection, RelationFieldRef};
 use prisma_value::PrismaValue;
 use std::collections::HashMap;
 
+/// The relation table and the related records can be stored in different datasources, so the
+/// related records are matched with the IDs from the relation table in memory.
 pub async fn m2m<'a, 'b>(
-    tx: &'a ConnectionLike<'a, 'b>,
+    conns: &'a DatasourceConnections<'a, 'b>,
     query: &RelatedRecordsQuery,
     parent_result: Option<&'a ManyRecords>,
     processor: InMemoryRecordProcessor,
@@ -27,7 +32,10 @@ pub async fn m2m<'a, 'b>(
     if parent_ids.is_empty() {
         return Ok(ManyRecords::empty(&query.selected_fields));
     }
-    let ids = tx.get_related_m2m_record_ids(&query.parent_field, &parent_ids).await?;
+    let ids = conns
+        .for_relation(&query.parent_field.relation())
+        .get_related_m2m_record_ids(&query.parent_field, &parent_ids)
+        .await?;
 
     if ids.is_empty() {
         return Ok(ManyRecords::empty(&query.selected_fields));
@@ -54,7 +62,11 @@ pub async fn m2m<'a, 'b>(
             Some(existing_filter) => Some(Filter::and(vec![existing_filter, filter])),
             None => Some(filter),
         };
-        tx.get_many_records(&query.parent_field.related_model(), args, &query.selected_fields)
+        let child_model = query.parent_field.related_model();
+
+        conns
+            .for_model(&child_model)
+            .get_many_records(&child_model, args, &query.selected_fields)
             .await?
     };
 
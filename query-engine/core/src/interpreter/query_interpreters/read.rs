@@ -1,21 +1,26 @@
 use super::*;
-use crate::{interpreter::InterpretationResult, query_ast::*, result_ast::*};
+use crate::{
+    interpreter::{DatasourceConnections, InterpretationResult},
+    query_ast::*,
+    result_ast::*,
+};
 use connector::{self, ConnectionLike, QueryArguments, ReadOperations};
 use futures::future::{BoxFuture, FutureExt};
 use inmemory_record_processor::InMemoryRecordProcessor;
 use prisma_models::ManyRecords;
 
 pub fn execute<'a, 'b>(
-    tx: &'a ConnectionLike<'a, 'b>,
+    conns: &'a DatasourceConnections<'a, 'b>,
     query: ReadQuery,
     parent_result: Option<&'a ManyRecords>,
 ) -> BoxFuture<'a, InterpretationResult<QueryResult>> {
     let fut = async move {
         match query {
-            ReadQuery::RecordQuery(q) => read_one(tx, q).await,
-            ReadQuery::ManyRecordsQuery(q) => read_many(tx, q).await,
-            ReadQuery::RelatedRecordsQuery(q) => read_related(tx, q, parent_result).await,
-            ReadQuery::AggregateRecordsQuery(q) => aggregate(tx, q).await,
+            ReadQuery::RecordQuery(q) => read_one(conns, q).await,
+            ReadQuery::ManyRecordsQuery(q) => read_many(conns, q).await,
+            ReadQuery::RelatedRecordsQuery(q) => read_related(conns, q, parent_result).await,
+            ReadQuery::AggregateRecordsQuery(q) => aggregate(conns.for_model(&q.model), q).await,
+            ReadQuery::GroupByRecordsQuery(q) => group_by(conns.for_model(&q.model), q).await,
         }
     };
 
@@ -24,19 +29,22 @@ pub fn execute<'a, 'b>(
 
 /// Queries a single record.
 fn read_one<'conn, 'tx>(
-    tx: &'conn ConnectionLike<'conn, 'tx>,
+    conns: &'conn DatasourceConnections<'conn, 'tx>,
     query: RecordQuery,
 ) -> BoxFuture<'conn, InterpretationResult<QueryResult>> {
     let fut = async move {
         let model = query.model;
         let model_id = model.primary_identifier();
         let filter = query.filter.expect("Expected filter to be set for ReadOne query.");
-        let scalars = tx.get_single_record(&model, &filter, &query.selected_fields).await?;
+        let scalars = conns
+            .for_model(&model)
+            .get_single_record(&model, &filter, &query.selected_fields)
+            .await?;
 
         match scalars {
             Some(record) => {
                 let records: ManyRecords = record.into();
-                let nested: Vec<QueryResult> = process_nested(tx, query.nested, Some(&records)).await?;
+                let nested: Vec<QueryResult> = process_nested(conns, query.nested, Some(&records)).await?;
 
                 Ok(QueryResult::RecordSelection(RecordSelection {
                     name: query.name,
@@ -69,10 +77,11 @@ fn read_one<'conn, 'tx>(
 ///    are distinct by definition if a unique is in the selection set.
 /// -> Unstable cursors can't reliably be fetched by the underlying datasource, so we need to process part of it in-memory.
 fn read_many<'a, 'b>(
-    tx: &'a ConnectionLike<'a, 'b>,
+    conns: &'a DatasourceConnections<'a, 'b>,
     mut query: ManyRecordsQuery,
 ) -> BoxFuture<'a, InterpretationResult<QueryResult>> {
     let fut = async move {
+        let tx = conns.for_model(&query.model);
         let scalars = if query.args.distinct.is_some()
             || query.args.contains_unstable_cursor()
             || query.args.contains_null_cursor()
@@ -89,7 +98,7 @@ fn read_many<'a, 'b>(
         };
 
         let model_id = query.model.primary_identifier();
-        let nested: Vec<QueryResult> = process_nested(tx, query.nested, Some(&scalars)).await?;
+        let nested: Vec<QueryResult> = process_nested(conns, query.nested, Some(&scalars)).await?;
 
         Ok(QueryResult::RecordSelection(RecordSelection {
             name: query.name,
@@ -105,8 +114,10 @@ fn read_many<'a, 'b>(
 }
 
 /// Queries related records for a set of parent IDs.
+/// The related records are read on the connection of their own datasource, and matched with
+/// the parent records in memory.
 fn read_related<'a, 'b>(
-    tx: &'a ConnectionLike<'a, 'b>,
+    conns: &'a DatasourceConnections<'a, 'b>,
     mut query: RelatedRecordsQuery,
     parent_result: Option<&'a ManyRecords>,
 ) -> BoxFuture<'a, InterpretationResult<QueryResult>> {
@@ -116,10 +127,10 @@ fn read_related<'a, 'b>(
         let processor = InMemoryRecordProcessor::new_from_query_args(&mut query.args);
 
         let scalars = if is_m2m {
-            nested_read::m2m(tx, &query, parent_result, processor).await?
+            nested_read::m2m(conns, &query, parent_result, processor).await?
         } else {
             nested_read::one2m(
-                tx,
+                conns.for_model(&query.parent_field.related_model()),
                 &query.parent_field,
                 query.parent_projections,
                 parent_result,
@@ -132,7 +143,7 @@ fn read_related<'a, 'b>(
 
         let model = query.parent_field.related_model();
         let model_id = model.primary_identifier();
-        let nested: Vec<QueryResult> = process_nested(tx, query.nested, Some(&scalars)).await?;
+        let nested: Vec<QueryResult> = process_nested(conns, query.nested, Some(&scalars)).await?;
 
         Ok(QueryResult::RecordSelection(RecordSelection {
             name: query.name,
@@ -162,8 +173,29 @@ async fn aggregate<'a, 'b>(
     }))
 }
 
-fn process_nested<'a, 'b>(
+async fn group_by<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
+    query: GroupByRecordsQuery,
+) -> InterpretationResult<QueryResult> {
+    let selection_order = query.selection_order;
+    let results = tx
+        .group_by_records(
+            &query.model,
+            query.group_by,
+            query.aggregators,
+            query.args,
+            query.having,
+        )
+        .await?;
+
+    Ok(QueryResult::RecordAggregations(RecordAggregations {
+        selection_order,
+        results,
+    }))
+}
+
+fn process_nested<'a, 'b>(
+    conns: &'a DatasourceConnections<'a, 'b>,
     nested: Vec<ReadQuery>,
     parent_result: Option<&'a ManyRecords>,
 ) -> BoxFuture<'a, InterpretationResult<Vec<QueryResult>>> {
@@ -176,7 +208,7 @@ fn process_nested<'a, 'b>(
             let mut nested_results = Vec::with_capacity(nested.len());
 
             for query in nested {
-                let result = execute(tx, query, parent_result).await?;
+                let result = execute(conns, query, parent_result).await?;
                 nested_results.push(result);
             }
 
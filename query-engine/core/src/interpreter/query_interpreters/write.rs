@@ -1,5 +1,5 @@
 use crate::{
-    interpreter::{InterpretationResult, InterpreterError},
+    interpreter::{DatasourceConnections, InterpretationResult, InterpreterError},
     query_ast::*,
     QueryResult, RawQueryType,
 };
@@ -7,11 +7,19 @@ use connector::{ConnectionLike, WriteOperations};
 use prisma_value::PrismaValue;
 
 pub async fn execute<'a, 'b>(
-    tx: &'a ConnectionLike<'a, 'b>,
+    conns: &'a DatasourceConnections<'a, 'b>,
     write_query: WriteQuery,
 ) -> InterpretationResult<QueryResult> {
+    let tx = match &write_query {
+        WriteQuery::Raw { .. } => conns.primary(),
+        WriteQuery::ConnectRecords(q) => conns.for_relation(&q.relation_field.relation()),
+        WriteQuery::DisconnectRecords(q) => conns.for_relation(&q.relation_field.relation()),
+        _ => conns.for_model(&write_query.model()),
+    };
+
     match write_query {
         WriteQuery::CreateRecord(q) => create_one(tx, q).await,
+        WriteQuery::CreateManyRecords(q) => create_many(tx, q).await,
         WriteQuery::UpdateRecord(q) => update_one(tx, q).await,
         WriteQuery::DeleteRecord(q) => delete_one(tx, q).await,
         WriteQuery::UpdateManyRecords(q) => update_many(tx, q).await,
@@ -55,6 +63,15 @@ async fn create_one<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, q: CreateRecord) ->
     Ok(QueryResult::Id(Some(res)))
 }
 
+async fn create_many<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    q: CreateManyRecords,
+) -> InterpretationResult<QueryResult> {
+    let res = tx.create_records(&q.model, q.args, q.skip_duplicates).await?;
+
+    Ok(QueryResult::Count(res))
+}
+
 async fn update_one<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, q: UpdateRecord) -> InterpretationResult<QueryResult> {
     let mut res = tx.update_records(&q.model, q.record_filter, q.args).await?;
 
@@ -27,6 +27,19 @@ impl Query {
             Self::Write(wq) => wq.model(),
         }
     }
+
+    /// The models the query reads or writes, including the ones of nested reads and the `A` models
+    /// of the many-to-many relations it touches, which store the relation tables. Raw queries don't
+    /// belong to any model.
+    pub fn models(&self) -> Vec<ModelRef> {
+        match self {
+            Self::Read(rq) => rq.models(),
+            Self::Write(WriteQuery::ConnectRecords(q)) => vec![q.relation_field.relation().model_a()],
+            Self::Write(WriteQuery::DisconnectRecords(q)) => vec![q.relation_field.relation().model_a()],
+            Self::Write(WriteQuery::Raw { .. }) => vec![],
+            Self::Write(wq) => vec![wq.model()],
+        }
+    }
 }
 
 impl FilteredQuery for Query {
@@ -10,6 +10,7 @@ pub enum ReadQuery {
     ManyRecordsQuery(ManyRecordsQuery),
     RelatedRecordsQuery(RelatedRecordsQuery),
     AggregateRecordsQuery(AggregateRecordsQuery),
+    GroupByRecordsQuery(GroupByRecordsQuery),
 }
 
 impl ReadQuery {
@@ -19,6 +20,7 @@ impl ReadQuery {
             ReadQuery::ManyRecordsQuery(x) => &x.name,
             ReadQuery::RelatedRecordsQuery(x) => &x.name,
             ReadQuery::AggregateRecordsQuery(x) => &x.name,
+            ReadQuery::GroupByRecordsQuery(x) => &x.name,
         }
     }
 
@@ -30,6 +32,7 @@ impl ReadQuery {
             ReadQuery::ManyRecordsQuery(x) => x.selected_fields.contains_all_db_names(db_names),
             ReadQuery::RelatedRecordsQuery(x) => x.selected_fields.contains_all_db_names(db_names),
             ReadQuery::AggregateRecordsQuery(_x) => false,
+            ReadQuery::GroupByRecordsQuery(_x) => false,
         }
     }
 
@@ -39,8 +42,26 @@ impl ReadQuery {
             ReadQuery::ManyRecordsQuery(x) => x.model.clone(),
             ReadQuery::RelatedRecordsQuery(x) => x.parent_field.related_field().model(),
             ReadQuery::AggregateRecordsQuery(x) => x.model.clone(),
+            ReadQuery::GroupByRecordsQuery(x) => x.model.clone(),
         }
     }
+
+    /// The models the query reads, including the ones of its nested reads.
+    pub fn models(&self) -> Vec<ModelRef> {
+        let (mut models, nested) = match self {
+            ReadQuery::RecordQuery(x) => (vec![x.model.clone()], &x.nested),
+            ReadQuery::ManyRecordsQuery(x) => (vec![x.model.clone()], &x.nested),
+            ReadQuery::RelatedRecordsQuery(x) => (
+                vec![x.parent_field.related_model(), x.parent_field.relation().model_a()],
+                &x.nested,
+            ),
+            ReadQuery::AggregateRecordsQuery(x) => return vec![x.model.clone()],
+            ReadQuery::GroupByRecordsQuery(x) => return vec![x.model.clone()],
+        };
+
+        models.extend(nested.iter().flat_map(|nested| nested.models()));
+        models
+    }
 }
 
 impl FilteredQuery for ReadQuery {
@@ -88,6 +109,13 @@ impl Display for ReadQuery {
                 q.selected_fields.names().collect::<Vec<_>>()
             ),
             Self::AggregateRecordsQuery(q) => write!(f, "AggregateRecordsQuery: {}", q.name),
+            Self::GroupByRecordsQuery(q) => write!(
+                f,
+                "GroupByRecordsQuery(name: '{}', model: {}, by: {:?})",
+                q.name,
+                q.model.name,
+                q.group_by.iter().map(|f| f.name.as_str()).collect::<Vec<_>>()
+            ),
         }
     }
 }
@@ -139,6 +167,18 @@ pub struct AggregateRecordsQuery {
     pub aggregators: Vec<Aggregator>,
 }
 
+#[derive(Debug, Clone)]
+pub struct GroupByRecordsQuery {
+    pub name: String,
+    pub alias: Option<String>,
+    pub model: ModelRef,
+    pub selection_order: Vec<(String, Option<Vec<String>>)>,
+    pub args: QueryArguments,
+    pub group_by: Vec<ScalarFieldRef>,
+    pub aggregators: Vec<Aggregator>,
+    pub having: Option<Filter>,
+}
+
 impl FilteredQuery for RecordQuery {
     fn get_filter(&mut self) -> Option<&mut Filter> {
         self.filter.as_mut()
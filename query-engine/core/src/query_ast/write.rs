@@ -8,6 +8,7 @@ use std::sync::Arc;
 #[derive(Debug, Clone)]
 pub enum WriteQuery {
     CreateRecord(CreateRecord),
+    CreateManyRecords(CreateManyRecords),
     UpdateRecord(UpdateRecord),
     DeleteRecord(DeleteRecord),
     UpdateManyRecords(UpdateManyRecords),
@@ -53,9 +54,10 @@ impl WriteQuery {
 
         // Write operations only return IDs at the moment, so anything different
         // from the primary ID is automatically not returned.
-        // DeleteMany, Connect and Disconnect do not return anything.
+        // CreateMany, DeleteMany, Connect and Disconnect do not return anything.
         match self {
             Self::CreateRecord(_) => returns_id,
+            Self::CreateManyRecords(_) => false,
             Self::UpdateRecord(_) => returns_id,
             Self::DeleteRecord(_) => returns_id,
             Self::UpdateManyRecords(_) => returns_id,
@@ -69,6 +71,7 @@ impl WriteQuery {
     pub fn model(&self) -> ModelRef {
         match self {
             Self::CreateRecord(q) => Arc::clone(&q.model),
+            Self::CreateManyRecords(q) => Arc::clone(&q.model),
             Self::UpdateRecord(q) => Arc::clone(&q.model),
             Self::DeleteRecord(q) => Arc::clone(&q.model),
             Self::UpdateManyRecords(q) => Arc::clone(&q.model),
@@ -106,6 +109,13 @@ impl std::fmt::Display for WriteQuery {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
             Self::CreateRecord(q) => write!(f, "CreateRecord(model: {}, args: {:?})", q.model.name, q.args,),
+            Self::CreateManyRecords(q) => write!(
+                f,
+                "CreateManyRecords(model: {}, records: {}, skip_duplicates: {})",
+                q.model.name,
+                q.args.len(),
+                q.skip_duplicates
+            ),
             Self::UpdateRecord(q) => write!(
                 f,
                 "UpdateRecord(model: {}, filter: {:?}, args: {:?})",
@@ -131,6 +141,13 @@ pub struct CreateRecord {
     pub args: WriteArgs,
 }
 
+#[derive(Debug, Clone)]
+pub struct CreateManyRecords {
+    pub model: ModelRef,
+    pub args: Vec<WriteArgs>,
+    pub skip_duplicates: bool,
+}
+
 #[derive(Debug, Clone)]
 pub struct UpdateRecord {
     pub model: ModelRef,
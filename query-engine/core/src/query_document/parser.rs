@@ -231,6 +231,18 @@ impl QueryDocumentParser {
             (QueryValue::String(s), ScalarType::UUID) => {
                 Self::parse_uuid(parent_path, s.as_str()).map(PrismaValue::Uuid)
             }
+            (QueryValue::String(s), ScalarType::BigInt) => {
+                Self::parse_big_int(parent_path, s.as_str()).map(PrismaValue::BigInt)
+            }
+            (QueryValue::String(s), ScalarType::Bytes) => {
+                Self::parse_bytes(parent_path, s.as_str()).map(PrismaValue::Bytes)
+            }
+            (QueryValue::Int(i), ScalarType::BigInt) => Ok(PrismaValue::BigInt(i)),
+            (QueryValue::String(s), ScalarType::Decimal) => {
+                Self::parse_decimal(parent_path, s.as_str()).map(PrismaValue::Decimal)
+            }
+            (QueryValue::Int(i), ScalarType::Decimal) => Ok(PrismaValue::Decimal(Decimal::from(i))),
+            (QueryValue::Float(f), ScalarType::Decimal) => Ok(PrismaValue::Decimal(f)),
             (QueryValue::Int(i), ScalarType::Float) => Ok(PrismaValue::Float(Decimal::from(i))),
             (QueryValue::Int(i), ScalarType::Int) => Ok(PrismaValue::Int(i)),
             (QueryValue::Float(f), ScalarType::Float) => Ok(PrismaValue::Float(f)),
@@ -298,6 +310,30 @@ impl QueryDocumentParser {
         })
     }
 
+    pub fn parse_big_int(path: &QueryPath, s: &str) -> QueryParserResult<i64> {
+        s.parse::<i64>().map_err(|err| QueryParserError {
+            path: path.clone(),
+            error_kind: QueryParserErrorKind::ValueParseError(format!("Invalid BigInt: {}", err)),
+        })
+    }
+
+    pub fn parse_decimal(path: &QueryPath, s: &str) -> QueryParserResult<Decimal> {
+        s.parse::<Decimal>().map_err(|err| QueryParserError {
+            path: path.clone(),
+            error_kind: QueryParserErrorKind::ValueParseError(format!("Invalid Decimal: {}", err)),
+        })
+    }
+
+    pub fn parse_bytes(path: &QueryPath, s: &str) -> QueryParserResult<Vec<u8>> {
+        prisma_value::decode_bytes(s).map_err(|_| QueryParserError {
+            path: path.clone(),
+            error_kind: QueryParserErrorKind::ValueParseError(format!(
+                "Invalid Bytes: {} is not a valid base64 string",
+                s
+            )),
+        })
+    }
+
     pub fn parse_list(
         path: &QueryPath,
         values: Vec<QueryValue>,
@@ -1,5 +1,5 @@
 use indexmap::IndexMap;
-use prisma_value::{stringify_date, PrismaValue};
+use prisma_value::{encode_bytes, stringify_date, PrismaValue};
 use rust_decimal::Decimal;
 use std::hash::Hash;
 
@@ -55,6 +55,9 @@ impl From<PrismaValue> for QueryValue {
             PrismaValue::Null => Self::Null,
             PrismaValue::Uuid(u) => Self::String(u.to_hyphenated().to_string()),
             PrismaValue::Json(s) => Self::String(s),
+            PrismaValue::BigInt(i) => Self::String(i.to_string()),
+            PrismaValue::Bytes(b) => Self::String(encode_bytes(&b)),
+            PrismaValue::Decimal(d) => Self::String(d.to_string()),
         }
     }
 }
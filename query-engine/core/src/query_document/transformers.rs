@@ -207,7 +207,7 @@ impl TryInto<Option<i64>> for ParsedInputValue {
         let prisma_value: PrismaValue = self.try_into()?;
 
         match prisma_value {
-            PrismaValue::Int(i) => Ok(Some(i)),
+            PrismaValue::Int(i) | PrismaValue::BigInt(i) => Ok(Some(i)),
             PrismaValue::Null => Ok(None),
             v => Err(QueryParserError {
                 path: QueryPath::default(),
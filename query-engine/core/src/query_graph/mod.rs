@@ -309,6 +309,18 @@ impl QueryGraph {
         self.needs_transaction
     }
 
+    /// The models the queries of the graph read or write.
+    pub fn models(&self) -> Vec<ModelRef> {
+        self.graph
+            .node_indices()
+            .filter_map(|ix| match self.graph.node_weight(ix).unwrap().borrow() {
+                Some(Node::Query(query)) => Some(query.models()),
+                _ => None,
+            })
+            .flatten()
+            .collect()
+    }
+
     /// Returns a reference to the content of `node`, if the content is still present.
     pub fn node_content(&self, node: &NodeRef) -> Option<&Node> {
         self.graph.node_weight(node.node_ix).unwrap().borrow()
@@ -1,6 +1,7 @@
 use super::*;
-use crate::{query_document::*, query_graph::*, schema::*, IrSerializer};
+use crate::{query_document::*, query_graph::*, schema::*, IrSerializer, ManyRecordsQuery, ReadQuery};
 use prisma_value::PrismaValue;
+use std::sync::Arc;
 
 // TODO: Think about if this is really necessary here, or if the whole code should move into
 // the query_document module, possibly already as part of the parser.
@@ -86,6 +87,51 @@ impl QueryGraphBuilder {
         Ok((graph, ir_ser))
     }
 
+    /// Maps a flat `findMany` operation to a query whose records can be streamed in batches.
+    /// Queries that require processing across all records, like nested reads or `distinct`, are rejected.
+    pub fn build_stream(self, operation: Operation) -> QueryGraphBuilderResult<(ManyRecordsQuery, IrSerializer)> {
+        let selection = match operation {
+            Operation::Read(selection) => selection,
+            Operation::Write(_) => return Err(not_streamable("Only findMany queries can be streamed.")),
+        };
+
+        let query_object = self.query_schema.query();
+        let mut selections = vec![selection];
+        let mut parsed_object = QueryDocumentParser::parse_object(QueryPath::default(), &selections, &query_object)?;
+
+        let parsed_field = parsed_object.fields.pop().unwrap();
+        let serializer = Self::derive_serializer(&selections.pop().unwrap(), &parsed_field);
+
+        let model = match parsed_field.schema_field.query_builder() {
+            Some(SchemaQueryBuilder::ModelQueryBuilder(builder)) if builder.tag == QueryTag::FindMany => {
+                Arc::clone(&builder.model)
+            }
+            _ => return Err(not_streamable("Only findMany queries can be streamed.")),
+        };
+
+        let query = match ReadManyRecordsBuilder::new(parsed_field, model).build()? {
+            ReadQuery::ManyRecordsQuery(query) => query,
+            _ => unreachable!(),
+        };
+
+        if !query.nested.is_empty() {
+            return Err(not_streamable("Streamed queries can not select relations."));
+        }
+
+        let args = &query.args;
+
+        if args.distinct.is_some()
+            || args.take.map(|take| take < 0).unwrap_or(false)
+            || args.order_by.iter().any(|order_by| order_by.is_nullable())
+        {
+            return Err(not_streamable(
+                "Streamed queries do not support `distinct`, a negative `take` or ordering by optional fields.",
+            ));
+        }
+
+        Ok((query, serializer))
+    }
+
     fn process(
         selection: Selection,
         object: &ObjectTypeStrongRef,
@@ -130,3 +176,7 @@ impl QueryGraphBuilder {
         }
     }
 }
+
+fn not_streamable(message: &str) -> QueryGraphBuilderError {
+    QueryGraphBuilderError::InputError(message.to_owned())
+}
@@ -7,9 +7,12 @@ use crate::{
     query_document::{ParsedInputMap, ParsedInputValue},
     QueryGraphBuilderError, QueryGraphBuilderResult,
 };
-use connector::{filter::Filter, QueryMode, RelationCompare, ScalarCompare};
+use connector::{
+    filter::{AggregationFilter, Filter, ScalarCondition, ScalarFilter, ScalarProjection},
+    QueryMode, RelationCompare, ScalarCompare,
+};
 use filter_grouping::*;
-use prisma_models::{Field, ModelRef, PrismaValue, RelationFieldRef, ScalarFieldRef};
+use prisma_models::{Field, ModelRef, PrismaValue, RelationFieldRef, ScalarFieldRef, TypeIdentifier};
 use std::{convert::TryInto, str::FromStr};
 
 /// Extracts a filter for a unique selector, i.e. a filter that selects exactly one record.
@@ -90,6 +93,126 @@ pub fn extract_filter(value_map: ParsedInputMap, model: &ModelRef) -> QueryGraph
     Ok(Filter::and(filters))
 }
 
+/// Extracts a filter on groups of records, as used by the `having` argument of group by queries.
+/// Regular scalar conditions filter on the grouped values, while the nested aggregation conditions
+/// (`count`, `avg`, `sum`, `min` and `max`) filter on the aggregated values of a field.
+pub fn extract_having_filter(value_map: ParsedInputMap, model: &ModelRef) -> QueryGraphBuilderResult<Filter> {
+    let filters = value_map
+        .into_iter()
+        .map(|(key, value): (String, ParsedInputValue)| {
+            if let Ok(nested) = FilterGrouping::from_str(&key) {
+                let value: QueryGraphBuilderResult<Vec<Filter>> = match value {
+                    ParsedInputValue::List(values) => values
+                        .into_iter()
+                        .map(|val| extract_having_filter(val.try_into()?, model))
+                        .collect(),
+
+                    // Single map to vec coercion
+                    ParsedInputValue::Map(map) => extract_having_filter(map, model).map(|res| vec![res]),
+
+                    _ => unreachable!(),
+                };
+
+                value.map(|value| match nested {
+                    FilterGrouping::And => Filter::and(value),
+                    FilterGrouping::Or => Filter::or(value),
+                    FilterGrouping::Not => Filter::not(value),
+                })
+            } else {
+                let field = model.fields().find_from_scalar(&key)?;
+                let filters = extract_scalar_filters_with_aggregations(&field, value)?;
+
+                Ok(Filter::And(filters))
+            }
+        })
+        .collect::<QueryGraphBuilderResult<Vec<Filter>>>()?;
+
+    Ok(Filter::and(filters))
+}
+
+/// Same as `extract_scalar_filters`, but additionally allows conditions on aggregates of the field.
+/// E.g. `having: { <field>: { equals: 1, avg: { gt: 2 } } }`.
+fn extract_scalar_filters_with_aggregations(
+    field: &ScalarFieldRef,
+    value: ParsedInputValue,
+) -> QueryGraphBuilderResult<Vec<Filter>> {
+    match value {
+        ParsedInputValue::Map(mut filter_map) => {
+            let mode = match filter_map.remove("mode") {
+                Some(i) => parse_query_mode(i)?,
+                None => QueryMode::Default,
+            };
+
+            let mut filters = filter_map
+                .into_iter()
+                .map(|(k, v)| match k.as_str() {
+                    "count" | "avg" | "sum" | "min" | "max" => extract_aggregation_filter(&k, field, v),
+                    _ => scalar::parse(&k, field, v, false),
+                })
+                .collect::<QueryGraphBuilderResult<Vec<_>>>()?;
+
+            filters.iter_mut().for_each(|f| f.set_mode(mode.clone()));
+
+            Ok(filters)
+        }
+        value => extract_scalar_filters(field, value),
+    }
+}
+
+fn extract_aggregation_filter(
+    aggregation: &str,
+    field: &ScalarFieldRef,
+    value: ParsedInputValue,
+) -> QueryGraphBuilderResult<Filter> {
+    let filter = Filter::and(extract_scalar_filters(field, value)?);
+    validate_aggregation_filter(aggregation, field, &filter)?;
+
+    let filter = Box::new(filter);
+    let filter = match aggregation {
+        "count" => AggregationFilter::Count(filter),
+        "avg" => AggregationFilter::Average(filter),
+        "sum" => AggregationFilter::Sum(filter),
+        "min" => AggregationFilter::Min(filter),
+        "max" => AggregationFilter::Max(filter),
+        _ => unreachable!(),
+    };
+
+    Ok(filter.into())
+}
+
+/// Aggregated values can only be compared with plain values: relation, list, Json and full-text search
+/// conditions have no meaning on the result of an aggregation, and can't be rendered in a `HAVING` clause.
+fn validate_aggregation_filter(
+    aggregation: &str,
+    field: &ScalarFieldRef,
+    filter: &Filter,
+) -> QueryGraphBuilderResult<()> {
+    match filter {
+        Filter::And(filters) | Filter::Or(filters) | Filter::Not(filters) => filters
+            .iter()
+            .try_for_each(|filter| validate_aggregation_filter(aggregation, field, filter)),
+
+        Filter::Scalar(ScalarFilter {
+            projection: ScalarProjection::Single(_),
+            condition,
+            ..
+        }) if !matches!(
+            condition,
+            ScalarCondition::JsonCompare(_) | ScalarCondition::Search(_) | ScalarCondition::NotSearch(_)
+        ) =>
+        {
+            Ok(())
+        }
+
+        Filter::Empty => Ok(()),
+
+        _ => Err(QueryGraphBuilderError::InputError(format!(
+            "The `{}` aggregate of the `{}` field can only be compared with scalar values.",
+            aggregation, field.name
+        ))),
+    }
+}
+
 /// Field is the field the filter is refering to and `value` is the passed filter. E.g. `where: { <field>: <value> }.
 /// `value` can be either a flat scalar (for shorthand filter notation) or an object (full filter syntax).
 fn extract_scalar_filters(field: &ScalarFieldRef, value: ParsedInputValue) -> QueryGraphBuilderResult<Vec<Filter>> {
@@ -101,10 +224,22 @@ fn extract_scalar_filters(field: &ScalarFieldRef, value: ParsedInputValue) -> Qu
                 None => QueryMode::Default,
             };
 
-            let mut filters = filter_map
-                .into_iter()
-                .map(|(k, v)| scalar::parse(&k, field, v, false))
-                .collect::<QueryGraphBuilderResult<Vec<_>>>()?;
+            let mut filters = if field.type_identifier == TypeIdentifier::Json {
+                let path = match filter_map.remove("path") {
+                    Some(path) => scalar::parse_json_path(path)?,
+                    None => vec![],
+                };
+
+                filter_map
+                    .into_iter()
+                    .map(|(k, v)| scalar::parse_json(&k, field, v, &path))
+                    .collect::<QueryGraphBuilderResult<Vec<_>>>()?
+            } else {
+                filter_map
+                    .into_iter()
+                    .map(|(k, v)| scalar::parse(&k, field, v, false))
+                    .collect::<QueryGraphBuilderResult<Vec<_>>>()?
+            };
 
             filters.iter_mut().for_each(|f| f.set_mode(mode.clone()));
 
@@ -1,5 +1,5 @@
 use crate::{ParsedInputMap, ParsedInputValue, QueryGraphBuilderError, QueryGraphBuilderResult};
-use connector::{Filter, ScalarCompare};
+use connector::{Filter, JsonCompare, JsonFilterCondition, ScalarCompare};
 use prisma_models::{PrismaValue, ScalarFieldRef};
 use std::convert::TryInto;
 
@@ -58,11 +58,13 @@ pub fn parse(
         "contains" if reverse => field.not_contains(as_prisma_value(input)?),
         "startsWith" if reverse => field.not_starts_with(as_prisma_value(input)?),
         "endsWith" if reverse => field.not_ends_with(as_prisma_value(input)?),
+        "search" if reverse => field.not_search(as_prisma_value(input)?),
 
         "equals" => field.equals(as_prisma_value(input)?),
         "contains" => field.contains(as_prisma_value(input)?),
         "startsWith" => field.starts_with(as_prisma_value(input)?),
         "endsWith" => field.ends_with(as_prisma_value(input)?),
+        "search" => field.search(as_prisma_value(input)?),
 
         "lt" if reverse => field.greater_than_or_equals(as_prisma_value(input)?),
         "gt" if reverse => field.less_than_or_equals(as_prisma_value(input)?),
@@ -85,6 +87,59 @@ pub fn parse(
     Ok(filter)
 }
 
+/// Parses a filter on a Json field. Conditions are applied to the value found at `path`,
+/// equality filters without a path fall back to the regular scalar filters.
+pub fn parse_json(
+    filter_key: &str,
+    field: &ScalarFieldRef,
+    input: ParsedInputValue,
+    path: &[String],
+) -> QueryGraphBuilderResult<Filter> {
+    let condition = match filter_key {
+        "equals" | "not" if path.is_empty() => return parse(filter_key, field, input, false),
+
+        "equals" => JsonFilterCondition::Equals(as_prisma_value(input)?),
+        "not" => JsonFilterCondition::NotEquals(as_prisma_value(input)?),
+
+        "string_contains" => JsonFilterCondition::StringContains(as_string(input)?),
+        "string_starts_with" => JsonFilterCondition::StringStartsWith(as_string(input)?),
+        "string_ends_with" => JsonFilterCondition::StringEndsWith(as_string(input)?),
+
+        "array_contains" => JsonFilterCondition::ArrayContains(as_prisma_value(input)?),
+        "array_starts_with" => JsonFilterCondition::ArrayStartsWith(as_prisma_value(input)?),
+        "array_ends_with" => JsonFilterCondition::ArrayEndsWith(as_prisma_value(input)?),
+
+        _ => return parse(filter_key, field, input, false),
+    };
+
+    Ok(field.json_compare(path.to_vec(), condition))
+}
+
+/// Parses the `path` of a Json filter, a list of keys and array indices.
+pub fn parse_json_path(input: ParsedInputValue) -> QueryGraphBuilderResult<Vec<String>> {
+    match input {
+        ParsedInputValue::List(segments) => segments.into_iter().map(as_string).collect(),
+        ParsedInputValue::Single(PrismaValue::List(segments)) => segments
+            .into_iter()
+            .map(|segment| as_string(ParsedInputValue::Single(segment)))
+            .collect(),
+        x => Err(QueryGraphBuilderError::InputError(format!(
+            "Invalid Json filter path: {:?}",
+            x
+        ))),
+    }
+}
+
+fn as_string(input: ParsedInputValue) -> QueryGraphBuilderResult<String> {
+    match as_prisma_value(input)? {
+        PrismaValue::String(s) => Ok(s),
+        x => Err(QueryGraphBuilderError::InputError(format!(
+            "Expected a string in Json filter, got: {:?}",
+            x
+        ))),
+    }
+}
+
 fn as_prisma_value(input: ParsedInputValue) -> QueryGraphBuilderResult<PrismaValue> {
     Ok(input.try_into()?)
 }
@@ -5,7 +5,8 @@ use crate::{
 };
 use connector::QueryArguments;
 use prisma_models::{
-    Field, ModelProjection, ModelRef, OrderBy, PrismaValue, RecordProjection, ScalarFieldRef, SortOrder,
+    Field, ModelProjection, ModelRef, OrderBy, PrismaValue, RecordProjection, RelationFieldRef, ScalarFieldRef,
+    SortAggregation, SortOrder,
 };
 use std::convert::{identity, TryInto};
 
@@ -72,21 +73,7 @@ fn extract_order_by(model: &ModelRef, value: ParsedInputValue) -> QueryGraphBuil
             .into_iter()
             .map(|list_value| {
                 let object: ParsedInputMap = list_value.try_into()?;
-
-                match object.into_iter().next() {
-                    None => Ok(None),
-                    Some((field_name, sort_order)) => {
-                        let field = model.fields().find_from_scalar(&field_name)?;
-                        let value: PrismaValue = sort_order.try_into()?;
-                        let sort_order = match value.into_string().unwrap().to_lowercase().as_str() {
-                            "asc" => SortOrder::Ascending,
-                            "desc" => SortOrder::Descending,
-                            _ => unreachable!(),
-                        };
-
-                        Ok(Some(OrderBy::new(field, sort_order)))
-                    }
-                }
+                process_order_object(model, object)
             })
             .collect::<QueryGraphBuilderResult<Vec<_>>>()
             .map(|results| results.into_iter().filter_map(identity).collect()),
@@ -101,24 +88,120 @@ fn extract_order_by(model: &ModelRef, value: ParsedInputValue) -> QueryGraphBuil
 }
 
 fn process_order_object(model: &ModelRef, object: ParsedInputMap) -> QueryGraphBuilderResult<Option<OrderBy>> {
-    // let object: ParsedInputMap = list_value.try_into()?;
+    process_order_object_with_path(model, object, vec![])
+}
 
+/// Walks the order object through to-one relations, e.g. `{ author: { name: asc } }`,
+/// collecting the traversed relation fields as the path of the resulting ordering.
+fn process_order_object_with_path(
+    model: &ModelRef,
+    object: ParsedInputMap,
+    mut path: Vec<RelationFieldRef>,
+) -> QueryGraphBuilderResult<Option<OrderBy>> {
     match object.into_iter().next() {
         None => Ok(None),
-        Some((field_name, sort_order)) => {
-            let field = model.fields().find_from_scalar(&field_name)?;
-            let value: PrismaValue = sort_order.try_into()?;
-            let sort_order = match value.into_string().unwrap().to_lowercase().as_str() {
-                "asc" => SortOrder::Ascending,
-                "desc" => SortOrder::Descending,
+
+        Some((field_name, ParsedInputValue::Map(inner)))
+            if model.fields().find_from_relation_fields(&field_name).is_ok() =>
+        {
+            let rf = model.fields().find_from_relation_fields(&field_name)?;
+            let related_model = rf.related_model();
+
+            path.push(rf.clone());
+
+            if rf.is_list {
+                // Ordering by the number of related records, e.g. `{ posts: { count: desc } }`.
+                match inner.into_iter().next() {
+                    None => Ok(None),
+                    Some((_, sort_order)) => {
+                        let sort_order = extract_sort_order(sort_order)?;
+                        Ok(Some(OrderBy::relation_count(path, sort_order)))
+                    }
+                }
+            } else {
+                process_order_object_with_path(&related_model, inner, path)
+            }
+        }
+
+        // Ordering by the relevance of a full-text search, e.g. `{ _relevance: { fields: [title], search: "cat", sort: desc } }`.
+        Some((field_name, ParsedInputValue::Map(inner))) if field_name == "_relevance" => {
+            if !path.is_empty() {
+                return Err(QueryGraphBuilderError::InputError(
+                    "Ordering by relevance is not supported on related records.".to_owned(),
+                ));
+            }
+
+            extract_order_by_relevance(inner).map(Some)
+        }
+
+        // Ordering by an aggregate of a field, e.g. `{ avg: { age: asc } }`. Only available on grouped queries.
+        Some((aggregation, ParsedInputValue::Map(inner))) => {
+            let sort_aggregation = match aggregation.as_str() {
+                "count" => SortAggregation::Count,
+                "avg" => SortAggregation::Avg,
+                "sum" => SortAggregation::Sum,
+                "min" => SortAggregation::Min,
+                "max" => SortAggregation::Max,
                 _ => unreachable!(),
             };
 
-            Ok(Some(OrderBy::new(field, sort_order)))
+            match inner.into_iter().next() {
+                None => Ok(None),
+                Some((field_name, sort_order)) => {
+                    let field = model.fields().find_from_scalar(&field_name)?;
+                    let sort_order = extract_sort_order(sort_order)?;
+
+                    Ok(Some(OrderBy::aggregation(field, sort_order, sort_aggregation)))
+                }
+            }
+        }
+
+        Some((field_name, sort_order)) => {
+            let field = model.fields().find_from_scalar(&field_name)?;
+            let sort_order = extract_sort_order(sort_order)?;
+
+            if path.is_empty() {
+                Ok(Some(OrderBy::new(field, sort_order)))
+            } else {
+                Ok(Some(OrderBy::relation(field, path, sort_order)))
+            }
         }
     }
 }
 
+fn extract_order_by_relevance(mut object: ParsedInputMap) -> QueryGraphBuilderResult<OrderBy> {
+    let fields: Vec<ScalarFieldRef> = match object.remove("fields").unwrap() {
+        ParsedInputValue::List(list) => list
+            .into_iter()
+            .map(|element| Ok(element.try_into()?))
+            .collect::<QueryGraphBuilderResult<Vec<_>>>()?,
+
+        // Single value to vec coercion
+        value => vec![value.try_into()?],
+    };
+
+    if fields.is_empty() {
+        return Err(QueryGraphBuilderError::InputError(
+            "Ordering by relevance requires at least one field.".to_owned(),
+        ));
+    }
+
+    let search: PrismaValue = object.remove("search").unwrap().try_into()?;
+    let sort_order = extract_sort_order(object.remove("sort").unwrap())?;
+
+    Ok(OrderBy::relevance(fields, search.into_string().unwrap(), sort_order))
+}
+
+fn extract_sort_order(value: ParsedInputValue) -> QueryGraphBuilderResult<SortOrder> {
+    let value: PrismaValue = value.try_into()?;
+
+    Ok(match value.into_string().unwrap().to_lowercase().as_str() {
+        "asc" => SortOrder::Ascending,
+        "desc" => SortOrder::Descending,
+        _ => unreachable!(),
+    })
+}
+
 fn extract_distinct(value: ParsedInputValue) -> QueryGraphBuilderResult<ModelProjection> {
     let fields: Vec<Field> = match value {
         ParsedInputValue::List(list) => list
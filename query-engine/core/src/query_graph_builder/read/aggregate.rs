@@ -14,7 +14,7 @@ impl AggregateRecordsBuilder {
     }
 
     /// Resolves the given field as a aggregation query.
-    fn resolve_query(field: ParsedField, model: &ModelRef) -> QueryGraphBuilderResult<Aggregator> {
+    pub(super) fn resolve_query(field: ParsedField, model: &ModelRef) -> QueryGraphBuilderResult<Aggregator> {
         let query = match field.name.as_str() {
             "count" => Aggregator::Count,
             "avg" => Aggregator::Average(Self::resolve_fields(model, field)),
@@ -42,7 +42,7 @@ impl AggregateRecordsBuilder {
             .collect()
     }
 
-    fn collect_selection_tree(fields: &[ParsedField]) -> Vec<(String, Option<Vec<String>>)> {
+    pub(super) fn collect_selection_tree(fields: &[ParsedField]) -> Vec<(String, Option<Vec<String>>)> {
         fields
             .iter()
             .map(|field| {
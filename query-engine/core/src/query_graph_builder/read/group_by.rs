@@ -0,0 +1,160 @@
+use super::*;
+use crate::{
+    query_document::{ArgumentListLookup, ParsedField, ParsedInputMap, ParsedInputValue},
+    GroupByRecordsQuery, ReadQuery,
+};
+use connector::{
+    filter::{Filter, ScalarProjection},
+    Aggregator, QueryArguments,
+};
+use prisma_models::{ModelRef, ScalarFieldRef};
+use std::convert::TryInto;
+
+pub struct GroupByRecordsBuilder {
+    field: ParsedField,
+    model: ModelRef,
+}
+
+impl GroupByRecordsBuilder {
+    pub fn new(field: ParsedField, model: ModelRef) -> Self {
+        Self { field, model }
+    }
+
+    fn is_aggregation(field: &ParsedField) -> bool {
+        matches!(field.name.as_str(), "count" | "avg" | "sum" | "min" | "max")
+    }
+
+    fn extract_group_by(value: ParsedInputValue) -> QueryGraphBuilderResult<Vec<ScalarFieldRef>> {
+        match value {
+            ParsedInputValue::List(list) => list
+                .into_iter()
+                .map(|element| {
+                    let field: ScalarFieldRef = element.try_into()?;
+                    Ok(field)
+                })
+                .collect::<QueryGraphBuilderResult<Vec<_>>>(),
+
+            // Single value to vec coercion
+            value => {
+                let field: ScalarFieldRef = value.try_into()?;
+                Ok(vec![field])
+            }
+        }
+    }
+
+    /// Ensures that all fields that are not aggregated are part of the group by fields,
+    /// as the database can't return or compare a single value for them otherwise.
+    fn verify_grouped_fields(
+        group_by: &[ScalarFieldRef],
+        selected: &[ParsedField],
+        args: &QueryArguments,
+        having: &Option<Filter>,
+    ) -> QueryGraphBuilderResult<()> {
+        let is_grouped = |name: &str| group_by.iter().any(|field| field.name == name);
+
+        if let Some(field) = selected
+            .iter()
+            .find(|field| !Self::is_aggregation(field) && !is_grouped(&field.name))
+        {
+            return Err(QueryGraphBuilderError::InputError(format!(
+                "Every field used in the selection of a group by query must be included in the `by` argument, found: {}",
+                field.name
+            )));
+        }
+
+        if let Some(order_by) = args
+            .order_by
+            .iter()
+            .find(|order_by| order_by.sort_aggregation.is_none() && !is_grouped(&order_by.field.name))
+        {
+            return Err(QueryGraphBuilderError::InputError(format!(
+                "Every field used for orderBy must be included in the `by` argument of a group by query, found: {}",
+                order_by.field.name
+            )));
+        }
+
+        if let Some(filter) = having {
+            if let Some(field) = Self::ungrouped_having_field(filter, group_by) {
+                return Err(QueryGraphBuilderError::InputError(format!(
+                    "Every field used in `having` filters must either be an aggregation filter or be included in the `by` argument, found: {}",
+                    field.name
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the first field of a non-aggregated `having` condition that is not grouped by.
+    fn ungrouped_having_field(filter: &Filter, group_by: &[ScalarFieldRef]) -> Option<ScalarFieldRef> {
+        match filter {
+            Filter::And(filters) | Filter::Or(filters) | Filter::Not(filters) => filters
+                .iter()
+                .find_map(|filter| Self::ungrouped_having_field(filter, group_by)),
+
+            Filter::Scalar(sf) => match &sf.projection {
+                ScalarProjection::Single(field) => Some(field.clone()),
+                ScalarProjection::Compound(fields) => fields.first().cloned(),
+            }
+            .filter(|field| !group_by.contains(field)),
+
+            _ => None,
+        }
+    }
+}
+
+impl Builder<ReadQuery> for GroupByRecordsBuilder {
+    fn build(self) -> QueryGraphBuilderResult<ReadQuery> {
+        let name = self.field.name;
+        let alias = self.field.alias;
+        let model = self.model;
+        let nested_fields = self.field.nested_fields.unwrap().fields;
+        let selection_order = AggregateRecordsBuilder::collect_selection_tree(&nested_fields);
+        let mut arguments = self.field.arguments;
+
+        let group_by = match arguments.lookup("by") {
+            Some(arg) => Self::extract_group_by(arg.value)?,
+            None => vec![],
+        };
+
+        let having = match arguments.lookup("having") {
+            Some(arg) => {
+                let map: Option<ParsedInputMap> = arg.value.try_into()?;
+                map.map(|map| extractors::extract_having_filter(map, &model))
+                    .transpose()?
+            }
+            None => None,
+        };
+
+        // Without an explicit ordering, an implicit ordering by primary key would be added for pagination,
+        // which is invalid for grouped records unless the primary key is grouped by.
+        let has_order_by = arguments.iter().any(|arg| arg.name == "orderBy");
+        let has_pagination = arguments.iter().any(|arg| arg.name == "take" || arg.name == "skip");
+
+        if has_pagination && !has_order_by {
+            return Err(QueryGraphBuilderError::InputError(
+                "Using `take` or `skip` on a group by query requires an `orderBy` argument.".to_owned(),
+            ));
+        }
+
+        let args = extractors::extract_query_args(arguments, &model)?;
+        Self::verify_grouped_fields(&group_by, &nested_fields, &args, &having)?;
+
+        let aggregators: Vec<Aggregator> = nested_fields
+            .into_iter()
+            .filter(Self::is_aggregation)
+            .map(|field| AggregateRecordsBuilder::resolve_query(field, &model))
+            .collect::<QueryGraphBuilderResult<_>>()?;
+
+        Ok(ReadQuery::GroupByRecordsQuery(GroupByRecordsQuery {
+            name,
+            alias,
+            model,
+            selection_order,
+            args,
+            group_by,
+            aggregators,
+            having,
+        }))
+    }
+}
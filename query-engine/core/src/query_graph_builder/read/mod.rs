@@ -1,11 +1,13 @@
 mod aggregate;
 mod first;
+mod group_by;
 mod many;
 mod one;
 mod related;
 
 pub use aggregate::*;
 pub use first::*;
+pub use group_by::*;
 pub use many::*;
 pub use one::*;
 pub use related::*;
@@ -21,6 +23,7 @@ pub enum ReadQueryBuilder {
     ReadManyRecordsBuilder(ReadManyRecordsBuilder),
     ReadRelatedRecordsBuilder(ReadRelatedRecordsBuilder),
     AggregateRecordsBuilder(AggregateRecordsBuilder),
+    GroupByRecordsBuilder(GroupByRecordsBuilder),
 }
 
 impl Builder<ReadQuery> for ReadQueryBuilder {
@@ -31,6 +34,7 @@ impl Builder<ReadQuery> for ReadQueryBuilder {
             ReadQueryBuilder::ReadManyRecordsBuilder(b) => b.build(),
             ReadQueryBuilder::ReadRelatedRecordsBuilder(b) => b.build(),
             ReadQueryBuilder::AggregateRecordsBuilder(b) => b.build(),
+            ReadQueryBuilder::GroupByRecordsBuilder(b) => b.build(),
         }
     }
 }
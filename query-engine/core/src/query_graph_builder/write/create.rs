@@ -2,7 +2,7 @@ use super::*;
 use crate::{
     query_ast::*,
     query_graph::{Node, NodeRef, QueryGraph, QueryGraphDependency},
-    ArgumentListLookup, ParsedField, ParsedInputMap, ReadOneRecordBuilder,
+    ArgumentListLookup, ParsedField, ParsedInputMap, ParsedInputValue, ReadOneRecordBuilder,
 };
 use connector::IdFilter;
 use prisma_models::ModelRef;
@@ -48,6 +48,47 @@ pub fn create_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
     Ok(())
 }
 
+/// Creates a create many records query and adds it to the query graph.
+/// Records are inserted without nested writes and without a follow-up read, the result is the number of created records.
+pub fn create_many_records(
+    graph: &mut QueryGraph,
+    model: ModelRef,
+    mut field: ParsedField,
+) -> QueryGraphBuilderResult<()> {
+    let data_list: Vec<ParsedInputValue> = match field.arguments.lookup("data") {
+        Some(data) => match data.value {
+            ParsedInputValue::Map(map) => vec![ParsedInputValue::Map(map)],
+            value => value.try_into()?,
+        },
+        None => vec![],
+    };
+
+    let skip_duplicates: bool = match field.arguments.lookup("skipDuplicates") {
+        Some(arg) => arg.value.try_into()?,
+        None => false,
+    };
+
+    let args = data_list
+        .into_iter()
+        .map(|data_value| {
+            let data_map: ParsedInputMap = data_value.try_into()?;
+            let mut args = WriteArgsParser::from(&model, data_map)?.args;
+
+            args.add_datetimes(Arc::clone(&model));
+            Ok(args)
+        })
+        .collect::<QueryGraphBuilderResult<Vec<_>>>()?;
+
+    let create_many = WriteQuery::CreateManyRecords(CreateManyRecords {
+        model,
+        args,
+        skip_duplicates,
+    });
+
+    graph.create_node(Query::Write(create_many));
+    Ok(())
+}
+
 pub fn create_record_node(
     graph: &mut QueryGraph,
     model: ModelRef,
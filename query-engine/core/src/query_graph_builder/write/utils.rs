@@ -5,7 +5,7 @@ use crate::{
 };
 use connector::{Filter, WriteArgs};
 use itertools::Itertools;
-use prisma_models::{ModelProjection, ModelRef, RelationFieldRef};
+use prisma_models::{dml::ReferentialAction, ModelProjection, ModelRef, RelationFieldRef};
 use std::sync::Arc;
 
 /// Coerces single values (`ParsedInputValue::Single` and `ParsedInputValue::Map`) into a vector.
@@ -307,7 +307,11 @@ pub fn insert_deletion_checks(
     child_node: &NodeRef,
 ) -> QueryGraphBuilderResult<()> {
     let internal_model = model.internal_data_model();
-    let relation_fields = internal_model.fields_requiring_model(model);
+    let relation_fields: Vec<_> = internal_model
+        .fields_requiring_model(model)
+        .into_iter()
+        .filter(|rf| !is_handled_by_database(rf.on_delete()))
+        .collect();
     let mut check_nodes = vec![];
 
     if !relation_fields.is_empty() {
@@ -353,3 +357,12 @@ pub fn insert_deletion_checks(
 
     Ok(())
 }
+
+/// Cascading and nulling referential actions are taken care of by the database,
+/// so deleting a record with such relations must not be prevented by the deletion checks.
+fn is_handled_by_database(on_delete: Option<ReferentialAction>) -> bool {
+    matches!(
+        on_delete,
+        Some(ReferentialAction::Cascade) | Some(ReferentialAction::SetNull) | Some(ReferentialAction::SetDefault)
+    )
+}
@@ -1,7 +1,7 @@
 use super::*;
 use crate::{
     schema::{IntoArc, ObjectTypeStrongRef, OutputType, OutputTypeRef, ScalarType},
-    CoreError, EnumType, OutputFieldRef, QueryResult, RecordAggregation, RecordSelection,
+    CoreError, EnumType, OutputFieldRef, QueryResult, RecordAggregation, RecordAggregations, RecordSelection,
 };
 use connector::AggregationResult;
 use indexmap::IndexMap;
@@ -41,6 +41,7 @@ pub fn serialize_internal(
     match result {
         QueryResult::RecordSelection(rs) => serialize_record_selection(rs, field, &field.field_type, is_list),
         QueryResult::RecordAggregation(ra) => serialize_aggregation(ra),
+        QueryResult::RecordAggregations(ras) => serialize_aggregations(ras),
 
         QueryResult::Count(c) => {
             // Todo needs a real implementation or needs to move to RecordAggregation
@@ -60,13 +61,37 @@ pub fn serialize_internal(
 }
 
 fn serialize_aggregation(record_aggregation: RecordAggregation) -> crate::Result<CheckedItemsWithParents> {
-    let ordering = record_aggregation.selection_order;
-    let results = record_aggregation.results;
+    let inner_map = serialize_aggregation_row(&record_aggregation.selection_order, record_aggregation.results);
 
+    let mut envelope = CheckedItemsWithParents::new();
+    envelope.insert(None, Item::Map(inner_map));
+
+    Ok(envelope)
+}
+
+fn serialize_aggregations(record_aggregations: RecordAggregations) -> crate::Result<CheckedItemsWithParents> {
+    let ordering = record_aggregations.selection_order;
+    let rows = record_aggregations
+        .results
+        .into_iter()
+        .map(|row| Item::Map(serialize_aggregation_row(&ordering, row)))
+        .collect();
+
+    let mut envelope = CheckedItemsWithParents::new();
+    envelope.insert(None, Item::list(rows));
+
+    Ok(envelope)
+}
+
+fn serialize_aggregation_row(ordering: &[(String, Option<Vec<String>>)], results: Vec<AggregationResult>) -> Map {
     let mut flattened = HashMap::with_capacity(ordering.len());
 
     for result in results {
         match result {
+            AggregationResult::Field(field, value) => {
+                flattened.insert(field.name.clone(), Item::Value(value));
+            }
+
             AggregationResult::Count(count) => {
                 flattened.insert("count".to_owned(), Item::Value(count));
             }
@@ -97,20 +122,17 @@ fn serialize_aggregation(record_aggregation: RecordAggregation) -> crate::Result
 
             for field in order {
                 let item = flattened.remove(&format!("{}_{}", query, field)).unwrap();
-                nested_map.insert(field, item);
+                nested_map.insert(field.clone(), item);
             }
 
-            inner_map.insert(query, Item::Map(nested_map));
+            inner_map.insert(query.clone(), Item::Map(nested_map));
         } else {
-            let item = flattened.remove(&query).unwrap();
-            inner_map.insert(query, item);
+            let item = flattened.remove(query).unwrap();
+            inner_map.insert(query.clone(), item);
         }
     }
 
-    let mut envelope = CheckedItemsWithParents::new();
-    envelope.insert(None, Item::Map(inner_map));
-
-    Ok(envelope)
+    inner_map
 }
 
 fn serialize_record_selection(
@@ -371,6 +393,16 @@ fn convert_prisma_value(value: PrismaValue, st: &ScalarType) -> Result<PrismaVal
         (ScalarType::DateTime, PrismaValue::DateTime(dt)) => PrismaValue::DateTime(dt),
         (ScalarType::UUID, PrismaValue::Uuid(u)) => PrismaValue::Uuid(u),
 
+        (ScalarType::BigInt, PrismaValue::BigInt(i)) => PrismaValue::BigInt(i),
+        (ScalarType::BigInt, PrismaValue::Int(i)) => PrismaValue::BigInt(i),
+
+        (ScalarType::Bytes, PrismaValue::Bytes(b)) => PrismaValue::Bytes(b),
+
+        (ScalarType::Decimal, PrismaValue::Decimal(d)) => PrismaValue::Decimal(d),
+        (ScalarType::Decimal, PrismaValue::Float(f)) => PrismaValue::Decimal(f),
+        (ScalarType::Decimal, PrismaValue::Int(i)) => PrismaValue::Decimal(i.into()),
+        (ScalarType::Float, PrismaValue::Decimal(d)) => PrismaValue::Float(d),
+
         (st, pv) => {
             return Err(CoreError::SerializationError(format!(
                 "Attempted to serialize scalar '{}' with incompatible type '{:?}'",
@@ -8,6 +8,7 @@ pub enum QueryResult {
     RecordSelection(RecordSelection),
     Json(serde_json::Value),
     RecordAggregation(RecordAggregation),
+    RecordAggregations(RecordAggregations),
     Unit,
 }
 
@@ -42,3 +43,12 @@ pub struct RecordAggregation {
     /// Actual aggregation results.
     pub results: Vec<AggregationResult>,
 }
+
+#[derive(Debug, Clone)]
+pub struct RecordAggregations {
+    /// Ordered list of selected fields as defined by the original incoming query.
+    pub selection_order: Vec<(String, Option<Vec<String>>)>,
+
+    /// Actual aggregation results, one entry per group.
+    pub results: Vec<Vec<AggregationResult>>,
+}
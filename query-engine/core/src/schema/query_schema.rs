@@ -248,12 +248,14 @@ pub enum QueryTag {
     FindFirst,
     FindMany,
     CreateOne,
+    CreateMany,
     UpdateOne,
     UpdateMany,
     DeleteOne,
     DeleteMany,
     UpsertOne,
     Aggregate,
+    GroupBy,
 }
 
 impl fmt::Display for QueryTag {
@@ -263,12 +265,14 @@ impl fmt::Display for QueryTag {
             QueryTag::FindFirst => "findFirst",
             QueryTag::FindMany => "findMany",
             QueryTag::CreateOne => "createOne",
+            QueryTag::CreateMany => "createMany",
             QueryTag::UpdateOne => "updateOne",
             QueryTag::UpdateMany => "updateMany",
             QueryTag::DeleteOne => "deleteOne",
             QueryTag::DeleteMany => "deleteMany",
             QueryTag::UpsertOne => "upsertOne",
             QueryTag::Aggregate => "aggregate",
+            QueryTag::GroupBy => "groupBy",
         };
 
         write!(f, "{}", s)
@@ -466,6 +470,18 @@ impl InputType {
         InputType::Scalar(ScalarType::UUID)
     }
 
+    pub fn big_int() -> InputType {
+        InputType::Scalar(ScalarType::BigInt)
+    }
+
+    pub fn bytes() -> InputType {
+        InputType::Scalar(ScalarType::Bytes)
+    }
+
+    pub fn decimal() -> InputType {
+        InputType::Scalar(ScalarType::Decimal)
+    }
+
     pub fn null() -> InputType {
         InputType::Scalar(ScalarType::Null)
     }
@@ -520,6 +536,18 @@ impl OutputType {
         OutputType::Scalar(ScalarType::UUID)
     }
 
+    pub fn big_int() -> OutputType {
+        OutputType::Scalar(ScalarType::BigInt)
+    }
+
+    pub fn bytes() -> OutputType {
+        OutputType::Scalar(ScalarType::Bytes)
+    }
+
+    pub fn decimal() -> OutputType {
+        OutputType::Scalar(ScalarType::Decimal)
+    }
+
     /// Attempts to recurse through the type until an object type is found.
     /// Returns Some(ObjectTypeStrongRef) if ab object type is found, None otherwise.
     pub fn as_object_type(&self) -> Option<ObjectTypeStrongRef> {
@@ -552,6 +580,9 @@ pub enum ScalarType {
     Json,
     JsonList,
     UUID,
+    BigInt,
+    Bytes,
+    Decimal,
 }
 
 impl From<EnumType> for OutputType {
@@ -29,6 +29,22 @@ pub(crate) fn create_arguments(ctx: &mut BuilderContext, model: &ModelRef) -> Op
     }
 }
 
+/// Builds "data" and "skipDuplicates" arguments intended for the create many field.
+pub(crate) fn create_many_arguments(ctx: &mut BuilderContext, model: &ModelRef) -> Option<Vec<InputField>> {
+    let input_object_type = input_types::create_input_objects::create_many_input_type(ctx, model);
+
+    if input_object_type.into_arc().is_empty() {
+        None
+    } else {
+        let data_type = InputType::object(input_object_type);
+
+        Some(vec![
+            input_field("data", vec![InputType::list(data_type.clone()), data_type], None),
+            input_field("skipDuplicates", InputType::boolean(), None).optional(),
+        ])
+    }
+}
+
 /// Builds "where" (unique) argument intended for the delete field.
 pub(crate) fn delete_arguments(ctx: &mut BuilderContext, model: &ModelRef) -> Option<Vec<InputField>> {
     where_unique_argument(ctx, model).map(|arg| vec![arg])
@@ -92,28 +108,60 @@ pub(crate) fn many_records_field_arguments(ctx: &mut BuilderContext, field: &Mod
 
 /// Builds "many records where" arguments solely based on the given model.
 pub(crate) fn many_records_arguments(ctx: &mut BuilderContext, model: &ModelRef) -> Vec<InputField> {
-    let unique_input_type = InputType::object(input_types::filter_input_objects::where_unique_object_type(ctx, model));
+    let unique_input_type = input_types::filter_input_objects::where_unique_object_type(ctx, model);
+    let mut args = vec![where_argument(ctx, &model), order_by_argument(ctx, &model)];
+
+    // Models without unique fields, i.e. views, can't be paginated with a cursor.
+    if !unique_input_type.into_arc().is_empty() {
+        args.push(input_field("cursor", InputType::object(unique_input_type), None).optional());
+    }
+
+    args.push(input_field("take", InputType::int(), None).optional());
+    args.push(input_field("skip", InputType::int(), None).optional());
 
-    let mut args = vec![
+    let enum_type = scalar_field_enum_type(model, "DistinctFieldEnum");
+
+    args.push(input_field("distinct", InputType::list(InputType::Enum(enum_type)), None).optional());
+    args
+}
+
+/// Builds the arguments of the group by field: "where", "orderBy", "by", "having", "take" and "skip".
+pub(crate) fn group_by_arguments(ctx: &mut BuilderContext, model: &ModelRef) -> Vec<InputField> {
+    let field_enum_type = InputType::Enum(scalar_field_enum_type(model, "ScalarFieldEnum"));
+    let order_object_type = InputType::object(input_types::order_by_object_type_with_aggregates(ctx, model));
+    let having_object_type =
+        InputType::object(input_types::filter_input_objects::scalar_filter_object_type_with_aggregates(ctx, model));
+
+    vec![
         where_argument(ctx, &model),
-        order_by_argument(ctx, &model),
-        input_field("cursor", unique_input_type, None).optional(),
+        input_field(
+            "orderBy",
+            vec![InputType::list(order_object_type.clone()), order_object_type],
+            None,
+        )
+        .optional(),
+        input_field(
+            "by",
+            vec![InputType::list(field_enum_type.clone()), field_enum_type],
+            None,
+        ),
+        input_field("having", having_object_type, None).optional(),
         input_field("take", InputType::int(), None).optional(),
         input_field("skip", InputType::int(), None).optional(),
-    ];
+    ]
+}
 
-    let enum_type = Arc::new(EnumType::FieldRef(FieldRefEnumType {
-        name: format!("{}DistinctFieldEnum", capitalize(&model.name)),
+/// Builds an enum type referencing all scalar fields of the model, named "<Model><suffix>".
+fn scalar_field_enum_type(model: &ModelRef, suffix: &str) -> EnumTypeRef {
+    Arc::new(EnumType::FieldRef(FieldRefEnumType {
+        name: format!("{}{}", capitalize(&model.name), suffix),
         values: model
             .fields()
             .scalar()
             .into_iter()
             .map(|field| (field.name.clone(), field))
             .collect(),
-    }));
-
-    args.push(input_field("distinct", InputType::list(InputType::Enum(enum_type)), None).optional());
-    args
+    }))
 }
 
 // Builds "orderBy" argument.
@@ -12,19 +12,33 @@ pub(crate) fn get_field_filter_types(ctx: &mut BuilderContext, field: &ModelFiel
         }
         ModelField::Scalar(sf) if field.is_list() => vec![InputType::object(scalar_list_filter_type(ctx, sf))],
         ModelField::Scalar(sf) => {
-            let mut types = vec![InputType::object(full_scalar_filter_type(ctx, sf, false))];
+            let mut types = vec![InputType::object(full_scalar_filter_type(ctx, sf, false, false))];
+            types.extend(scalar_filter_shorthand_types(sf));
+            types
+        }
+    }
+}
 
-            if sf.type_identifier != TypeIdentifier::Json {
-                types.push(map_scalar_input_type(sf)); // Scalar equality shorthand
+/// Builds filter types for the given scalar field that additionally allow filtering on aggregates of the field.
+/// Used for filtering groups of records (`having`).
+pub(crate) fn get_field_filter_types_with_aggregates(ctx: &mut BuilderContext, sf: &ScalarFieldRef) -> Vec<InputType> {
+    let mut types = vec![InputType::object(full_scalar_filter_type(ctx, sf, false, true))];
+    types.extend(scalar_filter_shorthand_types(sf));
+    types
+}
 
-                if !sf.is_required {
-                    types.push(InputType::null()); // Scalar null-equality shorthand
-                }
-            }
+fn scalar_filter_shorthand_types(sf: &ScalarFieldRef) -> Vec<InputType> {
+    let mut types = vec![];
 
-            types
+    if sf.type_identifier != TypeIdentifier::Json {
+        types.push(map_scalar_input_type(sf)); // Scalar equality shorthand
+
+        if !sf.is_required {
+            types.push(InputType::null()); // Scalar null-equality shorthand
         }
     }
+
+    types
 }
 
 /// Builds shorthand relation equality (`is`) filter for to-one: `where: { relation_field: { ... } }` (no `is` in between).
@@ -77,7 +91,7 @@ fn full_relation_filter(ctx: &mut BuilderContext, rf: &RelationFieldRef) -> Inpu
 }
 
 fn scalar_list_filter_type(ctx: &mut BuilderContext, sf: &ScalarFieldRef) -> InputObjectTypeWeakRef {
-    let name = scalar_filter_name(sf, false);
+    let name = scalar_filter_name(sf, false, false);
     return_cached_input!(ctx, &name);
 
     let object = Arc::new(init_input_object_type(name.clone()));
@@ -89,8 +103,13 @@ fn scalar_list_filter_type(ctx: &mut BuilderContext, sf: &ScalarFieldRef) -> Inp
     Arc::downgrade(&object)
 }
 
-fn full_scalar_filter_type(ctx: &mut BuilderContext, sf: &ScalarFieldRef, nested: bool) -> InputObjectTypeWeakRef {
-    let name = scalar_filter_name(sf, nested);
+fn full_scalar_filter_type(
+    ctx: &mut BuilderContext,
+    sf: &ScalarFieldRef,
+    nested: bool,
+    with_aggregates: bool,
+) -> InputObjectTypeWeakRef {
+    let name = scalar_filter_name(sf, nested, with_aggregates);
     return_cached_input!(ctx, &name);
 
     let object = Arc::new(init_input_object_type(name.clone()));
@@ -101,15 +120,23 @@ fn full_scalar_filter_type(ctx: &mut BuilderContext, sf: &ScalarFieldRef, nested
             .chain(inclusion_filters(sf))
             .chain(alphanumeric_filters(sf))
             .chain(string_filters(sf))
+            .chain(search_filters(ctx, sf))
             .chain(query_mode_field(ctx, nested))
             .collect(),
 
-        TypeIdentifier::Int | TypeIdentifier::Float | TypeIdentifier::DateTime => equality_filters(sf)
+        TypeIdentifier::Int
+        | TypeIdentifier::BigInt
+        | TypeIdentifier::Float
+        | TypeIdentifier::Decimal
+        | TypeIdentifier::DateTime => equality_filters(sf)
             .chain(inclusion_filters(sf))
             .chain(alphanumeric_filters(sf))
             .collect(),
 
-        TypeIdentifier::Boolean | TypeIdentifier::Json => equality_filters(sf).collect(),
+        TypeIdentifier::Bytes => equality_filters(sf).chain(inclusion_filters(sf)).collect(),
+
+        TypeIdentifier::Json => equality_filters(sf).chain(json_filters(ctx)).collect(),
+        TypeIdentifier::Boolean => equality_filters(sf).collect(),
         TypeIdentifier::Enum(_) => equality_filters(sf).chain(inclusion_filters(sf)).collect(),
     };
 
@@ -118,7 +145,7 @@ fn full_scalar_filter_type(ctx: &mut BuilderContext, sf: &ScalarFieldRef, nested
 
     if sf.type_identifier != TypeIdentifier::Json {
         // Full nested filter. Only available on non-JSON fields.
-        not_types.push(InputType::object(full_scalar_filter_type(ctx, sf, true)));
+        not_types.push(InputType::object(full_scalar_filter_type(ctx, sf, true, false)));
     }
 
     let not_field = input_field("not", not_types, None)
@@ -126,6 +153,11 @@ fn full_scalar_filter_type(ctx: &mut BuilderContext, sf: &ScalarFieldRef, nested
         .nullable_if(!sf.is_required);
 
     fields.push(not_field);
+
+    if with_aggregates {
+        fields.extend(aggregate_filters(ctx, sf));
+    }
+
     object.set_fields(fields);
 
     Arc::downgrade(&object)
@@ -175,6 +207,107 @@ fn string_filters(sf: &ScalarFieldRef) -> impl Iterator<Item = InputField> {
     .into_iter()
 }
 
+/// Full-text search on String fields, using the native search of the database.
+/// Only built for connectors that support full-text search.
+fn search_filters(ctx: &BuilderContext, sf: &ScalarFieldRef) -> impl Iterator<Item = InputField> {
+    let fields = if feature_flags::get().fullTextSearch
+        && ctx.capabilities.contains(ConnectorCapability::FullTextSearch)
+        && sf.type_identifier == TypeIdentifier::String
+    {
+        vec![input_field("search", InputType::string(), None).optional()]
+    } else {
+        vec![]
+    };
+
+    fields.into_iter()
+}
+
+/// Filters on the contents of Json values, optionally at a nested `path`.
+/// Only built for connectors that support filtering on Json values.
+fn json_filters(ctx: &BuilderContext) -> impl Iterator<Item = InputField> {
+    let fields = if feature_flags::get().filterJson && ctx.capabilities.contains(ConnectorCapability::JsonFiltering) {
+        vec![
+            input_field("path", InputType::list(InputType::string()), None).optional(),
+            input_field("string_contains", InputType::string(), None).optional(),
+            input_field("string_starts_with", InputType::string(), None).optional(),
+            input_field("string_ends_with", InputType::string(), None).optional(),
+            input_field("array_contains", InputType::json(), None)
+                .optional()
+                .nullable(),
+            input_field("array_starts_with", InputType::json(), None)
+                .optional()
+                .nullable(),
+            input_field("array_ends_with", InputType::json(), None)
+                .optional()
+                .nullable(),
+        ]
+    } else {
+        vec![]
+    };
+
+    fields.into_iter()
+}
+
+/// Filters on aggregates of the field. Averages and sums are only available on numeric fields,
+/// as are minimum and maximum to mirror the aggregation output types.
+fn aggregate_filters(ctx: &mut BuilderContext, sf: &ScalarFieldRef) -> Vec<InputField> {
+    let mut fields = vec![input_field(
+        "count",
+        InputType::object(aggregate_filter_type(ctx, "Int", InputType::int())),
+        None,
+    )
+    .optional()];
+
+    if matches!(
+        sf.type_identifier,
+        TypeIdentifier::Int | TypeIdentifier::BigInt | TypeIdentifier::Float | TypeIdentifier::Decimal
+    ) && !sf.is_list
+    {
+        let field_filter = InputType::object(full_scalar_filter_type(ctx, sf, true, false));
+
+        fields.push(
+            input_field(
+                "avg",
+                InputType::object(aggregate_filter_type(ctx, "Float", InputType::float())),
+                None,
+            )
+            .optional(),
+        );
+
+        fields.push(input_field("sum", field_filter.clone(), None).optional());
+        fields.push(input_field("min", field_filter.clone(), None).optional());
+        fields.push(input_field("max", field_filter, None).optional());
+    }
+
+    fields
+}
+
+/// Builds a filter object for an aggregated value of a fixed type, e.g. `IntAggregateFilter` for counts.
+fn aggregate_filter_type(ctx: &mut BuilderContext, type_name: &str, typ: InputType) -> InputObjectTypeWeakRef {
+    let name = format!("{}AggregateFilter", type_name);
+    return_cached_input!(ctx, &name);
+
+    let object = Arc::new(init_input_object_type(name.clone()));
+    ctx.cache_input_type(name, object.clone());
+
+    let weak_ref = Arc::downgrade(&object);
+    let list_type = InputType::list(typ.clone());
+
+    let fields = vec![
+        input_field("equals", typ.clone(), None).optional(),
+        input_field("in", list_type.clone(), None).optional(),
+        input_field("notIn", list_type, None).optional(),
+        input_field("lt", typ.clone(), None).optional(),
+        input_field("lte", typ.clone(), None).optional(),
+        input_field("gt", typ.clone(), None).optional(),
+        input_field("gte", typ.clone(), None).optional(),
+        input_field("not", vec![typ, InputType::object(weak_ref.clone())], None).optional(),
+    ];
+
+    object.set_fields(fields);
+    weak_ref
+}
+
 fn query_mode_field(ctx: &BuilderContext, nested: bool) -> impl Iterator<Item = InputField> {
     // Limit query mode field to the topmost filter level.
     // Only build mode field for connectors with insensitive filter support.
@@ -199,19 +332,23 @@ fn query_mode_field(ctx: &BuilderContext, nested: bool) -> impl Iterator<Item =
     fields.into_iter()
 }
 
-fn scalar_filter_name(sf: &ScalarFieldRef, nested: bool) -> String {
+fn scalar_filter_name(sf: &ScalarFieldRef, nested: bool, with_aggregates: bool) -> String {
     let list = if sf.is_list { "List" } else { "" };
     let nullable = if sf.is_required { "" } else { "Nullable" };
     let nested = if nested { "Nested" } else { "" };
+    let aggregates = if with_aggregates { "WithAggregates" } else { "" };
 
     match sf.type_identifier {
-        TypeIdentifier::UUID => format!("{}Uuid{}{}Filter", nested, nullable, list),
-        TypeIdentifier::String => format!("{}String{}{}Filter", nested, nullable, list),
-        TypeIdentifier::Int => format!("{}Int{}{}Filter", nested, nullable, list),
-        TypeIdentifier::Float => format!("{}Float{}{}Filter", nested, nullable, list),
-        TypeIdentifier::Boolean => format!("{}Bool{}{}Filter", nested, nullable, list),
-        TypeIdentifier::DateTime => format!("{}DateTime{}{}Filter", nested, nullable, list),
-        TypeIdentifier::Json => format!("{}Json{}{}Filter", nested, nullable, list),
-        TypeIdentifier::Enum(ref e) => format!("{}Enum{}{}{}Filter", nested, e, nullable, list),
+        TypeIdentifier::UUID => format!("{}Uuid{}{}{}Filter", nested, nullable, list, aggregates),
+        TypeIdentifier::String => format!("{}String{}{}{}Filter", nested, nullable, list, aggregates),
+        TypeIdentifier::Int => format!("{}Int{}{}{}Filter", nested, nullable, list, aggregates),
+        TypeIdentifier::BigInt => format!("{}BigInt{}{}{}Filter", nested, nullable, list, aggregates),
+        TypeIdentifier::Float => format!("{}Float{}{}{}Filter", nested, nullable, list, aggregates),
+        TypeIdentifier::Decimal => format!("{}Decimal{}{}{}Filter", nested, nullable, list, aggregates),
+        TypeIdentifier::Boolean => format!("{}Bool{}{}{}Filter", nested, nullable, list, aggregates),
+        TypeIdentifier::DateTime => format!("{}DateTime{}{}{}Filter", nested, nullable, list, aggregates),
+        TypeIdentifier::Json => format!("{}Json{}{}{}Filter", nested, nullable, list, aggregates),
+        TypeIdentifier::Bytes => format!("{}Bytes{}{}{}Filter", nested, nullable, list, aggregates),
+        TypeIdentifier::Enum(ref e) => format!("{}Enum{}{}{}{}Filter", nested, e, nullable, list, aggregates),
     }
 }
@@ -36,6 +36,45 @@ pub(crate) fn scalar_filter_object_type(ctx: &mut BuilderContext, model: &ModelR
     weak_ref
 }
 
+/// Builds "<Model>ScalarWhereWithAggregatesInput" object types, used to filter groups of records.
+pub(crate) fn scalar_filter_object_type_with_aggregates(
+    ctx: &mut BuilderContext,
+    model: &ModelRef,
+) -> InputObjectTypeWeakRef {
+    let object_name = format!("{}ScalarWhereWithAggregatesInput", model.name);
+    return_cached_input!(ctx, &object_name);
+
+    let input_object = Arc::new(init_input_object_type(object_name.clone()));
+    ctx.cache_input_type(object_name, input_object.clone());
+
+    let weak_ref = Arc::downgrade(&input_object);
+    let object_type = InputType::object(weak_ref.clone());
+
+    let mut input_fields = vec![
+        input_field(
+            "AND",
+            vec![object_type.clone(), InputType::list(object_type.clone())],
+            None,
+        )
+        .optional(),
+        input_field(
+            "OR",
+            vec![object_type.clone(), InputType::list(object_type.clone())],
+            None,
+        )
+        .optional(),
+        input_field("NOT", vec![object_type.clone(), InputType::list(object_type)], None).optional(),
+    ];
+
+    input_fields.extend(model.fields().scalar().into_iter().filter(|sf| !sf.is_list).map(|sf| {
+        let types = field_filter_types::get_field_filter_types_with_aggregates(ctx, &sf);
+        input_field(sf.name.clone(), types, None).optional()
+    }));
+
+    input_object.set_fields(input_fields);
+    weak_ref
+}
+
 pub(crate) fn where_object_type(ctx: &mut BuilderContext, model: &ModelRef) -> InputObjectTypeWeakRef {
     let name = format!("{}WhereInput", model.name);
     return_cached_input!(ctx, &name);
@@ -62,11 +101,13 @@ pub(crate) fn where_object_type(ctx: &mut BuilderContext, model: &ModelRef) -> I
         input_field("NOT", vec![object_type.clone(), InputType::list(object_type)], None).optional(),
     ];
 
+    // Relations to models in other datasources can't be part of the query on this model.
     fields.extend(
         model
             .fields()
             .all
             .iter()
+            .filter(|f| !matches!(f, ModelField::Relation(rf) if rf.spans_datasources()))
             .map(|f| input_fields::filter_input_field(ctx, f)),
     );
 
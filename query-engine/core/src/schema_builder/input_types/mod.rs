@@ -6,6 +6,7 @@ pub(crate) mod update_input_objects;
 
 use super::*;
 use crate::schema::*;
+use datamodel_connector::ConnectorCapability;
 use prisma_models::{RelationFieldRef, ScalarFieldRef};
 
 /// Builds "<Model>OrderByInput" object types.
@@ -21,13 +22,204 @@ pub(crate) fn order_by_object_type(ctx: &mut BuilderContext, model: &ModelRef) -
     let input_object = Arc::new(input_object);
     ctx.cache_input_type(name, input_object.clone());
 
-    let fields = model
+    let mut fields: Vec<_> = model
         .fields()
         .scalar()
         .iter()
         .map(|sf| input_field(sf.name.clone(), InputType::Enum(enum_type.clone()), None).optional())
         .collect();
 
+    if feature_flags::get().orderByRelation {
+        for rf in model
+            .fields()
+            .relation()
+            .into_iter()
+            .filter(|rf| !rf.spans_datasources())
+        {
+            let related_model = rf.related_model();
+            let object_type = if rf.is_list {
+                order_by_relation_aggregate_object_type(ctx, &related_model, &enum_type)
+            } else {
+                order_by_object_type(ctx, &related_model)
+            };
+
+            fields.push(input_field(rf.name.clone(), InputType::object(object_type), None).optional());
+        }
+    }
+
+    if let Some(object_type) = order_by_relevance_object_type(ctx, model, &enum_type) {
+        fields.push(input_field("_relevance", InputType::object(object_type), None).optional());
+    }
+
+    input_object.set_fields(fields);
+    Arc::downgrade(&input_object)
+}
+
+/// Builds "<Model>OrderByRelevanceInput" object types. Allows ordering records by the relevance
+/// of a full-text search on String fields, e.g. `{ _relevance: { fields: [title], search: "cat", sort: desc } }`.
+/// Only built for connectors that support full-text search with relevance.
+fn order_by_relevance_object_type(
+    ctx: &mut BuilderContext,
+    model: &ModelRef,
+    enum_type: &EnumTypeRef,
+) -> Option<InputObjectTypeWeakRef> {
+    if !feature_flags::get().fullTextSearch || !ctx.capabilities.contains(ConnectorCapability::FullTextRelevance) {
+        return None;
+    }
+
+    let string_fields: Vec<_> = model
+        .fields()
+        .scalar()
+        .into_iter()
+        .filter(|sf| sf.type_identifier == TypeIdentifier::String && !sf.is_list)
+        .map(|sf| (sf.name.clone(), sf))
+        .collect();
+
+    if string_fields.is_empty() {
+        return None;
+    }
+
+    let name = format!("{}OrderByRelevanceInput", model.name);
+    if let Some(cached) = ctx.get_input_type(&name) {
+        return Some(cached);
+    }
+
+    let input_object = Arc::new(init_input_object_type(name.clone()));
+    ctx.cache_input_type(name, input_object.clone());
+
+    let fields_enum_type = Arc::new(EnumType::FieldRef(FieldRefEnumType {
+        name: format!("{}OrderByRelevanceFieldEnum", model.name),
+        values: string_fields,
+    }));
+
+    let fields = vec![
+        input_field(
+            "fields",
+            vec![
+                InputType::list(InputType::Enum(fields_enum_type.clone())),
+                InputType::Enum(fields_enum_type),
+            ],
+            None,
+        ),
+        input_field("sort", InputType::Enum(enum_type.clone()), None),
+        input_field("search", InputType::string(), None),
+    ];
+
+    input_object.set_fields(fields);
+    Some(Arc::downgrade(&input_object))
+}
+
+/// Builds "<Model>OrderByRelationAggregateInput" object types. Allows ordering records
+/// by the number of related records on a to-many relation, e.g. `{ posts: { count: desc } }`.
+fn order_by_relation_aggregate_object_type(
+    ctx: &mut BuilderContext,
+    model: &ModelRef,
+    enum_type: &EnumTypeRef,
+) -> InputObjectTypeWeakRef {
+    let name = format!("{}OrderByRelationAggregateInput", model.name);
+    return_cached_input!(ctx, &name);
+
+    let input_object = Arc::new(init_input_object_type(name.clone()));
+    ctx.cache_input_type(name, input_object.clone());
+
+    let fields = vec![input_field("count", InputType::Enum(enum_type.clone()), None).optional()];
+
+    input_object.set_fields(fields);
+    Arc::downgrade(&input_object)
+}
+
+/// Builds "<Model>OrderByWithAggregationInput" object types. Allows ordering groups of
+/// records by the grouped fields and by aggregates of fields, e.g. `{ avg: { age: asc } }`.
+pub(crate) fn order_by_object_type_with_aggregates(
+    ctx: &mut BuilderContext,
+    model: &ModelRef,
+) -> InputObjectTypeWeakRef {
+    let enum_type = Arc::new(string_enum_type("SortOrder", vec!["asc".to_owned(), "desc".to_owned()]));
+    let name = format!("{}OrderByWithAggregationInput", model.name);
+
+    return_cached_input!(ctx, &name);
+
+    let mut input_object = init_input_object_type(name.clone());
+    input_object.allow_at_most_one_field();
+
+    let input_object = Arc::new(input_object);
+    ctx.cache_input_type(name, input_object.clone());
+
+    let scalar_fields = model.fields().scalar();
+    let numeric_fields: Vec<_> = scalar_fields
+        .iter()
+        .filter(|sf| {
+            matches!(
+                sf.type_identifier,
+                TypeIdentifier::Int | TypeIdentifier::BigInt | TypeIdentifier::Float | TypeIdentifier::Decimal
+            ) && !sf.is_list
+        })
+        .cloned()
+        .collect();
+
+    let mut fields: Vec<_> = scalar_fields
+        .iter()
+        .map(|sf| input_field(sf.name.clone(), InputType::Enum(enum_type.clone()), None).optional())
+        .collect();
+
+    fields.push(aggregate_order_by_field(
+        ctx,
+        model,
+        "count",
+        &scalar_fields,
+        &enum_type,
+    ));
+
+    if !numeric_fields.is_empty() {
+        for aggregation in &["avg", "sum", "min", "max"] {
+            fields.push(aggregate_order_by_field(
+                ctx,
+                model,
+                aggregation,
+                &numeric_fields,
+                &enum_type,
+            ));
+        }
+    }
+
+    input_object.set_fields(fields);
+    Arc::downgrade(&input_object)
+}
+
+/// Builds an order by field on an aggregate (e.g. "avg") with an object of the given fields to order by.
+fn aggregate_order_by_field(
+    ctx: &mut BuilderContext,
+    model: &ModelRef,
+    aggregation: &str,
+    fields: &[ScalarFieldRef],
+    enum_type: &EnumTypeRef,
+) -> InputField {
+    let object_type = aggregate_order_by_object_type(ctx, model, aggregation, fields, enum_type);
+    input_field(aggregation, InputType::object(object_type), None).optional()
+}
+
+/// Builds "<Model><Aggregation>OrderByAggregateInput" object types.
+fn aggregate_order_by_object_type(
+    ctx: &mut BuilderContext,
+    model: &ModelRef,
+    aggregation: &str,
+    fields: &[ScalarFieldRef],
+    enum_type: &EnumTypeRef,
+) -> InputObjectTypeWeakRef {
+    let name = format!("{}{}OrderByAggregateInput", model.name, capitalize(aggregation));
+    return_cached_input!(ctx, &name);
+
+    let mut input_object = init_input_object_type(name.clone());
+    input_object.allow_at_most_one_field();
+
+    let input_object = Arc::new(input_object);
+    ctx.cache_input_type(name, input_object.clone());
+
+    let fields = fields
+        .iter()
+        .map(|sf| input_field(sf.name.clone(), InputType::Enum(enum_type.clone()), None).optional())
+        .collect();
+
     input_object.set_fields(fields);
     Arc::downgrade(&input_object)
 }
@@ -36,11 +228,14 @@ fn map_scalar_input_type(field: &ScalarFieldRef) -> InputType {
     let typ = match field.type_identifier {
         TypeIdentifier::String => InputType::string(),
         TypeIdentifier::Int => InputType::int(),
+        TypeIdentifier::BigInt => InputType::big_int(),
         TypeIdentifier::Float => InputType::float(),
+        TypeIdentifier::Decimal => InputType::decimal(),
         TypeIdentifier::Boolean => InputType::boolean(),
         TypeIdentifier::UUID => InputType::uuid(),
         TypeIdentifier::DateTime => InputType::date_time(),
         TypeIdentifier::Json => InputType::json(),
+        TypeIdentifier::Bytes => InputType::bytes(),
         TypeIdentifier::Enum(_) => map_enum_input_type(&field),
     };
 
@@ -54,13 +54,16 @@ fn non_list_scalar_update_field_mapper(
 ) -> InputField {
     let base_update_type = match &field.type_identifier {
         TypeIdentifier::Float => InputType::object(operations_object_type(ctx, "Float", field, true)),
+        TypeIdentifier::Decimal => InputType::object(operations_object_type(ctx, "Decimal", field, true)),
         TypeIdentifier::Int => InputType::object(operations_object_type(ctx, "Int", field, true)),
+        TypeIdentifier::BigInt => InputType::object(operations_object_type(ctx, "BigInt", field, true)),
         TypeIdentifier::String => InputType::object(operations_object_type(ctx, "String", field, false)),
         TypeIdentifier::Boolean => InputType::object(operations_object_type(ctx, "Bool", field, false)),
         TypeIdentifier::Enum(e) => InputType::object(operations_object_type(ctx, &format!("Enum{}", e), field, false)),
         TypeIdentifier::Json => map_scalar_input_type(field),
         TypeIdentifier::DateTime => InputType::object(operations_object_type(ctx, "DateTime", field, false)),
         TypeIdentifier::UUID => InputType::object(operations_object_type(ctx, "Uuid", field, false)),
+        TypeIdentifier::Bytes => InputType::object(operations_object_type(ctx, "Bytes", field, false)),
     };
 
     let input_field = if field.type_identifier != TypeIdentifier::Json {
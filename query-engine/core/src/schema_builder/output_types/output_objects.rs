@@ -75,12 +75,15 @@ pub(crate) fn map_output_type(ctx: &mut BuilderContext, model_field: &ModelField
         ModelField::Scalar(sf) => match sf.type_identifier {
             TypeIdentifier::String => OutputType::string(),
             TypeIdentifier::Float => OutputType::float(),
+            TypeIdentifier::Decimal => OutputType::decimal(),
             TypeIdentifier::Boolean => OutputType::boolean(),
             TypeIdentifier::Enum(_) => map_enum_field(sf).into(),
             TypeIdentifier::Json => OutputType::json(),
             TypeIdentifier::DateTime => OutputType::date_time(),
             TypeIdentifier::UUID => OutputType::uuid(),
             TypeIdentifier::Int => OutputType::int(),
+            TypeIdentifier::BigInt => OutputType::big_int(),
+            TypeIdentifier::Bytes => OutputType::bytes(),
         },
     };
 
@@ -141,6 +144,47 @@ pub(crate) fn aggregation_object_type(ctx: &mut BuilderContext, model: &ModelRef
     ObjectTypeStrongRef::downgrade(&object)
 }
 
+/// Builds group by object type for given model (e.g. UserGroupByOutputType).
+/// Contains the scalar fields of the model that can be grouped by, as well as all aggregations.
+pub(crate) fn group_by_output_object_type(ctx: &mut BuilderContext, model: &ModelRef) -> ObjectTypeWeakRef {
+    let name = format!("{}GroupByOutputType", capitalize(&model.name));
+    return_cached_output!(ctx, &name);
+
+    let object = ObjectTypeStrongRef::new(ObjectType::new(&name, Some(ModelRef::clone(model))));
+
+    // Grouped fields are only present if they are part of the `by` argument.
+    let mut fields: Vec<OutputField> = model
+        .fields()
+        .scalar()
+        .into_iter()
+        .map(|sf| {
+            field(
+                sf.name.clone(),
+                vec![],
+                map_output_type(ctx, &ModelField::Scalar(sf)),
+                None,
+            )
+            .optional()
+        })
+        .collect();
+
+    fields.push(count_field());
+
+    append_opt(
+        &mut fields,
+        numeric_aggregation_field(ctx, "avg", &model, Some(OutputType::float())),
+    );
+
+    append_opt(&mut fields, numeric_aggregation_field(ctx, "sum", &model, None));
+    append_opt(&mut fields, numeric_aggregation_field(ctx, "min", &model, None));
+    append_opt(&mut fields, numeric_aggregation_field(ctx, "max", &model, None));
+
+    object.set_fields(fields);
+    ctx.cache_output_type(name, ObjectTypeStrongRef::clone(&object));
+
+    ObjectTypeStrongRef::downgrade(&object)
+}
+
 pub(crate) fn count_field() -> OutputField {
     field("count", vec![], OutputType::int(), None)
 }
@@ -210,7 +254,9 @@ fn collect_numeric_fields(model: &ModelRef) -> Vec<ScalarFieldRef> {
         .into_iter()
         .filter(|f| match f.type_identifier {
             TypeIdentifier::Int => true,
+            TypeIdentifier::BigInt => true,
             TypeIdentifier::Float => true,
+            TypeIdentifier::Decimal => true,
             _ => false,
         })
         .collect()
@@ -1,7 +1,7 @@
 use super::*;
 use crate::{
-    AggregateRecordsBuilder, Builder, Query, QueryGraph, ReadFirstRecordBuilder, ReadManyRecordsBuilder,
-    ReadOneRecordBuilder,
+    AggregateRecordsBuilder, Builder, GroupByRecordsBuilder, Query, QueryGraph, ReadFirstRecordBuilder,
+    ReadManyRecordsBuilder, ReadOneRecordBuilder,
 };
 
 /// Builds the root `Query` type.
@@ -17,6 +17,11 @@ pub(crate) fn build(ctx: &mut BuilderContext) -> (OutputType, ObjectTypeStrongRe
             ];
 
             append_opt(&mut vec, find_one_field(ctx, &model));
+
+            if feature_flags::get().groupBy {
+                vec.push(group_by_field(ctx, &model));
+            }
+
             vec
         })
         .flatten()
@@ -125,3 +130,31 @@ fn aggregation_field(ctx: &mut BuilderContext, model: &ModelRef) -> OutputField
         ))),
     )
 }
+
+/// Builds a "group by" aggregation query field (e.g. "groupByUser") for given model.
+fn group_by_field(ctx: &mut BuilderContext, model: &ModelRef) -> OutputField {
+    let args = arguments::group_by_arguments(ctx, &model);
+    let field_name = ctx.pluralize_internal(
+        format!("groupBy{}", model.name), // Has no legacy counterpart.
+        format!("groupBy{}", model.name),
+    );
+
+    field(
+        field_name,
+        args,
+        OutputType::list(OutputType::object(output_objects::group_by_output_object_type(
+            ctx, &model,
+        ))),
+        Some(SchemaQueryBuilder::ModelQueryBuilder(ModelQueryBuilder::new(
+            model.clone(),
+            QueryTag::GroupBy,
+            Box::new(|model, parsed_field| {
+                let mut graph = QueryGraph::new();
+                let query = GroupByRecordsBuilder::new(parsed_field, model).build()?;
+
+                graph.create_node(Query::Read(query));
+                Ok(graph)
+            }),
+        ))),
+    )
+}
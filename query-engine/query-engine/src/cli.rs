@@ -10,7 +10,7 @@ use crate::{
 use datamodel::{Configuration, Datamodel};
 use datamodel_connector::ConnectorCapabilities;
 use prisma_models::DatamodelConverter;
-use query_core::{schema::QuerySchemaRef, schema_builder, BuildMode};
+use query_core::{schema::QuerySchemaRef, schema_builder, BuildMode, ReadPreference};
 use std::sync::Arc;
 
 pub struct ExecuteRequest {
@@ -134,7 +134,7 @@ impl CliCommand {
         let cx = Arc::new(cx);
 
         let body: GraphQlBody = serde_json::from_str(&decoded_request)?;
-        let res = graphql::handle(body, cx).await;
+        let res = graphql::handle(body, ReadPreference::default(), cx).await;
         let res = serde_json::to_string(&res).unwrap();
 
         let encoded_response = base64::encode(&res);
@@ -1,10 +1,15 @@
-use crate::{exec_loader, PrismaError, PrismaResult};
+use crate::{
+    exec_loader,
+    transactions::{TransactionRegistry, TxId},
+    PrismaError, PrismaResult,
+};
 use datamodel::{Configuration, Datamodel};
 use prisma_models::DatamodelConverter;
-use query_core::{schema::QuerySchemaRef, schema_builder, BuildMode, QueryExecutor};
-use std::sync::Arc;
+use query_core::{schema::QuerySchemaRef, schema_builder, BuildMode, QueryExecutor, DEFAULT_STREAM_BATCH_SIZE};
+use std::{collections::HashMap, sync::Arc, time::Duration};
 
-/// Prisma request context containing all immutable state of the process.
+/// Prisma request context containing all immutable state of the process,
+/// as well as the interactive transactions that are currently open.
 /// There is usually only one context initialized per process.
 pub struct PrismaContext {
     /// The api query schema.
@@ -13,11 +18,14 @@ pub struct PrismaContext {
     dm: Datamodel,
     /// Central query executor.
     pub executor: Box<dyn QueryExecutor + Send + Sync + 'static>,
+    /// Open interactive transactions.
+    transactions: TransactionRegistry,
 }
 
 pub struct ContextBuilder {
     legacy: bool,
     enable_raw_queries: bool,
+    stream_batch_size: usize,
     datamodel: Datamodel,
     config: Configuration,
 }
@@ -33,24 +41,52 @@ impl ContextBuilder {
         self
     }
 
+    /// Sets the number of records fetched from the database per batch when streaming.
+    pub fn stream_batch_size(mut self, val: usize) -> Self {
+        self.stream_batch_size = val;
+        self
+    }
+
     pub async fn build(self) -> PrismaResult<PrismaContext> {
-        PrismaContext::new(self.config, self.datamodel, self.legacy, self.enable_raw_queries).await
+        PrismaContext::new(
+            self.config,
+            self.datamodel,
+            self.legacy,
+            self.enable_raw_queries,
+            self.stream_batch_size,
+        )
+        .await
     }
 }
 
 impl PrismaContext {
     /// Initializes a new Prisma context.
-    async fn new(config: Configuration, dm: Datamodel, legacy: bool, enable_raw_queries: bool) -> PrismaResult<Self> {
-        let template = DatamodelConverter::convert(&dm);
-
-        // We only support one data source at the moment, so take the first one (default not exposed yet).
-        let data_source = config
+    async fn new(
+        config: Configuration,
+        dm: Datamodel,
+        legacy: bool,
+        enable_raw_queries: bool,
+        stream_batch_size: usize,
+    ) -> PrismaResult<Self> {
+        let mut template = DatamodelConverter::convert(&dm);
+
+        // The first data source is the default one, models are only stored in the others if they say so.
+        let (data_source, other_data_sources) = config
             .datasources
-            .first()
+            .split_first()
             .ok_or_else(|| PrismaError::ConfigurationError("No valid data source found".into()))?;
 
+        let mut datasources = HashMap::new();
+
+        for source in other_data_sources {
+            let (db_name, connector) = exec_loader::load_datasource(source).await?;
+
+            template.datasource_db_names.insert(source.name.clone(), db_name);
+            datasources.insert(source.name.clone(), connector);
+        }
+
         // Load executor
-        let (db_name, executor) = exec_loader::load(&data_source).await?;
+        let (db_name, executor) = exec_loader::load(data_source, datasources, stream_batch_size).await?;
 
         // Build internal data model
         let internal_data_model = template.build(db_name);
@@ -68,6 +104,7 @@ impl PrismaContext {
             query_schema,
             dm,
             executor,
+            transactions: TransactionRegistry::default(),
         };
 
         context.verify_connection().await?;
@@ -84,6 +121,7 @@ impl PrismaContext {
         ContextBuilder {
             legacy: false,
             enable_raw_queries: false,
+            stream_batch_size: DEFAULT_STREAM_BATCH_SIZE,
             datamodel,
             config,
         }
@@ -100,4 +138,22 @@ impl PrismaContext {
     pub fn primary_connector(&self) -> String {
         self.executor.primary_connector().name()
     }
+
+    pub fn transactions(&self) -> &TransactionRegistry {
+        &self.transactions
+    }
+
+    /// Starts an interactive transaction that is rolled back automatically after `timeout`.
+    pub async fn start_transaction(&self, timeout: Duration) -> PrismaResult<TxId> {
+        let tx = self.executor.start_transaction().await?;
+        Ok(self.transactions.insert(tx, timeout))
+    }
+
+    pub async fn commit_transaction(&self, id: &str) -> PrismaResult<()> {
+        self.transactions.commit(id).await
+    }
+
+    pub async fn rollback_transaction(&self, id: &str) -> PrismaResult<()> {
+        self.transactions.rollback(id).await
+    }
 }
@@ -44,6 +44,9 @@ pub enum PrismaError {
 
     #[error("{}", _0)]
     FeatureError(String),
+
+    #[error("{}", _0)]
+    InteractiveTransactionError(String),
 }
 
 impl PrismaError {
@@ -12,28 +12,99 @@ use url::Url;
 #[cfg(feature = "sql")]
 use sql_connector::*;
 
-pub async fn load(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
+/// Loads the executor of the first datasource. The connectors to the other datasources are
+/// passed on to the executor, which executes the queries of the models stored in them.
+pub async fn load(
+    source: &Datasource,
+    datasources: HashMap<String, Box<dyn Connector + Send + Sync + 'static>>,
+    stream_batch_size: usize,
+) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
     match source.active_provider.as_str() {
         #[cfg(feature = "sql")]
-        SQLITE_SOURCE_NAME => sqlite(source).await,
+        SQLITE_SOURCE_NAME => {
+            replicas_unsupported(source, "SQLite")?;
+
+            let (db_name, sqlite) = sqlite(source).await?;
+            Ok((
+                db_name,
+                sql_executor(sqlite, Vec::new(), false, datasources, stream_batch_size),
+            ))
+        }
 
         #[cfg(feature = "sql")]
-        MYSQL_SOURCE_NAME => mysql(source).await,
+        MYSQL_SOURCE_NAME => {
+            let (db_name, mysql) = mysql(source).await?;
+            let replicas = Mysql::replicas_from_source(source)?;
+
+            Ok((
+                db_name,
+                sql_executor(mysql, replicas, false, datasources, stream_batch_size),
+            ))
+        }
 
         #[cfg(feature = "sql")]
-        POSTGRES_SOURCE_NAME => postgres(source).await,
+        POSTGRES_SOURCE_NAME => {
+            let (db_name, psql, force_transactions) = postgres(source).await?;
+            let replicas = PostgreSql::replicas_from_source(source)?;
+
+            Ok((
+                db_name,
+                sql_executor(psql, replicas, force_transactions, datasources, stream_batch_size),
+            ))
+        }
 
         #[cfg(feature = "sql")]
         MSSQL_SOURCE_NAME => {
-            if !feature_flags::get().microsoftSqlServer {
-                let error = query_core::CoreError::UnsupportedFeatureError(
-                    "Microsoft SQL Server (experimental feature, needs to be enabled)".into(),
-                );
+            replicas_unsupported(source, "SQL Server")?;
+
+            let (db_name, mssql) = mssql(source).await?;
+            Ok((
+                db_name,
+                sql_executor(mssql, Vec::new(), false, datasources, stream_batch_size),
+            ))
+        }
+
+        x => Err(PrismaError::ConfigurationError(format!(
+            "Unsupported connector type: {}",
+            x
+        ))),
+    }
+}
+
+/// Loads the connector of a datasource other than the first one, along with its db name.
+pub async fn load_datasource(
+    source: &Datasource,
+) -> PrismaResult<(String, Box<dyn Connector + Send + Sync + 'static>)> {
+    if !source.replica_urls.is_empty() {
+        return Err(PrismaError::ConfigurationError(format!(
+            "Read replicas are only supported on the first datasource, but are configured on `{}`.",
+            source.name
+        )));
+    }
+
+    match source.active_provider.as_str() {
+        #[cfg(feature = "sql")]
+        SQLITE_SOURCE_NAME => {
+            let (db_name, sqlite) = sqlite(source).await?;
+            Ok((db_name, Box::new(sqlite)))
+        }
+
+        #[cfg(feature = "sql")]
+        MYSQL_SOURCE_NAME => {
+            let (db_name, mysql) = mysql(source).await?;
+            Ok((db_name, Box::new(mysql)))
+        }
 
-                return Err(PrismaError::CoreError(error));
-            }
+        #[cfg(feature = "sql")]
+        POSTGRES_SOURCE_NAME => {
+            let (db_name, psql, _) = postgres(source).await?;
+            Ok((db_name, Box::new(psql)))
+        }
 
-            mssql(source).await
+        #[cfg(feature = "sql")]
+        MSSQL_SOURCE_NAME => {
+            let (db_name, mssql) = mssql(source).await?;
+            Ok((db_name, Box::new(mssql)))
         }
 
         x => Err(PrismaError::ConfigurationError(format!(
@@ -44,7 +115,7 @@ pub async fn load(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExe
 }
 
 #[cfg(feature = "sql")]
-async fn sqlite(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
+async fn sqlite(source: &Datasource) -> PrismaResult<(String, Sqlite)> {
     trace!("Loading SQLite connector...");
 
     let sqlite = Sqlite::from_source(source).await?;
@@ -52,11 +123,13 @@ async fn sqlite(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecu
     let db_name = path.file_stem().unwrap().to_str().unwrap().to_owned(); // Safe due to previous validations.
 
     trace!("Loaded SQLite connector.");
-    Ok((db_name, sql_executor(sqlite, false)))
+    Ok((db_name, sqlite))
 }
 
+/// Also returns whether the connection goes through PgBouncer, which forces
+/// individual operations to run in a transaction.
 #[cfg(feature = "sql")]
-async fn postgres(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
+async fn postgres(source: &Datasource) -> PrismaResult<(String, PostgreSql, bool)> {
     trace!("Loading Postgres connector...");
 
     let url = Url::parse(&source.url().value)?;
@@ -75,11 +148,11 @@ async fn postgres(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExe
         .unwrap_or(false);
 
     trace!("Loaded Postgres connector.");
-    Ok((db_name, sql_executor(psql, force_transactions)))
+    Ok((db_name, psql, force_transactions))
 }
 
 #[cfg(feature = "sql")]
-async fn mysql(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
+async fn mysql(source: &Datasource) -> PrismaResult<(String, Mysql)> {
     trace!("Loading MySQL connector...");
 
     let mysql = Mysql::from_source(source).await?;
@@ -93,11 +166,19 @@ async fn mysql(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecut
     let db_name = db_name.next().expect(err_str).to_owned();
 
     trace!("Loaded MySQL connector.");
-    Ok((db_name, sql_executor(mysql, false)))
+    Ok((db_name, mysql))
 }
 
 #[cfg(feature = "sql")]
-async fn mssql(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
+async fn mssql(source: &Datasource) -> PrismaResult<(String, Mssql)> {
+    if !feature_flags::get().microsoftSqlServer {
+        let error = query_core::CoreError::UnsupportedFeatureError(
+            "Microsoft SQL Server (experimental feature, needs to be enabled)".into(),
+        );
+
+        return Err(PrismaError::CoreError(error));
+    }
+
     trace!("Loading SQL Server connector...");
 
     let mssql = Mssql::from_source(source).await?;
@@ -118,13 +199,36 @@ async fn mssql(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecut
     let db_name = params.remove("schema").unwrap_or_else(|| String::from("dbo"));
 
     trace!("Loaded SQL Server connector.");
-    Ok((db_name, sql_executor(mssql, false)))
+    Ok((db_name, mssql))
 }
 
 #[cfg(feature = "sql")]
-fn sql_executor<T>(connector: T, force_transactions: bool) -> Box<dyn QueryExecutor + Send + Sync + 'static>
+fn sql_executor<T>(
+    connector: T,
+    replicas: Vec<T>,
+    force_transactions: bool,
+    datasources: HashMap<String, Box<dyn Connector + Send + Sync + 'static>>,
+    stream_batch_size: usize,
+) -> Box<dyn QueryExecutor + Send + Sync + 'static>
 where
     T: Connector + Send + Sync + 'static,
 {
-    Box::new(InterpretingExecutor::new(connector, force_transactions))
+    Box::new(
+        InterpretingExecutor::new(connector, force_transactions)
+            .with_replicas(replicas)
+            .with_datasources(datasources)
+            .with_stream_batch_size(stream_batch_size),
+    )
+}
+
+#[cfg(feature = "sql")]
+fn replicas_unsupported(source: &Datasource, connector_name: &str) -> PrismaResult<()> {
+    if source.replica_urls.is_empty() {
+        Ok(())
+    } else {
+        Err(PrismaError::ConfigurationError(format!(
+            "Read replicas are not supported by the {} connector.",
+            connector_name
+        )))
+    }
 }
@@ -9,6 +9,7 @@ pub mod exec_loader;
 pub mod opt;
 pub mod request_handlers;
 pub mod server;
+pub mod transactions;
 
 use error::*;
 use std::error::Error;
@@ -1,9 +1,12 @@
 use super::{protocol_adapter::GraphQLProtocolAdapter, GQLResponse};
-use crate::{context::PrismaContext, PrismaResponse, PrismaResult};
+use crate::{context::PrismaContext, transactions, PrismaError, PrismaResponse, PrismaResult};
 use futures::FutureExt;
 use graphql_parser as gql;
 use indexmap::IndexMap;
-use query_core::{BatchDocument, CompactedDocument, Item, Operation, QueryDocument, QueryValue, ResponseData};
+use query_core::{
+    BatchDocument, CompactedDocument, Item, Operation, QueryDocument, QueryValue, ReadPreference, ResponseData,
+    ResponseStream,
+};
 use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, panic::AssertUnwindSafe, sync::Arc};
 
@@ -74,24 +77,104 @@ impl GraphQlBody {
     }
 }
 
-/// Handle a Graphql request.
-pub async fn handle(body: GraphQlBody, cx: Arc<PrismaContext>) -> PrismaResponse {
+/// Handle a Graphql request. Read operations are executed according to `read_preference`
+/// if the datasource has read replicas.
+pub async fn handle(body: GraphQlBody, read_preference: ReadPreference, cx: Arc<PrismaContext>) -> PrismaResponse {
     debug!("Incoming GraphQL query: {:?}", body);
 
     match body.into_doc() {
-        Ok(QueryDocument::Single(query)) => handle_single_query(query, cx.clone()).await,
+        Ok(QueryDocument::Single(query)) => handle_single_query(query, read_preference, cx.clone()).await,
         Ok(QueryDocument::Multi(batch)) => match batch.compact() {
-            BatchDocument::Multi(batch, transactional) => handle_batch(batch, transactional, &cx).await,
-            BatchDocument::Compact(compacted) => handle_compacted(compacted, &cx).await,
+            BatchDocument::Multi(batch, transactional) => {
+                handle_batch(batch, transactional, read_preference, &cx).await
+            }
+            BatchDocument::Compact(compacted) => handle_compacted(compacted, read_preference, &cx).await,
         },
         Err(err) => PrismaResponse::Single(err.into()),
     }
 }
 
-async fn handle_single_query(query: Operation, ctx: Arc<PrismaContext>) -> PrismaResponse {
+/// Handle a GraphQL request whose records are streamed in batches instead of being returned
+/// in a single response. Only a single, flat `findMany` query can be streamed.
+pub async fn handle_stream(
+    body: GraphQlBody,
+    read_preference: ReadPreference,
+    cx: Arc<PrismaContext>,
+) -> PrismaResult<ResponseStream> {
+    debug!("Incoming streamed GraphQL query: {:?}", body);
+
+    match body.into_doc()? {
+        QueryDocument::Single(query) => Ok(cx
+            .executor
+            .execute_stream(query, Arc::clone(cx.query_schema()), read_preference)
+            .await?),
+        QueryDocument::Multi(_) => Err(PrismaError::UnsupportedFeatureError(
+            "Streaming batches",
+            "Only a single query can be streamed.".to_owned(),
+        )),
+    }
+}
+
+/// Handle a GraphQL request within an open interactive transaction.
+/// Operations of a batch are executed in order, and every operation gets its own result, even
+/// after an operation failed.
+pub async fn handle_in_transaction(body: GraphQlBody, tx_id: &str, cx: Arc<PrismaContext>) -> PrismaResponse {
+    debug!("Incoming GraphQL query in transaction {}: {:?}", tx_id, body);
+
+    let tx = match cx.transactions().get(tx_id) {
+        Ok(tx) => tx,
+        Err(err) => return PrismaResponse::Single(err.into()),
+    };
+
+    let (operations, is_batch) = match body.into_doc() {
+        Ok(QueryDocument::Single(query)) => (vec![query], false),
+        Ok(QueryDocument::Multi(BatchDocument::Multi(batch, _))) => (batch, true),
+        Ok(QueryDocument::Multi(BatchDocument::Compact(_))) => unreachable!(),
+        Err(err) => return PrismaResponse::Single(err.into()),
+    };
+
+    let tx = tx.lock().await;
+    let tx = match tx.as_ref() {
+        Some(tx) => tx,
+        None => return PrismaResponse::Single(transactions::not_found(tx_id).into()),
+    };
+
+    // Like in non-transactional batches, every operation gets its own result, even after an
+    // operation failed.
+    let mut responses = Vec::with_capacity(operations.len());
+
+    for operation in operations {
+        let result = AssertUnwindSafe(
+            cx.executor
+                .execute_in_transaction(operation, &**tx, cx.query_schema().clone()),
+        )
+        .catch_unwind()
+        .await;
+
+        let gql_response = match result {
+            Ok(Ok(data)) => GQLResponse::from(data),
+            Ok(Err(err)) => GQLResponse::from(err),
+            Err(err) => user_facing_errors::Error::from_panic_payload(&err).into(),
+        };
+
+        responses.push(PrismaResponse::Single(gql_response));
+    }
+
+    if is_batch {
+        PrismaResponse::Multi(responses)
+    } else {
+        responses.pop().unwrap()
+    }
+}
+
+async fn handle_single_query(
+    query: Operation,
+    read_preference: ReadPreference,
+    ctx: Arc<PrismaContext>,
+) -> PrismaResponse {
     use user_facing_errors::Error;
 
-    let gql_response = match AssertUnwindSafe(handle_graphql_query(query, &*ctx))
+    let gql_response = match AssertUnwindSafe(handle_graphql_query(query, read_preference, &*ctx))
         .catch_unwind()
         .await
     {
@@ -107,13 +190,20 @@ async fn handle_single_query(query: Operation, ctx: Arc<PrismaContext>) -> Prism
     PrismaResponse::Single(gql_response)
 }
 
-async fn handle_batch(queries: Vec<Operation>, transactional: bool, ctx: &Arc<PrismaContext>) -> PrismaResponse {
+async fn handle_batch(
+    queries: Vec<Operation>,
+    transactional: bool,
+    read_preference: ReadPreference,
+    ctx: &Arc<PrismaContext>,
+) -> PrismaResponse {
     use user_facing_errors::Error;
 
-    match AssertUnwindSafe(
-        ctx.executor
-            .execute_batch(queries, transactional, ctx.query_schema().clone()),
-    )
+    match AssertUnwindSafe(ctx.executor.execute_batch(
+        queries,
+        transactional,
+        ctx.query_schema().clone(),
+        read_preference,
+    ))
     .catch_unwind()
     .await
     {
@@ -139,7 +229,11 @@ async fn handle_batch(queries: Vec<Operation>, transactional: bool, ctx: &Arc<Pr
     }
 }
 
-async fn handle_compacted(document: CompactedDocument, ctx: &Arc<PrismaContext>) -> PrismaResponse {
+async fn handle_compacted(
+    document: CompactedDocument,
+    read_preference: ReadPreference,
+    ctx: &Arc<PrismaContext>,
+) -> PrismaResponse {
     use user_facing_errors::Error;
 
     let plural_name = document.plural_name();
@@ -148,7 +242,7 @@ async fn handle_compacted(document: CompactedDocument, ctx: &Arc<PrismaContext>)
     let arguments = document.arguments;
     let nested_selection = document.nested_selection;
 
-    match AssertUnwindSafe(handle_graphql_query(document.operation, ctx))
+    match AssertUnwindSafe(handle_graphql_query(document.operation, read_preference, ctx))
         .catch_unwind()
         .await
     {
@@ -206,6 +300,13 @@ async fn handle_compacted(document: CompactedDocument, ctx: &Arc<PrismaContext>)
     }
 }
 
-async fn handle_graphql_query(query_doc: Operation, ctx: &PrismaContext) -> PrismaResult<ResponseData> {
-    Ok(ctx.executor.execute(query_doc, Arc::clone(ctx.query_schema())).await?)
+async fn handle_graphql_query(
+    query_doc: Operation,
+    read_preference: ReadPreference,
+    ctx: &PrismaContext,
+) -> PrismaResult<ResponseData> {
+    Ok(ctx
+        .executor
+        .execute(query_doc, Arc::clone(ctx.query_schema()), read_preference)
+        .await?)
 }
@@ -1,20 +1,39 @@
 use crate::context::PrismaContext;
 use crate::dmmf;
 use crate::opt::PrismaOpt;
-use crate::request_handlers::graphql::{self, GraphQLSchemaRenderer, GraphQlBody};
-use crate::PrismaResult;
+use crate::request_handlers::graphql::{self, GQLResponse, GraphQLSchemaRenderer, GraphQlBody};
+use crate::transactions::DEFAULT_TX_TIMEOUT;
+use crate::{PrismaError, PrismaResult};
 use elapsed_middleware::ElapsedMiddleware;
 
-use query_core::schema::QuerySchemaRenderer;
+use futures::{channel::mpsc, io::BufReader, SinkExt, StreamExt, TryStreamExt};
+use query_core::{schema::QuerySchemaRenderer, ReadPreference, ResponseStream};
+use serde::{Deserialize, Serialize};
 use serde_json::json;
 use tide::http::{mime, StatusCode};
 use tide::{Body, Request, Response};
 use tide_server_timing::TimingMiddleware;
 
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 mod elapsed_middleware;
 
+/// Header that executes a GraphQL request within an open interactive transaction.
+static TRANSACTION_ID_HEADER: &str = "x-transaction-id";
+
+/// Header that executes the reads of a GraphQL request on the primary database instead of the
+/// read replicas, for read-after-write consistency. Its value is `true` or `false`.
+static READ_FROM_PRIMARY_HEADER: &str = "x-read-from-primary";
+
+/// Content type of streamed responses, which contain one JSON document per line.
+static NDJSON_CONTENT_TYPE: &str = "application/x-ndjson";
+
+/// Content type of the Prometheus text exposition format.
+static PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+/// Number of serialized batches that are buffered while the client is reading a streamed response.
+const STREAM_BUFFER_SIZE: usize = 2;
+
 //// Shared application state.
 pub(crate) struct State {
     cx: Arc<PrismaContext>,
@@ -62,9 +81,14 @@ pub async fn listen(opts: PrismaOpt) -> PrismaResult<()> {
 
     app.at("/").post(graphql_handler);
     app.at("/").get(playground_handler);
+    app.at("/stream").post(stream_handler);
     app.at("/sdl").get(sdl_handler);
     app.at("/dmmf").get(dmmf_handler);
     app.at("/server_info").get(server_info_handler);
+    app.at("/transaction/start").post(transaction_start_handler);
+    app.at("/transaction/:id/commit").post(transaction_commit_handler);
+    app.at("/transaction/:id/rollback").post(transaction_rollback_handler);
+    app.at("/metrics").get(metrics_handler);
     app.at("/status").get(|_| async move { Ok(json!({"status": "ok"})) });
 
     // NOTE: This println is essential for the correct working of the client.
@@ -92,12 +116,180 @@ async fn graphql_handler(mut req: Request<State>) -> tide::Result {
 
     let body: GraphQlBody = req.body_json().await?;
     let cx = req.state().cx.clone();
-    let result = graphql::handle(body, cx).await;
+
+    let result = match req.header(TRANSACTION_ID_HEADER) {
+        Some(tx_id) => match interactive_transactions_enabled() {
+            Ok(()) => graphql::handle_in_transaction(body, tx_id.as_str(), cx).await,
+            Err(err) => crate::PrismaResponse::Single(err.into()),
+        },
+        None => graphql::handle(body, read_preference(&req), cx).await,
+    };
+
     let mut res = Response::new(StatusCode::Ok);
     res.set_body(Body::from_json(&result)?);
     Ok(res)
 }
 
+/// Streams the records of a single `findMany` query as newline-delimited JSON, one record per line.
+/// Errors that occur before the first record is sent are returned like regular GraphQL errors,
+/// errors that occur while streaming are written as the last line.
+async fn stream_handler(mut req: Request<State>) -> tide::Result {
+    let body: GraphQlBody = req.body_json().await?;
+    let cx = req.state().cx.clone();
+
+    let stream = match graphql::handle_stream(body, read_preference(&req), cx).await {
+        Ok(stream) => stream,
+        Err(err) => {
+            let mut res = Response::new(StatusCode::Ok);
+            res.set_body(Body::from_json(&GQLResponse::from(err))?);
+            return Ok(res);
+        }
+    };
+
+    // The channel is bounded, so batches are only fetched as fast as the client reads them.
+    let (sender, receiver) = mpsc::channel(STREAM_BUFFER_SIZE);
+    async_std::task::spawn(write_ndjson(stream, sender));
+
+    let reader = BufReader::new(receiver.map(Ok::<_, std::io::Error>).into_async_read());
+    let mut res = Response::new(StatusCode::Ok);
+    res.set_body(Body::from_reader(reader, None));
+    res.set_content_type(NDJSON_CONTENT_TYPE);
+    Ok(res)
+}
+
+/// Serializes every batch of the stream into JSON lines and sends them, until either the stream
+/// ends, an error occurs, or the client disconnects.
+async fn write_ndjson(mut stream: ResponseStream, mut sender: mpsc::Sender<Vec<u8>>) {
+    while let Some(batch) = stream.next().await {
+        let mut chunk = Vec::new();
+
+        let failed = match batch {
+            Ok(items) => {
+                for item in items {
+                    if let Err(err) = write_json_line(&mut chunk, &item) {
+                        error!("Error serializing streamed record: {}", err);
+                        return;
+                    }
+                }
+
+                false
+            }
+            Err(err) => {
+                let _ = write_json_line(&mut chunk, &GQLResponse::from(err));
+                true
+            }
+        };
+
+        if sender.send(chunk).await.is_err() || failed {
+            return;
+        }
+    }
+}
+
+fn write_json_line<T: Serialize>(buf: &mut Vec<u8>, value: &T) -> serde_json::Result<()> {
+    serde_json::to_writer(&mut *buf, value)?;
+    buf.push(b'\n');
+    Ok(())
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TransactionStartBody {
+    /// Time in milliseconds after which the transaction is rolled back.
+    timeout: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+struct TransactionStartResponse {
+    id: String,
+}
+
+/// Starts an interactive transaction and returns its ID. GraphQL requests are executed within the
+/// transaction by passing the ID in the `x-transaction-id` header, until the transaction is committed,
+/// rolled back, or it expires.
+async fn transaction_start_handler(mut req: Request<State>) -> tide::Result {
+    let body = req.body_string().await?;
+    let body: TransactionStartBody = if body.trim().is_empty() {
+        TransactionStartBody::default()
+    } else {
+        serde_json::from_str(&body)?
+    };
+
+    let timeout = body.timeout.map(Duration::from_millis).unwrap_or(DEFAULT_TX_TIMEOUT);
+    let cx = req.state().cx.clone();
+
+    let result = match interactive_transactions_enabled() {
+        Ok(()) => cx.start_transaction(timeout).await,
+        Err(err) => Err(err),
+    };
+
+    transaction_response(result.map(|id| TransactionStartResponse { id }))
+}
+
+async fn transaction_commit_handler(req: Request<State>) -> tide::Result {
+    let id: String = req.param("id")?;
+    let cx = req.state().cx.clone();
+
+    let result = match interactive_transactions_enabled() {
+        Ok(()) => cx.commit_transaction(&id).await,
+        Err(err) => Err(err),
+    };
+
+    transaction_response(result.map(|_| json!({})))
+}
+
+async fn transaction_rollback_handler(req: Request<State>) -> tide::Result {
+    let id: String = req.param("id")?;
+    let cx = req.state().cx.clone();
+
+    let result = match interactive_transactions_enabled() {
+        Ok(()) => cx.rollback_transaction(&id).await,
+        Err(err) => Err(err),
+    };
+
+    transaction_response(result.map(|_| json!({})))
+}
+
+fn read_preference(req: &Request<State>) -> ReadPreference {
+    parse_read_preference(
+        req.header(READ_FROM_PRIMARY_HEADER)
+            .map(|values| values.last().as_str()),
+    )
+}
+
+/// Reads are only spread across the replicas when the header is absent or false. Values that are
+/// neither true nor false pin the reads to the primary, which is consistent in any case.
+fn parse_read_preference(read_from_primary: Option<&str>) -> ReadPreference {
+    match read_from_primary.map(str::trim) {
+        None => ReadPreference::Replica,
+        Some(value) if value.eq_ignore_ascii_case("false") || value == "0" => ReadPreference::Replica,
+        Some(_) => ReadPreference::Primary,
+    }
+}
+
+fn interactive_transactions_enabled() -> PrismaResult<()> {
+    if feature_flags::get().interactiveTransactions {
+        Ok(())
+    } else {
+        Err(PrismaError::UnsupportedFeatureError(
+            "Interactive transactions",
+            "Experimental feature, needs to be enabled.".to_owned(),
+        ))
+    }
+}
+
+/// Renders the result of a transaction endpoint. Errors are rendered like GraphQL errors.
+fn transaction_response<T: Serialize>(result: PrismaResult<T>) -> tide::Result {
+    let (status, body) = match result {
+        Ok(data) => (StatusCode::Ok, Body::from_json(&data)?),
+        Err(err) => (StatusCode::BadRequest, Body::from_json(&GQLResponse::from(err))?),
+    };
+
+    let mut res = Response::new(status);
+    res.set_body(body);
+    Ok(res)
+}
+
 /// Expose the GraphQL playground if enabled.
 ///
 /// # Security
@@ -140,6 +332,15 @@ async fn server_info_handler(req: Request<State>) -> tide::Result<impl Into<Resp
     }))
 }
 
+/// Renders the operational metrics of the engine in the Prometheus text format: operations by
+/// model, action and outcome, query graph and SQL query durations, pool connections and transactions.
+async fn metrics_handler(_: Request<State>) -> tide::Result {
+    let mut res = Response::new(StatusCode::Ok);
+    res.set_body(connector::metrics::render());
+    res.set_content_type(PROMETHEUS_CONTENT_TYPE);
+    Ok(res)
+}
+
 /// Handle debug headers inside the main GraphQL endpoint.
 async fn handle_debug_headers(req: &Request<State>) -> tide::Result<Option<impl Into<Response>>> {
     /// Debug header that triggers a panic in the request thread.
@@ -160,3 +361,19 @@ async fn handle_debug_headers(req: &Request<State>) -> tide::Result<Option<impl
         Ok(None)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_read_from_primary_header_is_parsed() {
+        assert_eq!(parse_read_preference(None), ReadPreference::Replica);
+        assert_eq!(parse_read_preference(Some("false")), ReadPreference::Replica);
+        assert_eq!(parse_read_preference(Some("FALSE")), ReadPreference::Replica);
+        assert_eq!(parse_read_preference(Some("0")), ReadPreference::Replica);
+        assert_eq!(parse_read_preference(Some("true")), ReadPreference::Primary);
+        assert_eq!(parse_read_preference(Some("1")), ReadPreference::Primary);
+        assert_eq!(parse_read_preference(Some("")), ReadPreference::Primary);
+    }
+}
@@ -1,5 +1,10 @@
+mod datasources;
 mod decimal;
 mod dmmf;
 mod execute_raw;
+mod interactive_transactions;
+mod metrics;
+mod streaming;
 mod test_api;
 mod type_mappings;
+mod views;
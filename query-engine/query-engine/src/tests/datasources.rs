@@ -0,0 +1,121 @@
+use super::test_api::*;
+use indoc::indoc;
+use migration_core::{
+    api::{GenericApi, MigrationApi},
+    commands::SchemaPushInput,
+};
+use test_macros::test_each_connector_mssql as test_each_connector;
+use test_setup::server_root;
+
+static USER: &str = indoc! {r#"
+    model User {
+        id   Int    @id
+        name String
+    }
+"#};
+
+static EVENT: &str = indoc! {r#"
+    model Event {
+        id     Int    @id
+        kind   String
+        userId Int
+    }
+"#};
+
+/// Both models, with `Event` in the `analytics` datasource and a relation between them.
+static USER_AND_EVENT: &str = indoc! {r#"
+    model User {
+        id     Int     @id
+        name   String
+        events Event[]
+    }
+
+    model Event {
+        id     Int    @id
+        kind   String
+        userId Int
+        user   User   @relation(fields: [userId], references: [id])
+
+        @@datasource(analytics)
+    }
+"#};
+
+/// Creates an engine over the datasource of the test and a second SQLite database named
+/// `analytics_db_name` as the `analytics` datasource. Migrations only support one datasource, so
+/// each database gets its own tables pushed separately.
+async fn create_engine(api: &TestApi, analytics_db_name: &str) -> anyhow::Result<QueryEngine> {
+    api.push_schema(USER).await?;
+
+    let analytics_api = MigrationApi::new(sqlite_migration_connector(analytics_db_name).await).await?;
+    let analytics_config = format!(
+        r#"
+        datasource analytics {{
+            provider = "sqlite"
+            url = "file:{}/db/{}.db"
+        }}
+    "#,
+        server_root(),
+        analytics_db_name
+    );
+
+    analytics_api
+        .schema_push(&SchemaPushInput {
+            schema: format!("{}\n\n{}", analytics_config, EVENT),
+            force: true,
+            assume_empty: true,
+        })
+        .await?;
+
+    api.create_engine_without_push(&analytics_config, USER_AND_EVENT).await
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn operations_are_dispatched_to_the_datasource_of_their_model(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = create_engine(api, "operations_are_dispatched_analytics").await?;
+
+    // The tables only exist in the database of their datasource, so misrouted writes fail.
+    query_engine
+        .request(r#"mutation { createOneUser(data: { id: 1, name: "Ada" }) { id } }"#)
+        .await;
+
+    query_engine
+        .request(r#"mutation { createOneEvent(data: { id: 1, kind: "click", user: { connect: { id: 1 } } }) { id } }"#)
+        .await;
+
+    assert_eq!(
+        serde_json::json!({ "data": { "findManyUser": [{ "name": "Ada", "events": [{ "kind": "click" }] }] } }),
+        query_engine
+            .request("query { findManyUser { name events { kind } } }")
+            .await
+    );
+
+    assert_eq!(
+        serde_json::json!({ "data": { "findManyEvent": [{ "kind": "click", "user": { "name": "Ada" } }] } }),
+        query_engine
+            .request("query { findManyEvent { kind user { name } } }")
+            .await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn relation_filters_across_datasources_are_rejected(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = create_engine(api, "relation_filters_across_datasources_analytics").await?;
+
+    let response = query_engine
+        .request(r#"query { findManyUser(where: { events: { some: { kind: "click" } } }) { id } }"#)
+        .await;
+
+    assert!(response["data"].is_null(), "{}", response);
+    assert!(
+        response["errors"][0]["user_facing_error"]["message"]
+            .as_str()
+            .unwrap()
+            .contains("Field does not exist on enclosing type."),
+        "{}",
+        response
+    );
+
+    Ok(())
+}
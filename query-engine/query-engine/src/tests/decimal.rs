@@ -1,4 +1,5 @@
 use super::test_api::*;
+use datamodel::dml::{FieldType, ScalarType};
 use indoc::indoc;
 use serde_json::json;
 use test_macros::test_each_connector_mssql as test_each_connector;
@@ -34,3 +35,30 @@ async fn decimal_conversion(api: &TestApi) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test_each_connector(ignore("mssql"))]
+async fn decimal_and_float_fields_survive_a_migration_and_introspection(api: &TestApi) -> anyhow::Result<()> {
+    let dm = indoc! {"
+        model Transaction {
+            id      Int     @id
+            amount  Decimal
+            ratio   Float
+        }
+    "};
+
+    api.push_schema(dm).await?;
+
+    let introspected = api.introspect().await?;
+    let model = introspected.find_model("Transaction").unwrap();
+
+    assert_eq!(
+        model.find_scalar_field("amount").unwrap().field_type,
+        FieldType::Base(ScalarType::Decimal, None)
+    );
+    assert_eq!(
+        model.find_scalar_field("ratio").unwrap().field_type,
+        FieldType::Base(ScalarType::Float, None)
+    );
+
+    Ok(())
+}
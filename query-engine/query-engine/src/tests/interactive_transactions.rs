@@ -0,0 +1,117 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use std::time::Duration;
+use test_macros::test_each_connector_mssql as test_each_connector;
+
+static TODO: &str = indoc! {"
+    model Todo {
+        id    Int    @id
+        title String
+    }
+"};
+
+static CREATE_TODO: &str = r#"mutation { createOneTodo(data: { id: 1, title: "first" }) { id } }"#;
+static FIND_TODOS: &str = "query { findManyTodo { id } }";
+
+#[test_each_connector]
+async fn committed_transactions_persist_writes(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&TODO).await?;
+    let tx_id = query_engine
+        .context()
+        .start_transaction(Duration::from_secs(10))
+        .await?;
+
+    assert_eq!(
+        json!({ "data": { "createOneTodo": { "id": 1 } } }),
+        query_engine.request_in_transaction(&tx_id, CREATE_TODO).await
+    );
+
+    assert_eq!(
+        json!({ "data": { "findManyTodo": [{ "id": 1 }] } }),
+        query_engine.request_in_transaction(&tx_id, FIND_TODOS).await
+    );
+
+    query_engine.context().commit_transaction(&tx_id).await?;
+
+    assert_eq!(
+        json!({ "data": { "findManyTodo": [{ "id": 1 }] } }),
+        query_engine.request(FIND_TODOS).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn rolled_back_transactions_discard_writes(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&TODO).await?;
+    let tx_id = query_engine
+        .context()
+        .start_transaction(Duration::from_secs(10))
+        .await?;
+
+    query_engine.request_in_transaction(&tx_id, CREATE_TODO).await;
+    query_engine.context().rollback_transaction(&tx_id).await?;
+
+    assert_eq!(
+        json!({ "data": { "findManyTodo": [] } }),
+        query_engine.request(FIND_TODOS).await
+    );
+
+    // The transaction is gone after it has been finished.
+    assert!(query_engine.context().commit_transaction(&tx_id).await.is_err());
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn expired_transactions_are_rolled_back(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&TODO).await?;
+    let tx_id = query_engine
+        .context()
+        .start_transaction(Duration::from_millis(200))
+        .await?;
+
+    query_engine.request_in_transaction(&tx_id, CREATE_TODO).await;
+    async_std::task::sleep(Duration::from_millis(1000)).await;
+
+    let response = query_engine.request_in_transaction(&tx_id, FIND_TODOS).await;
+    assert_eq!(response["errors"][0]["user_facing_error"]["error_code"], json!("P2023"));
+
+    assert_eq!(
+        json!({ "data": { "findManyTodo": [] } }),
+        query_engine.request(FIND_TODOS).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn batches_in_transactions_return_one_result_per_operation(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&TODO).await?;
+    let tx_id = query_engine
+        .context()
+        .start_transaction(Duration::from_secs(10))
+        .await?;
+
+    let response = query_engine
+        .request_batch_in_transaction(&tx_id, &[CREATE_TODO, CREATE_TODO, FIND_TODOS])
+        .await;
+
+    let results = response.as_array().unwrap();
+
+    assert_eq!(3, results.len());
+    assert_eq!(json!({ "createOneTodo": { "id": 1 } }), results[0]["data"]);
+    assert_eq!(
+        json!("P2002"),
+        results[1]["errors"][0]["user_facing_error"]["error_code"]
+    );
+
+    query_engine.context().rollback_transaction(&tx_id).await?;
+
+    Ok(())
+}
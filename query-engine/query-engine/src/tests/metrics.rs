@@ -0,0 +1,82 @@
+use super::test_api::*;
+use indoc::indoc;
+use test_macros::test_each_connector_mssql as test_each_connector;
+
+static MODEL: &str = indoc! {"
+    model MetricsCat {
+        id   Int    @id
+        name String
+    }
+"};
+
+#[test_each_connector]
+async fn executed_operations_are_rendered_as_prometheus_metrics(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&MODEL).await?;
+
+    query_engine
+        .request(r#"mutation { createOneMetricsCat(data: { id: 1, name: "Felix" }) { id } }"#)
+        .await;
+    query_engine.request("query { findManyMetricsCat { id name } }").await;
+    query_engine
+        .request(r#"mutation { createOneMetricsCat(data: { id: 1, name: "Felix" }) { id } }"#)
+        .await;
+
+    let metrics = connector::metrics::render();
+
+    for series in &[
+        r#"prisma_operations_total{model="MetricsCat",action="createOne",outcome="success"}"#,
+        r#"prisma_operations_total{model="MetricsCat",action="createOne",outcome="error"}"#,
+        r#"prisma_operations_total{model="MetricsCat",action="findMany",outcome="success"}"#,
+        r#"prisma_query_graph_duration_seconds_count{model="MetricsCat",action="findMany"}"#,
+        "prisma_sql_query_duration_seconds_count{connector=",
+        "prisma_pool_connections_active{connector=",
+        "prisma_pool_connections_available{connector=",
+        "prisma_transactions_started_total{connector=",
+        "prisma_transactions_committed_total{connector=",
+        "prisma_transactions_rolled_back_total{connector=",
+    ] {
+        assert!(metrics.contains(series), "`{}` is missing from:\n{}", series, metrics);
+    }
+
+    // The pool gauges are labeled with the datasource of the schema and its pool.
+    for gauge in &["prisma_pool_connections_active{", "prisma_pool_connections_available{"] {
+        assert!(
+            metrics
+                .lines()
+                .any(|line| line.starts_with(gauge) && line.contains(r#",datasource="my_db",pool="primary"}"#)),
+            "`{}` has no series of the primary pool in:\n{}",
+            gauge,
+            metrics
+        );
+    }
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn transactions_dropped_without_being_finished_are_counted_as_rolled_back(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&MODEL).await?;
+    let primary = query_engine.context().executor.primary_connector();
+    let rolled_back_before = rolled_back_transactions();
+
+    let conn = primary.get_connection().await?;
+    drop(conn.start_transaction().await?);
+
+    let conn = primary.get_connection().await?;
+    drop(conn.start_owned_transaction().await?);
+
+    // Other tests can roll back transactions concurrently, so the count can only be bounded from below.
+    assert!(rolled_back_transactions() >= rolled_back_before + 2);
+
+    Ok(())
+}
+
+/// The transactions rolled back so far, over all connectors.
+fn rolled_back_transactions() -> u64 {
+    connector::metrics::render()
+        .lines()
+        .filter(|line| line.starts_with("prisma_transactions_rolled_back_total{"))
+        .filter_map(|line| line.rsplit(' ').next())
+        .map(|count| count.parse::<u64>().unwrap())
+        .sum()
+}
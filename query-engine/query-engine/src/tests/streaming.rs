@@ -0,0 +1,110 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector_mssql as test_each_connector;
+
+static MODEL: &str = indoc! {"
+    model User {
+        id    Int    @id
+        name  String
+        posts Post[]
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id])
+    }
+"};
+
+#[test_each_connector]
+async fn streamed_find_many_returns_all_records_in_order(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&MODEL).await?;
+
+    for (id, name) in &[(1, "c"), (2, "a"), (3, "b")] {
+        let query = format!(
+            r#"mutation {{ createOneUser(data: {{ id: {}, name: "{}" }}) {{ id }} }}"#,
+            id, name
+        );
+        query_engine.request(query).await;
+    }
+
+    assert_eq!(
+        vec![
+            json!({ "id": 2, "name": "a" }),
+            json!({ "id": 3, "name": "b" }),
+            json!({ "id": 1, "name": "c" }),
+        ],
+        query_engine
+            .request_stream("query { findManyUser(orderBy: { name: asc }) { id name } }")
+            .await?
+    );
+
+    assert_eq!(
+        vec![json!({ "id": 3 }), json!({ "id": 2 })],
+        query_engine
+            .request_stream("query { findManyUser(orderBy: { id: desc }, take: 2) { id } }")
+            .await?
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn streamed_find_many_pages_through_batches(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine_with_stream_batch_size(&MODEL, 2).await?;
+
+    for (id, name) in &[(1, "b"), (2, "a"), (3, "b"), (4, "a"), (5, "c"), (6, "b"), (7, "a")] {
+        let query = format!(
+            r#"mutation {{ createOneUser(data: {{ id: {}, name: "{}" }}) {{ id }} }}"#,
+            id, name
+        );
+        query_engine.request(query).await;
+    }
+
+    let ids = |records: Vec<serde_json::Value>| -> Vec<serde_json::Value> {
+        records.into_iter().map(|record| record["id"].clone()).collect()
+    };
+
+    // Records with the same name are ordered by id, also across batch boundaries.
+    let batches = query_engine
+        .request_stream_batches("query { findManyUser(orderBy: { name: asc }) { id name } }")
+        .await?;
+
+    assert_eq!(vec![2, 2, 2, 1], batches.iter().map(Vec::len).collect::<Vec<_>>());
+    assert_eq!(
+        vec![json!(2), json!(4), json!(7), json!(1), json!(3), json!(6), json!(5)],
+        ids(batches.into_iter().flatten().collect())
+    );
+
+    assert_eq!(
+        vec![json!(5), json!(1), json!(3), json!(6), json!(2), json!(4), json!(7)],
+        ids(query_engine
+            .request_stream("query { findManyUser(orderBy: { name: desc }) { id } }")
+            .await?)
+    );
+
+    assert_eq!(
+        vec![json!(2), json!(4), json!(7), json!(1), json!(3)],
+        ids(query_engine
+            .request_stream("query { findManyUser(orderBy: { name: asc }, take: 5) { id } }")
+            .await?)
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn streamed_find_many_rejects_relation_selections(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&MODEL).await?;
+    let result = query_engine
+        .request_stream("query { findManyUser { id posts { id } } }")
+        .await;
+
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .contains("Streamed queries can not select relations."));
+
+    Ok(())
+}
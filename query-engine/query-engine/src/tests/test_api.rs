@@ -1,8 +1,9 @@
 use crate::{
-    context::PrismaContext,
+    context::{ContextBuilder, PrismaContext},
     request_handlers::{graphql, GraphQlBody, SingleQuery},
     PrismaResponse,
 };
+use futures::TryStreamExt;
 use migration_core::{
     api::{GenericApi, MigrationApi},
     commands::SchemaPushInput,
@@ -12,6 +13,7 @@ use quaint::{
     connector::ConnectionInfo,
     visitor::{self, Visitor},
 };
+use query_core::ReadPreference;
 use sql_migration_connector::{sql_migration::SqlMigration, SqlMigrationConnector};
 use std::sync::Arc;
 use test_setup::*;
@@ -28,11 +30,66 @@ impl QueryEngine {
     pub async fn request(&self, body: impl Into<SingleQuery>) -> serde_json::Value {
         let body = GraphQlBody::Single(body.into());
         let cx = self.context.clone();
-        match graphql::handle(body, cx).await {
+        match graphql::handle(body, ReadPreference::default(), cx).await {
             PrismaResponse::Single(response) => serde_json::to_value(response).unwrap(),
             _ => unreachable!(),
         }
     }
+
+    pub async fn request_in_transaction(&self, tx_id: &str, body: impl Into<SingleQuery>) -> serde_json::Value {
+        let body = GraphQlBody::Single(body.into());
+        let cx = self.context.clone();
+        match graphql::handle_in_transaction(body, tx_id, cx).await {
+            PrismaResponse::Single(response) => serde_json::to_value(response).unwrap(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Executes a non-transactional batch of queries within an open interactive transaction.
+    pub async fn request_batch_in_transaction(&self, tx_id: &str, queries: &[&str]) -> serde_json::Value {
+        let batch: Vec<_> = queries
+            .iter()
+            .map(|query| serde_json::json!({ "query": query, "variables": {} }))
+            .collect();
+
+        let body = GraphQlBody::Multi(
+            serde_json::from_value(serde_json::json!({ "batch": batch, "transaction": false })).unwrap(),
+        );
+        let cx = self.context.clone();
+        serde_json::to_value(graphql::handle_in_transaction(body, tx_id, cx).await).unwrap()
+    }
+
+    /// Executes a streamed query and collects the serialized records of all batches.
+    pub async fn request_stream(&self, body: impl Into<SingleQuery>) -> crate::PrismaResult<Vec<serde_json::Value>> {
+        Ok(self.request_stream_batches(body).await?.into_iter().flatten().collect())
+    }
+
+    /// Executes a streamed query and collects the serialized records, batch by batch.
+    pub async fn request_stream_batches(
+        &self,
+        body: impl Into<SingleQuery>,
+    ) -> crate::PrismaResult<Vec<Vec<serde_json::Value>>> {
+        let body = GraphQlBody::Single(body.into());
+        let cx = self.context.clone();
+        let batches: Vec<_> = graphql::handle_stream(body, ReadPreference::default(), cx)
+            .await?
+            .try_collect()
+            .await?;
+
+        Ok(batches
+            .into_iter()
+            .map(|batch| {
+                batch
+                    .into_iter()
+                    .map(|item| serde_json::to_value(item).unwrap())
+                    .collect()
+            })
+            .collect())
+    }
+
+    pub fn context(&self) -> &PrismaContext {
+        &self.context
+    }
 }
 
 pub struct TestApi {
@@ -43,20 +100,49 @@ pub struct TestApi {
 
 impl TestApi {
     pub async fn create_engine(&self, datamodel: &str) -> anyhow::Result<QueryEngine> {
-        let datamodel_string = format!("{}\n\n{}", self.config, datamodel);
-        let dml = datamodel::parse_datamodel(&datamodel_string).unwrap().subject;
-        let config = datamodel::parse_configuration(&datamodel_string).unwrap();
+        self.push_schema(datamodel).await?;
+        self.create_engine_without_push("", datamodel).await
+    }
 
+    /// Pushes the datamodel to the database of the test, without creating an engine.
+    pub async fn push_schema(&self, datamodel: &str) -> anyhow::Result<()> {
         self.migration_api
             .schema_push(&SchemaPushInput {
-                schema: datamodel_string,
+                schema: format!("{}\n\n{}", self.config, datamodel),
                 force: true,
                 assume_empty: true,
             })
             .await?;
 
-        let context = PrismaContext::builder(config.subject, dml)
-            .enable_raw_queries(true)
+        Ok(())
+    }
+
+    /// Creates an engine for the datamodel without migrating the database. The datasources in
+    /// `additional_config` come after the datasource of the test.
+    pub async fn create_engine_without_push(
+        &self,
+        additional_config: &str,
+        datamodel: &str,
+    ) -> anyhow::Result<QueryEngine> {
+        let datamodel_string = format!("{}\n\n{}\n\n{}", self.config, additional_config, datamodel);
+        let context = context_builder(&datamodel_string).build().await.unwrap();
+
+        Ok(QueryEngine {
+            context: Arc::new(context),
+        })
+    }
+
+    /// Creates an engine for the datamodel that streams records in batches of `stream_batch_size`.
+    pub async fn create_engine_with_stream_batch_size(
+        &self,
+        datamodel: &str,
+        stream_batch_size: usize,
+    ) -> anyhow::Result<QueryEngine> {
+        self.push_schema(datamodel).await?;
+
+        let datamodel_string = format!("{}\n\n{}", self.config, datamodel);
+        let context = context_builder(&datamodel_string)
+            .stream_batch_size(stream_batch_size)
             .build()
             .await
             .unwrap();
@@ -66,6 +152,17 @@ impl TestApi {
         })
     }
 
+    /// Introspects the database of the test.
+    pub async fn introspect(&self) -> anyhow::Result<datamodel::Datamodel> {
+        let introspection_result = introspection_core::RpcImpl::introspect_internal(self.config.clone(), false)
+            .await
+            .map_err(|err| anyhow::anyhow!("{:?}", err.data))?;
+
+        Ok(datamodel::parse_datamodel(&introspection_result.datamodel)
+            .unwrap()
+            .subject)
+    }
+
     pub fn connection_info(&self) -> &ConnectionInfo {
         &self.connection_info
     }
@@ -284,6 +381,13 @@ pub async fn mssql_2019_test_api(args: TestAPIArgs) -> TestApi {
     }
 }
 
+fn context_builder(datamodel_string: &str) -> ContextBuilder {
+    let dml = datamodel::parse_datamodel(datamodel_string).unwrap().subject;
+    let config = datamodel::parse_configuration(datamodel_string).unwrap();
+
+    PrismaContext::builder(config.subject, dml).enable_raw_queries(true)
+}
+
 pub(super) async fn mysql_migration_connector(url_str: &str) -> SqlMigrationConnector {
     create_mysql_database(&url_str.parse().unwrap()).await.unwrap();
     SqlMigrationConnector::new(url_str).await.unwrap()
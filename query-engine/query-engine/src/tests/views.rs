@@ -0,0 +1,76 @@
+use super::test_api::*;
+use indoc::indoc;
+use quaint::ast::*;
+use serde_json::json;
+use test_macros::test_each_connector_mssql as test_each_connector;
+
+static TODO_WITH_VIEW: &str = indoc! {r#"
+    model Todo {
+        id    Int    @id
+        title String
+    }
+
+    model TodoTitle {
+        title String
+
+        @@view
+        @@map("todo_titles")
+    }
+"#};
+
+/// Creates the view backing the `TodoTitle` model, which is not managed by migrations.
+async fn create_view(api: &TestApi, query_engine: &QueryEngine) -> anyhow::Result<()> {
+    let (select, _) = api.to_sql_string(Select::from_table("Todo").column("title"))?;
+    let create_view = format!("CREATE VIEW todo_titles AS {}", select);
+
+    let mutation = format!(
+        r#"mutation {{ executeRaw(query: "{}", parameters: "[]") }}"#,
+        create_view.replace("\"", "\\\"")
+    );
+
+    query_engine.request(mutation).await;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn views_can_be_queried(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO_WITH_VIEW).await?;
+    create_view(api, &query_engine).await?;
+
+    query_engine
+        .request(r#"mutation { createOneTodo(data: { id: 1, title: "first" }) { id } }"#)
+        .await;
+
+    assert_eq!(
+        json!({ "data": { "findManyTodoTitle": [{ "title": "first" }] } }),
+        query_engine.request("query { findManyTodoTitle { title } }").await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn views_are_read_only(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO_WITH_VIEW).await?;
+    create_view(api, &query_engine).await?;
+
+    let response = query_engine
+        .request(r#"mutation { createOneTodoTitle(data: { title: "first" }) { title } }"#)
+        .await;
+
+    let error = &response["errors"][0]["user_facing_error"];
+
+    // View-backed models have no mutations, so `createOneTodoTitle` is an unknown field.
+    assert_eq!(error["error_code"], json!("P2009"));
+    assert_eq!(
+        error["meta"]["query_validation_error"],
+        json!("Field does not exist on enclosing type.")
+    );
+    assert!(error["meta"]["query_position"]
+        .as_str()
+        .unwrap()
+        .ends_with("createOneTodoTitle"));
+
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use crate::{PrismaError, PrismaResult};
+use connector::Transaction;
+use futures::lock::Mutex as AsyncMutex;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// Identifier of an open interactive transaction, handed out to the client on start.
+pub type TxId = String;
+
+/// An open interactive transaction. Requests against the same transaction are serialized.
+/// `None` once the transaction is committed or rolled back, for the requests that were waiting on it.
+pub type OpenTransaction = Arc<AsyncMutex<Option<Box<dyn Transaction>>>>;
+
+/// Time after which an interactive transaction is rolled back if the client didn't set a timeout.
+pub const DEFAULT_TX_TIMEOUT: Duration = Duration::from_millis(5000);
+
+/// Registry of all interactive transactions that are currently open.
+///
+/// Every transaction is rolled back automatically once its timeout expires,
+/// unless it has been committed or rolled back by the client before. Transactions are only
+/// removed from the registry once they are finished, so that a transaction whose commit failed
+/// can still be rolled back by the client.
+#[derive(Clone, Default)]
+pub struct TransactionRegistry {
+    transactions: Arc<Mutex<HashMap<TxId, OpenTransaction>>>,
+}
+
+impl TransactionRegistry {
+    /// Registers an open transaction and schedules its rollback after `timeout`.
+    pub fn insert(&self, tx: Box<dyn Transaction>, timeout: Duration) -> TxId {
+        let id = uuid::Uuid::new_v4().to_string();
+
+        self.transactions
+            .lock()
+            .unwrap()
+            .insert(id.clone(), Arc::new(AsyncMutex::new(Some(tx))));
+
+        let registry = self.clone();
+        let expiring_id = id.clone();
+
+        async_std::task::spawn(async move {
+            async_std::task::sleep(timeout).await;
+
+            if let Ok(tx) = registry.get(&expiring_id) {
+                let mut tx = tx.lock().await;
+
+                // A transaction that fails to roll back is rolled back again when it is dropped.
+                if let Some(open_tx) = tx.take() {
+                    info!("Interactive transaction {} expired, rolling back.", expiring_id);
+
+                    if let Err(err) = open_tx.rollback().await {
+                        error!("Error rolling back expired transaction {}: {}", expiring_id, err);
+                    }
+                }
+
+                registry.remove(&expiring_id);
+            }
+        });
+
+        id
+    }
+
+    /// Returns the open transaction with the given ID.
+    pub fn get(&self, id: &str) -> PrismaResult<OpenTransaction> {
+        self.transactions
+            .lock()
+            .unwrap()
+            .get(id)
+            .cloned()
+            .ok_or_else(|| not_found(id))
+    }
+
+    /// Commits the transaction with the given ID. It stays registered if the commit fails.
+    pub async fn commit(&self, id: &str) -> PrismaResult<()> {
+        let tx = self.get(id)?;
+        let mut tx = tx.lock().await;
+
+        tx.as_ref().ok_or_else(|| not_found(id))?.commit().await?;
+        tx.take();
+        self.remove(id);
+
+        Ok(())
+    }
+
+    /// Rolls back the transaction with the given ID. It stays registered if the rollback fails.
+    pub async fn rollback(&self, id: &str) -> PrismaResult<()> {
+        let tx = self.get(id)?;
+        let mut tx = tx.lock().await;
+
+        tx.as_ref().ok_or_else(|| not_found(id))?.rollback().await?;
+        tx.take();
+        self.remove(id);
+
+        Ok(())
+    }
+
+    fn remove(&self, id: &str) -> Option<OpenTransaction> {
+        self.transactions.lock().unwrap().remove(id)
+    }
+}
+
+pub(crate) fn not_found(id: &str) -> PrismaError {
+    PrismaError::InteractiveTransactionError(format!(
+        "Transaction {} not found. It has either been committed, rolled back or it expired.",
+        id
+    ))
+}